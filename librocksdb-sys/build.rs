@@ -285,6 +285,29 @@ fn build_rocksdb() {
     for file in lib_sources {
         config.file(format!("rocksdb/{file}"));
     }
+    config.file("c_api_extensions/backup.cc");
+    config.file("c_api_extensions/checkpoint.cc");
+    config.file("c_api_extensions/compaction_filter.cc");
+    config.file("c_api_extensions/compaction_options.cc");
+    config.file("c_api_extensions/db.cc");
+    config.file("c_api_extensions/encryption.cc");
+    config.file("c_api_extensions/event_listener.cc");
+    config.file("c_api_extensions/file_system.cc");
+    config.file("c_api_extensions/logger.cc");
+    config.file("c_api_extensions/merge_operator.cc");
+    config.file("c_api_extensions/metadata.cc");
+    config.file("c_api_extensions/perf.cc");
+    config.file("c_api_extensions/rate_limiter.cc");
+    config.file("c_api_extensions/sst_file_manager.cc");
+    config.file("c_api_extensions/sst_file_writer.cc");
+    config.file("c_api_extensions/statistics.cc");
+    config.file("c_api_extensions/status.cc");
+    config.file("c_api_extensions/system_clock.cc");
+    config.file("c_api_extensions/table_properties.cc");
+    config.file("c_api_extensions/thread_status.cc");
+    config.file("c_api_extensions/transaction.cc");
+    config.file("c_api_extensions/write_batch.cc");
+    config.file("c_api_extensions/wide_columns.cc");
     config.file("c_api_extensions/write_buffer_manager.cc");
     config.file("build_version.cc");
 
@@ -0,0 +1,48 @@
+// Implementation of `BackupEngine` functions in `c.h`.
+#include "c_api_extensions/ctypes.hpp"
+
+using namespace ROCKSDB_NAMESPACE;
+
+extern "C" {
+rocksdb_backup_engine_t* rocksdb_backup_engine_open_opts_with_status(const rocksdb_backup_engine_options_t* options,
+                                                                     rocksdb_env_t* env,
+                                                                     rocksdb_status_t** statusptr) {
+  BackupEngine* be;
+  if (SaveStatus(statusptr, BackupEngine::Open(options->rep, env->rep, &be))) {
+    return nullptr;
+  }
+  return new rocksdb_backup_engine_t{be};
+}
+
+void rocksdb_backup_engine_create_new_backup_flush_with_status(rocksdb_backup_engine_t* be, rocksdb_t* db,
+                                                               unsigned char flush_before_backup,
+                                                               rocksdb_status_t** statusptr) {
+  SaveStatus(statusptr, be->rep->CreateNewBackup(db->rep, flush_before_backup));
+}
+
+void rocksdb_backup_engine_purge_old_backups_with_status(rocksdb_backup_engine_t* be, uint32_t num_backups_to_keep,
+                                                         rocksdb_status_t** statusptr) {
+  SaveStatus(statusptr, be->rep->PurgeOldBackups(num_backups_to_keep));
+}
+
+void rocksdb_backup_engine_restore_db_from_backup_with_status(rocksdb_backup_engine_t* be, const char* db_dir,
+                                                              const char* wal_dir,
+                                                              const rocksdb_restore_options_t* restore_options,
+                                                              uint32_t backup_id, rocksdb_status_t** statusptr) {
+  SaveStatus(statusptr, be->rep->RestoreDBFromBackup(backup_id, std::string(db_dir), std::string(wal_dir),
+                                                     restore_options->rep));
+}
+
+void rocksdb_backup_engine_restore_db_from_latest_backup_with_status(rocksdb_backup_engine_t* be, const char* db_dir,
+                                                                     const char* wal_dir,
+                                                                     const rocksdb_restore_options_t* restore_options,
+                                                                     rocksdb_status_t** statusptr) {
+  SaveStatus(statusptr,
+             be->rep->RestoreDBFromLatestBackup(std::string(db_dir), std::string(wal_dir), restore_options->rep));
+}
+
+void rocksdb_backup_engine_verify_backup_with_status(rocksdb_backup_engine_t* be, uint32_t backup_id,
+                                                     rocksdb_status_t** statusptr) {
+  SaveStatus(statusptr, be->rep->VerifyBackup(static_cast<BackupID>(backup_id)));
+}
+}
@@ -9,11 +9,75 @@ extern "C"
 #include <stddef.h>
 #include <stdint.h>
 
+/* status */
+typedef struct rocksdb_status_t rocksdb_status_t;
+
+// Functions that can fail take a `rocksdb_status_t **statusptr` that must
+// point to NULL. On failure it is set to a status that must be freed with
+// `rocksdb_status_destroy`.
+
+// Creates a status with the `Status::Code` `code` and `Status::SubCode`
+// `subcode`, e.g. for a callback to report an error. A `message` in the
+// `Status::ToString` format is not prefixed with the code again.
+rocksdb_status_t *rocksdb_status_create(int code, int subcode, unsigned char retryable, const char *message,
+                                        size_t message_len);
+void rocksdb_status_destroy(rocksdb_status_t *status);
+// Returns the `Status::Code`.
+int rocksdb_status_code(const rocksdb_status_t *status);
+// Returns the `Status::SubCode`.
+int rocksdb_status_subcode(const rocksdb_status_t *status);
+// Returns the `Status::Severity`.
+int rocksdb_status_severity(const rocksdb_status_t *status);
+// Returns 1 if the status came from an I/O error that may succeed if retried.
+unsigned char rocksdb_status_retryable(const rocksdb_status_t *status);
+// Returns the `Status::ToString` message, which is not NUL-terminated.
+const char *rocksdb_status_message(const rocksdb_status_t *status, size_t *len);
+
+typedef struct rocksdb_t rocksdb_t;
+typedef struct rocksdb_iterator_t rocksdb_iterator_t;
+typedef struct rocksdb_readoptions_t rocksdb_readoptions_t;
+typedef struct rocksdb_writeoptions_t rocksdb_writeoptions_t;
 typedef struct rocksdb_options_t rocksdb_options_t;
+typedef struct rocksdb_compactionoptions_t rocksdb_compactionoptions_t;
+typedef struct rocksdb_flushoptions_t rocksdb_flushoptions_t;
+typedef struct rocksdb_ingestexternalfileoptions_t rocksdb_ingestexternalfileoptions_t;
+typedef struct rocksdb_pinnableslice_t rocksdb_pinnableslice_t;
+typedef struct rocksdb_wal_iterator_t rocksdb_wal_iterator_t;
+typedef struct rocksdb_wal_readoptions_t rocksdb_wal_readoptions_t;
+typedef struct rocksdb_env_t rocksdb_env_t;
+/* backup */
+typedef struct rocksdb_backup_engine_t rocksdb_backup_engine_t;
+typedef struct rocksdb_backup_engine_options_t rocksdb_backup_engine_options_t;
+typedef struct rocksdb_restore_options_t rocksdb_restore_options_t;
+/* checkpoint */
+typedef struct rocksdb_checkpoint_t rocksdb_checkpoint_t;
+/* sst_file_writer */
+typedef struct rocksdb_sstfilewriter_t rocksdb_sstfilewriter_t;
+/* perf */
+typedef struct rocksdb_memory_consumers_t rocksdb_memory_consumers_t;
+typedef struct rocksdb_memory_usage_t rocksdb_memory_usage_t;
 /* write_buffer_manager */
 typedef struct rocksdb_write_buffer_manager_t rocksdb_write_buffer_manager_t;
 /* cache */
 typedef struct rocksdb_cache_t rocksdb_cache_t;
+typedef struct rocksdb_column_family_handle_t rocksdb_column_family_handle_t;
+typedef struct rocksdb_writebatch_t rocksdb_writebatch_t;
+typedef struct rocksdb_transaction_t rocksdb_transaction_t;
+typedef struct rocksdb_transactiondb_t rocksdb_transactiondb_t;
+typedef struct rocksdb_transactiondb_options_t rocksdb_transactiondb_options_t;
+typedef struct rocksdb_optimistictransactiondb_t rocksdb_optimistictransactiondb_t;
+/* metadata */
+typedef struct rocksdb_column_family_metadata_t rocksdb_column_family_metadata_t;
+typedef struct rocksdb_sst_file_metadata_t rocksdb_sst_file_metadata_t;
+typedef struct rocksdb_blob_metadata_t rocksdb_blob_metadata_t;
+/* table_properties */
+typedef struct rocksdb_table_properties_t rocksdb_table_properties_t;
+typedef struct rocksdb_table_properties_collection_t rocksdb_table_properties_collection_t;
+typedef struct rocksdb_table_properties_collector_t rocksdb_table_properties_collector_t;
+typedef struct rocksdb_table_properties_collector_factory_t rocksdb_table_properties_collector_factory_t;
+typedef struct rocksdb_user_collected_properties_t rocksdb_user_collected_properties_t;
+/* wide_columns */
+typedef struct rocksdb_pinnable_wide_columns_t rocksdb_pinnable_wide_columns_t;
 
 void rocksdb_options_set_write_buffer_manager(rocksdb_options_t *opt, rocksdb_write_buffer_manager_t *manager);
 
@@ -27,6 +91,866 @@ unsigned char rocksdb_write_buffer_manager_enabled(rocksdb_write_buffer_manager_
 size_t rocksdb_write_buffer_manager_memory_usage(rocksdb_write_buffer_manager_t *manager);
 size_t rocksdb_write_buffer_manager_buffer_size(rocksdb_write_buffer_manager_t *manager);
 
+/* db */
+// A null start or limit key leaves that side of the range unbounded.
+void rocksdb_approximate_sizes_with_flags(rocksdb_t *db, int num_ranges, const char *const *range_start_key,
+                                          const size_t *range_start_key_len, const char *const *range_limit_key,
+                                          const size_t *range_limit_key_len, unsigned char include_memtables,
+                                          unsigned char include_files, double files_size_error_margin,
+                                          uint64_t *sizes, rocksdb_status_t **statusptr);
+void rocksdb_approximate_sizes_cf_with_flags(rocksdb_t *db, rocksdb_column_family_handle_t *column_family,
+                                             int num_ranges, const char *const *range_start_key,
+                                             const size_t *range_start_key_len, const char *const *range_limit_key,
+                                             const size_t *range_limit_key_len, unsigned char include_memtables,
+                                             unsigned char include_files, double files_size_error_margin,
+                                             uint64_t *sizes, rocksdb_status_t **statusptr);
+void rocksdb_approximate_memtable_stats(rocksdb_t *db, const char *start_key, size_t start_key_len,
+                                        const char *limit_key, size_t limit_key_len, uint64_t *count,
+                                        uint64_t *size);
+void rocksdb_approximate_memtable_stats_cf(rocksdb_t *db, rocksdb_column_family_handle_t *column_family,
+                                           const char *start_key, size_t start_key_len, const char *limit_key,
+                                           size_t limit_key_len, uint64_t *count, uint64_t *size);
+// The returned collection must be released with
+// rocksdb_table_properties_collection_destroy. Null on error.
+rocksdb_table_properties_collection_t *rocksdb_get_properties_of_all_tables(rocksdb_t *db, rocksdb_status_t **statusptr);
+rocksdb_table_properties_collection_t *rocksdb_get_properties_of_all_tables_cf(
+    rocksdb_t *db, rocksdb_column_family_handle_t *column_family, rocksdb_status_t **statusptr);
+// A null start or limit key leaves that side of the range unbounded.
+rocksdb_table_properties_collection_t *rocksdb_get_properties_of_tables_in_range(
+    rocksdb_t *db, int num_ranges, const char *const *range_start_key, const size_t *range_start_key_len,
+    const char *const *range_limit_key, const size_t *range_limit_key_len, rocksdb_status_t **statusptr);
+rocksdb_table_properties_collection_t *rocksdb_get_properties_of_tables_in_range_cf(
+    rocksdb_t *db, rocksdb_column_family_handle_t *column_family, int num_ranges, const char *const *range_start_key,
+    const size_t *range_start_key_len, const char *const *range_limit_key, const size_t *range_limit_key_len,
+    rocksdb_status_t **statusptr);
+
+// Closes the database like `rocksdb_close`, reporting the status of `DB::Close`.
+// `db` is destroyed even if closing fails.
+void rocksdb_close_with_status(rocksdb_t *db, rocksdb_status_t **statusptr);
+void rocksdb_pause_background_work(rocksdb_t *db, rocksdb_status_t **statusptr);
+void rocksdb_continue_background_work(rocksdb_t *db, rocksdb_status_t **statusptr);
+// Flushes the memtables of all column families.
+void rocksdb_flush_all(rocksdb_t *db, rocksdb_status_t **statusptr);
+// Returns the error that stopped background work, or NULL if there is none.
+rocksdb_status_t *rocksdb_get_background_error(rocksdb_t *db);
+void rocksdb_resume(rocksdb_t *db, rocksdb_status_t **statusptr);
+// Returns 1 if a flush or compaction is running, or would be scheduled if
+// background work were not paused.
+unsigned char rocksdb_has_pending_background_work(rocksdb_t *db);
+void rocksdb_compact_files(rocksdb_t *db, const rocksdb_compactionoptions_t *options, size_t num_files,
+                           const char *const *file_names, int output_level, rocksdb_status_t **statusptr);
+void rocksdb_compact_files_cf(rocksdb_t *db, const rocksdb_compactionoptions_t *options,
+                              rocksdb_column_family_handle_t *column_family, size_t num_files,
+                              const char *const *file_names, int output_level, rocksdb_status_t **statusptr);
+void rocksdb_promote_l0(rocksdb_t *db, int target_level, rocksdb_status_t **statusptr);
+void rocksdb_promote_l0_cf(rocksdb_t *db, rocksdb_column_family_handle_t *column_family, int target_level,
+                           rocksdb_status_t **statusptr);
+
+// The `*_with_status` functions mirror the functions of the same name without
+// the suffix in RocksDB's `c.h`, reporting errors as a `rocksdb_status_t`.
+rocksdb_t *rocksdb_open_with_status(const rocksdb_options_t *options, const char *name, rocksdb_status_t **statusptr);
+rocksdb_t *rocksdb_open_column_families_with_status(const rocksdb_options_t *db_options, const char *name,
+                                                    int num_column_families, const char *const *column_family_names,
+                                                    const rocksdb_options_t *const *column_family_options,
+                                                    rocksdb_column_family_handle_t **column_family_handles,
+                                                    rocksdb_status_t **statusptr);
+rocksdb_t *rocksdb_open_for_read_only_with_status(const rocksdb_options_t *options, const char *name,
+                                                  unsigned char error_if_wal_file_exists, rocksdb_status_t **statusptr);
+rocksdb_t *rocksdb_open_for_read_only_column_families_with_status(
+    const rocksdb_options_t *db_options, const char *name, int num_column_families,
+    const char *const *column_family_names, const rocksdb_options_t *const *column_family_options,
+    rocksdb_column_family_handle_t **column_family_handles, unsigned char error_if_wal_file_exists,
+    rocksdb_status_t **statusptr);
+rocksdb_t *rocksdb_open_as_secondary_with_status(const rocksdb_options_t *options, const char *name,
+                                                 const char *secondary_path, rocksdb_status_t **statusptr);
+rocksdb_t *rocksdb_open_as_secondary_column_families_with_status(
+    const rocksdb_options_t *db_options, const char *name, const char *secondary_path, int num_column_families,
+    const char *const *column_family_names, const rocksdb_options_t *const *column_family_options,
+    rocksdb_column_family_handle_t **column_family_handles, rocksdb_status_t **statusptr);
+rocksdb_t *rocksdb_open_with_ttl_with_status(const rocksdb_options_t *options, const char *name, int ttl,
+                                             rocksdb_status_t **statusptr);
+rocksdb_t *rocksdb_open_column_families_with_ttl_with_status(
+    const rocksdb_options_t *db_options, const char *name, int num_column_families,
+    const char *const *column_family_names, const rocksdb_options_t *const *column_family_options,
+    rocksdb_column_family_handle_t **column_family_handles, const int *ttls, rocksdb_status_t **statusptr);
+void rocksdb_destroy_db_with_status(const rocksdb_options_t *options, const char *name, rocksdb_status_t **statusptr);
+void rocksdb_repair_db_with_status(const rocksdb_options_t *options, const char *name, rocksdb_status_t **statusptr);
+char **rocksdb_list_column_families_with_status(const rocksdb_options_t *options, const char *name, size_t *lencfs,
+                                                rocksdb_status_t **statusptr);
+void rocksdb_load_latest_options_with_status(const char *db_path, rocksdb_env_t *env, bool ignore_unknown_options,
+                                             rocksdb_cache_t *cache, rocksdb_options_t **db_options,
+                                             size_t *num_column_families, char ***list_column_family_names,
+                                             rocksdb_options_t ***list_column_family_options,
+                                             rocksdb_status_t **statusptr);
+rocksdb_column_family_handle_t *rocksdb_create_column_family_with_status(
+    rocksdb_t *db, const rocksdb_options_t *column_family_options, const char *column_family_name,
+    rocksdb_status_t **statusptr);
+void rocksdb_drop_column_family_with_status(rocksdb_t *db, rocksdb_column_family_handle_t *handle,
+                                            rocksdb_status_t **statusptr);
+void rocksdb_put_with_status(rocksdb_t *db, const rocksdb_writeoptions_t *options, const char *key, size_t keylen,
+                             const char *val, size_t vallen, rocksdb_status_t **statusptr);
+void rocksdb_put_cf_with_status(rocksdb_t *db, const rocksdb_writeoptions_t *options,
+                                rocksdb_column_family_handle_t *column_family, const char *key, size_t keylen,
+                                const char *val, size_t vallen, rocksdb_status_t **statusptr);
+void rocksdb_delete_with_status(rocksdb_t *db, const rocksdb_writeoptions_t *options, const char *key, size_t keylen,
+                                rocksdb_status_t **statusptr);
+void rocksdb_delete_cf_with_status(rocksdb_t *db, const rocksdb_writeoptions_t *options,
+                                   rocksdb_column_family_handle_t *column_family, const char *key, size_t keylen,
+                                   rocksdb_status_t **statusptr);
+void rocksdb_singledelete_with_status(rocksdb_t *db, const rocksdb_writeoptions_t *options, const char *key,
+                                      size_t keylen, rocksdb_status_t **statusptr);
+void rocksdb_singledelete_cf_with_status(rocksdb_t *db, const rocksdb_writeoptions_t *options,
+                                         rocksdb_column_family_handle_t *column_family, const char *key, size_t keylen,
+                                         rocksdb_status_t **statusptr);
+void rocksdb_delete_range_cf_with_status(rocksdb_t *db, const rocksdb_writeoptions_t *options,
+                                         rocksdb_column_family_handle_t *column_family, const char *start_key,
+                                         size_t start_key_len, const char *end_key, size_t end_key_len,
+                                         rocksdb_status_t **statusptr);
+void rocksdb_merge_with_status(rocksdb_t *db, const rocksdb_writeoptions_t *options, const char *key, size_t keylen,
+                               const char *val, size_t vallen, rocksdb_status_t **statusptr);
+void rocksdb_merge_cf_with_status(rocksdb_t *db, const rocksdb_writeoptions_t *options,
+                                  rocksdb_column_family_handle_t *column_family, const char *key, size_t keylen,
+                                  const char *val, size_t vallen, rocksdb_status_t **statusptr);
+void rocksdb_write_with_status(rocksdb_t *db, const rocksdb_writeoptions_t *options, rocksdb_writebatch_t *batch,
+                               rocksdb_status_t **statusptr);
+rocksdb_pinnableslice_t *rocksdb_get_pinned_with_status(rocksdb_t *db, const rocksdb_readoptions_t *options,
+                                                        const char *key, size_t keylen, rocksdb_status_t **statusptr);
+rocksdb_pinnableslice_t *rocksdb_get_pinned_cf_with_status(rocksdb_t *db, const rocksdb_readoptions_t *options,
+                                                           rocksdb_column_family_handle_t *column_family,
+                                                           const char *key, size_t keylen,
+                                                           rocksdb_status_t **statusptr);
+void rocksdb_multi_get_with_status(rocksdb_t *db, const rocksdb_readoptions_t *options, size_t num_keys,
+                                   const char *const *keys_list, const size_t *keys_list_sizes, char **values_list,
+                                   size_t *values_list_sizes, rocksdb_status_t **statuses);
+void rocksdb_multi_get_cf_with_status(rocksdb_t *db, const rocksdb_readoptions_t *options,
+                                      const rocksdb_column_family_handle_t *const *column_families, size_t num_keys,
+                                      const char *const *keys_list, const size_t *keys_list_sizes, char **values_list,
+                                      size_t *values_list_sizes, rocksdb_status_t **statuses);
+void rocksdb_batched_multi_get_cf_with_status(rocksdb_t *db, const rocksdb_readoptions_t *options,
+                                              rocksdb_column_family_handle_t *column_family, size_t num_keys,
+                                              const char *const *keys_list, const size_t *keys_list_sizes,
+                                              rocksdb_pinnableslice_t **values, rocksdb_status_t **statuses,
+                                              bool sorted_input);
+void rocksdb_flush_with_status(rocksdb_t *db, const rocksdb_flushoptions_t *options, rocksdb_status_t **statusptr);
+void rocksdb_flush_cf_with_status(rocksdb_t *db, const rocksdb_flushoptions_t *options,
+                                  rocksdb_column_family_handle_t *column_family, rocksdb_status_t **statusptr);
+void rocksdb_flush_cfs_with_status(rocksdb_t *db, const rocksdb_flushoptions_t *options,
+                                   rocksdb_column_family_handle_t **column_families, int num_column_families,
+                                   rocksdb_status_t **statusptr);
+void rocksdb_flush_wal_with_status(rocksdb_t *db, unsigned char sync, rocksdb_status_t **statusptr);
+void rocksdb_delete_file_in_range_cf_with_status(rocksdb_t *db, rocksdb_column_family_handle_t *column_family,
+                                                 const char *start_key, size_t start_key_len, const char *limit_key,
+                                                 size_t limit_key_len, rocksdb_status_t **statusptr);
+void rocksdb_delete_file_in_range_with_status(rocksdb_t *db, const char *start_key, size_t start_key_len,
+                                              const char *limit_key, size_t limit_key_len,
+                                              rocksdb_status_t **statusptr);
+void rocksdb_ingest_external_file_with_status(rocksdb_t *db, const char *const *file_list, size_t list_len,
+                                              const rocksdb_ingestexternalfileoptions_t *opt,
+                                              rocksdb_status_t **statusptr);
+void rocksdb_ingest_external_file_cf_with_status(rocksdb_t *db, rocksdb_column_family_handle_t *handle,
+                                                 const char *const *file_list, size_t list_len,
+                                                 const rocksdb_ingestexternalfileoptions_t *opt,
+                                                 rocksdb_status_t **statusptr);
+void rocksdb_set_options_with_status(rocksdb_t *db, int count, const char *const keys[], const char *const values[],
+                                     rocksdb_status_t **statusptr);
+void rocksdb_set_options_cf_with_status(rocksdb_t *db, rocksdb_column_family_handle_t *handle, int count,
+                                        const char *const keys[], const char *const values[],
+                                        rocksdb_status_t **statusptr);
+void rocksdb_try_catch_up_with_primary_with_status(rocksdb_t *db, rocksdb_status_t **statusptr);
+rocksdb_wal_iterator_t *rocksdb_get_updates_since_with_status(
+    rocksdb_t *db, uint64_t seq_number, const rocksdb_wal_readoptions_t *options, rocksdb_status_t **statusptr);
+void rocksdb_wal_iter_status_with_status(const rocksdb_wal_iterator_t *iter, rocksdb_status_t **statusptr);
+void rocksdb_iter_get_error_with_status(const rocksdb_iterator_t *iter, rocksdb_status_t **statusptr);
+
+/* compaction_filter */
+typedef struct rocksdb_compactionfilter_v2_t rocksdb_compactionfilter_v2_t;
+typedef struct rocksdb_compactionfilter_result_t rocksdb_compactionfilter_result_t;
+typedef struct rocksdb_compactionfilterfactory_v2_t rocksdb_compactionfilterfactory_v2_t;
+typedef struct rocksdb_compactionfiltercontext_t rocksdb_compactionfiltercontext_t;
+
+// Creates a filter of values and merge operands. `filter` receives the
+// `CompactionFilter::ValueType` of the value, returns a
+// `CompactionFilter::Decision` and sets the new value or the key to skip until
+// on `result`, which copies them. Wide-column entities are filtered by the
+// value of their default column. `filter_blob_by_key` is called for values
+// stored in blob files and returns `kUndetermined` to read them.
+rocksdb_compactionfilter_v2_t *rocksdb_compactionfilter_create_v2(
+    void *state, void (*destructor)(void *),
+    int (*filter)(void *, int, const char *, size_t, int, const char *, size_t, rocksdb_compactionfilter_result_t *),
+    int (*filter_blob_by_key)(void *, int, const char *, size_t, rocksdb_compactionfilter_result_t *),
+    const char *(*name)(void *));
+// Sets the filter of all compactions. The options do not take ownership, like
+// with `rocksdb_options_set_compaction_filter`: the filter must outlive them
+// and the databases opened with them.
+void rocksdb_options_set_compaction_filter_v2(rocksdb_options_t *opt, rocksdb_compactionfilter_v2_t *filter);
+void rocksdb_compactionfilter_result_set_new_value(rocksdb_compactionfilter_result_t *result, const char *value,
+                                                   size_t value_len);
+void rocksdb_compactionfilter_result_set_skip_until(rocksdb_compactionfilter_result_t *result, const char *key,
+                                                    size_t key_len);
+// Creates a factory like `rocksdb_compactionfilterfactory_create`, whose
+// filters are only created for the table files `should_filter_table_file_creation`
+// accepts the `TableFileCreationReason` of.
+// `create_compaction_filter` returns a filter created with
+// `rocksdb_compactionfilter_create_v2`, owned by the factory.
+rocksdb_compactionfilterfactory_v2_t *rocksdb_compactionfilterfactory_create_v2(
+    void *state, void (*destructor)(void *),
+    rocksdb_compactionfilter_v2_t *(*create_compaction_filter)(void *, rocksdb_compactionfiltercontext_t *),
+    unsigned char (*should_filter_table_file_creation)(void *, int), const char *(*name)(void *));
+// Sets the factory of compaction filters, which the options take ownership of.
+void rocksdb_options_set_compaction_filter_factory_v2(rocksdb_options_t *opt,
+                                                      rocksdb_compactionfilterfactory_v2_t *factory);
+uint32_t rocksdb_compactionfiltercontext_get_column_family_id(rocksdb_compactionfiltercontext_t *context);
+// Returns the `TableFileCreationReason` of the table files to filter.
+int rocksdb_compactionfiltercontext_get_reason(rocksdb_compactionfiltercontext_t *context);
+
+/* merge_operator */
+typedef struct rocksdb_mergeoperator_v2_t rocksdb_mergeoperator_v2_t;
+typedef struct rocksdb_mergeoperator_result_t rocksdb_mergeoperator_result_t;
+
+// Creates a merge operator whose callbacks return whether they succeeded and
+// set the merged value on `result`, which copies it. On failure,
+// `full_merge` sets the error, written to the info log, on `result`.
+// `should_merge` receives the operands read so far, newest first.
+rocksdb_mergeoperator_v2_t *rocksdb_mergeoperator_create_v2(
+    void *state, void (*destructor)(void *),
+    unsigned char (*full_merge)(void *, const char *, size_t, const char *, size_t, const char *const *,
+                                const size_t *, int, rocksdb_mergeoperator_result_t *),
+    unsigned char (*partial_merge)(void *, const char *, size_t, const char *, size_t, const char *, size_t,
+                                   rocksdb_mergeoperator_result_t *),
+    unsigned char (*partial_merge_multi)(void *, const char *, size_t, const char *const *, const size_t *, int,
+                                         rocksdb_mergeoperator_result_t *),
+    unsigned char (*should_merge)(void *, const char *const *, const size_t *, int),
+    unsigned char allow_single_operand, const char *(*name)(void *));
+// Sets the merge operator, which the options take ownership of.
+void rocksdb_options_set_merge_operator_v2(rocksdb_options_t *opt, rocksdb_mergeoperator_v2_t *merge_operator);
+void rocksdb_mergeoperator_result_set_value(rocksdb_mergeoperator_result_t *result, const char *value,
+                                            size_t value_len);
+// `op_failure_scope` is a `MergeOperator::OpFailureScope`.
+void rocksdb_mergeoperator_result_set_error(rocksdb_mergeoperator_result_t *result, int op_failure_scope,
+                                            const char *message, size_t message_len);
+
+/* compaction_options */
+rocksdb_compactionoptions_t *rocksdb_compactionoptions_create(void);
+void rocksdb_compactionoptions_destroy(rocksdb_compactionoptions_t *opt);
+void rocksdb_compactionoptions_set_compression(rocksdb_compactionoptions_t *opt, int compression);
+void rocksdb_compactionoptions_set_output_file_size_limit(rocksdb_compactionoptions_t *opt, uint64_t limit);
+void rocksdb_compactionoptions_set_max_subcompactions(rocksdb_compactionoptions_t *opt, uint32_t max);
+
+/* metadata */
+uint64_t rocksdb_sst_file_metadata_get_file_number(rocksdb_sst_file_metadata_t *file_meta);
+uint64_t rocksdb_sst_file_metadata_get_smallest_seqno(rocksdb_sst_file_metadata_t *file_meta);
+uint64_t rocksdb_sst_file_metadata_get_largest_seqno(rocksdb_sst_file_metadata_t *file_meta);
+uint64_t rocksdb_sst_file_metadata_get_num_reads_sampled(rocksdb_sst_file_metadata_t *file_meta);
+unsigned char rocksdb_sst_file_metadata_get_being_compacted(rocksdb_sst_file_metadata_t *file_meta);
+uint64_t rocksdb_sst_file_metadata_get_num_entries(rocksdb_sst_file_metadata_t *file_meta);
+uint64_t rocksdb_sst_file_metadata_get_num_deletions(rocksdb_sst_file_metadata_t *file_meta);
+uint64_t rocksdb_sst_file_metadata_get_oldest_blob_file_number(rocksdb_sst_file_metadata_t *file_meta);
+uint64_t rocksdb_sst_file_metadata_get_oldest_ancester_time(rocksdb_sst_file_metadata_t *file_meta);
+uint64_t rocksdb_sst_file_metadata_get_file_creation_time(rocksdb_sst_file_metadata_t *file_meta);
+uint64_t rocksdb_sst_file_metadata_get_epoch_number(rocksdb_sst_file_metadata_t *file_meta);
+unsigned char rocksdb_sst_file_metadata_get_temperature(rocksdb_sst_file_metadata_t *file_meta);
+const char *rocksdb_sst_file_metadata_get_file_checksum(rocksdb_sst_file_metadata_t *file_meta, size_t *len);
+const char *rocksdb_sst_file_metadata_get_file_checksum_func_name(rocksdb_sst_file_metadata_t *file_meta,
+                                                                  size_t *len);
+uint64_t rocksdb_column_family_metadata_get_blob_file_size(rocksdb_column_family_metadata_t *cf_meta);
+size_t rocksdb_column_family_metadata_get_blob_file_count(rocksdb_column_family_metadata_t *cf_meta);
+// The returned rocksdb_blob_metadata_t borrows from its parent and must be
+// released with rocksdb_blob_metadata_destroy before the parent is.
+rocksdb_blob_metadata_t *rocksdb_column_family_metadata_get_blob_file_metadata(
+    rocksdb_column_family_metadata_t *cf_meta, size_t i);
+void rocksdb_blob_metadata_destroy(rocksdb_blob_metadata_t *blob_meta);
+uint64_t rocksdb_blob_metadata_get_file_number(rocksdb_blob_metadata_t *blob_meta);
+const char *rocksdb_blob_metadata_get_file_name(rocksdb_blob_metadata_t *blob_meta, size_t *len);
+const char *rocksdb_blob_metadata_get_file_path(rocksdb_blob_metadata_t *blob_meta, size_t *len);
+uint64_t rocksdb_blob_metadata_get_file_size(rocksdb_blob_metadata_t *blob_meta);
+uint64_t rocksdb_blob_metadata_get_total_blob_count(rocksdb_blob_metadata_t *blob_meta);
+uint64_t rocksdb_blob_metadata_get_total_blob_bytes(rocksdb_blob_metadata_t *blob_meta);
+uint64_t rocksdb_blob_metadata_get_garbage_blob_count(rocksdb_blob_metadata_t *blob_meta);
+uint64_t rocksdb_blob_metadata_get_garbage_blob_bytes(rocksdb_blob_metadata_t *blob_meta);
+
+/* wide_columns */
+void rocksdb_put_entity(rocksdb_t *db, const rocksdb_writeoptions_t *options, const char *key, size_t keylen,
+                        size_t num_columns, const char *const *names_list, const size_t *names_list_sizes,
+                        const char *const *values_list, const size_t *values_list_sizes, rocksdb_status_t **statusptr);
+void rocksdb_put_entity_cf(rocksdb_t *db, const rocksdb_writeoptions_t *options,
+                           rocksdb_column_family_handle_t *column_family, const char *key, size_t keylen,
+                           size_t num_columns, const char *const *names_list, const size_t *names_list_sizes,
+                           const char *const *values_list, const size_t *values_list_sizes, rocksdb_status_t **statusptr);
+rocksdb_pinnable_wide_columns_t *rocksdb_get_entity(rocksdb_t *db, const rocksdb_readoptions_t *options,
+                                                    const char *key, size_t keylen, rocksdb_status_t **statusptr);
+rocksdb_pinnable_wide_columns_t *rocksdb_get_entity_cf(rocksdb_t *db, const rocksdb_readoptions_t *options,
+                                                       rocksdb_column_family_handle_t *column_family,
+                                                       const char *key, size_t keylen, rocksdb_status_t **statusptr);
+void rocksdb_pinnable_wide_columns_destroy(rocksdb_pinnable_wide_columns_t *columns);
+size_t rocksdb_pinnable_wide_columns_count(const rocksdb_pinnable_wide_columns_t *columns);
+const char *rocksdb_pinnable_wide_columns_name(const rocksdb_pinnable_wide_columns_t *columns, size_t index,
+                                               size_t *name_len);
+const char *rocksdb_pinnable_wide_columns_value(const rocksdb_pinnable_wide_columns_t *columns, size_t index,
+                                                size_t *value_len);
+size_t rocksdb_iter_columns_count(const rocksdb_iterator_t *iter);
+const char *rocksdb_iter_column_name(const rocksdb_iterator_t *iter, size_t index, size_t *name_len);
+const char *rocksdb_iter_column_value(const rocksdb_iterator_t *iter, size_t index, size_t *value_len);
+
+/* write_batch */
+void rocksdb_writebatch_iterate_with_single_delete(
+    rocksdb_writebatch_t *b, void *state,
+    void (*put)(void *, const char *k, size_t klen, const char *v, size_t vlen),
+    void (*deleted)(void *, const char *k, size_t klen),
+    void (*single_deleted)(void *, const char *k, size_t klen));
+void rocksdb_writebatch_put_entity(rocksdb_writebatch_t *b, const char *key, size_t klen, size_t num_columns,
+                                   const char *const *names_list, const size_t *names_list_sizes,
+                                   const char *const *values_list, const size_t *values_list_sizes, rocksdb_status_t **statusptr);
+void rocksdb_writebatch_put_entity_cf(rocksdb_writebatch_t *b, rocksdb_column_family_handle_t *column_family,
+                                      const char *key, size_t klen, size_t num_columns,
+                                      const char *const *names_list, const size_t *names_list_sizes,
+                                      const char *const *values_list, const size_t *values_list_sizes,
+                                      rocksdb_status_t **statusptr);
+
+/* transaction */
+// Like `rocksdb_close_with_status`, for transaction databases.
+void rocksdb_transactiondb_close_with_status(rocksdb_transactiondb_t *txn_db, rocksdb_status_t **statusptr);
+void rocksdb_optimistictransactiondb_close_with_status(rocksdb_optimistictransactiondb_t *otxn_db, rocksdb_status_t **statusptr);
+void rocksdb_transactiondb_cancel_all_background_work(rocksdb_transactiondb_t *txn_db, unsigned char wait);
+void rocksdb_transaction_singledelete(rocksdb_transaction_t *txn, const char *key, size_t klen, rocksdb_status_t **statusptr);
+void rocksdb_transaction_singledelete_cf(rocksdb_transaction_t *txn, rocksdb_column_family_handle_t *column_family,
+                                         const char *key, size_t klen, rocksdb_status_t **statusptr);
+
+rocksdb_transactiondb_t *rocksdb_transactiondb_open_with_status(
+    const rocksdb_options_t *options, const rocksdb_transactiondb_options_t *txn_db_options, const char *name,
+    rocksdb_status_t **statusptr);
+rocksdb_transactiondb_t *rocksdb_transactiondb_open_column_families_with_status(
+    const rocksdb_options_t *options, const rocksdb_transactiondb_options_t *txn_db_options, const char *name,
+    int num_column_families, const char *const *column_family_names,
+    const rocksdb_options_t *const *column_family_options, rocksdb_column_family_handle_t **column_family_handles,
+    rocksdb_status_t **statusptr);
+rocksdb_column_family_handle_t *rocksdb_transactiondb_create_column_family_with_status(
+    rocksdb_transactiondb_t *txn_db, const rocksdb_options_t *column_family_options, const char *column_family_name,
+    rocksdb_status_t **statusptr);
+void rocksdb_transactiondb_put_with_status(rocksdb_transactiondb_t *txn_db, const rocksdb_writeoptions_t *options,
+                                           const char *key, size_t klen, const char *val, size_t vlen,
+                                           rocksdb_status_t **statusptr);
+void rocksdb_transactiondb_put_cf_with_status(rocksdb_transactiondb_t *txn_db, const rocksdb_writeoptions_t *options,
+                                              rocksdb_column_family_handle_t *column_family, const char *key,
+                                              size_t keylen, const char *val, size_t vallen,
+                                              rocksdb_status_t **statusptr);
+void rocksdb_transactiondb_delete_with_status(rocksdb_transactiondb_t *txn_db, const rocksdb_writeoptions_t *options,
+                                              const char *key, size_t klen, rocksdb_status_t **statusptr);
+void rocksdb_transactiondb_delete_cf_with_status(rocksdb_transactiondb_t *txn_db, const rocksdb_writeoptions_t *options,
+                                                 rocksdb_column_family_handle_t *column_family, const char *key,
+                                                 size_t keylen, rocksdb_status_t **statusptr);
+void rocksdb_transactiondb_merge_with_status(rocksdb_transactiondb_t *txn_db, const rocksdb_writeoptions_t *options,
+                                             const char *key, size_t klen, const char *val, size_t vlen,
+                                             rocksdb_status_t **statusptr);
+void rocksdb_transactiondb_merge_cf_with_status(rocksdb_transactiondb_t *txn_db, const rocksdb_writeoptions_t *options,
+                                                rocksdb_column_family_handle_t *column_family, const char *key,
+                                                size_t klen, const char *val, size_t vlen,
+                                                rocksdb_status_t **statusptr);
+void rocksdb_transactiondb_write_with_status(rocksdb_transactiondb_t *txn_db, const rocksdb_writeoptions_t *options,
+                                             rocksdb_writebatch_t *batch, rocksdb_status_t **statusptr);
+rocksdb_pinnableslice_t *rocksdb_transactiondb_get_pinned_with_status(
+    rocksdb_transactiondb_t *txn_db, const rocksdb_readoptions_t *options, const char *key, size_t klen,
+    rocksdb_status_t **statusptr);
+rocksdb_pinnableslice_t *rocksdb_transactiondb_get_pinned_cf_with_status(
+    rocksdb_transactiondb_t *txn_db, const rocksdb_readoptions_t *options,
+    rocksdb_column_family_handle_t *column_family, const char *key, size_t keylen, rocksdb_status_t **statusptr);
+void rocksdb_transactiondb_multi_get_with_status(rocksdb_transactiondb_t *txn_db, const rocksdb_readoptions_t *options,
+                                                 size_t num_keys, const char *const *keys_list,
+                                                 const size_t *keys_list_sizes, char **values_list,
+                                                 size_t *values_list_sizes, rocksdb_status_t **statuses);
+void rocksdb_transactiondb_multi_get_cf_with_status(rocksdb_transactiondb_t *txn_db,
+                                                    const rocksdb_readoptions_t *options,
+                                                    const rocksdb_column_family_handle_t *const *column_families,
+                                                    size_t num_keys, const char *const *keys_list,
+                                                    const size_t *keys_list_sizes, char **values_list,
+                                                    size_t *values_list_sizes, rocksdb_status_t **statuses);
+rocksdb_optimistictransactiondb_t *rocksdb_optimistictransactiondb_open_with_status(
+    const rocksdb_options_t *options, const char *name, rocksdb_status_t **statusptr);
+rocksdb_optimistictransactiondb_t *rocksdb_optimistictransactiondb_open_column_families_with_status(
+    const rocksdb_options_t *db_options, const char *name, int num_column_families,
+    const char *const *column_family_names, const rocksdb_options_t *const *column_family_options,
+    rocksdb_column_family_handle_t **column_family_handles, rocksdb_status_t **statusptr);
+void rocksdb_optimistictransactiondb_write_with_status(rocksdb_optimistictransactiondb_t *otxn_db,
+                                                       const rocksdb_writeoptions_t *options,
+                                                       rocksdb_writebatch_t *batch, rocksdb_status_t **statusptr);
+void rocksdb_transaction_set_name_with_status(rocksdb_transaction_t *txn, const char *name, size_t name_len,
+                                              rocksdb_status_t **statusptr);
+void rocksdb_transaction_prepare_with_status(rocksdb_transaction_t *txn, rocksdb_status_t **statusptr);
+void rocksdb_transaction_commit_with_status(rocksdb_transaction_t *txn, rocksdb_status_t **statusptr);
+void rocksdb_transaction_rollback_with_status(rocksdb_transaction_t *txn, rocksdb_status_t **statusptr);
+void rocksdb_transaction_rollback_to_savepoint_with_status(rocksdb_transaction_t *txn, rocksdb_status_t **statusptr);
+void rocksdb_transaction_rebuild_from_writebatch_with_status(
+    rocksdb_transaction_t *txn, rocksdb_writebatch_t *writebatch, rocksdb_status_t **statusptr);
+void rocksdb_transaction_put_with_status(rocksdb_transaction_t *txn, const char *key, size_t klen, const char *val,
+                                         size_t vlen, rocksdb_status_t **statusptr);
+void rocksdb_transaction_put_cf_with_status(rocksdb_transaction_t *txn, rocksdb_column_family_handle_t *column_family,
+                                            const char *key, size_t klen, const char *val, size_t vlen,
+                                            rocksdb_status_t **statusptr);
+void rocksdb_transaction_delete_with_status(rocksdb_transaction_t *txn, const char *key, size_t klen,
+                                            rocksdb_status_t **statusptr);
+void rocksdb_transaction_delete_cf_with_status(rocksdb_transaction_t *txn,
+                                               rocksdb_column_family_handle_t *column_family, const char *key,
+                                               size_t klen, rocksdb_status_t **statusptr);
+void rocksdb_transaction_merge_with_status(rocksdb_transaction_t *txn, const char *key, size_t klen, const char *val,
+                                           size_t vlen, rocksdb_status_t **statusptr);
+void rocksdb_transaction_merge_cf_with_status(rocksdb_transaction_t *txn, rocksdb_column_family_handle_t *column_family,
+                                              const char *key, size_t klen, const char *val, size_t vlen,
+                                              rocksdb_status_t **statusptr);
+rocksdb_pinnableslice_t *rocksdb_transaction_get_pinned_with_status(
+    rocksdb_transaction_t *txn, const rocksdb_readoptions_t *options, const char *key, size_t klen,
+    rocksdb_status_t **statusptr);
+rocksdb_pinnableslice_t *rocksdb_transaction_get_pinned_cf_with_status(
+    rocksdb_transaction_t *txn, const rocksdb_readoptions_t *options, rocksdb_column_family_handle_t *column_family,
+    const char *key, size_t klen, rocksdb_status_t **statusptr);
+rocksdb_pinnableslice_t *rocksdb_transaction_get_pinned_for_update_with_status(
+    rocksdb_transaction_t *txn, const rocksdb_readoptions_t *options, const char *key, size_t klen,
+    unsigned char exclusive, rocksdb_status_t **statusptr);
+rocksdb_pinnableslice_t *rocksdb_transaction_get_pinned_for_update_cf_with_status(
+    rocksdb_transaction_t *txn, const rocksdb_readoptions_t *options, rocksdb_column_family_handle_t *column_family,
+    const char *key, size_t klen, unsigned char exclusive, rocksdb_status_t **statusptr);
+void rocksdb_transaction_multi_get_with_status(rocksdb_transaction_t *txn, const rocksdb_readoptions_t *options,
+                                               size_t num_keys, const char *const *keys_list,
+                                               const size_t *keys_list_sizes, char **values_list,
+                                               size_t *values_list_sizes, rocksdb_status_t **statuses);
+void rocksdb_transaction_multi_get_cf_with_status(rocksdb_transaction_t *txn, const rocksdb_readoptions_t *options,
+                                                  const rocksdb_column_family_handle_t *const *column_families,
+                                                  size_t num_keys, const char *const *keys_list,
+                                                  const size_t *keys_list_sizes, char **values_list,
+                                                  size_t *values_list_sizes, rocksdb_status_t **statuses);
+
+/* backup */
+rocksdb_backup_engine_t *rocksdb_backup_engine_open_opts_with_status(
+    const rocksdb_backup_engine_options_t *options, rocksdb_env_t *env, rocksdb_status_t **statusptr);
+void rocksdb_backup_engine_create_new_backup_flush_with_status(
+    rocksdb_backup_engine_t *be, rocksdb_t *db, unsigned char flush_before_backup, rocksdb_status_t **statusptr);
+void rocksdb_backup_engine_purge_old_backups_with_status(rocksdb_backup_engine_t *be, uint32_t num_backups_to_keep,
+                                                         rocksdb_status_t **statusptr);
+void rocksdb_backup_engine_restore_db_from_backup_with_status(
+    rocksdb_backup_engine_t *be, const char *db_dir, const char *wal_dir,
+    const rocksdb_restore_options_t *restore_options, uint32_t backup_id, rocksdb_status_t **statusptr);
+void rocksdb_backup_engine_restore_db_from_latest_backup_with_status(
+    rocksdb_backup_engine_t *be, const char *db_dir, const char *wal_dir,
+    const rocksdb_restore_options_t *restore_options, rocksdb_status_t **statusptr);
+void rocksdb_backup_engine_verify_backup_with_status(rocksdb_backup_engine_t *be, uint32_t backup_id,
+                                                     rocksdb_status_t **statusptr);
+
+/* checkpoint */
+rocksdb_checkpoint_t *rocksdb_checkpoint_object_create_with_status(rocksdb_t *db, rocksdb_status_t **statusptr);
+void rocksdb_checkpoint_create_with_status(rocksdb_checkpoint_t *checkpoint, const char *checkpoint_dir,
+                                           uint64_t log_size_for_flush, rocksdb_status_t **statusptr);
+
+/* sst_file_writer */
+void rocksdb_sstfilewriter_open_with_status(rocksdb_sstfilewriter_t *writer, const char *name,
+                                            rocksdb_status_t **statusptr);
+void rocksdb_sstfilewriter_put_with_status(rocksdb_sstfilewriter_t *writer, const char *key, size_t keylen,
+                                           const char *val, size_t vallen, rocksdb_status_t **statusptr);
+void rocksdb_sstfilewriter_merge_with_status(rocksdb_sstfilewriter_t *writer, const char *key, size_t keylen,
+                                             const char *val, size_t vallen, rocksdb_status_t **statusptr);
+void rocksdb_sstfilewriter_delete_with_status(rocksdb_sstfilewriter_t *writer, const char *key, size_t keylen,
+                                              rocksdb_status_t **statusptr);
+void rocksdb_sstfilewriter_finish_with_status(rocksdb_sstfilewriter_t *writer, rocksdb_status_t **statusptr);
+
+/* perf */
+rocksdb_memory_usage_t *rocksdb_approximate_memory_usage_create_with_status(
+    rocksdb_memory_consumers_t *consumers, rocksdb_status_t **statusptr);
+
+/* rate_limiter */
+typedef struct rocksdb_ratelimiter_t rocksdb_ratelimiter_t;
+
+// Like `rocksdb_ratelimiter_create`, but adjusts the rate between
+// `rate_bytes_per_sec / 20` and `rate_bytes_per_sec` to the demand.
+rocksdb_ratelimiter_t *rocksdb_ratelimiter_create_auto_tuned(int64_t rate_bytes_per_sec, int64_t refill_period_us,
+                                                             int32_t fairness);
+void rocksdb_ratelimiter_set_bytes_per_second(rocksdb_ratelimiter_t *limiter, int64_t bytes_per_second);
+int64_t rocksdb_ratelimiter_get_bytes_per_second(rocksdb_ratelimiter_t *limiter);
+int64_t rocksdb_ratelimiter_get_single_burst_bytes(rocksdb_ratelimiter_t *limiter);
+int64_t rocksdb_ratelimiter_get_total_bytes_through(rocksdb_ratelimiter_t *limiter);
+int64_t rocksdb_ratelimiter_get_total_requests(rocksdb_ratelimiter_t *limiter);
+
+/* sst_file_manager */
+typedef struct rocksdb_env_t rocksdb_env_t;
+typedef struct rocksdb_sst_file_manager_t rocksdb_sst_file_manager_t;
+
+rocksdb_sst_file_manager_t *rocksdb_sst_file_manager_create(rocksdb_env_t *env);
+void rocksdb_sst_file_manager_destroy(rocksdb_sst_file_manager_t *sfm);
+void rocksdb_options_set_sst_file_manager(rocksdb_options_t *opt, rocksdb_sst_file_manager_t *sfm);
+void rocksdb_sst_file_manager_set_max_allowed_space_usage(rocksdb_sst_file_manager_t *sfm,
+                                                          uint64_t max_allowed_space);
+void rocksdb_sst_file_manager_set_compaction_buffer_size(rocksdb_sst_file_manager_t *sfm,
+                                                         uint64_t compaction_buffer_size);
+bool rocksdb_sst_file_manager_is_max_allowed_space_reached(rocksdb_sst_file_manager_t *sfm);
+bool rocksdb_sst_file_manager_is_max_allowed_space_reached_including_compactions(rocksdb_sst_file_manager_t *sfm);
+uint64_t rocksdb_sst_file_manager_get_total_size(rocksdb_sst_file_manager_t *sfm);
+int64_t rocksdb_sst_file_manager_get_delete_rate_bytes_per_second(rocksdb_sst_file_manager_t *sfm);
+void rocksdb_sst_file_manager_set_delete_rate_bytes_per_second(rocksdb_sst_file_manager_t *sfm,
+                                                               int64_t delete_rate);
+double rocksdb_sst_file_manager_get_max_trash_db_ratio(rocksdb_sst_file_manager_t *sfm);
+void rocksdb_sst_file_manager_set_max_trash_db_ratio(rocksdb_sst_file_manager_t *sfm, double ratio);
+uint64_t rocksdb_sst_file_manager_get_total_trash_size(rocksdb_sst_file_manager_t *sfm);
+
+/* table_properties */
+void rocksdb_table_properties_collection_destroy(rocksdb_table_properties_collection_t *collection);
+size_t rocksdb_table_properties_collection_count(const rocksdb_table_properties_collection_t *collection);
+const char *rocksdb_table_properties_collection_file_path(const rocksdb_table_properties_collection_t *collection,
+                                                          size_t i, size_t *len);
+// The returned properties borrow from the collection.
+const rocksdb_table_properties_t *rocksdb_table_properties_collection_get(
+    const rocksdb_table_properties_collection_t *collection, size_t i);
+uint64_t rocksdb_table_properties_get_orig_file_number(const rocksdb_table_properties_t *props);
+uint64_t rocksdb_table_properties_get_data_size(const rocksdb_table_properties_t *props);
+uint64_t rocksdb_table_properties_get_index_size(const rocksdb_table_properties_t *props);
+uint64_t rocksdb_table_properties_get_filter_size(const rocksdb_table_properties_t *props);
+uint64_t rocksdb_table_properties_get_raw_key_size(const rocksdb_table_properties_t *props);
+uint64_t rocksdb_table_properties_get_raw_value_size(const rocksdb_table_properties_t *props);
+uint64_t rocksdb_table_properties_get_num_data_blocks(const rocksdb_table_properties_t *props);
+uint64_t rocksdb_table_properties_get_num_entries(const rocksdb_table_properties_t *props);
+uint64_t rocksdb_table_properties_get_num_deletions(const rocksdb_table_properties_t *props);
+uint64_t rocksdb_table_properties_get_num_merge_operands(const rocksdb_table_properties_t *props);
+uint64_t rocksdb_table_properties_get_num_range_deletions(const rocksdb_table_properties_t *props);
+uint64_t rocksdb_table_properties_get_format_version(const rocksdb_table_properties_t *props);
+uint64_t rocksdb_table_properties_get_fixed_key_len(const rocksdb_table_properties_t *props);
+uint64_t rocksdb_table_properties_get_column_family_id(const rocksdb_table_properties_t *props);
+uint64_t rocksdb_table_properties_get_creation_time(const rocksdb_table_properties_t *props);
+uint64_t rocksdb_table_properties_get_oldest_key_time(const rocksdb_table_properties_t *props);
+uint64_t rocksdb_table_properties_get_file_creation_time(const rocksdb_table_properties_t *props);
+const char *rocksdb_table_properties_get_column_family_name(const rocksdb_table_properties_t *props, size_t *len);
+const char *rocksdb_table_properties_get_filter_policy_name(const rocksdb_table_properties_t *props, size_t *len);
+const char *rocksdb_table_properties_get_comparator_name(const rocksdb_table_properties_t *props, size_t *len);
+const char *rocksdb_table_properties_get_merge_operator_name(const rocksdb_table_properties_t *props, size_t *len);
+const char *rocksdb_table_properties_get_prefix_extractor_name(const rocksdb_table_properties_t *props, size_t *len);
+const char *rocksdb_table_properties_get_property_collectors_names(const rocksdb_table_properties_t *props,
+                                                                   size_t *len);
+const char *rocksdb_table_properties_get_compression_name(const rocksdb_table_properties_t *props, size_t *len);
+const char *rocksdb_table_properties_get_compression_options(const rocksdb_table_properties_t *props, size_t *len);
+// Calls `callback` with each key and value collected by table properties
+// collectors.
+void rocksdb_table_properties_iterate_user_collected_properties(
+    const rocksdb_table_properties_t *props, void *state,
+    void (*callback)(void *, const char *, size_t, const char *, size_t));
+// `add_user_key` receives the key, the value, the `EntryType`, the sequence
+// number and the current file size.
+rocksdb_table_properties_collector_t *rocksdb_table_properties_collector_create(
+    void *state, void (*destructor)(void *),
+    void (*add_user_key)(void *, const char *, size_t, const char *, size_t, int, uint64_t, uint64_t),
+    void (*finish)(void *, rocksdb_user_collected_properties_t *), unsigned char (*need_compact)(void *),
+    const char *(*name)(void *));
+// `create` receives the column family id and the level the file is created
+// at, or -1 if unknown. It is called concurrently.
+rocksdb_table_properties_collector_factory_t *rocksdb_table_properties_collector_factory_create(
+    void *state, void (*destructor)(void *), rocksdb_table_properties_collector_t *(*create)(void *, uint32_t, int),
+    const char *(*name)(void *));
+// Takes ownership of `factory`.
+void rocksdb_options_add_table_properties_collector_factory(rocksdb_options_t *opt,
+                                                            rocksdb_table_properties_collector_factory_t *factory);
+void rocksdb_user_collected_properties_insert(rocksdb_user_collected_properties_t *properties, const char *key,
+                                              size_t key_len, const char *value, size_t value_len);
+
+/* thread_status */
+typedef struct rocksdb_env_t rocksdb_env_t;
+typedef struct rocksdb_thread_status_list_t rocksdb_thread_status_list_t;
+
+// `priority` is an `Env::Priority`.
+unsigned int rocksdb_env_get_thread_pool_queue_len(rocksdb_env_t *env, int priority);
+int rocksdb_env_get_background_threads_with_priority(rocksdb_env_t *env, int priority);
+void rocksdb_options_set_enable_thread_tracking(rocksdb_options_t *opt, unsigned char v);
+rocksdb_thread_status_list_t *rocksdb_env_get_thread_list(rocksdb_env_t *env, rocksdb_status_t **statusptr);
+void rocksdb_thread_status_list_destroy(rocksdb_thread_status_list_t *list);
+size_t rocksdb_thread_status_list_count(const rocksdb_thread_status_list_t *list);
+uint64_t rocksdb_thread_status_list_get_thread_id(const rocksdb_thread_status_list_t *list, size_t i);
+int rocksdb_thread_status_list_get_thread_type(const rocksdb_thread_status_list_t *list, size_t i);
+const char *rocksdb_thread_status_list_get_db_name(const rocksdb_thread_status_list_t *list, size_t i,
+                                                   size_t *len);
+const char *rocksdb_thread_status_list_get_cf_name(const rocksdb_thread_status_list_t *list, size_t i,
+                                                   size_t *len);
+int rocksdb_thread_status_list_get_operation_type(const rocksdb_thread_status_list_t *list, size_t i);
+uint64_t rocksdb_thread_status_list_get_op_elapsed_micros(const rocksdb_thread_status_list_t *list, size_t i);
+int rocksdb_thread_status_list_get_operation_stage(const rocksdb_thread_status_list_t *list, size_t i);
+int rocksdb_thread_status_list_get_state_type(const rocksdb_thread_status_list_t *list, size_t i);
+// Calls `callback` with the name and value of each property of the current
+// operation, e.g. the job id and the input level of a compaction.
+void rocksdb_thread_status_list_iterate_operation_properties(const rocksdb_thread_status_list_t *list, size_t i,
+                                                             void *state,
+                                                             void (*callback)(void *, const char *, size_t, uint64_t));
+
+/* encryption */
+typedef struct rocksdb_env_t rocksdb_env_t;
+typedef struct rocksdb_encryption_provider_t rocksdb_encryption_provider_t;
+
+// Creates a provider that encrypts files in CTR mode with a block cipher
+// whose `encrypt` and `decrypt` callbacks transform one block of
+// `block_size` bytes in place. They are called concurrently and must not
+// fail. `destructor` is called with `state` once the cipher is no longer
+// used.
+rocksdb_encryption_provider_t *rocksdb_encryption_provider_create_ctr(void *state, void (*destructor)(void *),
+                                                                      size_t block_size,
+                                                                      void (*encrypt)(void *, char *),
+                                                                      void (*decrypt)(void *, char *));
+// Creates a CTR provider with a ROT13 cipher, for tests only.
+rocksdb_encryption_provider_t *rocksdb_encryption_provider_create_ctr_rot13(size_t block_size);
+void rocksdb_encryption_provider_destroy(rocksdb_encryption_provider_t *provider);
+// Creates an env that encrypts the files it writes through `base` and
+// decrypts the files it reads. `base` must outlive the returned env.
+rocksdb_env_t *rocksdb_create_encrypted_env(rocksdb_env_t *base, rocksdb_encryption_provider_t *provider);
+
+/* file_system */
+typedef struct rocksdb_env_t rocksdb_env_t;
+typedef struct rocksdb_file_system_t rocksdb_file_system_t;
+typedef struct rocksdb_fs_sequential_file_t rocksdb_fs_sequential_file_t;
+typedef struct rocksdb_fs_random_access_file_t rocksdb_fs_random_access_file_t;
+typedef struct rocksdb_fs_writable_file_t rocksdb_fs_writable_file_t;
+typedef struct rocksdb_fs_file_lock_t rocksdb_fs_file_lock_t;
+typedef struct rocksdb_fs_children_t rocksdb_fs_children_t;
+typedef struct rocksdb_fs_path_t rocksdb_fs_path_t;
+
+// Creates a file system that calls back into `state` for every file
+// operation. Paths are not NUL-terminated. A callback reports an error by
+// setting `*statusptr` to a status created with `rocksdb_status_create`,
+// which the file system takes ownership of. The `new_*_file` and `lock_file`
+// callbacks return objects created with the `rocksdb_fs_*_create`
+// functions, or null on error. `get_absolute_path` sets its result with
+// `rocksdb_fs_path_set`. `destructor` is called with `state` once the file
+// system is no longer used.
+rocksdb_file_system_t *rocksdb_file_system_create(
+    void *state, void (*destructor)(void *),
+    rocksdb_fs_sequential_file_t *(*new_sequential_file)(void *, const char *, size_t, rocksdb_status_t **),
+    rocksdb_fs_random_access_file_t *(*new_random_access_file)(void *, const char *, size_t, rocksdb_status_t **),
+    rocksdb_fs_writable_file_t *(*new_writable_file)(void *, const char *, size_t, rocksdb_status_t **),
+    unsigned char (*file_exists)(void *, const char *, size_t, rocksdb_status_t **),
+    void (*get_children)(void *, const char *, size_t, rocksdb_fs_children_t *, rocksdb_status_t **),
+    void (*delete_file)(void *, const char *, size_t, rocksdb_status_t **),
+    void (*create_dir)(void *, const char *, size_t, rocksdb_status_t **),
+    void (*create_dir_if_missing)(void *, const char *, size_t, rocksdb_status_t **),
+    void (*delete_dir)(void *, const char *, size_t, rocksdb_status_t **),
+    void (*fsync_dir)(void *, const char *, size_t, rocksdb_status_t **),
+    uint64_t (*get_file_size)(void *, const char *, size_t, rocksdb_status_t **),
+    uint64_t (*get_file_modification_time)(void *, const char *, size_t, rocksdb_status_t **),
+    void (*rename_file)(void *, const char *, size_t, const char *, size_t, rocksdb_status_t **),
+    rocksdb_fs_file_lock_t *(*lock_file)(void *, const char *, size_t, rocksdb_status_t **),
+    unsigned char (*is_directory)(void *, const char *, size_t, rocksdb_status_t **),
+    void (*get_absolute_path)(void *, const char *, size_t, rocksdb_fs_path_t *, rocksdb_status_t **));
+// Returns the default file system. It must not be destroyed.
+rocksdb_file_system_t *rocksdb_file_system_default();
+void rocksdb_file_system_destroy(rocksdb_file_system_t *fs);
+// Creates an env that accesses files through `fs` and uses the default env
+// for everything else.
+rocksdb_env_t *rocksdb_create_env_from_file_system(rocksdb_file_system_t *fs);
+
+// `read` fills the buffer and returns the number of bytes read, which is
+// less than requested only at the end of the file.
+rocksdb_fs_sequential_file_t *rocksdb_fs_sequential_file_create(void *state, void (*destructor)(void *),
+                                                                 size_t (*read)(void *, char *, size_t, rocksdb_status_t **),
+                                                                 void (*skip)(void *, uint64_t, rocksdb_status_t **));
+rocksdb_fs_random_access_file_t *rocksdb_fs_random_access_file_create(
+    void *state, void (*destructor)(void *), size_t (*read)(void *, uint64_t, char *, size_t, rocksdb_status_t **));
+rocksdb_fs_writable_file_t *rocksdb_fs_writable_file_create(void *state, void (*destructor)(void *),
+                                                            void (*append)(void *, const char *, size_t, rocksdb_status_t **),
+                                                            void (*flush)(void *, rocksdb_status_t **),
+                                                            void (*sync)(void *, rocksdb_status_t **),
+                                                            void (*close)(void *, rocksdb_status_t **),
+                                                            uint64_t (*get_file_size)(void *));
+// `unlock` is called once, with `state`, when the lock is released.
+rocksdb_fs_file_lock_t *rocksdb_fs_file_lock_create(void *state, void (*unlock)(void *, rocksdb_status_t **));
+void rocksdb_fs_children_push(rocksdb_fs_children_t *children, const char *name, size_t name_len);
+void rocksdb_fs_path_set(rocksdb_fs_path_t *path, const char *value, size_t value_len);
+
+rocksdb_fs_sequential_file_t *rocksdb_file_system_new_sequential_file(rocksdb_file_system_t *fs, const char *fname,
+                                                                      size_t fname_len, rocksdb_status_t **statusptr);
+rocksdb_fs_random_access_file_t *rocksdb_file_system_new_random_access_file(rocksdb_file_system_t *fs,
+                                                                            const char *fname, size_t fname_len,
+                                                                            rocksdb_status_t **statusptr);
+rocksdb_fs_writable_file_t *rocksdb_file_system_new_writable_file(rocksdb_file_system_t *fs, const char *fname,
+                                                                  size_t fname_len, rocksdb_status_t **statusptr);
+unsigned char rocksdb_file_system_file_exists(rocksdb_file_system_t *fs, const char *fname, size_t fname_len,
+                                              rocksdb_status_t **statusptr);
+// Calls `push` with the name of each entry of `dir`.
+void rocksdb_file_system_get_children(rocksdb_file_system_t *fs, const char *dir, size_t dir_len, void *state,
+                                      void (*push)(void *, const char *, size_t), rocksdb_status_t **statusptr);
+void rocksdb_file_system_delete_file(rocksdb_file_system_t *fs, const char *fname, size_t fname_len, rocksdb_status_t **statusptr);
+void rocksdb_file_system_create_dir(rocksdb_file_system_t *fs, const char *dir, size_t dir_len, rocksdb_status_t **statusptr);
+void rocksdb_file_system_create_dir_if_missing(rocksdb_file_system_t *fs, const char *dir, size_t dir_len,
+                                               rocksdb_status_t **statusptr);
+void rocksdb_file_system_delete_dir(rocksdb_file_system_t *fs, const char *dir, size_t dir_len, rocksdb_status_t **statusptr);
+void rocksdb_file_system_fsync_dir(rocksdb_file_system_t *fs, const char *dir, size_t dir_len, rocksdb_status_t **statusptr);
+uint64_t rocksdb_file_system_get_file_size(rocksdb_file_system_t *fs, const char *fname, size_t fname_len,
+                                           rocksdb_status_t **statusptr);
+uint64_t rocksdb_file_system_get_file_modification_time(rocksdb_file_system_t *fs, const char *fname,
+                                                        size_t fname_len, rocksdb_status_t **statusptr);
+void rocksdb_file_system_rename_file(rocksdb_file_system_t *fs, const char *src, size_t src_len, const char *target,
+                                     size_t target_len, rocksdb_status_t **statusptr);
+rocksdb_fs_file_lock_t *rocksdb_file_system_lock_file(rocksdb_file_system_t *fs, const char *fname,
+                                                      size_t fname_len, rocksdb_status_t **statusptr);
+unsigned char rocksdb_file_system_is_directory(rocksdb_file_system_t *fs, const char *path, size_t path_len,
+                                               rocksdb_status_t **statusptr);
+// Returns the absolute path, `malloc`ed and not NUL-terminated.
+char *rocksdb_file_system_get_absolute_path(rocksdb_file_system_t *fs, const char *path, size_t path_len,
+                                            size_t *output_len, rocksdb_status_t **statusptr);
+// Releases the lock and destroys `lock`, even on error.
+void rocksdb_file_system_unlock_file(rocksdb_fs_file_lock_t *lock, rocksdb_status_t **statusptr);
+
+size_t rocksdb_fs_sequential_file_read(rocksdb_fs_sequential_file_t *file, char *scratch, size_t n, rocksdb_status_t **statusptr);
+void rocksdb_fs_sequential_file_skip(rocksdb_fs_sequential_file_t *file, uint64_t n, rocksdb_status_t **statusptr);
+void rocksdb_fs_sequential_file_destroy(rocksdb_fs_sequential_file_t *file);
+size_t rocksdb_fs_random_access_file_read(rocksdb_fs_random_access_file_t *file, uint64_t offset, char *scratch,
+                                          size_t n, rocksdb_status_t **statusptr);
+void rocksdb_fs_random_access_file_destroy(rocksdb_fs_random_access_file_t *file);
+void rocksdb_fs_writable_file_append(rocksdb_fs_writable_file_t *file, const char *data, size_t len, rocksdb_status_t **statusptr);
+void rocksdb_fs_writable_file_flush(rocksdb_fs_writable_file_t *file, rocksdb_status_t **statusptr);
+void rocksdb_fs_writable_file_sync(rocksdb_fs_writable_file_t *file, rocksdb_status_t **statusptr);
+void rocksdb_fs_writable_file_close(rocksdb_fs_writable_file_t *file, rocksdb_status_t **statusptr);
+uint64_t rocksdb_fs_writable_file_get_file_size(rocksdb_fs_writable_file_t *file);
+void rocksdb_fs_writable_file_destroy(rocksdb_fs_writable_file_t *file);
+
+/* system_clock */
+typedef struct rocksdb_system_clock_t rocksdb_system_clock_t;
+
+// Creates a clock that reads the wall clock time, in microseconds since the
+// Unix epoch, and a monotonic time, in nanoseconds, from callbacks, and sleeps
+// by calling `sleep_for_micros`. `destructor` is called with `state` once the
+// clock is no longer used.
+rocksdb_system_clock_t *rocksdb_system_clock_create(void *state, void (*destructor)(void *),
+                                                    uint64_t (*now_micros)(void *), uint64_t (*now_nanos)(void *),
+                                                    void (*sleep_for_micros)(void *, uint64_t));
+void rocksdb_system_clock_destroy(rocksdb_system_clock_t *clock);
+// Creates an env that reads the time from `clock`, accesses files through
+// `fs`, or the default file system if null, and uses the default env for
+// everything else.
+rocksdb_env_t *rocksdb_create_env_with_clock(rocksdb_file_system_t *fs, rocksdb_system_clock_t *clock);
+
+/* logger */
+typedef struct rocksdb_logger_t rocksdb_logger_t;
+
+// Creates a logger that formats messages at or above `log_level` and passes
+// them, with their `InfoLogLevel`, to `log`. The message is not
+// NUL-terminated. `destructor` is called with `state` once the logger is no
+// longer used by any options or database.
+rocksdb_logger_t *rocksdb_logger_create_callback_logger(int log_level, void *state, void (*destructor)(void *),
+                                                        void (*log)(void *, int, const char *, size_t));
+void rocksdb_logger_destroy(rocksdb_logger_t *logger);
+
+/* statistics */
+typedef struct rocksdb_statistics_t rocksdb_statistics_t;
+typedef struct rocksdb_statistics_histogram_data_t rocksdb_statistics_histogram_data_t;
+
+rocksdb_statistics_t *rocksdb_statistics_create();
+void rocksdb_statistics_destroy(rocksdb_statistics_t *stats);
+void rocksdb_options_set_statistics(rocksdb_options_t *opt, rocksdb_statistics_t *stats);
+// Returns the type of the ticker or histogram called `name` in the linked
+// RocksDB version, or -1 if there is none.
+int rocksdb_statistics_ticker_type(const char *name, size_t name_len);
+int rocksdb_statistics_histogram_type(const char *name, size_t name_len);
+uint64_t rocksdb_statistics_get_ticker_count(rocksdb_statistics_t *stats, uint32_t ticker_type);
+void rocksdb_statistics_get_histogram_data(rocksdb_statistics_t *stats, uint32_t histogram_type,
+                                           rocksdb_statistics_histogram_data_t *data);
+void rocksdb_statistics_reset(rocksdb_statistics_t *stats, rocksdb_status_t **statusptr);
+void rocksdb_statistics_set_stats_level(rocksdb_statistics_t *stats, unsigned char level);
+unsigned char rocksdb_statistics_get_stats_level(rocksdb_statistics_t *stats);
+char *rocksdb_statistics_to_string(rocksdb_statistics_t *stats);
+
+rocksdb_statistics_histogram_data_t *rocksdb_statistics_histogram_data_create();
+void rocksdb_statistics_histogram_data_destroy(rocksdb_statistics_histogram_data_t *data);
+double rocksdb_statistics_histogram_data_get_median(rocksdb_statistics_histogram_data_t *data);
+double rocksdb_statistics_histogram_data_get_p95(rocksdb_statistics_histogram_data_t *data);
+double rocksdb_statistics_histogram_data_get_p99(rocksdb_statistics_histogram_data_t *data);
+double rocksdb_statistics_histogram_data_get_average(rocksdb_statistics_histogram_data_t *data);
+double rocksdb_statistics_histogram_data_get_std_dev(rocksdb_statistics_histogram_data_t *data);
+double rocksdb_statistics_histogram_data_get_max(rocksdb_statistics_histogram_data_t *data);
+double rocksdb_statistics_histogram_data_get_min(rocksdb_statistics_histogram_data_t *data);
+uint64_t rocksdb_statistics_histogram_data_get_count(rocksdb_statistics_histogram_data_t *data);
+uint64_t rocksdb_statistics_histogram_data_get_sum(rocksdb_statistics_histogram_data_t *data);
+
+/* event_listener */
+typedef struct rocksdb_eventlistener_t rocksdb_eventlistener_t;
+typedef struct rocksdb_flushjobinfo_t rocksdb_flushjobinfo_t;
+typedef struct rocksdb_compactionjobinfo_t rocksdb_compactionjobinfo_t;
+typedef struct rocksdb_tablefilecreationinfo_t rocksdb_tablefilecreationinfo_t;
+typedef struct rocksdb_tablefiledeletioninfo_t rocksdb_tablefiledeletioninfo_t;
+typedef struct rocksdb_externalfileingestioninfo_t rocksdb_externalfileingestioninfo_t;
+typedef struct rocksdb_writestallinfo_t rocksdb_writestallinfo_t;
+
+/* flushjobinfo */
+const char *rocksdb_flushjobinfo_cf_name(const rocksdb_flushjobinfo_t *info, size_t *len);
+const char *rocksdb_flushjobinfo_file_path(const rocksdb_flushjobinfo_t *info, size_t *len);
+int rocksdb_flushjobinfo_job_id(const rocksdb_flushjobinfo_t *info);
+uint64_t rocksdb_flushjobinfo_thread_id(const rocksdb_flushjobinfo_t *info);
+unsigned char rocksdb_flushjobinfo_triggered_writes_slowdown(const rocksdb_flushjobinfo_t *info);
+unsigned char rocksdb_flushjobinfo_triggered_writes_stop(const rocksdb_flushjobinfo_t *info);
+uint64_t rocksdb_flushjobinfo_smallest_seqno(const rocksdb_flushjobinfo_t *info);
+uint64_t rocksdb_flushjobinfo_largest_seqno(const rocksdb_flushjobinfo_t *info);
+uint32_t rocksdb_flushjobinfo_flush_reason(const rocksdb_flushjobinfo_t *info);
+
+/* compactionjobinfo */
+void rocksdb_compactionjobinfo_status(const rocksdb_compactionjobinfo_t *info, rocksdb_status_t **statusptr);
+const char *rocksdb_compactionjobinfo_cf_name(const rocksdb_compactionjobinfo_t *info, size_t *len);
+size_t rocksdb_compactionjobinfo_input_files_count(const rocksdb_compactionjobinfo_t *info);
+const char *rocksdb_compactionjobinfo_input_file_at(const rocksdb_compactionjobinfo_t *info, size_t pos, size_t *len);
+size_t rocksdb_compactionjobinfo_output_files_count(const rocksdb_compactionjobinfo_t *info);
+const char *rocksdb_compactionjobinfo_output_file_at(const rocksdb_compactionjobinfo_t *info, size_t pos, size_t *len);
+uint64_t rocksdb_compactionjobinfo_thread_id(const rocksdb_compactionjobinfo_t *info);
+int rocksdb_compactionjobinfo_job_id(const rocksdb_compactionjobinfo_t *info);
+int rocksdb_compactionjobinfo_base_input_level(const rocksdb_compactionjobinfo_t *info);
+int rocksdb_compactionjobinfo_output_level(const rocksdb_compactionjobinfo_t *info);
+uint32_t rocksdb_compactionjobinfo_compaction_reason(const rocksdb_compactionjobinfo_t *info);
+uint64_t rocksdb_compactionjobinfo_elapsed_micros(const rocksdb_compactionjobinfo_t *info);
+uint64_t rocksdb_compactionjobinfo_cpu_micros(const rocksdb_compactionjobinfo_t *info);
+uint64_t rocksdb_compactionjobinfo_input_records(const rocksdb_compactionjobinfo_t *info);
+size_t rocksdb_compactionjobinfo_num_input_files(const rocksdb_compactionjobinfo_t *info);
+size_t rocksdb_compactionjobinfo_num_input_files_at_output_level(const rocksdb_compactionjobinfo_t *info);
+uint64_t rocksdb_compactionjobinfo_output_records(const rocksdb_compactionjobinfo_t *info);
+size_t rocksdb_compactionjobinfo_num_output_files(const rocksdb_compactionjobinfo_t *info);
+unsigned char rocksdb_compactionjobinfo_is_full_compaction(const rocksdb_compactionjobinfo_t *info);
+unsigned char rocksdb_compactionjobinfo_is_manual_compaction(const rocksdb_compactionjobinfo_t *info);
+uint64_t rocksdb_compactionjobinfo_total_input_bytes(const rocksdb_compactionjobinfo_t *info);
+uint64_t rocksdb_compactionjobinfo_total_output_bytes(const rocksdb_compactionjobinfo_t *info);
+uint64_t rocksdb_compactionjobinfo_num_records_replaced(const rocksdb_compactionjobinfo_t *info);
+uint64_t rocksdb_compactionjobinfo_total_input_raw_key_bytes(const rocksdb_compactionjobinfo_t *info);
+uint64_t rocksdb_compactionjobinfo_total_input_raw_value_bytes(const rocksdb_compactionjobinfo_t *info);
+uint64_t rocksdb_compactionjobinfo_num_input_deletion_records(const rocksdb_compactionjobinfo_t *info);
+uint64_t rocksdb_compactionjobinfo_num_expired_deletion_records(const rocksdb_compactionjobinfo_t *info);
+uint64_t rocksdb_compactionjobinfo_num_corrupt_keys(const rocksdb_compactionjobinfo_t *info);
+
+/* tablefilecreationinfo */
+void rocksdb_tablefilecreationinfo_status(const rocksdb_tablefilecreationinfo_t *info, rocksdb_status_t **statusptr);
+const char *rocksdb_tablefilecreationinfo_db_name(const rocksdb_tablefilecreationinfo_t *info, size_t *len);
+const char *rocksdb_tablefilecreationinfo_cf_name(const rocksdb_tablefilecreationinfo_t *info, size_t *len);
+const char *rocksdb_tablefilecreationinfo_file_path(const rocksdb_tablefilecreationinfo_t *info, size_t *len);
+const char *rocksdb_tablefilecreationinfo_file_checksum(const rocksdb_tablefilecreationinfo_t *info, size_t *len);
+const char *rocksdb_tablefilecreationinfo_file_checksum_func_name(const rocksdb_tablefilecreationinfo_t *info, size_t *len);
+int rocksdb_tablefilecreationinfo_job_id(const rocksdb_tablefilecreationinfo_t *info);
+uint32_t rocksdb_tablefilecreationinfo_reason(const rocksdb_tablefilecreationinfo_t *info);
+uint64_t rocksdb_tablefilecreationinfo_file_size(const rocksdb_tablefilecreationinfo_t *info);
+
+/* tablefiledeletioninfo */
+void rocksdb_tablefiledeletioninfo_status(const rocksdb_tablefiledeletioninfo_t *info, rocksdb_status_t **statusptr);
+const char *rocksdb_tablefiledeletioninfo_db_name(const rocksdb_tablefiledeletioninfo_t *info, size_t *len);
+const char *rocksdb_tablefiledeletioninfo_file_path(const rocksdb_tablefiledeletioninfo_t *info, size_t *len);
+int rocksdb_tablefiledeletioninfo_job_id(const rocksdb_tablefiledeletioninfo_t *info);
+
+/* externalfileingestioninfo */
+const char *rocksdb_externalfileingestioninfo_cf_name(const rocksdb_externalfileingestioninfo_t *info, size_t *len);
+const char *rocksdb_externalfileingestioninfo_external_file_path(const rocksdb_externalfileingestioninfo_t *info, size_t *len);
+const char *rocksdb_externalfileingestioninfo_internal_file_path(const rocksdb_externalfileingestioninfo_t *info, size_t *len);
+uint64_t rocksdb_externalfileingestioninfo_global_seqno(const rocksdb_externalfileingestioninfo_t *info);
+
+/* writestallinfo */
+const char *rocksdb_writestallinfo_cf_name(const rocksdb_writestallinfo_t *info, size_t *len);
+uint32_t rocksdb_writestallinfo_cur(const rocksdb_writestallinfo_t *info);
+uint32_t rocksdb_writestallinfo_prev(const rocksdb_writestallinfo_t *info);
+// Callbacks are called from RocksDB background threads, possibly concurrently.
+// `on_background_error` receives the `BackgroundErrorReason` and the error,
+// which is only valid during the call.
+rocksdb_eventlistener_t *rocksdb_eventlistener_create(
+    void *state, void (*destructor)(void *),
+    void (*on_flush_begin)(void *, const rocksdb_flushjobinfo_t *),
+    void (*on_flush_completed)(void *, const rocksdb_flushjobinfo_t *),
+    void (*on_compaction_begin)(void *, const rocksdb_compactionjobinfo_t *),
+    void (*on_compaction_completed)(void *, const rocksdb_compactionjobinfo_t *),
+    void (*on_table_file_created)(void *, const rocksdb_tablefilecreationinfo_t *),
+    void (*on_table_file_deleted)(void *, const rocksdb_tablefiledeletioninfo_t *),
+    void (*on_external_file_ingested)(void *, const rocksdb_externalfileingestioninfo_t *),
+    void (*on_stall_conditions_changed)(void *, const rocksdb_writestallinfo_t *),
+    void (*on_background_error)(void *, uint32_t, const rocksdb_status_t *));
+// Takes ownership of `listener`.
+void rocksdb_options_add_eventlistener(rocksdb_options_t *opt, rocksdb_eventlistener_t *listener);
+
 #ifdef __cplusplus
 }
 #endif
@@ -0,0 +1,19 @@
+// Implementation of `Checkpoint` functions in `c.h`.
+#include "c_api_extensions/ctypes.hpp"
+
+using namespace ROCKSDB_NAMESPACE;
+
+extern "C" {
+rocksdb_checkpoint_t* rocksdb_checkpoint_object_create_with_status(rocksdb_t* db, rocksdb_status_t** statusptr) {
+  Checkpoint* checkpoint;
+  if (SaveStatus(statusptr, Checkpoint::Create(db->rep, &checkpoint))) {
+    return nullptr;
+  }
+  return new rocksdb_checkpoint_t{checkpoint};
+}
+
+void rocksdb_checkpoint_create_with_status(rocksdb_checkpoint_t* checkpoint, const char* checkpoint_dir,
+                                           uint64_t log_size_for_flush, rocksdb_status_t** statusptr) {
+  SaveStatus(statusptr, checkpoint->rep->CreateCheckpoint(std::string(checkpoint_dir), log_size_for_flush));
+}
+}
@@ -0,0 +1,139 @@
+// Implementation of `CompactionFilter` functions in `c.h`.
+#include "c_api_extensions/ctypes.hpp"
+#include "rocksdb/compaction_filter.h"
+
+using namespace ROCKSDB_NAMESPACE;
+
+// Filters values and merge operands with C callbacks that return a
+// `CompactionFilter::Decision` and copy the new value or the key to skip
+// until into `result`. `filter_blob_by_key` may return `kUndetermined` to
+// read the blob value and call `filter` with it.
+struct rocksdb_compactionfilter_v2_t : public CompactionFilter {
+  rocksdb_compactionfilter_v2_t(void* state, void (*destructor)(void*),
+                           int (*filter)(void*, int, const char*, size_t, int, const char*, size_t,
+                                         rocksdb_compactionfilter_result_t*),
+                           int (*filter_blob_by_key)(void*, int, const char*, size_t,
+                                                     rocksdb_compactionfilter_result_t*),
+                           const char* (*name)(void*))
+      : state_(state),
+        destructor_(destructor),
+        filter_(filter),
+        filter_blob_by_key_(filter_blob_by_key),
+        name_(name) {}
+
+  ~rocksdb_compactionfilter_v2_t() override { (*destructor_)(state_); }
+
+  // Entities are filtered by the value of their default column, empty if
+  // they have none.
+  Decision FilterV3(int level, const Slice& key, ValueType value_type, const Slice* existing_value,
+                    const WideColumns* existing_columns, std::string* new_value,
+                    std::vector<std::pair<std::string, std::string>>* /* new_columns */,
+                    std::string* skip_until) const override {
+    Slice value;
+    if (existing_value != nullptr) {
+      value = *existing_value;
+    } else if (existing_columns != nullptr && !existing_columns->empty() &&
+               existing_columns->front().name() == kDefaultWideColumnName) {
+      value = existing_columns->front().value();
+    }
+    rocksdb_compactionfilter_result_t result{new_value, skip_until};
+    return static_cast<Decision>((*filter_)(state_, level, key.data(), key.size(), static_cast<int>(value_type),
+                                            value.data(), value.size(), &result));
+  }
+
+  Decision FilterBlobByKey(int level, const Slice& key, std::string* new_value,
+                           std::string* skip_until) const override {
+    rocksdb_compactionfilter_result_t result{new_value, skip_until};
+    return static_cast<Decision>((*filter_blob_by_key_)(state_, level, key.data(), key.size(), &result));
+  }
+
+  const char* Name() const override { return (*name_)(state_); }
+
+ private:
+  void* state_;
+  void (*destructor_)(void*);
+  int (*filter_)(void*, int, const char*, size_t, int, const char*, size_t, rocksdb_compactionfilter_result_t*);
+  int (*filter_blob_by_key_)(void*, int, const char*, size_t, rocksdb_compactionfilter_result_t*);
+  const char* (*name_)(void*);
+};
+
+// Creates filters with a C callback, for the kinds of table files that
+// `should_filter_table_file_creation` accepts.
+struct rocksdb_compactionfilterfactory_v2_t : public CompactionFilterFactory {
+  rocksdb_compactionfilterfactory_v2_t(void* state, void (*destructor)(void*),
+                                       rocksdb_compactionfilter_v2_t* (*create_compaction_filter)(
+                                           void*, rocksdb_compactionfiltercontext_t*),
+                                       unsigned char (*should_filter_table_file_creation)(void*, int),
+                                       const char* (*name)(void*))
+      : state_(state),
+        destructor_(destructor),
+        create_compaction_filter_(create_compaction_filter),
+        should_filter_table_file_creation_(should_filter_table_file_creation),
+        name_(name) {}
+
+  ~rocksdb_compactionfilterfactory_v2_t() override { (*destructor_)(state_); }
+
+  bool ShouldFilterTableFileCreation(TableFileCreationReason reason) const override {
+    return (*should_filter_table_file_creation_)(state_, static_cast<int>(reason));
+  }
+
+  std::unique_ptr<CompactionFilter> CreateCompactionFilter(const CompactionFilter::Context& context) override {
+    rocksdb_compactionfiltercontext_t ccontext;
+    ccontext.rep = context;
+    return std::unique_ptr<CompactionFilter>((*create_compaction_filter_)(state_, &ccontext));
+  }
+
+  const char* Name() const override { return (*name_)(state_); }
+
+ private:
+  void* state_;
+  void (*destructor_)(void*);
+  rocksdb_compactionfilter_v2_t* (*create_compaction_filter_)(void*, rocksdb_compactionfiltercontext_t*);
+  unsigned char (*should_filter_table_file_creation_)(void*, int);
+  const char* (*name_)(void*);
+};
+
+extern "C" {
+rocksdb_compactionfilter_v2_t* rocksdb_compactionfilter_create_v2(
+    void* state, void (*destructor)(void*),
+    int (*filter)(void*, int, const char*, size_t, int, const char*, size_t, rocksdb_compactionfilter_result_t*),
+    int (*filter_blob_by_key)(void*, int, const char*, size_t, rocksdb_compactionfilter_result_t*),
+    const char* (*name)(void*)) {
+  return new rocksdb_compactionfilter_v2_t(state, destructor, filter, filter_blob_by_key, name);
+}
+
+void rocksdb_options_set_compaction_filter_v2(rocksdb_options_t* opt, rocksdb_compactionfilter_v2_t* filter) {
+  opt->rep.compaction_filter = filter;
+}
+
+void rocksdb_compactionfilter_result_set_new_value(rocksdb_compactionfilter_result_t* result, const char* value,
+                                                   size_t value_len) {
+  result->new_value->assign(value, value_len);
+}
+
+void rocksdb_compactionfilter_result_set_skip_until(rocksdb_compactionfilter_result_t* result, const char* key,
+                                                    size_t key_len) {
+  result->skip_until->assign(key, key_len);
+}
+
+rocksdb_compactionfilterfactory_v2_t* rocksdb_compactionfilterfactory_create_v2(
+    void* state, void (*destructor)(void*),
+    rocksdb_compactionfilter_v2_t* (*create_compaction_filter)(void*, rocksdb_compactionfiltercontext_t*),
+    unsigned char (*should_filter_table_file_creation)(void*, int), const char* (*name)(void*)) {
+  return new rocksdb_compactionfilterfactory_v2_t(state, destructor, create_compaction_filter,
+                                                  should_filter_table_file_creation, name);
+}
+
+void rocksdb_options_set_compaction_filter_factory_v2(rocksdb_options_t* opt,
+                                                      rocksdb_compactionfilterfactory_v2_t* factory) {
+  opt->rep.compaction_filter_factory = std::shared_ptr<CompactionFilterFactory>(factory);
+}
+
+uint32_t rocksdb_compactionfiltercontext_get_column_family_id(rocksdb_compactionfiltercontext_t* context) {
+  return context->rep.column_family_id;
+}
+
+int rocksdb_compactionfiltercontext_get_reason(rocksdb_compactionfiltercontext_t* context) {
+  return static_cast<int>(context->rep.reason);
+}
+}
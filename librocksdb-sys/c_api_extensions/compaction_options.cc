@@ -0,0 +1,28 @@
+// Implementation of `CompactionOptions` functions in `c.h`.
+#include "c_api_extensions/ctypes.hpp"
+
+using namespace ROCKSDB_NAMESPACE;
+
+extern "C" {
+rocksdb_compactionoptions_t* rocksdb_compactionoptions_create() {
+  auto opt = new rocksdb_compactionoptions_t;
+  // Use the column family's compression settings rather than the C++ default
+  // of Snappy, which may not be compiled in.
+  opt->rep.compression = kDisableCompressionOption;
+  return opt;
+}
+
+void rocksdb_compactionoptions_destroy(rocksdb_compactionoptions_t* opt) { delete opt; }
+
+void rocksdb_compactionoptions_set_compression(rocksdb_compactionoptions_t* opt, int compression) {
+  opt->rep.compression = static_cast<CompressionType>(compression);
+}
+
+void rocksdb_compactionoptions_set_output_file_size_limit(rocksdb_compactionoptions_t* opt, uint64_t limit) {
+  opt->rep.output_file_size_limit = limit;
+}
+
+void rocksdb_compactionoptions_set_max_subcompactions(rocksdb_compactionoptions_t* opt, uint32_t max) {
+  opt->rep.max_subcompactions = max;
+}
+}
@@ -1,11 +1,39 @@
 // Additional cpp types used in `c.h`.
 #pragma once
 
+#include <cassert>
+#include <cstdlib>
+#include <cstring>
 #include <iostream>
+#include <string>
+#include <unordered_set>
+#include <vector>
 
+#include "rocksdb/compaction_filter.h"
+#include "rocksdb/db.h"
+#include "rocksdb/env_encryption.h"
+#include "rocksdb/file_system.h"
+#include "rocksdb/iterator.h"
+#include "rocksdb/merge_operator.h"
+#include "rocksdb/metadata.h"
 #include "rocksdb/options.h"
+#include "rocksdb/rate_limiter.h"
+#include "rocksdb/sst_file_manager.h"
+#include "rocksdb/statistics.h"
+#include "rocksdb/system_clock.h"
+#include "rocksdb/table_properties.h"
+#include "rocksdb/thread_status.h"
+#include "rocksdb/wide_columns.h"
+#include "rocksdb/write_batch.h"
 #include "rocksdb/write_buffer_manager.h"
 #include "rocksdb/cache.h"
+#include "rocksdb/sst_file_writer.h"
+#include "rocksdb/transaction_log.h"
+#include "rocksdb/utilities/backup_engine.h"
+#include "rocksdb/utilities/checkpoint.h"
+#include "rocksdb/utilities/optimistic_transaction_db.h"
+#include "rocksdb/utilities/transaction.h"
+#include "rocksdb/utilities/transaction_db.h"
 
 using std::shared_ptr;
 
@@ -18,12 +46,206 @@ extern "C" {
 #include <stddef.h>
 #include <stdint.h>
 
+/* status */
+struct rocksdb_status_t {
+  Status rep;
+  // `rep.ToString()`, kept for `rocksdb_status_message`.
+  std::string message;
+};
+struct rocksdb_t {
+  DB* rep;
+};
+struct rocksdb_iterator_t {
+  Iterator* rep;
+};
+struct rocksdb_readoptions_t {
+  ReadOptions rep;
+  // stack variables to set pointers to in ReadOptions
+  Slice upper_bound;
+  Slice lower_bound;
+  Slice timestamp;
+  Slice iter_start_ts;
+};
+struct rocksdb_writeoptions_t {
+  WriteOptions rep;
+};
+struct rocksdb_env_t {
+  Env* rep;
+  bool is_default;
+};
 struct rocksdb_options_t {
   Options rep;
 };
+struct rocksdb_compactionoptions_t {
+  CompactionOptions rep;
+};
+struct rocksdb_flushoptions_t {
+  FlushOptions rep;
+};
+struct rocksdb_ingestexternalfileoptions_t {
+  IngestExternalFileOptions rep;
+};
+struct rocksdb_pinnableslice_t {
+  PinnableSlice rep;
+};
+struct rocksdb_wal_iterator_t {
+  TransactionLogIterator* rep;
+};
+struct rocksdb_wal_readoptions_t {
+  TransactionLogIterator::ReadOptions rep;
+};
+/* backup */
+struct rocksdb_backup_engine_t {
+  BackupEngine* rep;
+};
+struct rocksdb_backup_engine_options_t {
+  BackupEngineOptions rep;
+};
+struct rocksdb_restore_options_t {
+  RestoreOptions rep;
+};
+/* checkpoint */
+struct rocksdb_checkpoint_t {
+  Checkpoint* rep;
+};
+/* sst_file_writer */
+struct rocksdb_sstfilewriter_t {
+  SstFileWriter* rep;
+};
+/* merge_operator */
+struct rocksdb_mergeoperator_v2_t;
+struct rocksdb_mergeoperator_result_t {
+  std::string* value;
+  MergeOperator::OpFailureScope op_failure_scope;
+  std::string error;
+};
+/* compaction_filter */
+struct rocksdb_compactionfilter_v2_t;
+struct rocksdb_compactionfilterfactory_v2_t;
+struct rocksdb_compactionfiltercontext_t {
+  CompactionFilter::Context rep;
+};
+struct rocksdb_compactionfilter_result_t {
+  std::string* new_value;
+  std::string* skip_until;
+};
+struct rocksdb_column_family_handle_t {
+  ColumnFamilyHandle* rep;
+};
+struct rocksdb_writebatch_t {
+  WriteBatch rep;
+};
+struct rocksdb_transaction_t {
+  Transaction* rep;
+};
+struct rocksdb_transactiondb_t {
+  TransactionDB* rep;
+};
+struct rocksdb_transactiondb_options_t {
+  TransactionDBOptions rep;
+};
+struct rocksdb_optimistictransactiondb_t {
+  OptimisticTransactionDB* rep;
+};
 struct rocksdb_cache_t {
   std::shared_ptr<Cache> rep;
 };
+/* perf */
+struct rocksdb_memory_consumers_t {
+  std::vector<rocksdb_t*> dbs;
+  std::unordered_set<rocksdb_cache_t*> caches;
+};
+struct rocksdb_memory_usage_t {
+  uint64_t mem_table_total;
+  uint64_t mem_table_unflushed;
+  uint64_t mem_table_readers_total;
+  uint64_t cache_total;
+};
+/* metadata */
+struct rocksdb_column_family_metadata_t {
+  ColumnFamilyMetaData rep;
+};
+struct rocksdb_level_metadata_t {
+  const LevelMetaData* rep;
+};
+struct rocksdb_sst_file_metadata_t {
+  const SstFileMetaData* rep;
+};
+struct rocksdb_blob_metadata_t {
+  const BlobMetaData* rep;
+};
+/* wide_columns */
+struct rocksdb_pinnable_wide_columns_t {
+  PinnableWideColumns rep;
+};
+/* event_listener */
+// Never defined: these point to the corresponding C++ info structs.
+struct rocksdb_flushjobinfo_t;
+struct rocksdb_compactionjobinfo_t;
+struct rocksdb_tablefilecreationinfo_t;
+struct rocksdb_tablefiledeletioninfo_t;
+struct rocksdb_externalfileingestioninfo_t;
+struct rocksdb_writestallinfo_t;
+/* table_properties */
+struct rocksdb_table_properties_t;
+struct rocksdb_user_collected_properties_t;
+struct rocksdb_table_properties_collection_t {
+  std::vector<std::pair<std::string, std::shared_ptr<const TableProperties>>> rep;
+};
+/* thread_status */
+struct rocksdb_thread_status_list_t {
+  std::vector<ThreadStatus> rep;
+};
+/* encryption */
+struct rocksdb_encryption_provider_t {
+  std::shared_ptr<EncryptionProvider> rep;
+};
+/* file_system */
+struct rocksdb_file_system_t {
+  std::shared_ptr<FileSystem> rep;
+};
+struct rocksdb_fs_sequential_file_t {
+  std::unique_ptr<FSSequentialFile> rep;
+};
+struct rocksdb_fs_random_access_file_t {
+  std::unique_ptr<FSRandomAccessFile> rep;
+};
+struct rocksdb_fs_writable_file_t {
+  std::unique_ptr<FSWritableFile> rep;
+};
+struct rocksdb_fs_file_lock_t {
+  FileLock* rep;
+  // The file system to unlock `rep` with, or null for locks created from C.
+  std::shared_ptr<FileSystem> fs;
+};
+// Never defined: points to the `std::vector<std::string>` of `GetChildren`.
+struct rocksdb_fs_children_t;
+struct rocksdb_fs_path_t {
+  std::string rep;
+};
+/* system_clock */
+struct rocksdb_system_clock_t {
+  std::shared_ptr<SystemClock> rep;
+};
+/* logger */
+struct rocksdb_logger_t {
+  std::shared_ptr<Logger> rep;
+};
+/* rate_limiter */
+struct rocksdb_ratelimiter_t {
+  std::shared_ptr<RateLimiter> rep;
+};
+/* sst_file_manager */
+struct rocksdb_sst_file_manager_t {
+  std::shared_ptr<SstFileManager> rep;
+};
+/* statistics */
+struct rocksdb_statistics_t {
+  std::shared_ptr<Statistics> rep;
+};
+struct rocksdb_statistics_histogram_data_t {
+  HistogramData rep;
+};
 /* write_buffer_manager */
 struct rocksdb_write_buffer_manager_t {
   std::shared_ptr<WriteBufferManager> rep;
@@ -32,3 +254,69 @@ struct rocksdb_write_buffer_manager_t {
 #ifdef __cplusplus
 }
 #endif
+
+// Like `SaveError` in `db/c.cc`, keeping the code, subcode, severity and
+// retryable flag of `s` along with its message.
+static inline bool SaveStatus(rocksdb_status_t** statusptr, const Status& s) {
+  assert(statusptr != nullptr);
+  if (s.ok()) {
+    return false;
+  } else if (*statusptr == nullptr) {
+    *statusptr = new rocksdb_status_t{s, s.ToString()};
+  } else {
+    (*statusptr)->rep = s;
+    (*statusptr)->message = s.ToString();
+  }
+  return true;
+}
+
+// Sets the result of a point lookup: `value` if `s` is OK, null otherwise,
+// saving `s` unless the key was not found.
+static inline rocksdb_pinnableslice_t* SavePinnedValue(rocksdb_pinnableslice_t* value, const Status& s,
+                                                       rocksdb_status_t** statusptr) {
+  if (s.ok()) {
+    return value;
+  }
+  delete value;
+  if (!s.IsNotFound()) {
+    SaveStatus(statusptr, s);
+  }
+  return nullptr;
+}
+
+// Copies the results of a `MultiGet` to the output arrays of the
+// `rocksdb_*multi_get*` functions. Values are `malloc`ed.
+static inline void SaveMultiGetResults(const std::vector<Status>& results, const std::vector<std::string>& values,
+                                       char** values_list, size_t* values_list_sizes, rocksdb_status_t** statuses) {
+  for (size_t i = 0; i < results.size(); i++) {
+    values_list[i] = nullptr;
+    values_list_sizes[i] = 0;
+    statuses[i] = nullptr;
+    if (results[i].ok()) {
+      values_list[i] = static_cast<char*>(malloc(values[i].size()));
+      memcpy(values_list[i], values[i].data(), values[i].size());
+      values_list_sizes[i] = values[i].size();
+    } else if (!results[i].IsNotFound()) {
+      SaveStatus(&statuses[i], results[i]);
+    }
+  }
+}
+
+static inline std::vector<ColumnFamilyDescriptor> ToColumnFamilyDescriptors(
+    int num_column_families, const char* const* column_family_names,
+    const rocksdb_options_t* const* column_family_options) {
+  std::vector<ColumnFamilyDescriptor> column_families;
+  column_families.reserve(num_column_families);
+  for (int i = 0; i < num_column_families; i++) {
+    column_families.emplace_back(std::string(column_family_names[i]),
+                                 ColumnFamilyOptions(column_family_options[i]->rep));
+  }
+  return column_families;
+}
+
+static inline void ToColumnFamilyHandles(const std::vector<ColumnFamilyHandle*>& handles,
+                                         rocksdb_column_family_handle_t** column_family_handles) {
+  for (size_t i = 0; i < handles.size(); i++) {
+    column_family_handles[i] = new rocksdb_column_family_handle_t{handles[i]};
+  }
+}
@@ -0,0 +1,676 @@
+// Implementation of `DB` functions in `c.h`.
+#include <memory>
+#include <string>
+#include <unordered_map>
+#include <vector>
+
+#include "c_api_extensions/ctypes.hpp"
+#include "db/column_family.h"
+#include "db/db_impl/db_impl.h"
+#include "rocksdb/convenience.h"
+#include "rocksdb/experimental.h"
+#include "rocksdb/utilities/db_ttl.h"
+#include "rocksdb/utilities/options_util.h"
+#include "util/cast_util.h"
+
+using namespace ROCKSDB_NAMESPACE;
+
+// `DBImpl::error_handler_` is protected. A pointer to member formed in a
+// derived class is the only way to read it without changing RocksDB.
+struct DBImplErrorHandler : DBImpl {
+  static constexpr ErrorHandler DBImpl::*member = &DBImplErrorHandler::error_handler_;
+};
+
+// `Range` has no notion of an unbounded side, so a missing start is replaced
+// by the first key of the column family and a missing limit by a key just
+// past its last one, as the limit is exclusive.
+class RangeResolver {
+ public:
+  RangeResolver(DB* db, ColumnFamilyHandle* column_family) : db_(db), column_family_(column_family) {}
+
+  Slice Start(const char* key, size_t len) {
+    if (key != nullptr) {
+      return Slice(key, len);
+    }
+    Resolve();
+    return first_;
+  }
+
+  Slice Limit(const char* key, size_t len) {
+    if (key != nullptr) {
+      return Slice(key, len);
+    }
+    Resolve();
+    return limit_;
+  }
+
+ private:
+  void Resolve() {
+    if (resolved_) {
+      return;
+    }
+    resolved_ = true;
+    ReadOptions read_options;
+    read_options.fill_cache = false;
+    std::unique_ptr<Iterator> iter(db_->NewIterator(read_options, column_family_));
+    iter->SeekToFirst();
+    if (iter->Valid()) {
+      first_ = iter->key().ToString();
+    }
+    iter->SeekToLast();
+    if (iter->Valid()) {
+      limit_ = Successor(iter->key());
+    }
+  }
+
+  // The smallest key after `key` for bytewise ordering. Other comparators get
+  // a chance to pick one, and `key` itself is kept if neither is after it.
+  std::string Successor(const Slice& key) const {
+    const Comparator* comparator = column_family_->GetComparator();
+    std::string successor = key.ToString();
+    successor.push_back('\0');
+    if (comparator->Compare(successor, key) > 0) {
+      return successor;
+    }
+    successor = key.ToString();
+    comparator->FindShortSuccessor(&successor);
+    if (comparator->Compare(successor, key) > 0) {
+      return successor;
+    }
+    return key.ToString();
+  }
+
+  DB* db_;
+  ColumnFamilyHandle* column_family_;
+  bool resolved_ = false;
+  std::string first_;
+  std::string limit_;
+};
+
+extern "C" {
+void rocksdb_approximate_sizes_cf_with_flags(rocksdb_t* db, rocksdb_column_family_handle_t* column_family,
+                                             int num_ranges, const char* const* range_start_key,
+                                             const size_t* range_start_key_len, const char* const* range_limit_key,
+                                             const size_t* range_limit_key_len, unsigned char include_memtables,
+                                             unsigned char include_files, double files_size_error_margin,
+                                             uint64_t* sizes, rocksdb_status_t** statusptr) {
+  RangeResolver resolver(db->rep, column_family->rep);
+  std::vector<Range> ranges(num_ranges);
+  for (int i = 0; i < num_ranges; i++) {
+    ranges[i].start = resolver.Start(range_start_key[i], range_start_key_len[i]);
+    ranges[i].limit = resolver.Limit(range_limit_key[i], range_limit_key_len[i]);
+  }
+  SizeApproximationOptions options;
+  options.include_memtables = include_memtables;
+  options.include_files = include_files;
+  options.files_size_error_margin = files_size_error_margin;
+  SaveStatus(statusptr, db->rep->GetApproximateSizes(options, column_family->rep, ranges.data(), num_ranges, sizes));
+}
+
+void rocksdb_approximate_sizes_with_flags(rocksdb_t* db, int num_ranges, const char* const* range_start_key,
+                                          const size_t* range_start_key_len, const char* const* range_limit_key,
+                                          const size_t* range_limit_key_len, unsigned char include_memtables,
+                                          unsigned char include_files, double files_size_error_margin,
+                                          uint64_t* sizes, rocksdb_status_t** statusptr) {
+  rocksdb_column_family_handle_t column_family;
+  column_family.rep = db->rep->DefaultColumnFamily();
+  rocksdb_approximate_sizes_cf_with_flags(db, &column_family, num_ranges, range_start_key, range_start_key_len,
+                                          range_limit_key, range_limit_key_len, include_memtables, include_files,
+                                          files_size_error_margin, sizes, statusptr);
+}
+
+void rocksdb_approximate_memtable_stats_cf(rocksdb_t* db, rocksdb_column_family_handle_t* column_family,
+                                           const char* start_key, size_t start_key_len, const char* limit_key,
+                                           size_t limit_key_len, uint64_t* count, uint64_t* size) {
+  RangeResolver resolver(db->rep, column_family->rep);
+  Range range(resolver.Start(start_key, start_key_len), resolver.Limit(limit_key, limit_key_len));
+  db->rep->GetApproximateMemTableStats(column_family->rep, range, count, size);
+}
+
+void rocksdb_approximate_memtable_stats(rocksdb_t* db, const char* start_key, size_t start_key_len,
+                                        const char* limit_key, size_t limit_key_len, uint64_t* count,
+                                        uint64_t* size) {
+  rocksdb_column_family_handle_t column_family;
+  column_family.rep = db->rep->DefaultColumnFamily();
+  rocksdb_approximate_memtable_stats_cf(db, &column_family, start_key, start_key_len, limit_key, limit_key_len,
+                                        count, size);
+}
+
+static rocksdb_table_properties_collection_t* NewTablePropertiesCollection(const TablePropertiesCollection& props) {
+  auto* collection = new rocksdb_table_properties_collection_t;
+  collection->rep.assign(props.begin(), props.end());
+  return collection;
+}
+
+rocksdb_table_properties_collection_t* rocksdb_get_properties_of_all_tables_cf(
+    rocksdb_t* db, rocksdb_column_family_handle_t* column_family, rocksdb_status_t** statusptr) {
+  TablePropertiesCollection props;
+  if (SaveStatus(statusptr, db->rep->GetPropertiesOfAllTables(column_family->rep, &props))) {
+    return nullptr;
+  }
+  return NewTablePropertiesCollection(props);
+}
+
+rocksdb_table_properties_collection_t* rocksdb_get_properties_of_all_tables(rocksdb_t* db, rocksdb_status_t** statusptr) {
+  rocksdb_column_family_handle_t column_family;
+  column_family.rep = db->rep->DefaultColumnFamily();
+  return rocksdb_get_properties_of_all_tables_cf(db, &column_family, statusptr);
+}
+
+rocksdb_table_properties_collection_t* rocksdb_get_properties_of_tables_in_range_cf(
+    rocksdb_t* db, rocksdb_column_family_handle_t* column_family, int num_ranges, const char* const* range_start_key,
+    const size_t* range_start_key_len, const char* const* range_limit_key, const size_t* range_limit_key_len,
+    rocksdb_status_t** statusptr) {
+  RangeResolver resolver(db->rep, column_family->rep);
+  std::vector<Range> ranges(num_ranges);
+  for (int i = 0; i < num_ranges; i++) {
+    ranges[i].start = resolver.Start(range_start_key[i], range_start_key_len[i]);
+    ranges[i].limit = resolver.Limit(range_limit_key[i], range_limit_key_len[i]);
+  }
+  TablePropertiesCollection props;
+  if (SaveStatus(statusptr,
+                db->rep->GetPropertiesOfTablesInRange(column_family->rep, ranges.data(), ranges.size(), &props))) {
+    return nullptr;
+  }
+  return NewTablePropertiesCollection(props);
+}
+
+rocksdb_table_properties_collection_t* rocksdb_get_properties_of_tables_in_range(
+    rocksdb_t* db, int num_ranges, const char* const* range_start_key, const size_t* range_start_key_len,
+    const char* const* range_limit_key, const size_t* range_limit_key_len, rocksdb_status_t** statusptr) {
+  rocksdb_column_family_handle_t column_family;
+  column_family.rep = db->rep->DefaultColumnFamily();
+  return rocksdb_get_properties_of_tables_in_range_cf(db, &column_family, num_ranges, range_start_key,
+                                                      range_start_key_len, range_limit_key, range_limit_key_len,
+                                                      statusptr);
+}
+
+void rocksdb_compact_files_cf(rocksdb_t* db, const rocksdb_compactionoptions_t* options,
+                              rocksdb_column_family_handle_t* column_family, size_t num_files,
+                              const char* const* file_names, int output_level, rocksdb_status_t** statusptr) {
+  std::vector<std::string> input_file_names(file_names, file_names + num_files);
+  SaveStatus(statusptr, db->rep->CompactFiles(options->rep, column_family->rep, input_file_names, output_level));
+}
+
+void rocksdb_compact_files(rocksdb_t* db, const rocksdb_compactionoptions_t* options, size_t num_files,
+                           const char* const* file_names, int output_level, rocksdb_status_t** statusptr) {
+  rocksdb_column_family_handle_t column_family;
+  column_family.rep = db->rep->DefaultColumnFamily();
+  rocksdb_compact_files_cf(db, options, &column_family, num_files, file_names, output_level, statusptr);
+}
+
+void rocksdb_promote_l0_cf(rocksdb_t* db, rocksdb_column_family_handle_t* column_family, int target_level,
+                           rocksdb_status_t** statusptr) {
+  SaveStatus(statusptr, experimental::PromoteL0(db->rep, column_family->rep, target_level));
+}
+
+void rocksdb_promote_l0(rocksdb_t* db, int target_level, rocksdb_status_t** statusptr) {
+  SaveStatus(statusptr, experimental::PromoteL0(db->rep, db->rep->DefaultColumnFamily(), target_level));
+}
+
+void rocksdb_close_with_status(rocksdb_t* db, rocksdb_status_t** statusptr) {
+  SaveStatus(statusptr, db->rep->Close());
+  delete db->rep;
+  delete db;
+}
+
+void rocksdb_pause_background_work(rocksdb_t* db, rocksdb_status_t** statusptr) {
+  SaveStatus(statusptr, db->rep->PauseBackgroundWork());
+}
+
+void rocksdb_continue_background_work(rocksdb_t* db, rocksdb_status_t** statusptr) {
+  SaveStatus(statusptr, db->rep->ContinueBackgroundWork());
+}
+
+void rocksdb_flush_all(rocksdb_t* db, rocksdb_status_t** statusptr) {
+  // GetLiveFiles is the only public API that flushes every column family
+  // without needing their handles.
+  std::vector<std::string> files;
+  uint64_t manifest_file_size;
+  SaveStatus(statusptr, db->rep->GetLiveFiles(files, &manifest_file_size, /*flush_memtable=*/true));
+}
+
+// DB::WaitForCompact only exists from RocksDB 8.4, so approximate the
+// condition it waits on with what DBImpl exposes.
+unsigned char rocksdb_has_pending_background_work(rocksdb_t* db) {
+  auto db_impl = static_cast_with_check<DBImpl>(db->rep->GetRootDB());
+  InstrumentedMutexLock l(db_impl->mutex());
+  if (db_impl->num_running_flushes() > 0 || db_impl->num_running_compactions() > 0) {
+    return 1;
+  }
+  for (auto cfd : *db_impl->GetVersionSet()->GetColumnFamilySet()) {
+    if (cfd->IsDropped()) {
+      continue;
+    }
+    if (cfd->imm()->IsFlushPending()) {
+      return 1;
+    }
+    if (!cfd->GetLatestMutableCFOptions()->disable_auto_compactions && cfd->NeedsCompaction()) {
+      return 1;
+    }
+  }
+  return 0;
+}
+
+rocksdb_status_t* rocksdb_get_background_error(rocksdb_t* db) {
+  auto db_impl = static_cast_with_check<DBImpl>(db->rep->GetRootDB());
+  Status s;
+  {
+    InstrumentedMutexLock l(db_impl->mutex());
+    s = (db_impl->*DBImplErrorHandler::member).GetBGError();
+  }
+  rocksdb_status_t* status = nullptr;
+  SaveStatus(&status, s);
+  return status;
+}
+
+void rocksdb_resume(rocksdb_t* db, rocksdb_status_t** statusptr) {
+  SaveStatus(statusptr, db->rep->Resume());
+}
+
+// The `*_with_status` functions below mirror the functions of the same name
+// without the suffix in RocksDB's `c.h`, reporting errors as a
+// `rocksdb_status_t`.
+
+rocksdb_t* rocksdb_open_with_status(const rocksdb_options_t* options, const char* name, rocksdb_status_t** statusptr) {
+  DB* db;
+  if (SaveStatus(statusptr, DB::Open(options->rep, std::string(name), &db))) {
+    return nullptr;
+  }
+  return new rocksdb_t{db};
+}
+
+rocksdb_t* rocksdb_open_column_families_with_status(const rocksdb_options_t* db_options, const char* name,
+                                                    int num_column_families, const char* const* column_family_names,
+                                                    const rocksdb_options_t* const* column_family_options,
+                                                    rocksdb_column_family_handle_t** column_family_handles,
+                                                    rocksdb_status_t** statusptr) {
+  DB* db;
+  std::vector<ColumnFamilyHandle*> handles;
+  if (SaveStatus(statusptr,
+                 DB::Open(DBOptions(db_options->rep), std::string(name),
+                          ToColumnFamilyDescriptors(num_column_families, column_family_names, column_family_options),
+                          &handles, &db))) {
+    return nullptr;
+  }
+  ToColumnFamilyHandles(handles, column_family_handles);
+  return new rocksdb_t{db};
+}
+
+rocksdb_t* rocksdb_open_for_read_only_with_status(const rocksdb_options_t* options, const char* name,
+                                                  unsigned char error_if_wal_file_exists,
+                                                  rocksdb_status_t** statusptr) {
+  DB* db;
+  if (SaveStatus(statusptr, DB::OpenForReadOnly(options->rep, std::string(name), &db, error_if_wal_file_exists))) {
+    return nullptr;
+  }
+  return new rocksdb_t{db};
+}
+
+rocksdb_t* rocksdb_open_for_read_only_column_families_with_status(
+    const rocksdb_options_t* db_options, const char* name, int num_column_families,
+    const char* const* column_family_names, const rocksdb_options_t* const* column_family_options,
+    rocksdb_column_family_handle_t** column_family_handles, unsigned char error_if_wal_file_exists,
+    rocksdb_status_t** statusptr) {
+  DB* db;
+  std::vector<ColumnFamilyHandle*> handles;
+  if (SaveStatus(statusptr, DB::OpenForReadOnly(
+                                DBOptions(db_options->rep), std::string(name),
+                                ToColumnFamilyDescriptors(num_column_families, column_family_names,
+                                                          column_family_options),
+                                &handles, &db, error_if_wal_file_exists))) {
+    return nullptr;
+  }
+  ToColumnFamilyHandles(handles, column_family_handles);
+  return new rocksdb_t{db};
+}
+
+rocksdb_t* rocksdb_open_as_secondary_with_status(const rocksdb_options_t* options, const char* name,
+                                                 const char* secondary_path, rocksdb_status_t** statusptr) {
+  DB* db;
+  if (SaveStatus(statusptr, DB::OpenAsSecondary(options->rep, std::string(name), std::string(secondary_path), &db))) {
+    return nullptr;
+  }
+  return new rocksdb_t{db};
+}
+
+rocksdb_t* rocksdb_open_as_secondary_column_families_with_status(
+    const rocksdb_options_t* db_options, const char* name, const char* secondary_path, int num_column_families,
+    const char* const* column_family_names, const rocksdb_options_t* const* column_family_options,
+    rocksdb_column_family_handle_t** column_family_handles, rocksdb_status_t** statusptr) {
+  DB* db;
+  std::vector<ColumnFamilyHandle*> handles;
+  if (SaveStatus(statusptr, DB::OpenAsSecondary(
+                                DBOptions(db_options->rep), std::string(name), std::string(secondary_path),
+                                ToColumnFamilyDescriptors(num_column_families, column_family_names,
+                                                          column_family_options),
+                                &handles, &db))) {
+    return nullptr;
+  }
+  ToColumnFamilyHandles(handles, column_family_handles);
+  return new rocksdb_t{db};
+}
+
+rocksdb_t* rocksdb_open_with_ttl_with_status(const rocksdb_options_t* options, const char* name, int ttl,
+                                             rocksdb_status_t** statusptr) {
+  DBWithTTL* db;
+  if (SaveStatus(statusptr, DBWithTTL::Open(options->rep, std::string(name), &db, ttl))) {
+    return nullptr;
+  }
+  return new rocksdb_t{db};
+}
+
+rocksdb_t* rocksdb_open_column_families_with_ttl_with_status(
+    const rocksdb_options_t* db_options, const char* name, int num_column_families,
+    const char* const* column_family_names, const rocksdb_options_t* const* column_family_options,
+    rocksdb_column_family_handle_t** column_family_handles, const int* ttls, rocksdb_status_t** statusptr) {
+  DBWithTTL* db;
+  std::vector<ColumnFamilyHandle*> handles;
+  if (SaveStatus(statusptr, DBWithTTL::Open(DBOptions(db_options->rep), std::string(name),
+                                            ToColumnFamilyDescriptors(num_column_families, column_family_names,
+                                                                      column_family_options),
+                                            &handles, &db, std::vector<int32_t>(ttls, ttls + num_column_families)))) {
+    return nullptr;
+  }
+  ToColumnFamilyHandles(handles, column_family_handles);
+  return new rocksdb_t{db};
+}
+
+void rocksdb_destroy_db_with_status(const rocksdb_options_t* options, const char* name,
+                                    rocksdb_status_t** statusptr) {
+  SaveStatus(statusptr, DestroyDB(name, options->rep));
+}
+
+void rocksdb_repair_db_with_status(const rocksdb_options_t* options, const char* name, rocksdb_status_t** statusptr) {
+  SaveStatus(statusptr, RepairDB(name, options->rep));
+}
+
+char** rocksdb_list_column_families_with_status(const rocksdb_options_t* options, const char* name, size_t* lencfs,
+                                                rocksdb_status_t** statusptr) {
+  std::vector<std::string> fams;
+  if (SaveStatus(statusptr, DB::ListColumnFamilies(DBOptions(options->rep), std::string(name), &fams))) {
+    *lencfs = 0;
+    return nullptr;
+  }
+  *lencfs = fams.size();
+  auto column_families = static_cast<char**>(malloc(sizeof(char*) * fams.size()));
+  for (size_t i = 0; i < fams.size(); i++) {
+    column_families[i] = strdup(fams[i].c_str());
+  }
+  return column_families;
+}
+
+void rocksdb_load_latest_options_with_status(const char* db_path, rocksdb_env_t* env, bool ignore_unknown_options,
+                                             rocksdb_cache_t* cache, rocksdb_options_t** db_options,
+                                             size_t* num_column_families, char*** list_column_family_names,
+                                             rocksdb_options_t*** list_column_family_options,
+                                             rocksdb_status_t** statusptr) {
+  DBOptions db_opt;
+  std::vector<ColumnFamilyDescriptor> cf_descs;
+  ConfigOptions config_opts;
+  config_opts.ignore_unknown_options = ignore_unknown_options;
+  config_opts.input_strings_escaped = true;
+  config_opts.env = env->rep;
+  if (SaveStatus(statusptr, LoadLatestOptions(config_opts, std::string(db_path), &db_opt, &cf_descs, &cache->rep))) {
+    *num_column_families = 0;
+    *db_options = nullptr;
+    *list_column_family_names = nullptr;
+    *list_column_family_options = nullptr;
+    return;
+  }
+  auto cf_names = static_cast<char**>(malloc(cf_descs.size() * sizeof(char*)));
+  auto cf_options = static_cast<rocksdb_options_t**>(malloc(cf_descs.size() * sizeof(rocksdb_options_t*)));
+  for (size_t i = 0; i < cf_descs.size(); ++i) {
+    cf_names[i] = strdup(cf_descs[i].name.c_str());
+    cf_options[i] = new rocksdb_options_t{Options(DBOptions(), std::move(cf_descs[i].options))};
+  }
+  *num_column_families = cf_descs.size();
+  *db_options = new rocksdb_options_t{Options(std::move(db_opt), ColumnFamilyOptions())};
+  *list_column_family_names = cf_names;
+  *list_column_family_options = cf_options;
+}
+
+rocksdb_column_family_handle_t* rocksdb_create_column_family_with_status(rocksdb_t* db,
+                                                                         const rocksdb_options_t* column_family_options,
+                                                                         const char* column_family_name,
+                                                                         rocksdb_status_t** statusptr) {
+  ColumnFamilyHandle* handle;
+  if (SaveStatus(statusptr, db->rep->CreateColumnFamily(ColumnFamilyOptions(column_family_options->rep),
+                                                        std::string(column_family_name), &handle))) {
+    return nullptr;
+  }
+  return new rocksdb_column_family_handle_t{handle};
+}
+
+void rocksdb_drop_column_family_with_status(rocksdb_t* db, rocksdb_column_family_handle_t* handle,
+                                            rocksdb_status_t** statusptr) {
+  SaveStatus(statusptr, db->rep->DropColumnFamily(handle->rep));
+}
+
+void rocksdb_put_with_status(rocksdb_t* db, const rocksdb_writeoptions_t* options, const char* key, size_t keylen,
+                             const char* val, size_t vallen, rocksdb_status_t** statusptr) {
+  SaveStatus(statusptr, db->rep->Put(options->rep, Slice(key, keylen), Slice(val, vallen)));
+}
+
+void rocksdb_put_cf_with_status(rocksdb_t* db, const rocksdb_writeoptions_t* options,
+                                rocksdb_column_family_handle_t* column_family, const char* key, size_t keylen,
+                                const char* val, size_t vallen, rocksdb_status_t** statusptr) {
+  SaveStatus(statusptr, db->rep->Put(options->rep, column_family->rep, Slice(key, keylen), Slice(val, vallen)));
+}
+
+void rocksdb_delete_with_status(rocksdb_t* db, const rocksdb_writeoptions_t* options, const char* key, size_t keylen,
+                                rocksdb_status_t** statusptr) {
+  SaveStatus(statusptr, db->rep->Delete(options->rep, Slice(key, keylen)));
+}
+
+void rocksdb_delete_cf_with_status(rocksdb_t* db, const rocksdb_writeoptions_t* options,
+                                   rocksdb_column_family_handle_t* column_family, const char* key, size_t keylen,
+                                   rocksdb_status_t** statusptr) {
+  SaveStatus(statusptr, db->rep->Delete(options->rep, column_family->rep, Slice(key, keylen)));
+}
+
+void rocksdb_singledelete_with_status(rocksdb_t* db, const rocksdb_writeoptions_t* options, const char* key,
+                                      size_t keylen, rocksdb_status_t** statusptr) {
+  SaveStatus(statusptr, db->rep->SingleDelete(options->rep, Slice(key, keylen)));
+}
+
+void rocksdb_singledelete_cf_with_status(rocksdb_t* db, const rocksdb_writeoptions_t* options,
+                                         rocksdb_column_family_handle_t* column_family, const char* key,
+                                         size_t keylen, rocksdb_status_t** statusptr) {
+  SaveStatus(statusptr, db->rep->SingleDelete(options->rep, column_family->rep, Slice(key, keylen)));
+}
+
+void rocksdb_delete_range_cf_with_status(rocksdb_t* db, const rocksdb_writeoptions_t* options,
+                                         rocksdb_column_family_handle_t* column_family, const char* start_key,
+                                         size_t start_key_len, const char* end_key, size_t end_key_len,
+                                         rocksdb_status_t** statusptr) {
+  SaveStatus(statusptr, db->rep->DeleteRange(options->rep, column_family->rep, Slice(start_key, start_key_len),
+                                             Slice(end_key, end_key_len)));
+}
+
+void rocksdb_merge_with_status(rocksdb_t* db, const rocksdb_writeoptions_t* options, const char* key, size_t keylen,
+                               const char* val, size_t vallen, rocksdb_status_t** statusptr) {
+  SaveStatus(statusptr, db->rep->Merge(options->rep, Slice(key, keylen), Slice(val, vallen)));
+}
+
+void rocksdb_merge_cf_with_status(rocksdb_t* db, const rocksdb_writeoptions_t* options,
+                                  rocksdb_column_family_handle_t* column_family, const char* key, size_t keylen,
+                                  const char* val, size_t vallen, rocksdb_status_t** statusptr) {
+  SaveStatus(statusptr, db->rep->Merge(options->rep, column_family->rep, Slice(key, keylen), Slice(val, vallen)));
+}
+
+void rocksdb_write_with_status(rocksdb_t* db, const rocksdb_writeoptions_t* options, rocksdb_writebatch_t* batch,
+                               rocksdb_status_t** statusptr) {
+  SaveStatus(statusptr, db->rep->Write(options->rep, &batch->rep));
+}
+
+rocksdb_pinnableslice_t* rocksdb_get_pinned_with_status(rocksdb_t* db, const rocksdb_readoptions_t* options,
+                                                        const char* key, size_t keylen,
+                                                        rocksdb_status_t** statusptr) {
+  auto value = new rocksdb_pinnableslice_t;
+  Status s = db->rep->Get(options->rep, db->rep->DefaultColumnFamily(), Slice(key, keylen), &value->rep);
+  return SavePinnedValue(value, s, statusptr);
+}
+
+rocksdb_pinnableslice_t* rocksdb_get_pinned_cf_with_status(rocksdb_t* db, const rocksdb_readoptions_t* options,
+                                                           rocksdb_column_family_handle_t* column_family,
+                                                           const char* key, size_t keylen,
+                                                           rocksdb_status_t** statusptr) {
+  auto value = new rocksdb_pinnableslice_t;
+  Status s = db->rep->Get(options->rep, column_family->rep, Slice(key, keylen), &value->rep);
+  return SavePinnedValue(value, s, statusptr);
+}
+
+void rocksdb_multi_get_with_status(rocksdb_t* db, const rocksdb_readoptions_t* options, size_t num_keys,
+                                   const char* const* keys_list, const size_t* keys_list_sizes, char** values_list,
+                                   size_t* values_list_sizes, rocksdb_status_t** statuses) {
+  std::vector<Slice> keys(num_keys);
+  for (size_t i = 0; i < num_keys; i++) {
+    keys[i] = Slice(keys_list[i], keys_list_sizes[i]);
+  }
+  std::vector<std::string> values(num_keys);
+  SaveMultiGetResults(db->rep->MultiGet(options->rep, keys, &values), values, values_list, values_list_sizes,
+                      statuses);
+}
+
+void rocksdb_multi_get_cf_with_status(rocksdb_t* db, const rocksdb_readoptions_t* options,
+                                      const rocksdb_column_family_handle_t* const* column_families, size_t num_keys,
+                                      const char* const* keys_list, const size_t* keys_list_sizes,
+                                      char** values_list, size_t* values_list_sizes, rocksdb_status_t** statuses) {
+  std::vector<Slice> keys(num_keys);
+  std::vector<ColumnFamilyHandle*> cfs(num_keys);
+  for (size_t i = 0; i < num_keys; i++) {
+    keys[i] = Slice(keys_list[i], keys_list_sizes[i]);
+    cfs[i] = column_families[i]->rep;
+  }
+  std::vector<std::string> values(num_keys);
+  SaveMultiGetResults(db->rep->MultiGet(options->rep, cfs, keys, &values), values, values_list, values_list_sizes,
+                      statuses);
+}
+
+void rocksdb_batched_multi_get_cf_with_status(rocksdb_t* db, const rocksdb_readoptions_t* options,
+                                              rocksdb_column_family_handle_t* column_family, size_t num_keys,
+                                              const char* const* keys_list, const size_t* keys_list_sizes,
+                                              rocksdb_pinnableslice_t** values, rocksdb_status_t** statuses,
+                                              bool sorted_input) {
+  std::vector<Slice> keys(num_keys);
+  for (size_t i = 0; i < num_keys; i++) {
+    keys[i] = Slice(keys_list[i], keys_list_sizes[i]);
+  }
+  std::vector<PinnableSlice> pinned_values(num_keys);
+  std::vector<Status> results(num_keys);
+  db->rep->MultiGet(options->rep, column_family->rep, num_keys, keys.data(), pinned_values.data(), results.data(),
+                    sorted_input);
+  for (size_t i = 0; i < num_keys; i++) {
+    values[i] = nullptr;
+    statuses[i] = nullptr;
+    if (results[i].ok()) {
+      values[i] = new rocksdb_pinnableslice_t;
+      values[i]->rep = std::move(pinned_values[i]);
+    } else if (!results[i].IsNotFound()) {
+      SaveStatus(&statuses[i], results[i]);
+    }
+  }
+}
+
+void rocksdb_flush_with_status(rocksdb_t* db, const rocksdb_flushoptions_t* options, rocksdb_status_t** statusptr) {
+  SaveStatus(statusptr, db->rep->Flush(options->rep));
+}
+
+void rocksdb_flush_cf_with_status(rocksdb_t* db, const rocksdb_flushoptions_t* options,
+                                  rocksdb_column_family_handle_t* column_family, rocksdb_status_t** statusptr) {
+  SaveStatus(statusptr, db->rep->Flush(options->rep, column_family->rep));
+}
+
+void rocksdb_flush_cfs_with_status(rocksdb_t* db, const rocksdb_flushoptions_t* options,
+                                   rocksdb_column_family_handle_t** column_families, int num_column_families,
+                                   rocksdb_status_t** statusptr) {
+  std::vector<ColumnFamilyHandle*> column_family_handles;
+  for (int i = 0; i < num_column_families; i++) {
+    column_family_handles.push_back(column_families[i]->rep);
+  }
+  SaveStatus(statusptr, db->rep->Flush(options->rep, column_family_handles));
+}
+
+void rocksdb_flush_wal_with_status(rocksdb_t* db, unsigned char sync, rocksdb_status_t** statusptr) {
+  SaveStatus(statusptr, db->rep->FlushWAL(sync));
+}
+
+void rocksdb_delete_file_in_range_cf_with_status(rocksdb_t* db, rocksdb_column_family_handle_t* column_family,
+                                                 const char* start_key, size_t start_key_len, const char* limit_key,
+                                                 size_t limit_key_len, rocksdb_status_t** statusptr) {
+  Slice start, limit;
+  SaveStatus(statusptr, DeleteFilesInRange(db->rep, column_family->rep,
+                                           start_key ? (start = Slice(start_key, start_key_len), &start) : nullptr,
+                                           limit_key ? (limit = Slice(limit_key, limit_key_len), &limit) : nullptr));
+}
+
+void rocksdb_delete_file_in_range_with_status(rocksdb_t* db, const char* start_key, size_t start_key_len,
+                                              const char* limit_key, size_t limit_key_len,
+                                              rocksdb_status_t** statusptr) {
+  rocksdb_column_family_handle_t column_family;
+  column_family.rep = db->rep->DefaultColumnFamily();
+  rocksdb_delete_file_in_range_cf_with_status(db, &column_family, start_key, start_key_len, limit_key,
+                                              limit_key_len, statusptr);
+}
+
+void rocksdb_ingest_external_file_with_status(rocksdb_t* db, const char* const* file_list, size_t list_len,
+                                              const rocksdb_ingestexternalfileoptions_t* opt,
+                                              rocksdb_status_t** statusptr) {
+  std::vector<std::string> files(file_list, file_list + list_len);
+  SaveStatus(statusptr, db->rep->IngestExternalFile(files, opt->rep));
+}
+
+void rocksdb_ingest_external_file_cf_with_status(rocksdb_t* db, rocksdb_column_family_handle_t* handle,
+                                                 const char* const* file_list, size_t list_len,
+                                                 const rocksdb_ingestexternalfileoptions_t* opt,
+                                                 rocksdb_status_t** statusptr) {
+  std::vector<std::string> files(file_list, file_list + list_len);
+  SaveStatus(statusptr, db->rep->IngestExternalFile(handle->rep, files, opt->rep));
+}
+
+void rocksdb_set_options_with_status(rocksdb_t* db, int count, const char* const keys[], const char* const values[],
+                                     rocksdb_status_t** statusptr) {
+  std::unordered_map<std::string, std::string> options_map;
+  for (int i = 0; i < count; i++) {
+    options_map[keys[i]] = values[i];
+  }
+  SaveStatus(statusptr, db->rep->SetOptions(options_map));
+}
+
+void rocksdb_set_options_cf_with_status(rocksdb_t* db, rocksdb_column_family_handle_t* handle, int count,
+                                        const char* const keys[], const char* const values[],
+                                        rocksdb_status_t** statusptr) {
+  std::unordered_map<std::string, std::string> options_map;
+  for (int i = 0; i < count; i++) {
+    options_map[keys[i]] = values[i];
+  }
+  SaveStatus(statusptr, db->rep->SetOptions(handle->rep, options_map));
+}
+
+void rocksdb_try_catch_up_with_primary_with_status(rocksdb_t* db, rocksdb_status_t** statusptr) {
+  SaveStatus(statusptr, db->rep->TryCatchUpWithPrimary());
+}
+
+rocksdb_wal_iterator_t* rocksdb_get_updates_since_with_status(rocksdb_t* db, uint64_t seq_number,
+                                                              const rocksdb_wal_readoptions_t* options,
+                                                              rocksdb_status_t** statusptr) {
+  std::unique_ptr<TransactionLogIterator> iter;
+  TransactionLogIterator::ReadOptions ro;
+  if (options != nullptr) {
+    ro = options->rep;
+  }
+  if (SaveStatus(statusptr, db->rep->GetUpdatesSince(seq_number, &iter, ro))) {
+    return nullptr;
+  }
+  return new rocksdb_wal_iterator_t{iter.release()};
+}
+
+void rocksdb_wal_iter_status_with_status(const rocksdb_wal_iterator_t* iter, rocksdb_status_t** statusptr) {
+  SaveStatus(statusptr, iter->rep->status());
+}
+
+void rocksdb_iter_get_error_with_status(const rocksdb_iterator_t* iter, rocksdb_status_t** statusptr) {
+  SaveStatus(statusptr, iter->rep->status());
+}
+}
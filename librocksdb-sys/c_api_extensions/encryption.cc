@@ -0,0 +1,68 @@
+// Implementation of encryption functions in `c.h`.
+#include "c_api_extensions/ctypes.hpp"
+#include "rocksdb/env.h"
+#include "rocksdb/env_encryption.h"
+
+using namespace ROCKSDB_NAMESPACE;
+
+namespace {
+// Encrypts and decrypts blocks with C callbacks, which must not fail.
+// `destructor` releases `state` when the last reference to the cipher is
+// dropped.
+class CallbackBlockCipher : public BlockCipher {
+ public:
+  CallbackBlockCipher(void* state, void (*destructor)(void*), size_t block_size, void (*encrypt)(void*, char*),
+                      void (*decrypt)(void*, char*))
+      : state_(state), destructor_(destructor), block_size_(block_size), encrypt_(encrypt), decrypt_(decrypt) {}
+
+  ~CallbackBlockCipher() override { (*destructor_)(state_); }
+
+  const char* Name() const override { return "CallbackBlockCipher"; }
+
+  size_t BlockSize() override { return block_size_; }
+
+  Status Encrypt(char* data) override {
+    (*encrypt_)(state_, data);
+    return Status::OK();
+  }
+
+  Status Decrypt(char* data) override {
+    (*decrypt_)(state_, data);
+    return Status::OK();
+  }
+
+ private:
+  void* state_;
+  void (*destructor_)(void*);
+  size_t block_size_;
+  void (*encrypt_)(void*, char*);
+  void (*decrypt_)(void*, char*);
+};
+}  // namespace
+
+extern "C" {
+rocksdb_encryption_provider_t* rocksdb_encryption_provider_create_ctr(void* state, void (*destructor)(void*),
+                                                                      size_t block_size,
+                                                                      void (*encrypt)(void*, char*),
+                                                                      void (*decrypt)(void*, char*)) {
+  auto* provider = new rocksdb_encryption_provider_t;
+  provider->rep = EncryptionProvider::NewCTRProvider(
+      std::make_shared<CallbackBlockCipher>(state, destructor, block_size, encrypt, decrypt));
+  return provider;
+}
+
+rocksdb_encryption_provider_t* rocksdb_encryption_provider_create_ctr_rot13(size_t block_size) {
+  auto* provider = new rocksdb_encryption_provider_t;
+  provider->rep = EncryptionProvider::NewCTRProvider(BlockCipher::NewROT13Cipher(block_size));
+  return provider;
+}
+
+void rocksdb_encryption_provider_destroy(rocksdb_encryption_provider_t* provider) { delete provider; }
+
+rocksdb_env_t* rocksdb_create_encrypted_env(rocksdb_env_t* base, rocksdb_encryption_provider_t* provider) {
+  auto* env = new rocksdb_env_t;
+  env->rep = NewEncryptedEnv(base->rep, provider->rep);
+  env->is_default = false;
+  return env;
+}
+}
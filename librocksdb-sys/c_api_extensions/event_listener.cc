@@ -0,0 +1,402 @@
+// Implementation of `EventListener` functions in `c.h`.
+#include <memory>
+#include <string>
+
+#include "c_api_extensions/ctypes.hpp"
+#include "rocksdb/listener.h"
+
+using namespace ROCKSDB_NAMESPACE;
+
+struct rocksdb_eventlistener_t : public EventListener {
+  void* state_;
+  void (*destructor_)(void*);
+  void (*on_flush_begin_)(void*, const rocksdb_flushjobinfo_t*);
+  void (*on_flush_completed_)(void*, const rocksdb_flushjobinfo_t*);
+  void (*on_compaction_begin_)(void*, const rocksdb_compactionjobinfo_t*);
+  void (*on_compaction_completed_)(void*, const rocksdb_compactionjobinfo_t*);
+  void (*on_table_file_created_)(void*, const rocksdb_tablefilecreationinfo_t*);
+  void (*on_table_file_deleted_)(void*, const rocksdb_tablefiledeletioninfo_t*);
+  void (*on_external_file_ingested_)(void*, const rocksdb_externalfileingestioninfo_t*);
+  void (*on_stall_conditions_changed_)(void*, const rocksdb_writestallinfo_t*);
+  void (*on_background_error_)(void*, uint32_t, const rocksdb_status_t*);
+
+  ~rocksdb_eventlistener_t() override { (*destructor_)(state_); }
+
+  const char* Name() const override { return "RustEventListener"; }
+
+  void OnFlushBegin(DB* /*db*/, const FlushJobInfo& info) override {
+    on_flush_begin_(state_, reinterpret_cast<const rocksdb_flushjobinfo_t*>(&info));
+  }
+
+  void OnFlushCompleted(DB* /*db*/, const FlushJobInfo& info) override {
+    on_flush_completed_(state_, reinterpret_cast<const rocksdb_flushjobinfo_t*>(&info));
+  }
+
+  void OnCompactionBegin(DB* /*db*/, const CompactionJobInfo& info) override {
+    on_compaction_begin_(state_, reinterpret_cast<const rocksdb_compactionjobinfo_t*>(&info));
+  }
+
+  void OnCompactionCompleted(DB* /*db*/, const CompactionJobInfo& info) override {
+    on_compaction_completed_(state_, reinterpret_cast<const rocksdb_compactionjobinfo_t*>(&info));
+  }
+
+  void OnTableFileCreated(const TableFileCreationInfo& info) override {
+    on_table_file_created_(state_, reinterpret_cast<const rocksdb_tablefilecreationinfo_t*>(&info));
+  }
+
+  void OnTableFileDeleted(const TableFileDeletionInfo& info) override {
+    on_table_file_deleted_(state_, reinterpret_cast<const rocksdb_tablefiledeletioninfo_t*>(&info));
+  }
+
+  void OnExternalFileIngested(DB* /*db*/, const ExternalFileIngestionInfo& info) override {
+    on_external_file_ingested_(state_, reinterpret_cast<const rocksdb_externalfileingestioninfo_t*>(&info));
+  }
+
+  void OnStallConditionsChanged(const WriteStallInfo& info) override {
+    on_stall_conditions_changed_(state_, reinterpret_cast<const rocksdb_writestallinfo_t*>(&info));
+  }
+
+  void OnBackgroundError(BackgroundErrorReason reason, Status* bg_error) override {
+    rocksdb_status_t status{*bg_error, bg_error->ToString()};
+    on_background_error_(state_, static_cast<uint32_t>(reason), &status);
+  }
+};
+
+extern "C" {
+rocksdb_eventlistener_t* rocksdb_eventlistener_create(
+    void* state, void (*destructor)(void*), void (*on_flush_begin)(void*, const rocksdb_flushjobinfo_t*),
+    void (*on_flush_completed)(void*, const rocksdb_flushjobinfo_t*),
+    void (*on_compaction_begin)(void*, const rocksdb_compactionjobinfo_t*),
+    void (*on_compaction_completed)(void*, const rocksdb_compactionjobinfo_t*),
+    void (*on_table_file_created)(void*, const rocksdb_tablefilecreationinfo_t*),
+    void (*on_table_file_deleted)(void*, const rocksdb_tablefiledeletioninfo_t*),
+    void (*on_external_file_ingested)(void*, const rocksdb_externalfileingestioninfo_t*),
+    void (*on_stall_conditions_changed)(void*, const rocksdb_writestallinfo_t*),
+    void (*on_background_error)(void*, uint32_t, const rocksdb_status_t*)) {
+  auto* listener = new rocksdb_eventlistener_t;
+  listener->state_ = state;
+  listener->destructor_ = destructor;
+  listener->on_flush_begin_ = on_flush_begin;
+  listener->on_flush_completed_ = on_flush_completed;
+  listener->on_compaction_begin_ = on_compaction_begin;
+  listener->on_compaction_completed_ = on_compaction_completed;
+  listener->on_table_file_created_ = on_table_file_created;
+  listener->on_table_file_deleted_ = on_table_file_deleted;
+  listener->on_external_file_ingested_ = on_external_file_ingested;
+  listener->on_stall_conditions_changed_ = on_stall_conditions_changed;
+  listener->on_background_error_ = on_background_error;
+  return listener;
+}
+
+void rocksdb_options_add_eventlistener(rocksdb_options_t* opt, rocksdb_eventlistener_t* listener) {
+  opt->rep.listeners.emplace_back(std::shared_ptr<EventListener>(listener));
+}
+
+const char* rocksdb_flushjobinfo_cf_name(const rocksdb_flushjobinfo_t* c_info, size_t* len) {
+  auto info = reinterpret_cast<const FlushJobInfo*>(c_info);
+  *len = info->cf_name.size();
+  return info->cf_name.data();
+}
+
+const char* rocksdb_flushjobinfo_file_path(const rocksdb_flushjobinfo_t* c_info, size_t* len) {
+  auto info = reinterpret_cast<const FlushJobInfo*>(c_info);
+  *len = info->file_path.size();
+  return info->file_path.data();
+}
+
+int rocksdb_flushjobinfo_job_id(const rocksdb_flushjobinfo_t* c_info) {
+  auto info = reinterpret_cast<const FlushJobInfo*>(c_info);
+  return info->job_id;
+}
+
+uint64_t rocksdb_flushjobinfo_thread_id(const rocksdb_flushjobinfo_t* c_info) {
+  auto info = reinterpret_cast<const FlushJobInfo*>(c_info);
+  return info->thread_id;
+}
+
+unsigned char rocksdb_flushjobinfo_triggered_writes_slowdown(const rocksdb_flushjobinfo_t* c_info) {
+  auto info = reinterpret_cast<const FlushJobInfo*>(c_info);
+  return info->triggered_writes_slowdown;
+}
+
+unsigned char rocksdb_flushjobinfo_triggered_writes_stop(const rocksdb_flushjobinfo_t* c_info) {
+  auto info = reinterpret_cast<const FlushJobInfo*>(c_info);
+  return info->triggered_writes_stop;
+}
+
+uint64_t rocksdb_flushjobinfo_smallest_seqno(const rocksdb_flushjobinfo_t* c_info) {
+  auto info = reinterpret_cast<const FlushJobInfo*>(c_info);
+  return info->smallest_seqno;
+}
+
+uint64_t rocksdb_flushjobinfo_largest_seqno(const rocksdb_flushjobinfo_t* c_info) {
+  auto info = reinterpret_cast<const FlushJobInfo*>(c_info);
+  return info->largest_seqno;
+}
+
+uint32_t rocksdb_flushjobinfo_flush_reason(const rocksdb_flushjobinfo_t* c_info) {
+  auto info = reinterpret_cast<const FlushJobInfo*>(c_info);
+  return static_cast<uint32_t>(info->flush_reason);
+}
+
+void rocksdb_compactionjobinfo_status(const rocksdb_compactionjobinfo_t* c_info, rocksdb_status_t** statusptr) {
+  auto info = reinterpret_cast<const CompactionJobInfo*>(c_info);
+  SaveStatus(statusptr, info->status);
+}
+
+const char* rocksdb_compactionjobinfo_cf_name(const rocksdb_compactionjobinfo_t* c_info, size_t* len) {
+  auto info = reinterpret_cast<const CompactionJobInfo*>(c_info);
+  *len = info->cf_name.size();
+  return info->cf_name.data();
+}
+
+size_t rocksdb_compactionjobinfo_input_files_count(const rocksdb_compactionjobinfo_t* c_info) {
+  auto info = reinterpret_cast<const CompactionJobInfo*>(c_info);
+  return info->input_files.size();
+}
+
+const char* rocksdb_compactionjobinfo_input_file_at(const rocksdb_compactionjobinfo_t* c_info,
+                                                    size_t pos, size_t* len) {
+  auto info = reinterpret_cast<const CompactionJobInfo*>(c_info);
+  const std::string& file = info->input_files[pos];
+  *len = file.size();
+  return file.data();
+}
+
+size_t rocksdb_compactionjobinfo_output_files_count(const rocksdb_compactionjobinfo_t* c_info) {
+  auto info = reinterpret_cast<const CompactionJobInfo*>(c_info);
+  return info->output_files.size();
+}
+
+const char* rocksdb_compactionjobinfo_output_file_at(const rocksdb_compactionjobinfo_t* c_info,
+                                                     size_t pos, size_t* len) {
+  auto info = reinterpret_cast<const CompactionJobInfo*>(c_info);
+  const std::string& file = info->output_files[pos];
+  *len = file.size();
+  return file.data();
+}
+
+uint64_t rocksdb_compactionjobinfo_thread_id(const rocksdb_compactionjobinfo_t* c_info) {
+  auto info = reinterpret_cast<const CompactionJobInfo*>(c_info);
+  return info->thread_id;
+}
+
+int rocksdb_compactionjobinfo_job_id(const rocksdb_compactionjobinfo_t* c_info) {
+  auto info = reinterpret_cast<const CompactionJobInfo*>(c_info);
+  return info->job_id;
+}
+
+int rocksdb_compactionjobinfo_base_input_level(const rocksdb_compactionjobinfo_t* c_info) {
+  auto info = reinterpret_cast<const CompactionJobInfo*>(c_info);
+  return info->base_input_level;
+}
+
+int rocksdb_compactionjobinfo_output_level(const rocksdb_compactionjobinfo_t* c_info) {
+  auto info = reinterpret_cast<const CompactionJobInfo*>(c_info);
+  return info->output_level;
+}
+
+uint32_t rocksdb_compactionjobinfo_compaction_reason(const rocksdb_compactionjobinfo_t* c_info) {
+  auto info = reinterpret_cast<const CompactionJobInfo*>(c_info);
+  return static_cast<uint32_t>(info->compaction_reason);
+}
+
+uint64_t rocksdb_compactionjobinfo_elapsed_micros(const rocksdb_compactionjobinfo_t* c_info) {
+  auto info = reinterpret_cast<const CompactionJobInfo*>(c_info);
+  return info->stats.elapsed_micros;
+}
+
+uint64_t rocksdb_compactionjobinfo_cpu_micros(const rocksdb_compactionjobinfo_t* c_info) {
+  auto info = reinterpret_cast<const CompactionJobInfo*>(c_info);
+  return info->stats.cpu_micros;
+}
+
+uint64_t rocksdb_compactionjobinfo_input_records(const rocksdb_compactionjobinfo_t* c_info) {
+  auto info = reinterpret_cast<const CompactionJobInfo*>(c_info);
+  return info->stats.num_input_records;
+}
+
+size_t rocksdb_compactionjobinfo_num_input_files(const rocksdb_compactionjobinfo_t* c_info) {
+  auto info = reinterpret_cast<const CompactionJobInfo*>(c_info);
+  return info->stats.num_input_files;
+}
+
+size_t rocksdb_compactionjobinfo_num_input_files_at_output_level(const rocksdb_compactionjobinfo_t* c_info) {
+  auto info = reinterpret_cast<const CompactionJobInfo*>(c_info);
+  return info->stats.num_input_files_at_output_level;
+}
+
+uint64_t rocksdb_compactionjobinfo_output_records(const rocksdb_compactionjobinfo_t* c_info) {
+  auto info = reinterpret_cast<const CompactionJobInfo*>(c_info);
+  return info->stats.num_output_records;
+}
+
+size_t rocksdb_compactionjobinfo_num_output_files(const rocksdb_compactionjobinfo_t* c_info) {
+  auto info = reinterpret_cast<const CompactionJobInfo*>(c_info);
+  return info->stats.num_output_files;
+}
+
+unsigned char rocksdb_compactionjobinfo_is_full_compaction(const rocksdb_compactionjobinfo_t* c_info) {
+  auto info = reinterpret_cast<const CompactionJobInfo*>(c_info);
+  return info->stats.is_full_compaction;
+}
+
+unsigned char rocksdb_compactionjobinfo_is_manual_compaction(const rocksdb_compactionjobinfo_t* c_info) {
+  auto info = reinterpret_cast<const CompactionJobInfo*>(c_info);
+  return info->stats.is_manual_compaction;
+}
+
+uint64_t rocksdb_compactionjobinfo_total_input_bytes(const rocksdb_compactionjobinfo_t* c_info) {
+  auto info = reinterpret_cast<const CompactionJobInfo*>(c_info);
+  return info->stats.total_input_bytes;
+}
+
+uint64_t rocksdb_compactionjobinfo_total_output_bytes(const rocksdb_compactionjobinfo_t* c_info) {
+  auto info = reinterpret_cast<const CompactionJobInfo*>(c_info);
+  return info->stats.total_output_bytes;
+}
+
+uint64_t rocksdb_compactionjobinfo_num_records_replaced(const rocksdb_compactionjobinfo_t* c_info) {
+  auto info = reinterpret_cast<const CompactionJobInfo*>(c_info);
+  return info->stats.num_records_replaced;
+}
+
+uint64_t rocksdb_compactionjobinfo_total_input_raw_key_bytes(const rocksdb_compactionjobinfo_t* c_info) {
+  auto info = reinterpret_cast<const CompactionJobInfo*>(c_info);
+  return info->stats.total_input_raw_key_bytes;
+}
+
+uint64_t rocksdb_compactionjobinfo_total_input_raw_value_bytes(const rocksdb_compactionjobinfo_t* c_info) {
+  auto info = reinterpret_cast<const CompactionJobInfo*>(c_info);
+  return info->stats.total_input_raw_value_bytes;
+}
+
+uint64_t rocksdb_compactionjobinfo_num_input_deletion_records(const rocksdb_compactionjobinfo_t* c_info) {
+  auto info = reinterpret_cast<const CompactionJobInfo*>(c_info);
+  return info->stats.num_input_deletion_records;
+}
+
+uint64_t rocksdb_compactionjobinfo_num_expired_deletion_records(const rocksdb_compactionjobinfo_t* c_info) {
+  auto info = reinterpret_cast<const CompactionJobInfo*>(c_info);
+  return info->stats.num_expired_deletion_records;
+}
+
+uint64_t rocksdb_compactionjobinfo_num_corrupt_keys(const rocksdb_compactionjobinfo_t* c_info) {
+  auto info = reinterpret_cast<const CompactionJobInfo*>(c_info);
+  return info->stats.num_corrupt_keys;
+}
+
+void rocksdb_tablefilecreationinfo_status(const rocksdb_tablefilecreationinfo_t* c_info, rocksdb_status_t** statusptr) {
+  auto info = reinterpret_cast<const TableFileCreationInfo*>(c_info);
+  SaveStatus(statusptr, info->status);
+}
+
+const char* rocksdb_tablefilecreationinfo_db_name(const rocksdb_tablefilecreationinfo_t* c_info, size_t* len) {
+  auto info = reinterpret_cast<const TableFileCreationInfo*>(c_info);
+  *len = info->db_name.size();
+  return info->db_name.data();
+}
+
+const char* rocksdb_tablefilecreationinfo_cf_name(const rocksdb_tablefilecreationinfo_t* c_info, size_t* len) {
+  auto info = reinterpret_cast<const TableFileCreationInfo*>(c_info);
+  *len = info->cf_name.size();
+  return info->cf_name.data();
+}
+
+const char* rocksdb_tablefilecreationinfo_file_path(const rocksdb_tablefilecreationinfo_t* c_info, size_t* len) {
+  auto info = reinterpret_cast<const TableFileCreationInfo*>(c_info);
+  *len = info->file_path.size();
+  return info->file_path.data();
+}
+
+const char* rocksdb_tablefilecreationinfo_file_checksum(const rocksdb_tablefilecreationinfo_t* c_info, size_t* len) {
+  auto info = reinterpret_cast<const TableFileCreationInfo*>(c_info);
+  *len = info->file_checksum.size();
+  return info->file_checksum.data();
+}
+
+const char* rocksdb_tablefilecreationinfo_file_checksum_func_name(const rocksdb_tablefilecreationinfo_t* c_info,
+                                                                  size_t* len) {
+  auto info = reinterpret_cast<const TableFileCreationInfo*>(c_info);
+  *len = info->file_checksum_func_name.size();
+  return info->file_checksum_func_name.data();
+}
+
+int rocksdb_tablefilecreationinfo_job_id(const rocksdb_tablefilecreationinfo_t* c_info) {
+  auto info = reinterpret_cast<const TableFileCreationInfo*>(c_info);
+  return info->job_id;
+}
+
+uint32_t rocksdb_tablefilecreationinfo_reason(const rocksdb_tablefilecreationinfo_t* c_info) {
+  auto info = reinterpret_cast<const TableFileCreationInfo*>(c_info);
+  return static_cast<uint32_t>(info->reason);
+}
+
+uint64_t rocksdb_tablefilecreationinfo_file_size(const rocksdb_tablefilecreationinfo_t* c_info) {
+  auto info = reinterpret_cast<const TableFileCreationInfo*>(c_info);
+  return info->file_size;
+}
+
+void rocksdb_tablefiledeletioninfo_status(const rocksdb_tablefiledeletioninfo_t* c_info, rocksdb_status_t** statusptr) {
+  auto info = reinterpret_cast<const TableFileDeletionInfo*>(c_info);
+  SaveStatus(statusptr, info->status);
+}
+
+const char* rocksdb_tablefiledeletioninfo_db_name(const rocksdb_tablefiledeletioninfo_t* c_info, size_t* len) {
+  auto info = reinterpret_cast<const TableFileDeletionInfo*>(c_info);
+  *len = info->db_name.size();
+  return info->db_name.data();
+}
+
+const char* rocksdb_tablefiledeletioninfo_file_path(const rocksdb_tablefiledeletioninfo_t* c_info, size_t* len) {
+  auto info = reinterpret_cast<const TableFileDeletionInfo*>(c_info);
+  *len = info->file_path.size();
+  return info->file_path.data();
+}
+
+int rocksdb_tablefiledeletioninfo_job_id(const rocksdb_tablefiledeletioninfo_t* c_info) {
+  auto info = reinterpret_cast<const TableFileDeletionInfo*>(c_info);
+  return info->job_id;
+}
+
+const char* rocksdb_externalfileingestioninfo_cf_name(const rocksdb_externalfileingestioninfo_t* c_info, size_t* len) {
+  auto info = reinterpret_cast<const ExternalFileIngestionInfo*>(c_info);
+  *len = info->cf_name.size();
+  return info->cf_name.data();
+}
+
+const char* rocksdb_externalfileingestioninfo_external_file_path(const rocksdb_externalfileingestioninfo_t* c_info,
+                                                                 size_t* len) {
+  auto info = reinterpret_cast<const ExternalFileIngestionInfo*>(c_info);
+  *len = info->external_file_path.size();
+  return info->external_file_path.data();
+}
+
+const char* rocksdb_externalfileingestioninfo_internal_file_path(const rocksdb_externalfileingestioninfo_t* c_info,
+                                                                 size_t* len) {
+  auto info = reinterpret_cast<const ExternalFileIngestionInfo*>(c_info);
+  *len = info->internal_file_path.size();
+  return info->internal_file_path.data();
+}
+
+uint64_t rocksdb_externalfileingestioninfo_global_seqno(const rocksdb_externalfileingestioninfo_t* c_info) {
+  auto info = reinterpret_cast<const ExternalFileIngestionInfo*>(c_info);
+  return info->global_seqno;
+}
+
+const char* rocksdb_writestallinfo_cf_name(const rocksdb_writestallinfo_t* c_info, size_t* len) {
+  auto info = reinterpret_cast<const WriteStallInfo*>(c_info);
+  *len = info->cf_name.size();
+  return info->cf_name.data();
+}
+
+uint32_t rocksdb_writestallinfo_cur(const rocksdb_writestallinfo_t* c_info) {
+  auto info = reinterpret_cast<const WriteStallInfo*>(c_info);
+  return static_cast<uint32_t>(info->condition.cur);
+}
+
+uint32_t rocksdb_writestallinfo_prev(const rocksdb_writestallinfo_t* c_info) {
+  auto info = reinterpret_cast<const WriteStallInfo*>(c_info);
+  return static_cast<uint32_t>(info->condition.prev);
+}
+}
@@ -0,0 +1,671 @@
+// Implementation of `FileSystem` functions in `c.h`.
+#include <cstdlib>
+#include <cstring>
+
+#include "c_api_extensions/ctypes.hpp"
+#include "logging/env_logger.h"
+#include "rocksdb/env.h"
+#include "rocksdb/file_system.h"
+
+using namespace ROCKSDB_NAMESPACE;
+
+namespace {
+// Takes the status a callback reported, null if it succeeded.
+IOStatus ToIOStatus(rocksdb_status_t* status) {
+  if (status == nullptr) {
+    return IOStatus::OK();
+  }
+  IOStatus s = status_to_io_status(std::move(status->rep));
+  delete status;
+  return s;
+}
+
+class RustSequentialFile : public FSSequentialFile {
+ public:
+  RustSequentialFile(void* state, void (*destructor)(void*), size_t (*read)(void*, char*, size_t, rocksdb_status_t**),
+                     void (*skip)(void*, uint64_t, rocksdb_status_t**))
+      : state_(state), destructor_(destructor), read_(read), skip_(skip) {}
+
+  ~RustSequentialFile() override { (*destructor_)(state_); }
+
+  IOStatus Read(size_t n, const IOOptions& /*options*/, Slice* result, char* scratch,
+                IODebugContext* /*dbg*/) override {
+    rocksdb_status_t* status = nullptr;
+    size_t len = (*read_)(state_, scratch, n, &status);
+    *result = Slice(scratch, status == nullptr ? len : 0);
+    return ToIOStatus(status);
+  }
+
+  IOStatus Skip(uint64_t n) override {
+    rocksdb_status_t* status = nullptr;
+    (*skip_)(state_, n, &status);
+    return ToIOStatus(status);
+  }
+
+ private:
+  void* state_;
+  void (*destructor_)(void*);
+  size_t (*read_)(void*, char*, size_t, rocksdb_status_t**);
+  void (*skip_)(void*, uint64_t, rocksdb_status_t**);
+};
+
+class RustRandomAccessFile : public FSRandomAccessFile {
+ public:
+  RustRandomAccessFile(void* state, void (*destructor)(void*),
+                       size_t (*read)(void*, uint64_t, char*, size_t, rocksdb_status_t**))
+      : state_(state), destructor_(destructor), read_(read) {}
+
+  ~RustRandomAccessFile() override { (*destructor_)(state_); }
+
+  IOStatus Read(uint64_t offset, size_t n, const IOOptions& /*options*/, Slice* result, char* scratch,
+                IODebugContext* /*dbg*/) const override {
+    rocksdb_status_t* status = nullptr;
+    size_t len = (*read_)(state_, offset, scratch, n, &status);
+    *result = Slice(scratch, status == nullptr ? len : 0);
+    return ToIOStatus(status);
+  }
+
+ private:
+  void* state_;
+  void (*destructor_)(void*);
+  size_t (*read_)(void*, uint64_t, char*, size_t, rocksdb_status_t**);
+};
+
+class RustWritableFile : public FSWritableFile {
+ public:
+  RustWritableFile(void* state, void (*destructor)(void*), void (*append)(void*, const char*, size_t, rocksdb_status_t**),
+                   void (*flush)(void*, rocksdb_status_t**), void (*sync)(void*, rocksdb_status_t**), void (*close)(void*, rocksdb_status_t**),
+                   uint64_t (*get_file_size)(void*))
+      : state_(state),
+        destructor_(destructor),
+        append_(append),
+        flush_(flush),
+        sync_(sync),
+        close_(close),
+        get_file_size_(get_file_size) {}
+
+  ~RustWritableFile() override { (*destructor_)(state_); }
+
+  using FSWritableFile::Append;
+  IOStatus Append(const Slice& data, const IOOptions& /*options*/, IODebugContext* /*dbg*/) override {
+    rocksdb_status_t* status = nullptr;
+    (*append_)(state_, data.data(), data.size(), &status);
+    return ToIOStatus(status);
+  }
+
+  IOStatus Flush(const IOOptions& /*options*/, IODebugContext* /*dbg*/) override { return Call(flush_); }
+
+  IOStatus Sync(const IOOptions& /*options*/, IODebugContext* /*dbg*/) override { return Call(sync_); }
+
+  IOStatus Close(const IOOptions& /*options*/, IODebugContext* /*dbg*/) override { return Call(close_); }
+
+  uint64_t GetFileSize(const IOOptions& /*options*/, IODebugContext* /*dbg*/) override {
+    return (*get_file_size_)(state_);
+  }
+
+ private:
+  IOStatus Call(void (*callback)(void*, rocksdb_status_t**)) {
+    rocksdb_status_t* status = nullptr;
+    (*callback)(state_, &status);
+    return ToIOStatus(status);
+  }
+
+  void* state_;
+  void (*destructor_)(void*);
+  void (*append_)(void*, const char*, size_t, rocksdb_status_t**);
+  void (*flush_)(void*, rocksdb_status_t**);
+  void (*sync_)(void*, rocksdb_status_t**);
+  void (*close_)(void*, rocksdb_status_t**);
+  uint64_t (*get_file_size_)(void*);
+};
+
+// Holds a lock returned by Rust. `unlock` consumes `state`.
+class RustFileLock : public FileLock {
+ public:
+  RustFileLock(void* state, void (*unlock)(void*, rocksdb_status_t**)) : state_(state), unlock_(unlock) {}
+
+  IOStatus Unlock() {
+    rocksdb_status_t* status = nullptr;
+    (*unlock_)(state_, &status);
+    return ToIOStatus(status);
+  }
+
+ private:
+  void* state_;
+  void (*unlock_)(void*, rocksdb_status_t**);
+};
+
+typedef void (*PathCallback)(void*, const char*, size_t, rocksdb_status_t**);
+
+class RustDirectory : public FSDirectory {
+ public:
+  RustDirectory(void* state, PathCallback fsync_dir, std::string path)
+      : state_(state), fsync_dir_(fsync_dir), path_(std::move(path)) {}
+
+  IOStatus Fsync(const IOOptions& /*options*/, IODebugContext* /*dbg*/) override {
+    rocksdb_status_t* status = nullptr;
+    (*fsync_dir_)(state_, path_.data(), path_.size(), &status);
+    return ToIOStatus(status);
+  }
+
+ private:
+  void* state_;
+  PathCallback fsync_dir_;
+  std::string path_;
+};
+
+// Forwards the file operations of RocksDB to C callbacks. Only the test
+// directory is left to the default file system.
+class RustFileSystem : public FileSystem {
+ public:
+  RustFileSystem(void* state, void (*destructor)(void*),
+                 rocksdb_fs_sequential_file_t* (*new_sequential_file)(void*, const char*, size_t, rocksdb_status_t**),
+                 rocksdb_fs_random_access_file_t* (*new_random_access_file)(void*, const char*, size_t, rocksdb_status_t**),
+                 rocksdb_fs_writable_file_t* (*new_writable_file)(void*, const char*, size_t, rocksdb_status_t**),
+                 unsigned char (*file_exists)(void*, const char*, size_t, rocksdb_status_t**),
+                 void (*get_children)(void*, const char*, size_t, rocksdb_fs_children_t*, rocksdb_status_t**),
+                 PathCallback delete_file, PathCallback create_dir, PathCallback create_dir_if_missing,
+                 PathCallback delete_dir, PathCallback fsync_dir,
+                 uint64_t (*get_file_size)(void*, const char*, size_t, rocksdb_status_t**),
+                 uint64_t (*get_file_modification_time)(void*, const char*, size_t, rocksdb_status_t**),
+                 void (*rename_file)(void*, const char*, size_t, const char*, size_t, rocksdb_status_t**),
+                 rocksdb_fs_file_lock_t* (*lock_file)(void*, const char*, size_t, rocksdb_status_t**),
+                 unsigned char (*is_directory)(void*, const char*, size_t, rocksdb_status_t**),
+                 void (*get_absolute_path)(void*, const char*, size_t, rocksdb_fs_path_t*, rocksdb_status_t**))
+      : state_(state),
+        destructor_(destructor),
+        new_sequential_file_(new_sequential_file),
+        new_random_access_file_(new_random_access_file),
+        new_writable_file_(new_writable_file),
+        file_exists_(file_exists),
+        get_children_(get_children),
+        delete_file_(delete_file),
+        create_dir_(create_dir),
+        create_dir_if_missing_(create_dir_if_missing),
+        delete_dir_(delete_dir),
+        fsync_dir_(fsync_dir),
+        get_file_size_(get_file_size),
+        get_file_modification_time_(get_file_modification_time),
+        rename_file_(rename_file),
+        lock_file_(lock_file),
+        is_directory_(is_directory),
+        get_absolute_path_(get_absolute_path),
+        base_(FileSystem::Default()) {}
+
+  ~RustFileSystem() override { (*destructor_)(state_); }
+
+  const char* Name() const override { return "RustFileSystem"; }
+
+  IOStatus NewSequentialFile(const std::string& fname, const FileOptions& /*options*/,
+                             std::unique_ptr<FSSequentialFile>* result, IODebugContext* /*dbg*/) override {
+    rocksdb_status_t* status = nullptr;
+    rocksdb_fs_sequential_file_t* file = (*new_sequential_file_)(state_, fname.data(), fname.size(), &status);
+    if (file != nullptr) {
+      *result = std::move(file->rep);
+      delete file;
+    }
+    return ToIOStatus(status);
+  }
+
+  IOStatus NewRandomAccessFile(const std::string& fname, const FileOptions& /*options*/,
+                               std::unique_ptr<FSRandomAccessFile>* result, IODebugContext* /*dbg*/) override {
+    rocksdb_status_t* status = nullptr;
+    rocksdb_fs_random_access_file_t* file = (*new_random_access_file_)(state_, fname.data(), fname.size(), &status);
+    if (file != nullptr) {
+      *result = std::move(file->rep);
+      delete file;
+    }
+    return ToIOStatus(status);
+  }
+
+  IOStatus NewWritableFile(const std::string& fname, const FileOptions& /*options*/,
+                           std::unique_ptr<FSWritableFile>* result, IODebugContext* /*dbg*/) override {
+    rocksdb_status_t* status = nullptr;
+    rocksdb_fs_writable_file_t* file = (*new_writable_file_)(state_, fname.data(), fname.size(), &status);
+    if (file != nullptr) {
+      *result = std::move(file->rep);
+      delete file;
+    }
+    return ToIOStatus(status);
+  }
+
+  IOStatus NewDirectory(const std::string& name, const IOOptions& options, std::unique_ptr<FSDirectory>* result,
+                        IODebugContext* dbg) override {
+    bool is_dir = false;
+    IOStatus s = IsDirectory(name, options, &is_dir, dbg);
+    if (s.ok() && !is_dir) {
+      s = IOStatus::IOError(name, "Not a directory");
+    }
+    if (s.ok()) {
+      result->reset(new RustDirectory(state_, fsync_dir_, name));
+    }
+    return s;
+  }
+
+  IOStatus FileExists(const std::string& fname, const IOOptions& /*options*/, IODebugContext* /*dbg*/) override {
+    rocksdb_status_t* status = nullptr;
+    unsigned char exists = (*file_exists_)(state_, fname.data(), fname.size(), &status);
+    if (status == nullptr && !exists) {
+      return IOStatus::NotFound();
+    }
+    return ToIOStatus(status);
+  }
+
+  IOStatus GetChildren(const std::string& dir, const IOOptions& /*options*/, std::vector<std::string>* result,
+                       IODebugContext* /*dbg*/) override {
+    rocksdb_status_t* status = nullptr;
+    result->clear();
+    (*get_children_)(state_, dir.data(), dir.size(), reinterpret_cast<rocksdb_fs_children_t*>(result), &status);
+    return ToIOStatus(status);
+  }
+
+  IOStatus DeleteFile(const std::string& fname, const IOOptions& /*options*/, IODebugContext* /*dbg*/) override {
+    return CallPath(delete_file_, fname);
+  }
+
+  IOStatus CreateDir(const std::string& dirname, const IOOptions& /*options*/, IODebugContext* /*dbg*/) override {
+    return CallPath(create_dir_, dirname);
+  }
+
+  IOStatus CreateDirIfMissing(const std::string& dirname, const IOOptions& /*options*/,
+                              IODebugContext* /*dbg*/) override {
+    return CallPath(create_dir_if_missing_, dirname);
+  }
+
+  IOStatus DeleteDir(const std::string& dirname, const IOOptions& /*options*/, IODebugContext* /*dbg*/) override {
+    return CallPath(delete_dir_, dirname);
+  }
+
+  IOStatus GetFileSize(const std::string& fname, const IOOptions& /*options*/, uint64_t* file_size,
+                       IODebugContext* /*dbg*/) override {
+    rocksdb_status_t* status = nullptr;
+    *file_size = (*get_file_size_)(state_, fname.data(), fname.size(), &status);
+    return ToIOStatus(status);
+  }
+
+  IOStatus GetFileModificationTime(const std::string& fname, const IOOptions& /*options*/, uint64_t* file_mtime,
+                                   IODebugContext* /*dbg*/) override {
+    rocksdb_status_t* status = nullptr;
+    *file_mtime = (*get_file_modification_time_)(state_, fname.data(), fname.size(), &status);
+    return ToIOStatus(status);
+  }
+
+  IOStatus RenameFile(const std::string& src, const std::string& target, const IOOptions& /*options*/,
+                      IODebugContext* /*dbg*/) override {
+    rocksdb_status_t* status = nullptr;
+    (*rename_file_)(state_, src.data(), src.size(), target.data(), target.size(), &status);
+    return ToIOStatus(status);
+  }
+
+  IOStatus LockFile(const std::string& fname, const IOOptions& /*options*/, FileLock** lock,
+                    IODebugContext* /*dbg*/) override {
+    rocksdb_status_t* status = nullptr;
+    rocksdb_fs_file_lock_t* handle = (*lock_file_)(state_, fname.data(), fname.size(), &status);
+    if (handle != nullptr) {
+      *lock = handle->rep;
+      delete handle;
+    }
+    return ToIOStatus(status);
+  }
+
+  IOStatus UnlockFile(FileLock* lock, const IOOptions& /*options*/, IODebugContext* /*dbg*/) override {
+    auto* rust_lock = static_cast<RustFileLock*>(lock);
+    IOStatus s = rust_lock->Unlock();
+    delete rust_lock;
+    return s;
+  }
+
+  IOStatus GetTestDirectory(const IOOptions& options, std::string* path, IODebugContext* dbg) override {
+    return base_->GetTestDirectory(options, path, dbg);
+  }
+
+  // Writes the info log to a file created through `new_writable_file`, like
+  // `NewEnvLogger` does for envs without a logger of their own.
+  IOStatus NewLogger(const std::string& fname, const IOOptions& /*options*/, std::shared_ptr<Logger>* result,
+                     IODebugContext* dbg) override {
+    FileOptions options;
+    options.writable_file_max_buffer_size = 1024 * 1024;
+    std::unique_ptr<FSWritableFile> file;
+    IOStatus s = NewWritableFile(fname, options, &file, dbg);
+    if (s.ok()) {
+      *result = std::make_shared<EnvLogger>(std::move(file), fname, options, Env::Default());
+    }
+    return s;
+  }
+
+  IOStatus GetAbsolutePath(const std::string& db_path, const IOOptions& /*options*/, std::string* output_path,
+                           IODebugContext* /*dbg*/) override {
+    rocksdb_status_t* status = nullptr;
+    rocksdb_fs_path_t path;
+    (*get_absolute_path_)(state_, db_path.data(), db_path.size(), &path, &status);
+    if (status == nullptr) {
+      *output_path = std::move(path.rep);
+    }
+    return ToIOStatus(status);
+  }
+
+  IOStatus IsDirectory(const std::string& path, const IOOptions& /*options*/, bool* is_dir,
+                       IODebugContext* /*dbg*/) override {
+    rocksdb_status_t* status = nullptr;
+    unsigned char result = (*is_directory_)(state_, path.data(), path.size(), &status);
+    if (is_dir != nullptr) {
+      *is_dir = result != 0;
+    }
+    return ToIOStatus(status);
+  }
+
+ private:
+  IOStatus CallPath(PathCallback callback, const std::string& path) {
+    rocksdb_status_t* status = nullptr;
+    (*callback)(state_, path.data(), path.size(), &status);
+    return ToIOStatus(status);
+  }
+
+  void* state_;
+  void (*destructor_)(void*);
+  rocksdb_fs_sequential_file_t* (*new_sequential_file_)(void*, const char*, size_t, rocksdb_status_t**);
+  rocksdb_fs_random_access_file_t* (*new_random_access_file_)(void*, const char*, size_t, rocksdb_status_t**);
+  rocksdb_fs_writable_file_t* (*new_writable_file_)(void*, const char*, size_t, rocksdb_status_t**);
+  unsigned char (*file_exists_)(void*, const char*, size_t, rocksdb_status_t**);
+  void (*get_children_)(void*, const char*, size_t, rocksdb_fs_children_t*, rocksdb_status_t**);
+  PathCallback delete_file_;
+  PathCallback create_dir_;
+  PathCallback create_dir_if_missing_;
+  PathCallback delete_dir_;
+  PathCallback fsync_dir_;
+  uint64_t (*get_file_size_)(void*, const char*, size_t, rocksdb_status_t**);
+  uint64_t (*get_file_modification_time_)(void*, const char*, size_t, rocksdb_status_t**);
+  void (*rename_file_)(void*, const char*, size_t, const char*, size_t, rocksdb_status_t**);
+  rocksdb_fs_file_lock_t* (*lock_file_)(void*, const char*, size_t, rocksdb_status_t**);
+  unsigned char (*is_directory_)(void*, const char*, size_t, rocksdb_status_t**);
+  void (*get_absolute_path_)(void*, const char*, size_t, rocksdb_fs_path_t*, rocksdb_status_t**);
+  std::shared_ptr<FileSystem> base_;
+};
+
+// Copies `result` into `scratch` unless the file already read into it, e.g.
+// when it is memory mapped.
+size_t CopyResult(const Slice& result, char* scratch) {
+  if (result.data() != scratch) {
+    memcpy(scratch, result.data(), result.size());
+  }
+  return result.size();
+}
+}  // namespace
+
+extern "C" {
+rocksdb_file_system_t* rocksdb_file_system_create(
+    void* state, void (*destructor)(void*),
+    rocksdb_fs_sequential_file_t* (*new_sequential_file)(void*, const char*, size_t, rocksdb_status_t**),
+    rocksdb_fs_random_access_file_t* (*new_random_access_file)(void*, const char*, size_t, rocksdb_status_t**),
+    rocksdb_fs_writable_file_t* (*new_writable_file)(void*, const char*, size_t, rocksdb_status_t**),
+    unsigned char (*file_exists)(void*, const char*, size_t, rocksdb_status_t**),
+    void (*get_children)(void*, const char*, size_t, rocksdb_fs_children_t*, rocksdb_status_t**),
+    void (*delete_file)(void*, const char*, size_t, rocksdb_status_t**), void (*create_dir)(void*, const char*, size_t, rocksdb_status_t**),
+    void (*create_dir_if_missing)(void*, const char*, size_t, rocksdb_status_t**),
+    void (*delete_dir)(void*, const char*, size_t, rocksdb_status_t**), void (*fsync_dir)(void*, const char*, size_t, rocksdb_status_t**),
+    uint64_t (*get_file_size)(void*, const char*, size_t, rocksdb_status_t**),
+    uint64_t (*get_file_modification_time)(void*, const char*, size_t, rocksdb_status_t**),
+    void (*rename_file)(void*, const char*, size_t, const char*, size_t, rocksdb_status_t**),
+    rocksdb_fs_file_lock_t* (*lock_file)(void*, const char*, size_t, rocksdb_status_t**),
+    unsigned char (*is_directory)(void*, const char*, size_t, rocksdb_status_t**),
+    void (*get_absolute_path)(void*, const char*, size_t, rocksdb_fs_path_t*, rocksdb_status_t**)) {
+  auto* fs = new rocksdb_file_system_t;
+  fs->rep = std::make_shared<RustFileSystem>(state, destructor, new_sequential_file, new_random_access_file,
+                                             new_writable_file, file_exists, get_children, delete_file, create_dir,
+                                             create_dir_if_missing, delete_dir, fsync_dir, get_file_size,
+                                             get_file_modification_time, rename_file, lock_file, is_directory,
+                                             get_absolute_path);
+  return fs;
+}
+
+rocksdb_file_system_t* rocksdb_file_system_default() {
+  static rocksdb_file_system_t* fs = new rocksdb_file_system_t{FileSystem::Default()};
+  return fs;
+}
+
+void rocksdb_file_system_destroy(rocksdb_file_system_t* fs) { delete fs; }
+
+rocksdb_env_t* rocksdb_create_env_from_file_system(rocksdb_file_system_t* fs) {
+  auto* env = new rocksdb_env_t;
+  env->rep = NewCompositeEnv(fs->rep).release();
+  env->is_default = false;
+  return env;
+}
+
+rocksdb_fs_sequential_file_t* rocksdb_fs_sequential_file_create(void* state, void (*destructor)(void*),
+                                                                 size_t (*read)(void*, char*, size_t, rocksdb_status_t**),
+                                                                 void (*skip)(void*, uint64_t, rocksdb_status_t**)) {
+  auto* file = new rocksdb_fs_sequential_file_t;
+  file->rep.reset(new RustSequentialFile(state, destructor, read, skip));
+  return file;
+}
+
+rocksdb_fs_random_access_file_t* rocksdb_fs_random_access_file_create(
+    void* state, void (*destructor)(void*), size_t (*read)(void*, uint64_t, char*, size_t, rocksdb_status_t**)) {
+  auto* file = new rocksdb_fs_random_access_file_t;
+  file->rep.reset(new RustRandomAccessFile(state, destructor, read));
+  return file;
+}
+
+rocksdb_fs_writable_file_t* rocksdb_fs_writable_file_create(void* state, void (*destructor)(void*),
+                                                            void (*append)(void*, const char*, size_t, rocksdb_status_t**),
+                                                            void (*flush)(void*, rocksdb_status_t**),
+                                                            void (*sync)(void*, rocksdb_status_t**),
+                                                            void (*close)(void*, rocksdb_status_t**),
+                                                            uint64_t (*get_file_size)(void*)) {
+  auto* file = new rocksdb_fs_writable_file_t;
+  file->rep.reset(new RustWritableFile(state, destructor, append, flush, sync, close, get_file_size));
+  return file;
+}
+
+rocksdb_fs_file_lock_t* rocksdb_fs_file_lock_create(void* state, void (*unlock)(void*, rocksdb_status_t**)) {
+  auto* lock = new rocksdb_fs_file_lock_t;
+  lock->rep = new RustFileLock(state, unlock);
+  return lock;
+}
+
+void rocksdb_fs_children_push(rocksdb_fs_children_t* children, const char* name, size_t name_len) {
+  reinterpret_cast<std::vector<std::string>*>(children)->emplace_back(name, name_len);
+}
+
+void rocksdb_fs_path_set(rocksdb_fs_path_t* path, const char* value, size_t value_len) {
+  path->rep.assign(value, value_len);
+}
+
+/* operations of a file system */
+
+rocksdb_fs_sequential_file_t* rocksdb_file_system_new_sequential_file(rocksdb_file_system_t* fs, const char* fname,
+                                                                      size_t fname_len, rocksdb_status_t** statusptr) {
+  std::unique_ptr<FSSequentialFile> result;
+  IOStatus s = fs->rep->NewSequentialFile(std::string(fname, fname_len), FileOptions(), &result, nullptr);
+  if (SaveStatus(statusptr, s)) {
+    return nullptr;
+  }
+  auto* file = new rocksdb_fs_sequential_file_t;
+  file->rep = std::move(result);
+  return file;
+}
+
+rocksdb_fs_random_access_file_t* rocksdb_file_system_new_random_access_file(rocksdb_file_system_t* fs,
+                                                                            const char* fname, size_t fname_len,
+                                                                            rocksdb_status_t** statusptr) {
+  std::unique_ptr<FSRandomAccessFile> result;
+  IOStatus s = fs->rep->NewRandomAccessFile(std::string(fname, fname_len), FileOptions(), &result, nullptr);
+  if (SaveStatus(statusptr, s)) {
+    return nullptr;
+  }
+  auto* file = new rocksdb_fs_random_access_file_t;
+  file->rep = std::move(result);
+  return file;
+}
+
+rocksdb_fs_writable_file_t* rocksdb_file_system_new_writable_file(rocksdb_file_system_t* fs, const char* fname,
+                                                                  size_t fname_len, rocksdb_status_t** statusptr) {
+  std::unique_ptr<FSWritableFile> result;
+  IOStatus s = fs->rep->NewWritableFile(std::string(fname, fname_len), FileOptions(), &result, nullptr);
+  if (SaveStatus(statusptr, s)) {
+    return nullptr;
+  }
+  auto* file = new rocksdb_fs_writable_file_t;
+  file->rep = std::move(result);
+  return file;
+}
+
+unsigned char rocksdb_file_system_file_exists(rocksdb_file_system_t* fs, const char* fname, size_t fname_len,
+                                              rocksdb_status_t** statusptr) {
+  IOStatus s = fs->rep->FileExists(std::string(fname, fname_len), IOOptions(), nullptr);
+  if (s.IsNotFound()) {
+    return 0;
+  }
+  return !SaveStatus(statusptr, s);
+}
+
+void rocksdb_file_system_get_children(rocksdb_file_system_t* fs, const char* dir, size_t dir_len, void* state,
+                                      void (*push)(void*, const char*, size_t), rocksdb_status_t** statusptr) {
+  std::vector<std::string> children;
+  IOStatus s = fs->rep->GetChildren(std::string(dir, dir_len), IOOptions(), &children, nullptr);
+  if (SaveStatus(statusptr, s)) {
+    return;
+  }
+  for (const auto& child : children) {
+    (*push)(state, child.data(), child.size());
+  }
+}
+
+void rocksdb_file_system_delete_file(rocksdb_file_system_t* fs, const char* fname, size_t fname_len,
+                                     rocksdb_status_t** statusptr) {
+  SaveStatus(statusptr, fs->rep->DeleteFile(std::string(fname, fname_len), IOOptions(), nullptr));
+}
+
+void rocksdb_file_system_create_dir(rocksdb_file_system_t* fs, const char* dir, size_t dir_len, rocksdb_status_t** statusptr) {
+  SaveStatus(statusptr, fs->rep->CreateDir(std::string(dir, dir_len), IOOptions(), nullptr));
+}
+
+void rocksdb_file_system_create_dir_if_missing(rocksdb_file_system_t* fs, const char* dir, size_t dir_len,
+                                               rocksdb_status_t** statusptr) {
+  SaveStatus(statusptr, fs->rep->CreateDirIfMissing(std::string(dir, dir_len), IOOptions(), nullptr));
+}
+
+void rocksdb_file_system_delete_dir(rocksdb_file_system_t* fs, const char* dir, size_t dir_len, rocksdb_status_t** statusptr) {
+  SaveStatus(statusptr, fs->rep->DeleteDir(std::string(dir, dir_len), IOOptions(), nullptr));
+}
+
+void rocksdb_file_system_fsync_dir(rocksdb_file_system_t* fs, const char* dir, size_t dir_len, rocksdb_status_t** statusptr) {
+  std::unique_ptr<FSDirectory> directory;
+  IOStatus s = fs->rep->NewDirectory(std::string(dir, dir_len), IOOptions(), &directory, nullptr);
+  if (s.ok()) {
+    s = directory->Fsync(IOOptions(), nullptr);
+  }
+  SaveStatus(statusptr, s);
+}
+
+uint64_t rocksdb_file_system_get_file_size(rocksdb_file_system_t* fs, const char* fname, size_t fname_len,
+                                           rocksdb_status_t** statusptr) {
+  uint64_t size = 0;
+  SaveStatus(statusptr, fs->rep->GetFileSize(std::string(fname, fname_len), IOOptions(), &size, nullptr));
+  return size;
+}
+
+uint64_t rocksdb_file_system_get_file_modification_time(rocksdb_file_system_t* fs, const char* fname,
+                                                        size_t fname_len, rocksdb_status_t** statusptr) {
+  uint64_t mtime = 0;
+  SaveStatus(statusptr, fs->rep->GetFileModificationTime(std::string(fname, fname_len), IOOptions(), &mtime, nullptr));
+  return mtime;
+}
+
+void rocksdb_file_system_rename_file(rocksdb_file_system_t* fs, const char* src, size_t src_len, const char* target,
+                                     size_t target_len, rocksdb_status_t** statusptr) {
+  SaveStatus(statusptr, fs->rep->RenameFile(std::string(src, src_len), std::string(target, target_len), IOOptions(),
+                                        nullptr));
+}
+
+rocksdb_fs_file_lock_t* rocksdb_file_system_lock_file(rocksdb_file_system_t* fs, const char* fname,
+                                                      size_t fname_len, rocksdb_status_t** statusptr) {
+  FileLock* result = nullptr;
+  IOStatus s = fs->rep->LockFile(std::string(fname, fname_len), IOOptions(), &result, nullptr);
+  if (SaveStatus(statusptr, s)) {
+    return nullptr;
+  }
+  auto* lock = new rocksdb_fs_file_lock_t;
+  lock->rep = result;
+  lock->fs = fs->rep;
+  return lock;
+}
+
+unsigned char rocksdb_file_system_is_directory(rocksdb_file_system_t* fs, const char* path, size_t path_len,
+                                               rocksdb_status_t** statusptr) {
+  bool is_dir = false;
+  SaveStatus(statusptr, fs->rep->IsDirectory(std::string(path, path_len), IOOptions(), &is_dir, nullptr));
+  return is_dir;
+}
+
+char* rocksdb_file_system_get_absolute_path(rocksdb_file_system_t* fs, const char* path, size_t path_len,
+                                            size_t* output_len, rocksdb_status_t** statusptr) {
+  std::string output;
+  IOStatus s = fs->rep->GetAbsolutePath(std::string(path, path_len), IOOptions(), &output, nullptr);
+  if (SaveStatus(statusptr, s)) {
+    *output_len = 0;
+    return nullptr;
+  }
+  *output_len = output.size();
+  char* result = static_cast<char*>(malloc(output.size()));
+  memcpy(result, output.data(), output.size());
+  return result;
+}
+
+void rocksdb_file_system_unlock_file(rocksdb_fs_file_lock_t* lock, rocksdb_status_t** statusptr) {
+  SaveStatus(statusptr, lock->fs->UnlockFile(lock->rep, IOOptions(), nullptr));
+  delete lock;
+}
+
+/* operations of a file */
+
+size_t rocksdb_fs_sequential_file_read(rocksdb_fs_sequential_file_t* file, char* scratch, size_t n,
+                                       rocksdb_status_t** statusptr) {
+  Slice result;
+  if (SaveStatus(statusptr, file->rep->Read(n, IOOptions(), &result, scratch, nullptr))) {
+    return 0;
+  }
+  return CopyResult(result, scratch);
+}
+
+void rocksdb_fs_sequential_file_skip(rocksdb_fs_sequential_file_t* file, uint64_t n, rocksdb_status_t** statusptr) {
+  SaveStatus(statusptr, file->rep->Skip(n));
+}
+
+void rocksdb_fs_sequential_file_destroy(rocksdb_fs_sequential_file_t* file) { delete file; }
+
+size_t rocksdb_fs_random_access_file_read(rocksdb_fs_random_access_file_t* file, uint64_t offset, char* scratch,
+                                          size_t n, rocksdb_status_t** statusptr) {
+  Slice result;
+  if (SaveStatus(statusptr, file->rep->Read(offset, n, IOOptions(), &result, scratch, nullptr))) {
+    return 0;
+  }
+  return CopyResult(result, scratch);
+}
+
+void rocksdb_fs_random_access_file_destroy(rocksdb_fs_random_access_file_t* file) { delete file; }
+
+void rocksdb_fs_writable_file_append(rocksdb_fs_writable_file_t* file, const char* data, size_t len,
+                                     rocksdb_status_t** statusptr) {
+  SaveStatus(statusptr, file->rep->Append(Slice(data, len), IOOptions(), nullptr));
+}
+
+void rocksdb_fs_writable_file_flush(rocksdb_fs_writable_file_t* file, rocksdb_status_t** statusptr) {
+  SaveStatus(statusptr, file->rep->Flush(IOOptions(), nullptr));
+}
+
+void rocksdb_fs_writable_file_sync(rocksdb_fs_writable_file_t* file, rocksdb_status_t** statusptr) {
+  SaveStatus(statusptr, file->rep->Sync(IOOptions(), nullptr));
+}
+
+void rocksdb_fs_writable_file_close(rocksdb_fs_writable_file_t* file, rocksdb_status_t** statusptr) {
+  SaveStatus(statusptr, file->rep->Close(IOOptions(), nullptr));
+}
+
+uint64_t rocksdb_fs_writable_file_get_file_size(rocksdb_fs_writable_file_t* file) {
+  return file->rep->GetFileSize(IOOptions(), nullptr);
+}
+
+void rocksdb_fs_writable_file_destroy(rocksdb_fs_writable_file_t* file) { delete file; }
+}
@@ -0,0 +1,69 @@
+// Implementation of `Logger` functions in `c.h`.
+#include <cstdarg>
+#include <cstdio>
+#include <cstdlib>
+
+#include "c_api_extensions/ctypes.hpp"
+#include "rocksdb/env.h"
+
+using namespace ROCKSDB_NAMESPACE;
+
+namespace {
+// Formats each message and hands it to a C callback. `destructor` releases
+// `state` when the last reference to the logger is dropped.
+class CallbackLogger : public Logger {
+ public:
+  static const int kStackBufferSize = 512;
+
+  CallbackLogger(InfoLogLevel log_level, void* state, void (*destructor)(void*),
+                 void (*log)(void*, int, const char*, size_t))
+      : Logger(log_level), state_(state), destructor_(destructor), log_(log) {}
+
+  ~CallbackLogger() override { (*destructor_)(state_); }
+
+  using Logger::Logv;
+  void Logv(const char* format, va_list ap) override { Logv(InfoLogLevel::INFO_LEVEL, format, ap); }
+
+  void LogHeader(const char* format, va_list ap) override { Logv(InfoLogLevel::HEADER_LEVEL, format, ap); }
+
+  void Logv(const InfoLogLevel level, const char* format, va_list ap) override {
+    if (level < GetInfoLogLevel()) {
+      return;
+    }
+
+    char stack_buffer[kStackBufferSize];
+    char* heap_buffer = nullptr;
+    char* buffer = stack_buffer;
+
+    va_list backup_ap;
+    va_copy(backup_ap, ap);
+    int len = vsnprintf(buffer, kStackBufferSize, format, ap);
+    if (len >= kStackBufferSize) {
+      buffer = heap_buffer = static_cast<char*>(malloc(len + 1));
+      len = buffer == nullptr ? -1 : vsnprintf(buffer, len + 1, format, backup_ap);
+    }
+    va_end(backup_ap);
+
+    if (len > 0) {
+      (*log_)(state_, static_cast<int>(level), buffer, static_cast<size_t>(len));
+    }
+    free(heap_buffer);
+  }
+
+ private:
+  void* state_;
+  void (*destructor_)(void*);
+  void (*log_)(void*, int, const char*, size_t);
+};
+}  // namespace
+
+extern "C" {
+rocksdb_logger_t* rocksdb_logger_create_callback_logger(int log_level, void* state, void (*destructor)(void*),
+                                                        void (*log)(void*, int, const char*, size_t)) {
+  auto* logger = new rocksdb_logger_t;
+  logger->rep = std::make_shared<CallbackLogger>(static_cast<InfoLogLevel>(log_level), state, destructor, log);
+  return logger;
+}
+
+void rocksdb_logger_destroy(rocksdb_logger_t* logger) { delete logger; }
+}
@@ -0,0 +1,134 @@
+// Implementation of `MergeOperator` functions in `c.h`.
+#include "c_api_extensions/ctypes.hpp"
+#include "rocksdb/env.h"
+#include "rocksdb/merge_operator.h"
+
+using namespace ROCKSDB_NAMESPACE;
+
+namespace {
+// Pointers to the data and sizes of `operands`, as passed to the callbacks.
+template <typename Operands>
+struct OperandArrays {
+  explicit OperandArrays(const Operands& operands) {
+    data.reserve(operands.size());
+    sizes.reserve(operands.size());
+    for (const Slice& operand : operands) {
+      data.push_back(operand.data());
+      sizes.push_back(operand.size());
+    }
+  }
+
+  int count() const { return static_cast<int>(data.size()); }
+
+  std::vector<const char*> data;
+  std::vector<size_t> sizes;
+};
+}  // namespace
+
+// Merges with C callbacks, which return whether they succeeded and set the
+// merged value or the error on `result`.
+struct rocksdb_mergeoperator_v2_t : public MergeOperator {
+  rocksdb_mergeoperator_v2_t(void* state, void (*destructor)(void*),
+                        unsigned char (*full_merge)(void*, const char*, size_t, const char*, size_t,
+                                                    const char* const*, const size_t*, int,
+                                                    rocksdb_mergeoperator_result_t*),
+                        unsigned char (*partial_merge)(void*, const char*, size_t, const char*, size_t, const char*,
+                                                       size_t, rocksdb_mergeoperator_result_t*),
+                        unsigned char (*partial_merge_multi)(void*, const char*, size_t, const char* const*,
+                                                             const size_t*, int, rocksdb_mergeoperator_result_t*),
+                        unsigned char (*should_merge)(void*, const char* const*, const size_t*, int),
+                        unsigned char allow_single_operand, const char* (*name)(void*))
+      : state_(state),
+        destructor_(destructor),
+        full_merge_(full_merge),
+        partial_merge_(partial_merge),
+        partial_merge_multi_(partial_merge_multi),
+        should_merge_(should_merge),
+        allow_single_operand_(allow_single_operand),
+        name_(name) {}
+
+  ~rocksdb_mergeoperator_v2_t() override { (*destructor_)(state_); }
+
+  bool FullMergeV2(const MergeOperationInput& merge_in, MergeOperationOutput* merge_out) const override {
+    OperandArrays<std::vector<Slice>> operands(merge_in.operand_list);
+    rocksdb_mergeoperator_result_t result{&merge_out->new_value, OpFailureScope::kDefault, std::string()};
+    const Slice* existing_value = merge_in.existing_value;
+    bool success = (*full_merge_)(state_, merge_in.key.data(), merge_in.key.size(),
+                                  existing_value == nullptr ? nullptr : existing_value->data(),
+                                  existing_value == nullptr ? 0 : existing_value->size(), operands.data.data(),
+                                  operands.sizes.data(), operands.count(), &result);
+    if (!success) {
+      merge_out->op_failure_scope = result.op_failure_scope;
+      Log(InfoLogLevel::ERROR_LEVEL, merge_in.logger, "[%s] Merge failed: %s", Name(), result.error.c_str());
+    }
+    return success;
+  }
+
+  bool PartialMerge(const Slice& key, const Slice& left_operand, const Slice& right_operand, std::string* new_value,
+                    Logger* /*logger*/) const override {
+    rocksdb_mergeoperator_result_t result{new_value, OpFailureScope::kDefault, std::string()};
+    return (*partial_merge_)(state_, key.data(), key.size(), left_operand.data(), left_operand.size(),
+                             right_operand.data(), right_operand.size(), &result);
+  }
+
+  bool PartialMergeMulti(const Slice& key, const std::deque<Slice>& operand_list, std::string* new_value,
+                         Logger* /*logger*/) const override {
+    OperandArrays<std::deque<Slice>> operands(operand_list);
+    rocksdb_mergeoperator_result_t result{new_value, OpFailureScope::kDefault, std::string()};
+    return (*partial_merge_multi_)(state_, key.data(), key.size(), operands.data.data(), operands.sizes.data(),
+                                   operands.count(), &result);
+  }
+
+  bool ShouldMerge(const std::vector<Slice>& operand_list) const override {
+    OperandArrays<std::vector<Slice>> operands(operand_list);
+    return (*should_merge_)(state_, operands.data.data(), operands.sizes.data(), operands.count());
+  }
+
+  bool AllowSingleOperand() const override { return allow_single_operand_; }
+
+  const char* Name() const override { return (*name_)(state_); }
+
+ private:
+  void* state_;
+  void (*destructor_)(void*);
+  unsigned char (*full_merge_)(void*, const char*, size_t, const char*, size_t, const char* const*, const size_t*,
+                               int, rocksdb_mergeoperator_result_t*);
+  unsigned char (*partial_merge_)(void*, const char*, size_t, const char*, size_t, const char*, size_t,
+                                  rocksdb_mergeoperator_result_t*);
+  unsigned char (*partial_merge_multi_)(void*, const char*, size_t, const char* const*, const size_t*, int,
+                                        rocksdb_mergeoperator_result_t*);
+  unsigned char (*should_merge_)(void*, const char* const*, const size_t*, int);
+  bool allow_single_operand_;
+  const char* (*name_)(void*);
+};
+
+extern "C" {
+rocksdb_mergeoperator_v2_t* rocksdb_mergeoperator_create_v2(
+    void* state, void (*destructor)(void*),
+    unsigned char (*full_merge)(void*, const char*, size_t, const char*, size_t, const char* const*, const size_t*,
+                                int, rocksdb_mergeoperator_result_t*),
+    unsigned char (*partial_merge)(void*, const char*, size_t, const char*, size_t, const char*, size_t,
+                                   rocksdb_mergeoperator_result_t*),
+    unsigned char (*partial_merge_multi)(void*, const char*, size_t, const char* const*, const size_t*, int,
+                                         rocksdb_mergeoperator_result_t*),
+    unsigned char (*should_merge)(void*, const char* const*, const size_t*, int), unsigned char allow_single_operand,
+    const char* (*name)(void*)) {
+  return new rocksdb_mergeoperator_v2_t(state, destructor, full_merge, partial_merge, partial_merge_multi,
+                                        should_merge, allow_single_operand, name);
+}
+
+void rocksdb_options_set_merge_operator_v2(rocksdb_options_t* opt, rocksdb_mergeoperator_v2_t* merge_operator) {
+  opt->rep.merge_operator = std::shared_ptr<MergeOperator>(merge_operator);
+}
+
+void rocksdb_mergeoperator_result_set_value(rocksdb_mergeoperator_result_t* result, const char* value,
+                                            size_t value_len) {
+  result->value->assign(value, value_len);
+}
+
+void rocksdb_mergeoperator_result_set_error(rocksdb_mergeoperator_result_t* result, int op_failure_scope,
+                                            const char* message, size_t message_len) {
+  result->op_failure_scope = static_cast<MergeOperator::OpFailureScope>(op_failure_scope);
+  result->error.assign(message, message_len);
+}
+}
@@ -0,0 +1,117 @@
+// Implementation of column family metadata functions in `c.h`.
+#include "c_api_extensions/ctypes.hpp"
+
+using namespace ROCKSDB_NAMESPACE;
+
+extern "C" {
+uint64_t rocksdb_sst_file_metadata_get_file_number(rocksdb_sst_file_metadata_t* file_meta) {
+  return file_meta->rep->file_number;
+}
+
+uint64_t rocksdb_sst_file_metadata_get_smallest_seqno(rocksdb_sst_file_metadata_t* file_meta) {
+  return file_meta->rep->smallest_seqno;
+}
+
+uint64_t rocksdb_sst_file_metadata_get_largest_seqno(rocksdb_sst_file_metadata_t* file_meta) {
+  return file_meta->rep->largest_seqno;
+}
+
+uint64_t rocksdb_sst_file_metadata_get_num_reads_sampled(rocksdb_sst_file_metadata_t* file_meta) {
+  return file_meta->rep->num_reads_sampled;
+}
+
+unsigned char rocksdb_sst_file_metadata_get_being_compacted(rocksdb_sst_file_metadata_t* file_meta) {
+  return file_meta->rep->being_compacted;
+}
+
+uint64_t rocksdb_sst_file_metadata_get_num_entries(rocksdb_sst_file_metadata_t* file_meta) {
+  return file_meta->rep->num_entries;
+}
+
+uint64_t rocksdb_sst_file_metadata_get_num_deletions(rocksdb_sst_file_metadata_t* file_meta) {
+  return file_meta->rep->num_deletions;
+}
+
+uint64_t rocksdb_sst_file_metadata_get_oldest_blob_file_number(rocksdb_sst_file_metadata_t* file_meta) {
+  return file_meta->rep->oldest_blob_file_number;
+}
+
+uint64_t rocksdb_sst_file_metadata_get_oldest_ancester_time(rocksdb_sst_file_metadata_t* file_meta) {
+  return file_meta->rep->oldest_ancester_time;
+}
+
+uint64_t rocksdb_sst_file_metadata_get_file_creation_time(rocksdb_sst_file_metadata_t* file_meta) {
+  return file_meta->rep->file_creation_time;
+}
+
+uint64_t rocksdb_sst_file_metadata_get_epoch_number(rocksdb_sst_file_metadata_t* file_meta) {
+  return file_meta->rep->epoch_number;
+}
+
+unsigned char rocksdb_sst_file_metadata_get_temperature(rocksdb_sst_file_metadata_t* file_meta) {
+  return static_cast<unsigned char>(file_meta->rep->temperature);
+}
+
+const char* rocksdb_sst_file_metadata_get_file_checksum(rocksdb_sst_file_metadata_t* file_meta, size_t* len) {
+  *len = file_meta->rep->file_checksum.size();
+  return file_meta->rep->file_checksum.data();
+}
+
+const char* rocksdb_sst_file_metadata_get_file_checksum_func_name(rocksdb_sst_file_metadata_t* file_meta,
+                                                                  size_t* len) {
+  *len = file_meta->rep->file_checksum_func_name.size();
+  return file_meta->rep->file_checksum_func_name.data();
+}
+
+uint64_t rocksdb_column_family_metadata_get_blob_file_size(rocksdb_column_family_metadata_t* cf_meta) {
+  return cf_meta->rep.blob_file_size;
+}
+
+size_t rocksdb_column_family_metadata_get_blob_file_count(rocksdb_column_family_metadata_t* cf_meta) {
+  return cf_meta->rep.blob_files.size();
+}
+
+rocksdb_blob_metadata_t* rocksdb_column_family_metadata_get_blob_file_metadata(
+    rocksdb_column_family_metadata_t* cf_meta, size_t i) {
+  if (i >= cf_meta->rep.blob_files.size()) {
+    return nullptr;
+  }
+  return new rocksdb_blob_metadata_t{&cf_meta->rep.blob_files[i]};
+}
+
+void rocksdb_blob_metadata_destroy(rocksdb_blob_metadata_t* blob_meta) { delete blob_meta; }
+
+uint64_t rocksdb_blob_metadata_get_file_number(rocksdb_blob_metadata_t* blob_meta) {
+  return blob_meta->rep->blob_file_number;
+}
+
+const char* rocksdb_blob_metadata_get_file_name(rocksdb_blob_metadata_t* blob_meta, size_t* len) {
+  *len = blob_meta->rep->blob_file_name.size();
+  return blob_meta->rep->blob_file_name.data();
+}
+
+const char* rocksdb_blob_metadata_get_file_path(rocksdb_blob_metadata_t* blob_meta, size_t* len) {
+  *len = blob_meta->rep->blob_file_path.size();
+  return blob_meta->rep->blob_file_path.data();
+}
+
+uint64_t rocksdb_blob_metadata_get_file_size(rocksdb_blob_metadata_t* blob_meta) {
+  return blob_meta->rep->blob_file_size;
+}
+
+uint64_t rocksdb_blob_metadata_get_total_blob_count(rocksdb_blob_metadata_t* blob_meta) {
+  return blob_meta->rep->total_blob_count;
+}
+
+uint64_t rocksdb_blob_metadata_get_total_blob_bytes(rocksdb_blob_metadata_t* blob_meta) {
+  return blob_meta->rep->total_blob_bytes;
+}
+
+uint64_t rocksdb_blob_metadata_get_garbage_blob_count(rocksdb_blob_metadata_t* blob_meta) {
+  return blob_meta->rep->garbage_blob_count;
+}
+
+uint64_t rocksdb_blob_metadata_get_garbage_blob_bytes(rocksdb_blob_metadata_t* blob_meta) {
+  return blob_meta->rep->garbage_blob_bytes;
+}
+}
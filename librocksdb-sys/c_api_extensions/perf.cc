@@ -0,0 +1,31 @@
+// Implementation of memory usage functions in `c.h`.
+#include <map>
+
+#include "c_api_extensions/ctypes.hpp"
+#include "rocksdb/utilities/memory_util.h"
+
+using namespace ROCKSDB_NAMESPACE;
+
+extern "C" {
+rocksdb_memory_usage_t* rocksdb_approximate_memory_usage_create_with_status(rocksdb_memory_consumers_t* consumers,
+                                                                            rocksdb_status_t** statusptr) {
+  std::vector<DB*> dbs;
+  for (auto db : consumers->dbs) {
+    dbs.push_back(db->rep);
+  }
+  std::unordered_set<const Cache*> caches;
+  for (auto cache : consumers->caches) {
+    caches.insert(cache->rep.get());
+  }
+  std::map<MemoryUtil::UsageType, uint64_t> usage_by_type;
+  if (SaveStatus(statusptr, MemoryUtil::GetApproximateMemoryUsageByType(dbs, caches, &usage_by_type))) {
+    return nullptr;
+  }
+  return new rocksdb_memory_usage_t{
+      usage_by_type[MemoryUtil::kMemTableTotal],
+      usage_by_type[MemoryUtil::kMemTableUnFlushed],
+      usage_by_type[MemoryUtil::kTableReadersTotal],
+      usage_by_type[MemoryUtil::kCacheTotal],
+  };
+}
+}
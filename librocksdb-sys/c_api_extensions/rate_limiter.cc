@@ -0,0 +1,35 @@
+// Implementation of `RateLimiter` functions in `c.h`.
+#include "c_api_extensions/ctypes.hpp"
+#include "rocksdb/rate_limiter.h"
+
+using namespace ROCKSDB_NAMESPACE;
+
+extern "C" {
+rocksdb_ratelimiter_t* rocksdb_ratelimiter_create_auto_tuned(int64_t rate_bytes_per_sec, int64_t refill_period_us,
+                                                             int32_t fairness) {
+  auto* limiter = new rocksdb_ratelimiter_t;
+  limiter->rep.reset(NewGenericRateLimiter(rate_bytes_per_sec, refill_period_us, fairness,
+                                           RateLimiter::Mode::kWritesOnly, true /* auto_tuned */));
+  return limiter;
+}
+
+void rocksdb_ratelimiter_set_bytes_per_second(rocksdb_ratelimiter_t* limiter, int64_t bytes_per_second) {
+  limiter->rep->SetBytesPerSecond(bytes_per_second);
+}
+
+int64_t rocksdb_ratelimiter_get_bytes_per_second(rocksdb_ratelimiter_t* limiter) {
+  return limiter->rep->GetBytesPerSecond();
+}
+
+int64_t rocksdb_ratelimiter_get_single_burst_bytes(rocksdb_ratelimiter_t* limiter) {
+  return limiter->rep->GetSingleBurstBytes();
+}
+
+int64_t rocksdb_ratelimiter_get_total_bytes_through(rocksdb_ratelimiter_t* limiter) {
+  return limiter->rep->GetTotalBytesThrough();
+}
+
+int64_t rocksdb_ratelimiter_get_total_requests(rocksdb_ratelimiter_t* limiter) {
+  return limiter->rep->GetTotalRequests();
+}
+}
@@ -0,0 +1,30 @@
+// Implementation of `SstFileWriter` functions in `c.h`.
+#include "c_api_extensions/ctypes.hpp"
+
+using namespace ROCKSDB_NAMESPACE;
+
+extern "C" {
+void rocksdb_sstfilewriter_open_with_status(rocksdb_sstfilewriter_t* writer, const char* name,
+                                            rocksdb_status_t** statusptr) {
+  SaveStatus(statusptr, writer->rep->Open(std::string(name)));
+}
+
+void rocksdb_sstfilewriter_put_with_status(rocksdb_sstfilewriter_t* writer, const char* key, size_t keylen,
+                                           const char* val, size_t vallen, rocksdb_status_t** statusptr) {
+  SaveStatus(statusptr, writer->rep->Put(Slice(key, keylen), Slice(val, vallen)));
+}
+
+void rocksdb_sstfilewriter_merge_with_status(rocksdb_sstfilewriter_t* writer, const char* key, size_t keylen,
+                                             const char* val, size_t vallen, rocksdb_status_t** statusptr) {
+  SaveStatus(statusptr, writer->rep->Merge(Slice(key, keylen), Slice(val, vallen)));
+}
+
+void rocksdb_sstfilewriter_delete_with_status(rocksdb_sstfilewriter_t* writer, const char* key, size_t keylen,
+                                              rocksdb_status_t** statusptr) {
+  SaveStatus(statusptr, writer->rep->Delete(Slice(key, keylen)));
+}
+
+void rocksdb_sstfilewriter_finish_with_status(rocksdb_sstfilewriter_t* writer, rocksdb_status_t** statusptr) {
+  SaveStatus(statusptr, writer->rep->Finish(nullptr));
+}
+}
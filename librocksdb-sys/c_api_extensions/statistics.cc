@@ -0,0 +1,104 @@
+// Implementation of `Statistics` functions in `c.h`.
+#include <cstring>
+#include <memory>
+#include <string>
+
+#include "c_api_extensions/ctypes.hpp"
+#include "rocksdb/statistics.h"
+
+using namespace ROCKSDB_NAMESPACE;
+
+template <typename T>
+static int FindType(const std::vector<std::pair<T, std::string>>& name_map, const char* name, size_t name_len) {
+  for (const auto& entry : name_map) {
+    if (entry.second.size() == name_len && memcmp(entry.second.data(), name, name_len) == 0) {
+      return static_cast<int>(entry.first);
+    }
+  }
+  return -1;
+}
+
+extern "C" {
+rocksdb_statistics_t* rocksdb_statistics_create() {
+  auto* stats = new rocksdb_statistics_t;
+  stats->rep = CreateDBStatistics();
+  return stats;
+}
+
+void rocksdb_statistics_destroy(rocksdb_statistics_t* stats) { delete stats; }
+
+void rocksdb_options_set_statistics(rocksdb_options_t* opt, rocksdb_statistics_t* stats) {
+  opt->rep.statistics = stats->rep;
+}
+
+int rocksdb_statistics_ticker_type(const char* name, size_t name_len) {
+  return FindType(TickersNameMap, name, name_len);
+}
+
+int rocksdb_statistics_histogram_type(const char* name, size_t name_len) {
+  return FindType(HistogramsNameMap, name, name_len);
+}
+
+uint64_t rocksdb_statistics_get_ticker_count(rocksdb_statistics_t* stats, uint32_t ticker_type) {
+  return stats->rep->getTickerCount(ticker_type);
+}
+
+void rocksdb_statistics_get_histogram_data(rocksdb_statistics_t* stats, uint32_t histogram_type,
+                                           rocksdb_statistics_histogram_data_t* data) {
+  stats->rep->histogramData(histogram_type, &data->rep);
+}
+
+void rocksdb_statistics_reset(rocksdb_statistics_t* stats, rocksdb_status_t** statusptr) { SaveStatus(statusptr, stats->rep->Reset()); }
+
+void rocksdb_statistics_set_stats_level(rocksdb_statistics_t* stats, unsigned char level) {
+  stats->rep->set_stats_level(static_cast<StatsLevel>(level));
+}
+
+unsigned char rocksdb_statistics_get_stats_level(rocksdb_statistics_t* stats) {
+  return static_cast<unsigned char>(stats->rep->get_stats_level());
+}
+
+char* rocksdb_statistics_to_string(rocksdb_statistics_t* stats) { return strdup(stats->rep->ToString().c_str()); }
+
+rocksdb_statistics_histogram_data_t* rocksdb_statistics_histogram_data_create() {
+  return new rocksdb_statistics_histogram_data_t{};
+}
+
+void rocksdb_statistics_histogram_data_destroy(rocksdb_statistics_histogram_data_t* data) { delete data; }
+
+double rocksdb_statistics_histogram_data_get_median(rocksdb_statistics_histogram_data_t* data) {
+  return data->rep.median;
+}
+
+double rocksdb_statistics_histogram_data_get_p95(rocksdb_statistics_histogram_data_t* data) {
+  return data->rep.percentile95;
+}
+
+double rocksdb_statistics_histogram_data_get_p99(rocksdb_statistics_histogram_data_t* data) {
+  return data->rep.percentile99;
+}
+
+double rocksdb_statistics_histogram_data_get_average(rocksdb_statistics_histogram_data_t* data) {
+  return data->rep.average;
+}
+
+double rocksdb_statistics_histogram_data_get_std_dev(rocksdb_statistics_histogram_data_t* data) {
+  return data->rep.standard_deviation;
+}
+
+double rocksdb_statistics_histogram_data_get_max(rocksdb_statistics_histogram_data_t* data) {
+  return data->rep.max;
+}
+
+double rocksdb_statistics_histogram_data_get_min(rocksdb_statistics_histogram_data_t* data) {
+  return data->rep.min;
+}
+
+uint64_t rocksdb_statistics_histogram_data_get_count(rocksdb_statistics_histogram_data_t* data) {
+  return data->rep.count;
+}
+
+uint64_t rocksdb_statistics_histogram_data_get_sum(rocksdb_statistics_histogram_data_t* data) {
+  return data->rep.sum;
+}
+}
@@ -0,0 +1,61 @@
+// Implementation of `rocksdb_status_t` functions in `c.h`.
+#include "c_api_extensions/ctypes.hpp"
+
+using namespace ROCKSDB_NAMESPACE;
+
+// `Status::retryable_` is protected, and only `IOStatus` has a getter for it.
+// A pointer to member formed in a derived class reads it for any `Status`.
+struct StatusRetryable : Status {
+  static constexpr bool Status::*member = &StatusRetryable::retryable_;
+};
+
+// Builds a `Status` from its code and subcode, which only `Status` and its
+// subclasses can do.
+struct StatusBuilder : Status {
+  StatusBuilder(Code code, SubCode subcode) : Status(code, subcode) {}
+  StatusBuilder(Code code, SubCode subcode, const Slice& msg) : Status(code, subcode, msg, Slice()) {}
+};
+
+extern "C" {
+rocksdb_status_t* rocksdb_status_create(int code, int subcode, unsigned char retryable, const char* message,
+                                        size_t message_len) {
+  auto c = static_cast<Status::Code>(code);
+  if (c == Status::kOk || code < 0 || code >= Status::kMaxCode) {
+    c = Status::kIOError;
+  }
+  auto sc = static_cast<Status::SubCode>(subcode);
+  if (subcode < 0 || subcode >= Status::kMaxSubCode) {
+    sc = Status::kNone;
+  }
+
+  // Strip the "<code>: " prefix `Status::ToString` adds again.
+  Slice msg(message, message_len);
+  std::string prefix = StatusBuilder(c, sc).ToString();
+  if (msg == prefix) {
+    msg = Slice();
+  } else if (msg.starts_with(prefix + ": ")) {
+    msg.remove_prefix(prefix.size() + 2);
+  }
+
+  Status s = StatusBuilder(c, sc, msg);
+  s.*StatusRetryable::member = retryable != 0;
+  return new rocksdb_status_t{s, s.ToString()};
+}
+
+void rocksdb_status_destroy(rocksdb_status_t* status) { delete status; }
+
+int rocksdb_status_code(const rocksdb_status_t* status) { return static_cast<int>(status->rep.code()); }
+
+int rocksdb_status_subcode(const rocksdb_status_t* status) { return static_cast<int>(status->rep.subcode()); }
+
+int rocksdb_status_severity(const rocksdb_status_t* status) { return static_cast<int>(status->rep.severity()); }
+
+unsigned char rocksdb_status_retryable(const rocksdb_status_t* status) {
+  return status->rep.*StatusRetryable::member;
+}
+
+const char* rocksdb_status_message(const rocksdb_status_t* status, size_t* len) {
+  *len = status->message.size();
+  return status->message.data();
+}
+}
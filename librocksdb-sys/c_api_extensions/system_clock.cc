@@ -0,0 +1,70 @@
+// Implementation of `SystemClock` functions in `c.h`.
+#include "c_api_extensions/ctypes.hpp"
+#include "env/composite_env_wrapper.h"
+#include "rocksdb/env.h"
+#include "rocksdb/system_clock.h"
+
+using namespace ROCKSDB_NAMESPACE;
+
+namespace {
+// Reads the time from C callbacks. `destructor` releases `state` when the
+// last reference to the clock is dropped.
+class CallbackClock : public SystemClock {
+ public:
+  CallbackClock(void* state, void (*destructor)(void*), uint64_t (*now_micros)(void*), uint64_t (*now_nanos)(void*),
+                void (*sleep_for_micros)(void*, uint64_t))
+      : state_(state),
+        destructor_(destructor),
+        now_micros_(now_micros),
+        now_nanos_(now_nanos),
+        sleep_for_micros_(sleep_for_micros) {}
+
+  ~CallbackClock() override { (*destructor_)(state_); }
+
+  const char* Name() const override { return "CallbackClock"; }
+
+  uint64_t NowMicros() override { return (*now_micros_)(state_); }
+
+  uint64_t NowNanos() override { return (*now_nanos_)(state_); }
+
+  void SleepForMicroseconds(int micros) override {
+    if (micros > 0) {
+      (*sleep_for_micros_)(state_, static_cast<uint64_t>(micros));
+    }
+  }
+
+  Status GetCurrentTime(int64_t* unix_time) override {
+    *unix_time = static_cast<int64_t>(NowMicros() / 1000000);
+    return Status::OK();
+  }
+
+  std::string TimeToString(uint64_t time) override { return SystemClock::Default()->TimeToString(time); }
+
+ private:
+  void* state_;
+  void (*destructor_)(void*);
+  uint64_t (*now_micros_)(void*);
+  uint64_t (*now_nanos_)(void*);
+  void (*sleep_for_micros_)(void*, uint64_t);
+};
+}  // namespace
+
+extern "C" {
+rocksdb_system_clock_t* rocksdb_system_clock_create(void* state, void (*destructor)(void*),
+                                                    uint64_t (*now_micros)(void*), uint64_t (*now_nanos)(void*),
+                                                    void (*sleep_for_micros)(void*, uint64_t)) {
+  auto* clock = new rocksdb_system_clock_t;
+  clock->rep = std::make_shared<CallbackClock>(state, destructor, now_micros, now_nanos, sleep_for_micros);
+  return clock;
+}
+
+void rocksdb_system_clock_destroy(rocksdb_system_clock_t* clock) { delete clock; }
+
+rocksdb_env_t* rocksdb_create_env_with_clock(rocksdb_file_system_t* fs, rocksdb_system_clock_t* clock) {
+  Env* base = Env::Default();
+  auto* env = new rocksdb_env_t;
+  env->rep = new CompositeEnvWrapper(base, fs == nullptr ? base->GetFileSystem() : fs->rep, clock->rep);
+  env->is_default = false;
+  return env;
+}
+}
@@ -0,0 +1,236 @@
+// Implementation of table properties functions in `c.h`.
+#include <memory>
+#include <string>
+
+#include "c_api_extensions/ctypes.hpp"
+#include "rocksdb/table_properties.h"
+
+using namespace ROCKSDB_NAMESPACE;
+
+struct rocksdb_table_properties_collector_t : public TablePropertiesCollector {
+  void* state_;
+  void (*destructor_)(void*);
+  void (*add_user_key_)(void*, const char*, size_t, const char*, size_t, int, uint64_t, uint64_t);
+  void (*finish_)(void*, rocksdb_user_collected_properties_t*);
+  unsigned char (*need_compact_)(void*);
+  const char* (*name_)(void*);
+
+  ~rocksdb_table_properties_collector_t() override { (*destructor_)(state_); }
+
+  Status AddUserKey(const Slice& key, const Slice& value, EntryType type, SequenceNumber seq,
+                    uint64_t file_size) override {
+    (*add_user_key_)(state_, key.data(), key.size(), value.data(), value.size(), static_cast<int>(type), seq,
+                     file_size);
+    return Status::OK();
+  }
+
+  Status Finish(UserCollectedProperties* properties) override {
+    (*finish_)(state_, reinterpret_cast<rocksdb_user_collected_properties_t*>(properties));
+    return Status::OK();
+  }
+
+  UserCollectedProperties GetReadableProperties() const override { return UserCollectedProperties(); }
+
+  bool NeedCompact() const override { return (*need_compact_)(state_); }
+
+  const char* Name() const override { return (*name_)(state_); }
+};
+
+struct rocksdb_table_properties_collector_factory_t : public TablePropertiesCollectorFactory {
+  void* state_;
+  void (*destructor_)(void*);
+  rocksdb_table_properties_collector_t* (*create_)(void*, uint32_t, int);
+  const char* (*name_)(void*);
+
+  ~rocksdb_table_properties_collector_factory_t() override { (*destructor_)(state_); }
+
+  TablePropertiesCollector* CreateTablePropertiesCollector(TablePropertiesCollectorFactory::Context context) override {
+    return (*create_)(state_, context.column_family_id, context.level_at_creation);
+  }
+
+  const char* Name() const override { return (*name_)(state_); }
+};
+
+extern "C" {
+rocksdb_table_properties_collector_t* rocksdb_table_properties_collector_create(
+    void* state, void (*destructor)(void*),
+    void (*add_user_key)(void*, const char*, size_t, const char*, size_t, int, uint64_t, uint64_t),
+    void (*finish)(void*, rocksdb_user_collected_properties_t*), unsigned char (*need_compact)(void*),
+    const char* (*name)(void*)) {
+  auto* collector = new rocksdb_table_properties_collector_t;
+  collector->state_ = state;
+  collector->destructor_ = destructor;
+  collector->add_user_key_ = add_user_key;
+  collector->finish_ = finish;
+  collector->need_compact_ = need_compact;
+  collector->name_ = name;
+  return collector;
+}
+
+rocksdb_table_properties_collector_factory_t* rocksdb_table_properties_collector_factory_create(
+    void* state, void (*destructor)(void*), rocksdb_table_properties_collector_t* (*create)(void*, uint32_t, int),
+    const char* (*name)(void*)) {
+  auto* factory = new rocksdb_table_properties_collector_factory_t;
+  factory->state_ = state;
+  factory->destructor_ = destructor;
+  factory->create_ = create;
+  factory->name_ = name;
+  return factory;
+}
+
+void rocksdb_options_add_table_properties_collector_factory(rocksdb_options_t* opt,
+                                                            rocksdb_table_properties_collector_factory_t* factory) {
+  opt->rep.table_properties_collector_factories.emplace_back(factory);
+}
+
+void rocksdb_user_collected_properties_insert(rocksdb_user_collected_properties_t* properties, const char* key,
+                                              size_t key_len, const char* value, size_t value_len) {
+  (*reinterpret_cast<UserCollectedProperties*>(properties))[std::string(key, key_len)] =
+      std::string(value, value_len);
+}
+
+void rocksdb_table_properties_collection_destroy(rocksdb_table_properties_collection_t* collection) {
+  delete collection;
+}
+
+size_t rocksdb_table_properties_collection_count(const rocksdb_table_properties_collection_t* collection) {
+  return collection->rep.size();
+}
+
+const char* rocksdb_table_properties_collection_file_path(const rocksdb_table_properties_collection_t* collection,
+                                                          size_t i, size_t* len) {
+  const std::string& path = collection->rep[i].first;
+  *len = path.size();
+  return path.data();
+}
+
+const rocksdb_table_properties_t* rocksdb_table_properties_collection_get(
+    const rocksdb_table_properties_collection_t* collection, size_t i) {
+  return reinterpret_cast<const rocksdb_table_properties_t*>(collection->rep[i].second.get());
+}
+
+uint64_t rocksdb_table_properties_get_orig_file_number(const rocksdb_table_properties_t* props) {
+  return reinterpret_cast<const TableProperties*>(props)->orig_file_number;
+}
+
+uint64_t rocksdb_table_properties_get_data_size(const rocksdb_table_properties_t* props) {
+  return reinterpret_cast<const TableProperties*>(props)->data_size;
+}
+
+uint64_t rocksdb_table_properties_get_index_size(const rocksdb_table_properties_t* props) {
+  return reinterpret_cast<const TableProperties*>(props)->index_size;
+}
+
+uint64_t rocksdb_table_properties_get_filter_size(const rocksdb_table_properties_t* props) {
+  return reinterpret_cast<const TableProperties*>(props)->filter_size;
+}
+
+uint64_t rocksdb_table_properties_get_raw_key_size(const rocksdb_table_properties_t* props) {
+  return reinterpret_cast<const TableProperties*>(props)->raw_key_size;
+}
+
+uint64_t rocksdb_table_properties_get_raw_value_size(const rocksdb_table_properties_t* props) {
+  return reinterpret_cast<const TableProperties*>(props)->raw_value_size;
+}
+
+uint64_t rocksdb_table_properties_get_num_data_blocks(const rocksdb_table_properties_t* props) {
+  return reinterpret_cast<const TableProperties*>(props)->num_data_blocks;
+}
+
+uint64_t rocksdb_table_properties_get_num_entries(const rocksdb_table_properties_t* props) {
+  return reinterpret_cast<const TableProperties*>(props)->num_entries;
+}
+
+uint64_t rocksdb_table_properties_get_num_deletions(const rocksdb_table_properties_t* props) {
+  return reinterpret_cast<const TableProperties*>(props)->num_deletions;
+}
+
+uint64_t rocksdb_table_properties_get_num_merge_operands(const rocksdb_table_properties_t* props) {
+  return reinterpret_cast<const TableProperties*>(props)->num_merge_operands;
+}
+
+uint64_t rocksdb_table_properties_get_num_range_deletions(const rocksdb_table_properties_t* props) {
+  return reinterpret_cast<const TableProperties*>(props)->num_range_deletions;
+}
+
+uint64_t rocksdb_table_properties_get_format_version(const rocksdb_table_properties_t* props) {
+  return reinterpret_cast<const TableProperties*>(props)->format_version;
+}
+
+uint64_t rocksdb_table_properties_get_fixed_key_len(const rocksdb_table_properties_t* props) {
+  return reinterpret_cast<const TableProperties*>(props)->fixed_key_len;
+}
+
+uint64_t rocksdb_table_properties_get_column_family_id(const rocksdb_table_properties_t* props) {
+  return reinterpret_cast<const TableProperties*>(props)->column_family_id;
+}
+
+uint64_t rocksdb_table_properties_get_creation_time(const rocksdb_table_properties_t* props) {
+  return reinterpret_cast<const TableProperties*>(props)->creation_time;
+}
+
+uint64_t rocksdb_table_properties_get_oldest_key_time(const rocksdb_table_properties_t* props) {
+  return reinterpret_cast<const TableProperties*>(props)->oldest_key_time;
+}
+
+uint64_t rocksdb_table_properties_get_file_creation_time(const rocksdb_table_properties_t* props) {
+  return reinterpret_cast<const TableProperties*>(props)->file_creation_time;
+}
+
+const char* rocksdb_table_properties_get_column_family_name(const rocksdb_table_properties_t* props, size_t* len) {
+  const std::string& value = reinterpret_cast<const TableProperties*>(props)->column_family_name;
+  *len = value.size();
+  return value.data();
+}
+
+const char* rocksdb_table_properties_get_filter_policy_name(const rocksdb_table_properties_t* props, size_t* len) {
+  const std::string& value = reinterpret_cast<const TableProperties*>(props)->filter_policy_name;
+  *len = value.size();
+  return value.data();
+}
+
+const char* rocksdb_table_properties_get_comparator_name(const rocksdb_table_properties_t* props, size_t* len) {
+  const std::string& value = reinterpret_cast<const TableProperties*>(props)->comparator_name;
+  *len = value.size();
+  return value.data();
+}
+
+const char* rocksdb_table_properties_get_merge_operator_name(const rocksdb_table_properties_t* props, size_t* len) {
+  const std::string& value = reinterpret_cast<const TableProperties*>(props)->merge_operator_name;
+  *len = value.size();
+  return value.data();
+}
+
+const char* rocksdb_table_properties_get_prefix_extractor_name(const rocksdb_table_properties_t* props, size_t* len) {
+  const std::string& value = reinterpret_cast<const TableProperties*>(props)->prefix_extractor_name;
+  *len = value.size();
+  return value.data();
+}
+
+const char* rocksdb_table_properties_get_property_collectors_names(const rocksdb_table_properties_t* props,
+                                                                   size_t* len) {
+  const std::string& value = reinterpret_cast<const TableProperties*>(props)->property_collectors_names;
+  *len = value.size();
+  return value.data();
+}
+
+const char* rocksdb_table_properties_get_compression_name(const rocksdb_table_properties_t* props, size_t* len) {
+  const std::string& value = reinterpret_cast<const TableProperties*>(props)->compression_name;
+  *len = value.size();
+  return value.data();
+}
+
+const char* rocksdb_table_properties_get_compression_options(const rocksdb_table_properties_t* props, size_t* len) {
+  const std::string& value = reinterpret_cast<const TableProperties*>(props)->compression_options;
+  *len = value.size();
+  return value.data();
+}
+
+void rocksdb_table_properties_iterate_user_collected_properties(
+    const rocksdb_table_properties_t* props, void* state,
+    void (*callback)(void*, const char*, size_t, const char*, size_t)) {
+  for (const auto& entry : reinterpret_cast<const TableProperties*>(props)->user_collected_properties) {
+    (*callback)(state, entry.first.data(), entry.first.size(), entry.second.data(), entry.second.size());
+  }
+}
+}
@@ -0,0 +1,80 @@
+// Implementation of thread pool and `ThreadStatus` functions in `c.h`.
+#include "c_api_extensions/ctypes.hpp"
+#include "rocksdb/env.h"
+#include "rocksdb/thread_status.h"
+
+using namespace ROCKSDB_NAMESPACE;
+
+extern "C" {
+unsigned int rocksdb_env_get_thread_pool_queue_len(rocksdb_env_t* env, int priority) {
+  return env->rep->GetThreadPoolQueueLen(static_cast<Env::Priority>(priority));
+}
+
+int rocksdb_env_get_background_threads_with_priority(rocksdb_env_t* env, int priority) {
+  return env->rep->GetBackgroundThreads(static_cast<Env::Priority>(priority));
+}
+
+void rocksdb_options_set_enable_thread_tracking(rocksdb_options_t* opt, unsigned char v) {
+  opt->rep.enable_thread_tracking = v;
+}
+
+rocksdb_thread_status_list_t* rocksdb_env_get_thread_list(rocksdb_env_t* env, rocksdb_status_t** statusptr) {
+  auto* list = new rocksdb_thread_status_list_t;
+  if (SaveStatus(statusptr, env->rep->GetThreadList(&list->rep))) {
+    delete list;
+    return nullptr;
+  }
+  return list;
+}
+
+void rocksdb_thread_status_list_destroy(rocksdb_thread_status_list_t* list) { delete list; }
+
+size_t rocksdb_thread_status_list_count(const rocksdb_thread_status_list_t* list) { return list->rep.size(); }
+
+uint64_t rocksdb_thread_status_list_get_thread_id(const rocksdb_thread_status_list_t* list, size_t i) {
+  return list->rep[i].thread_id;
+}
+
+int rocksdb_thread_status_list_get_thread_type(const rocksdb_thread_status_list_t* list, size_t i) {
+  return static_cast<int>(list->rep[i].thread_type);
+}
+
+const char* rocksdb_thread_status_list_get_db_name(const rocksdb_thread_status_list_t* list, size_t i,
+                                                   size_t* len) {
+  const std::string& name = list->rep[i].db_name;
+  *len = name.size();
+  return name.data();
+}
+
+const char* rocksdb_thread_status_list_get_cf_name(const rocksdb_thread_status_list_t* list, size_t i,
+                                                   size_t* len) {
+  const std::string& name = list->rep[i].cf_name;
+  *len = name.size();
+  return name.data();
+}
+
+int rocksdb_thread_status_list_get_operation_type(const rocksdb_thread_status_list_t* list, size_t i) {
+  return static_cast<int>(list->rep[i].operation_type);
+}
+
+uint64_t rocksdb_thread_status_list_get_op_elapsed_micros(const rocksdb_thread_status_list_t* list, size_t i) {
+  return list->rep[i].op_elapsed_micros;
+}
+
+int rocksdb_thread_status_list_get_operation_stage(const rocksdb_thread_status_list_t* list, size_t i) {
+  return static_cast<int>(list->rep[i].operation_stage);
+}
+
+int rocksdb_thread_status_list_get_state_type(const rocksdb_thread_status_list_t* list, size_t i) {
+  return static_cast<int>(list->rep[i].state_type);
+}
+
+void rocksdb_thread_status_list_iterate_operation_properties(const rocksdb_thread_status_list_t* list, size_t i,
+                                                             void* state,
+                                                             void (*callback)(void*, const char*, size_t, uint64_t)) {
+  const ThreadStatus& status = list->rep[i];
+  for (const auto& property : ThreadStatus::InterpretOperationProperties(status.operation_type, status.op_properties)) {
+    (*callback)(state, property.first.data(), property.first.size(), property.second);
+  }
+}
+}
@@ -0,0 +1,327 @@
+// Implementation of `Transaction` functions in `c.h`.
+#include "c_api_extensions/ctypes.hpp"
+#include "rocksdb/convenience.h"
+
+using namespace ROCKSDB_NAMESPACE;
+
+extern "C" {
+void rocksdb_transactiondb_close_with_status(rocksdb_transactiondb_t* txn_db, rocksdb_status_t** statusptr) {
+  SaveStatus(statusptr, txn_db->rep->Close());
+  delete txn_db->rep;
+  delete txn_db;
+}
+
+void rocksdb_optimistictransactiondb_close_with_status(rocksdb_optimistictransactiondb_t* otxn_db, rocksdb_status_t** statusptr) {
+  SaveStatus(statusptr, otxn_db->rep->Close());
+  delete otxn_db->rep;
+  delete otxn_db;
+}
+
+void rocksdb_transactiondb_cancel_all_background_work(rocksdb_transactiondb_t* txn_db, unsigned char wait) {
+  CancelAllBackgroundWork(txn_db->rep->GetRootDB(), wait);
+}
+
+void rocksdb_transaction_singledelete(rocksdb_transaction_t* txn, const char* key, size_t klen, rocksdb_status_t** statusptr) {
+  SaveStatus(statusptr, txn->rep->SingleDelete(Slice(key, klen)));
+}
+
+void rocksdb_transaction_singledelete_cf(rocksdb_transaction_t* txn, rocksdb_column_family_handle_t* column_family,
+                                         const char* key, size_t klen, rocksdb_status_t** statusptr) {
+  SaveStatus(statusptr, txn->rep->SingleDelete(column_family->rep, Slice(key, klen)));
+}
+
+// The `*_with_status` functions below mirror the functions of the same name
+// without the suffix in RocksDB's `c.h`, reporting errors as a
+// `rocksdb_status_t`.
+
+rocksdb_transactiondb_t* rocksdb_transactiondb_open_with_status(const rocksdb_options_t* options,
+                                                                const rocksdb_transactiondb_options_t* txn_db_options,
+                                                                const char* name, rocksdb_status_t** statusptr) {
+  TransactionDB* txn_db;
+  if (SaveStatus(statusptr, TransactionDB::Open(options->rep, txn_db_options->rep, std::string(name), &txn_db))) {
+    return nullptr;
+  }
+  return new rocksdb_transactiondb_t{txn_db};
+}
+
+rocksdb_transactiondb_t* rocksdb_transactiondb_open_column_families_with_status(
+    const rocksdb_options_t* options, const rocksdb_transactiondb_options_t* txn_db_options, const char* name,
+    int num_column_families, const char* const* column_family_names,
+    const rocksdb_options_t* const* column_family_options, rocksdb_column_family_handle_t** column_family_handles,
+    rocksdb_status_t** statusptr) {
+  TransactionDB* txn_db;
+  std::vector<ColumnFamilyHandle*> handles;
+  if (SaveStatus(statusptr, TransactionDB::Open(options->rep, txn_db_options->rep, std::string(name),
+                                                ToColumnFamilyDescriptors(num_column_families, column_family_names,
+                                                                          column_family_options),
+                                                &handles, &txn_db))) {
+    return nullptr;
+  }
+  ToColumnFamilyHandles(handles, column_family_handles);
+  return new rocksdb_transactiondb_t{txn_db};
+}
+
+rocksdb_column_family_handle_t* rocksdb_transactiondb_create_column_family_with_status(
+    rocksdb_transactiondb_t* txn_db, const rocksdb_options_t* column_family_options, const char* column_family_name,
+    rocksdb_status_t** statusptr) {
+  ColumnFamilyHandle* handle;
+  if (SaveStatus(statusptr, txn_db->rep->CreateColumnFamily(ColumnFamilyOptions(column_family_options->rep),
+                                                            std::string(column_family_name), &handle))) {
+    return nullptr;
+  }
+  return new rocksdb_column_family_handle_t{handle};
+}
+
+void rocksdb_transactiondb_put_with_status(rocksdb_transactiondb_t* txn_db, const rocksdb_writeoptions_t* options,
+                                           const char* key, size_t klen, const char* val, size_t vlen,
+                                           rocksdb_status_t** statusptr) {
+  SaveStatus(statusptr, txn_db->rep->Put(options->rep, Slice(key, klen), Slice(val, vlen)));
+}
+
+void rocksdb_transactiondb_put_cf_with_status(rocksdb_transactiondb_t* txn_db, const rocksdb_writeoptions_t* options,
+                                              rocksdb_column_family_handle_t* column_family, const char* key,
+                                              size_t keylen, const char* val, size_t vallen,
+                                              rocksdb_status_t** statusptr) {
+  SaveStatus(statusptr, txn_db->rep->Put(options->rep, column_family->rep, Slice(key, keylen), Slice(val, vallen)));
+}
+
+void rocksdb_transactiondb_delete_with_status(rocksdb_transactiondb_t* txn_db, const rocksdb_writeoptions_t* options,
+                                              const char* key, size_t klen, rocksdb_status_t** statusptr) {
+  SaveStatus(statusptr, txn_db->rep->Delete(options->rep, Slice(key, klen)));
+}
+
+void rocksdb_transactiondb_delete_cf_with_status(rocksdb_transactiondb_t* txn_db,
+                                                 const rocksdb_writeoptions_t* options,
+                                                 rocksdb_column_family_handle_t* column_family, const char* key,
+                                                 size_t keylen, rocksdb_status_t** statusptr) {
+  SaveStatus(statusptr, txn_db->rep->Delete(options->rep, column_family->rep, Slice(key, keylen)));
+}
+
+void rocksdb_transactiondb_merge_with_status(rocksdb_transactiondb_t* txn_db, const rocksdb_writeoptions_t* options,
+                                             const char* key, size_t klen, const char* val, size_t vlen,
+                                             rocksdb_status_t** statusptr) {
+  SaveStatus(statusptr, txn_db->rep->Merge(options->rep, Slice(key, klen), Slice(val, vlen)));
+}
+
+void rocksdb_transactiondb_merge_cf_with_status(rocksdb_transactiondb_t* txn_db,
+                                                const rocksdb_writeoptions_t* options,
+                                                rocksdb_column_family_handle_t* column_family, const char* key,
+                                                size_t klen, const char* val, size_t vlen,
+                                                rocksdb_status_t** statusptr) {
+  SaveStatus(statusptr, txn_db->rep->Merge(options->rep, column_family->rep, Slice(key, klen), Slice(val, vlen)));
+}
+
+void rocksdb_transactiondb_write_with_status(rocksdb_transactiondb_t* txn_db, const rocksdb_writeoptions_t* options,
+                                             rocksdb_writebatch_t* batch, rocksdb_status_t** statusptr) {
+  SaveStatus(statusptr, txn_db->rep->Write(options->rep, &batch->rep));
+}
+
+rocksdb_pinnableslice_t* rocksdb_transactiondb_get_pinned_with_status(rocksdb_transactiondb_t* txn_db,
+                                                                      const rocksdb_readoptions_t* options,
+                                                                      const char* key, size_t klen,
+                                                                      rocksdb_status_t** statusptr) {
+  auto value = new rocksdb_pinnableslice_t;
+  Status s = txn_db->rep->Get(options->rep, txn_db->rep->DefaultColumnFamily(), Slice(key, klen), &value->rep);
+  return SavePinnedValue(value, s, statusptr);
+}
+
+rocksdb_pinnableslice_t* rocksdb_transactiondb_get_pinned_cf_with_status(
+    rocksdb_transactiondb_t* txn_db, const rocksdb_readoptions_t* options,
+    rocksdb_column_family_handle_t* column_family, const char* key, size_t keylen, rocksdb_status_t** statusptr) {
+  auto value = new rocksdb_pinnableslice_t;
+  Status s = txn_db->rep->Get(options->rep, column_family->rep, Slice(key, keylen), &value->rep);
+  return SavePinnedValue(value, s, statusptr);
+}
+
+void rocksdb_transactiondb_multi_get_with_status(rocksdb_transactiondb_t* txn_db,
+                                                 const rocksdb_readoptions_t* options, size_t num_keys,
+                                                 const char* const* keys_list, const size_t* keys_list_sizes,
+                                                 char** values_list, size_t* values_list_sizes,
+                                                 rocksdb_status_t** statuses) {
+  std::vector<Slice> keys(num_keys);
+  for (size_t i = 0; i < num_keys; i++) {
+    keys[i] = Slice(keys_list[i], keys_list_sizes[i]);
+  }
+  std::vector<std::string> values(num_keys);
+  SaveMultiGetResults(txn_db->rep->MultiGet(options->rep, keys, &values), values, values_list, values_list_sizes,
+                      statuses);
+}
+
+void rocksdb_transactiondb_multi_get_cf_with_status(rocksdb_transactiondb_t* txn_db,
+                                                    const rocksdb_readoptions_t* options,
+                                                    const rocksdb_column_family_handle_t* const* column_families,
+                                                    size_t num_keys, const char* const* keys_list,
+                                                    const size_t* keys_list_sizes, char** values_list,
+                                                    size_t* values_list_sizes, rocksdb_status_t** statuses) {
+  std::vector<Slice> keys(num_keys);
+  std::vector<ColumnFamilyHandle*> cfs(num_keys);
+  for (size_t i = 0; i < num_keys; i++) {
+    keys[i] = Slice(keys_list[i], keys_list_sizes[i]);
+    cfs[i] = column_families[i]->rep;
+  }
+  std::vector<std::string> values(num_keys);
+  SaveMultiGetResults(txn_db->rep->MultiGet(options->rep, cfs, keys, &values), values, values_list,
+                      values_list_sizes, statuses);
+}
+
+rocksdb_optimistictransactiondb_t* rocksdb_optimistictransactiondb_open_with_status(const rocksdb_options_t* options,
+                                                                                    const char* name,
+                                                                                    rocksdb_status_t** statusptr) {
+  OptimisticTransactionDB* otxn_db;
+  if (SaveStatus(statusptr, OptimisticTransactionDB::Open(options->rep, std::string(name), &otxn_db))) {
+    return nullptr;
+  }
+  return new rocksdb_optimistictransactiondb_t{otxn_db};
+}
+
+rocksdb_optimistictransactiondb_t* rocksdb_optimistictransactiondb_open_column_families_with_status(
+    const rocksdb_options_t* db_options, const char* name, int num_column_families,
+    const char* const* column_family_names, const rocksdb_options_t* const* column_family_options,
+    rocksdb_column_family_handle_t** column_family_handles, rocksdb_status_t** statusptr) {
+  OptimisticTransactionDB* otxn_db;
+  std::vector<ColumnFamilyHandle*> handles;
+  if (SaveStatus(statusptr, OptimisticTransactionDB::Open(DBOptions(db_options->rep), std::string(name),
+                                                          ToColumnFamilyDescriptors(num_column_families,
+                                                                                    column_family_names,
+                                                                                    column_family_options),
+                                                          &handles, &otxn_db))) {
+    return nullptr;
+  }
+  ToColumnFamilyHandles(handles, column_family_handles);
+  return new rocksdb_optimistictransactiondb_t{otxn_db};
+}
+
+void rocksdb_optimistictransactiondb_write_with_status(rocksdb_optimistictransactiondb_t* otxn_db,
+                                                       const rocksdb_writeoptions_t* options,
+                                                       rocksdb_writebatch_t* batch, rocksdb_status_t** statusptr) {
+  SaveStatus(statusptr, otxn_db->rep->Write(options->rep, &batch->rep));
+}
+
+void rocksdb_transaction_set_name_with_status(rocksdb_transaction_t* txn, const char* name, size_t name_len,
+                                              rocksdb_status_t** statusptr) {
+  SaveStatus(statusptr, txn->rep->SetName(std::string(name, name_len)));
+}
+
+void rocksdb_transaction_prepare_with_status(rocksdb_transaction_t* txn, rocksdb_status_t** statusptr) {
+  SaveStatus(statusptr, txn->rep->Prepare());
+}
+
+void rocksdb_transaction_commit_with_status(rocksdb_transaction_t* txn, rocksdb_status_t** statusptr) {
+  SaveStatus(statusptr, txn->rep->Commit());
+}
+
+void rocksdb_transaction_rollback_with_status(rocksdb_transaction_t* txn, rocksdb_status_t** statusptr) {
+  SaveStatus(statusptr, txn->rep->Rollback());
+}
+
+void rocksdb_transaction_rollback_to_savepoint_with_status(rocksdb_transaction_t* txn, rocksdb_status_t** statusptr) {
+  SaveStatus(statusptr, txn->rep->RollbackToSavePoint());
+}
+
+void rocksdb_transaction_rebuild_from_writebatch_with_status(rocksdb_transaction_t* txn,
+                                                             rocksdb_writebatch_t* writebatch,
+                                                             rocksdb_status_t** statusptr) {
+  SaveStatus(statusptr, txn->rep->RebuildFromWriteBatch(&writebatch->rep));
+}
+
+void rocksdb_transaction_put_with_status(rocksdb_transaction_t* txn, const char* key, size_t klen, const char* val,
+                                         size_t vlen, rocksdb_status_t** statusptr) {
+  SaveStatus(statusptr, txn->rep->Put(Slice(key, klen), Slice(val, vlen)));
+}
+
+void rocksdb_transaction_put_cf_with_status(rocksdb_transaction_t* txn, rocksdb_column_family_handle_t* column_family,
+                                            const char* key, size_t klen, const char* val, size_t vlen,
+                                            rocksdb_status_t** statusptr) {
+  SaveStatus(statusptr, txn->rep->Put(column_family->rep, Slice(key, klen), Slice(val, vlen)));
+}
+
+void rocksdb_transaction_delete_with_status(rocksdb_transaction_t* txn, const char* key, size_t klen,
+                                            rocksdb_status_t** statusptr) {
+  SaveStatus(statusptr, txn->rep->Delete(Slice(key, klen)));
+}
+
+void rocksdb_transaction_delete_cf_with_status(rocksdb_transaction_t* txn,
+                                               rocksdb_column_family_handle_t* column_family, const char* key,
+                                               size_t klen, rocksdb_status_t** statusptr) {
+  SaveStatus(statusptr, txn->rep->Delete(column_family->rep, Slice(key, klen)));
+}
+
+void rocksdb_transaction_merge_with_status(rocksdb_transaction_t* txn, const char* key, size_t klen, const char* val,
+                                           size_t vlen, rocksdb_status_t** statusptr) {
+  SaveStatus(statusptr, txn->rep->Merge(Slice(key, klen), Slice(val, vlen)));
+}
+
+void rocksdb_transaction_merge_cf_with_status(rocksdb_transaction_t* txn,
+                                              rocksdb_column_family_handle_t* column_family, const char* key,
+                                              size_t klen, const char* val, size_t vlen,
+                                              rocksdb_status_t** statusptr) {
+  SaveStatus(statusptr, txn->rep->Merge(column_family->rep, Slice(key, klen), Slice(val, vlen)));
+}
+
+rocksdb_pinnableslice_t* rocksdb_transaction_get_pinned_with_status(rocksdb_transaction_t* txn,
+                                                                    const rocksdb_readoptions_t* options,
+                                                                    const char* key, size_t klen,
+                                                                    rocksdb_status_t** statusptr) {
+  auto value = new rocksdb_pinnableslice_t;
+  Status s = txn->rep->Get(options->rep, Slice(key, klen), &value->rep);
+  return SavePinnedValue(value, s, statusptr);
+}
+
+rocksdb_pinnableslice_t* rocksdb_transaction_get_pinned_cf_with_status(rocksdb_transaction_t* txn,
+                                                                       const rocksdb_readoptions_t* options,
+                                                                       rocksdb_column_family_handle_t* column_family,
+                                                                       const char* key, size_t klen,
+                                                                       rocksdb_status_t** statusptr) {
+  auto value = new rocksdb_pinnableslice_t;
+  Status s = txn->rep->Get(options->rep, column_family->rep, Slice(key, klen), &value->rep);
+  return SavePinnedValue(value, s, statusptr);
+}
+
+rocksdb_pinnableslice_t* rocksdb_transaction_get_pinned_for_update_with_status(rocksdb_transaction_t* txn,
+                                                                               const rocksdb_readoptions_t* options,
+                                                                               const char* key, size_t klen,
+                                                                               unsigned char exclusive,
+                                                                               rocksdb_status_t** statusptr) {
+  auto value = new rocksdb_pinnableslice_t;
+  Status s = txn->rep->GetForUpdate(options->rep, Slice(key, klen), value->rep.GetSelf(), exclusive);
+  value->rep.PinSelf();
+  return SavePinnedValue(value, s, statusptr);
+}
+
+rocksdb_pinnableslice_t* rocksdb_transaction_get_pinned_for_update_cf_with_status(
+    rocksdb_transaction_t* txn, const rocksdb_readoptions_t* options, rocksdb_column_family_handle_t* column_family,
+    const char* key, size_t klen, unsigned char exclusive, rocksdb_status_t** statusptr) {
+  auto value = new rocksdb_pinnableslice_t;
+  Status s = txn->rep->GetForUpdate(options->rep, column_family->rep, Slice(key, klen), &value->rep, exclusive);
+  return SavePinnedValue(value, s, statusptr);
+}
+
+void rocksdb_transaction_multi_get_with_status(rocksdb_transaction_t* txn, const rocksdb_readoptions_t* options,
+                                               size_t num_keys, const char* const* keys_list,
+                                               const size_t* keys_list_sizes, char** values_list,
+                                               size_t* values_list_sizes, rocksdb_status_t** statuses) {
+  std::vector<Slice> keys(num_keys);
+  for (size_t i = 0; i < num_keys; i++) {
+    keys[i] = Slice(keys_list[i], keys_list_sizes[i]);
+  }
+  std::vector<std::string> values(num_keys);
+  SaveMultiGetResults(txn->rep->MultiGet(options->rep, keys, &values), values, values_list, values_list_sizes,
+                      statuses);
+}
+
+void rocksdb_transaction_multi_get_cf_with_status(rocksdb_transaction_t* txn, const rocksdb_readoptions_t* options,
+                                                  const rocksdb_column_family_handle_t* const* column_families,
+                                                  size_t num_keys, const char* const* keys_list,
+                                                  const size_t* keys_list_sizes, char** values_list,
+                                                  size_t* values_list_sizes, rocksdb_status_t** statuses) {
+  std::vector<Slice> keys(num_keys);
+  std::vector<ColumnFamilyHandle*> cfs(num_keys);
+  for (size_t i = 0; i < num_keys; i++) {
+    keys[i] = Slice(keys_list[i], keys_list_sizes[i]);
+    cfs[i] = column_families[i]->rep;
+  }
+  std::vector<std::string> values(num_keys);
+  SaveMultiGetResults(txn->rep->MultiGet(options->rep, cfs, keys, &values), values, values_list, values_list_sizes,
+                      statuses);
+}
+}
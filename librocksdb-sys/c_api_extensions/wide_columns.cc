@@ -0,0 +1,106 @@
+// Implementation of wide-column entity functions in `c.h`.
+#include "c_api_extensions/ctypes.hpp"
+#include "db/write_batch_internal.h"
+
+using namespace ROCKSDB_NAMESPACE;
+
+static WideColumns ToWideColumns(size_t num_columns, const char* const* names_list, const size_t* names_list_sizes,
+                          const char* const* values_list, const size_t* values_list_sizes) {
+  WideColumns columns;
+  columns.reserve(num_columns);
+  for (size_t i = 0; i < num_columns; i++) {
+    columns.emplace_back(Slice(names_list[i], names_list_sizes[i]), Slice(values_list[i], values_list_sizes[i]));
+  }
+  return columns;
+}
+
+extern "C" {
+void rocksdb_put_entity(rocksdb_t* db, const rocksdb_writeoptions_t* options, const char* key, size_t keylen,
+                        size_t num_columns, const char* const* names_list, const size_t* names_list_sizes,
+                        const char* const* values_list, const size_t* values_list_sizes, rocksdb_status_t** statusptr) {
+  WideColumns columns = ToWideColumns(num_columns, names_list, names_list_sizes, values_list, values_list_sizes);
+  SaveStatus(statusptr, db->rep->PutEntity(options->rep, db->rep->DefaultColumnFamily(), Slice(key, keylen), columns));
+}
+
+void rocksdb_put_entity_cf(rocksdb_t* db, const rocksdb_writeoptions_t* options,
+                           rocksdb_column_family_handle_t* column_family, const char* key, size_t keylen,
+                           size_t num_columns, const char* const* names_list, const size_t* names_list_sizes,
+                           const char* const* values_list, const size_t* values_list_sizes, rocksdb_status_t** statusptr) {
+  WideColumns columns = ToWideColumns(num_columns, names_list, names_list_sizes, values_list, values_list_sizes);
+  SaveStatus(statusptr, db->rep->PutEntity(options->rep, column_family->rep, Slice(key, keylen), columns));
+}
+
+rocksdb_pinnable_wide_columns_t* rocksdb_get_entity_cf(rocksdb_t* db, const rocksdb_readoptions_t* options,
+                                                       rocksdb_column_family_handle_t* column_family,
+                                                       const char* key, size_t keylen, rocksdb_status_t** statusptr) {
+  auto columns = new rocksdb_pinnable_wide_columns_t;
+  Status s = db->rep->GetEntity(options->rep, column_family->rep, Slice(key, keylen), &columns->rep);
+  if (!s.ok()) {
+    delete columns;
+    if (!s.IsNotFound()) {
+      SaveStatus(statusptr, s);
+    }
+    return nullptr;
+  }
+  return columns;
+}
+
+rocksdb_pinnable_wide_columns_t* rocksdb_get_entity(rocksdb_t* db, const rocksdb_readoptions_t* options,
+                                                    const char* key, size_t keylen, rocksdb_status_t** statusptr) {
+  rocksdb_column_family_handle_t column_family;
+  column_family.rep = db->rep->DefaultColumnFamily();
+  return rocksdb_get_entity_cf(db, options, &column_family, key, keylen, statusptr);
+}
+
+void rocksdb_pinnable_wide_columns_destroy(rocksdb_pinnable_wide_columns_t* columns) { delete columns; }
+
+size_t rocksdb_pinnable_wide_columns_count(const rocksdb_pinnable_wide_columns_t* columns) {
+  return columns->rep.columns().size();
+}
+
+const char* rocksdb_pinnable_wide_columns_name(const rocksdb_pinnable_wide_columns_t* columns, size_t index,
+                                               size_t* name_len) {
+  const Slice& name = columns->rep.columns()[index].name();
+  *name_len = name.size();
+  return name.data();
+}
+
+const char* rocksdb_pinnable_wide_columns_value(const rocksdb_pinnable_wide_columns_t* columns, size_t index,
+                                                size_t* value_len) {
+  const Slice& value = columns->rep.columns()[index].value();
+  *value_len = value.size();
+  return value.data();
+}
+
+size_t rocksdb_iter_columns_count(const rocksdb_iterator_t* iter) { return iter->rep->columns().size(); }
+
+const char* rocksdb_iter_column_name(const rocksdb_iterator_t* iter, size_t index, size_t* name_len) {
+  const Slice& name = iter->rep->columns()[index].name();
+  *name_len = name.size();
+  return name.data();
+}
+
+const char* rocksdb_iter_column_value(const rocksdb_iterator_t* iter, size_t index, size_t* value_len) {
+  const Slice& value = iter->rep->columns()[index].value();
+  *value_len = value.size();
+  return value.data();
+}
+
+void rocksdb_writebatch_put_entity(rocksdb_writebatch_t* b, const char* key, size_t klen, size_t num_columns,
+                                   const char* const* names_list, const size_t* names_list_sizes,
+                                   const char* const* values_list, const size_t* values_list_sizes, rocksdb_status_t** statusptr) {
+  // `WriteBatch::PutEntity` needs a column family handle, which a batch has
+  // none of for the default column family.
+  WideColumns columns = ToWideColumns(num_columns, names_list, names_list_sizes, values_list, values_list_sizes);
+  SaveStatus(statusptr, WriteBatchInternal::PutEntity(&b->rep, 0, Slice(key, klen), columns));
+}
+
+void rocksdb_writebatch_put_entity_cf(rocksdb_writebatch_t* b, rocksdb_column_family_handle_t* column_family,
+                                      const char* key, size_t klen, size_t num_columns,
+                                      const char* const* names_list, const size_t* names_list_sizes,
+                                      const char* const* values_list, const size_t* values_list_sizes,
+                                      rocksdb_status_t** statusptr) {
+  WideColumns columns = ToWideColumns(num_columns, names_list, names_list_sizes, values_list, values_list_sizes);
+  SaveStatus(statusptr, b->rep.PutEntity(column_family->rep, Slice(key, klen), columns));
+}
+}
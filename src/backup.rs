@@ -53,7 +53,7 @@ impl BackupEngine {
     pub fn open(opts: &BackupEngineOptions, env: &Env) -> Result<Self, Error> {
         let be: *mut ffi::rocksdb_backup_engine_t;
         unsafe {
-            be = ffi_try!(ffi::rocksdb_backup_engine_open_opts(
+            be = ffi_try!(ffi::rocksdb_backup_engine_open_opts_with_status(
                 opts.inner,
                 env.0.inner
             ));
@@ -90,18 +90,20 @@ impl BackupEngine {
         flush_before_backup: bool,
     ) -> Result<(), Error> {
         unsafe {
-            ffi_try!(ffi::rocksdb_backup_engine_create_new_backup_flush(
-                self.inner,
-                db.inner.inner(),
-                c_uchar::from(flush_before_backup),
-            ));
+            ffi_try!(
+                ffi::rocksdb_backup_engine_create_new_backup_flush_with_status(
+                    self.inner,
+                    db.inner.inner(),
+                    c_uchar::from(flush_before_backup),
+                )
+            );
             Ok(())
         }
     }
 
     pub fn purge_old_backups(&mut self, num_backups_to_keep: usize) -> Result<(), Error> {
         unsafe {
-            ffi_try!(ffi::rocksdb_backup_engine_purge_old_backups(
+            ffi_try!(ffi::rocksdb_backup_engine_purge_old_backups_with_status(
                 self.inner,
                 num_backups_to_keep as u32,
             ));
@@ -141,12 +143,14 @@ impl BackupEngine {
         let c_wal_dir = to_cpath(wal_dir)?;
 
         unsafe {
-            ffi_try!(ffi::rocksdb_backup_engine_restore_db_from_latest_backup(
-                self.inner,
-                c_db_dir.as_ptr(),
-                c_wal_dir.as_ptr(),
-                opts.inner,
-            ));
+            ffi_try!(
+                ffi::rocksdb_backup_engine_restore_db_from_latest_backup_with_status(
+                    self.inner,
+                    c_db_dir.as_ptr(),
+                    c_wal_dir.as_ptr(),
+                    opts.inner,
+                )
+            );
         }
         Ok(())
     }
@@ -165,13 +169,15 @@ impl BackupEngine {
         let c_wal_dir = to_cpath(wal_dir)?;
 
         unsafe {
-            ffi_try!(ffi::rocksdb_backup_engine_restore_db_from_backup(
-                self.inner,
-                c_db_dir.as_ptr(),
-                c_wal_dir.as_ptr(),
-                opts.inner,
-                backup_id,
-            ));
+            ffi_try!(
+                ffi::rocksdb_backup_engine_restore_db_from_backup_with_status(
+                    self.inner,
+                    c_db_dir.as_ptr(),
+                    c_wal_dir.as_ptr(),
+                    opts.inner,
+                    backup_id,
+                )
+            );
         }
         Ok(())
     }
@@ -185,7 +191,7 @@ impl BackupEngine {
     /// the BackupEngine was opened.
     pub fn verify_backup(&self, backup_id: u32) -> Result<(), Error> {
         unsafe {
-            ffi_try!(ffi::rocksdb_backup_engine_verify_backup(
+            ffi_try!(ffi::rocksdb_backup_engine_verify_backup_with_status(
                 self.inner, backup_id,
             ));
         }
@@ -39,7 +39,9 @@ impl<'db> Checkpoint<'db> {
         let checkpoint: *mut ffi::rocksdb_checkpoint_t;
 
         unsafe {
-            checkpoint = ffi_try!(ffi::rocksdb_checkpoint_object_create(db.inner.inner()));
+            checkpoint = ffi_try!(ffi::rocksdb_checkpoint_object_create_with_status(
+                db.inner.inner()
+            ));
         }
 
         if checkpoint.is_null() {
@@ -56,7 +58,7 @@ impl<'db> Checkpoint<'db> {
     pub fn create_checkpoint<P: AsRef<Path>>(&self, path: P) -> Result<(), Error> {
         let cpath = to_cpath(path)?;
         unsafe {
-            ffi_try!(ffi::rocksdb_checkpoint_create(
+            ffi_try!(ffi::rocksdb_checkpoint_create_with_status(
                 self.inner,
                 cpath.as_ptr(),
                 LOG_SIZE_FOR_FLUSH,
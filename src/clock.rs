@@ -0,0 +1,134 @@
+//! `Clock` is the time source of an [`Env`](crate::Env), and `MockClock` a
+//! clock that tests move forward by hand.
+use std::convert::TryFrom;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use libc::c_void;
+
+use crate::ffi;
+
+/// The time source RocksDB reads the current time from and sleeps with, e.g.
+/// to expire entries of a database opened with a TTL, to delete obsolete WAL
+/// files after their TTL or to schedule the deletion of obsolete files. Wrap
+/// it into an env with [`Env::with_clock`](crate::Env::with_clock).
+///
+/// Methods are called concurrently from foreground and background threads.
+pub trait Clock: Send + Sync {
+    /// Returns the wall clock time, in microseconds since the Unix epoch.
+    fn now_micros(&self) -> u64;
+
+    /// Returns a monotonic time in nanoseconds, used to measure durations.
+    fn now_nanos(&self) -> u64 {
+        self.now_micros().saturating_mul(1000)
+    }
+
+    /// Blocks the calling thread for `micros` microseconds.
+    fn sleep_for_micros(&self, micros: u64);
+}
+
+/// A [`Clock`] that only moves when told to, so that time dependent behavior
+/// can be tested without sleeping. Sleeping on it advances it instead of
+/// blocking. Clones share the same time.
+///
+/// The background tasks RocksDB runs periodically, such as persisting
+/// statistics, are scheduled by a process wide timer and still follow the
+/// real time.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use rocksdb::{Env, Options, DB};
+///
+/// let (env, clock) = Env::with_mock_clock().unwrap();
+/// let mut opts = Options::default();
+/// opts.create_if_missing(true);
+/// opts.set_env(&env);
+///
+/// # let path = "_rust_rocksdb_mock_clock_doc";
+/// let db = DB::open_with_ttl(&opts, path, Duration::from_secs(60)).unwrap();
+/// db.put(b"k1", b"v1").unwrap();
+///
+/// clock.advance(Duration::from_secs(61));
+/// db.compact_range(None::<&[u8]>, None::<&[u8]>);
+/// assert!(db.get(b"k1").unwrap().is_none());
+/// # drop(db);
+/// # DB::destroy(&Options::default(), path).unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct MockClock(Arc<AtomicU64>);
+
+impl MockClock {
+    /// Creates a new `MockClock` that starts at `now_micros` microseconds
+    /// since the Unix epoch.
+    pub fn new(now_micros: u64) -> MockClock {
+        MockClock(Arc::new(AtomicU64::new(now_micros)))
+    }
+
+    /// Sets the time, in microseconds since the Unix epoch.
+    pub fn set_now_micros(&self, now_micros: u64) {
+        self.0.store(now_micros, Ordering::SeqCst);
+    }
+
+    /// Moves the time forward by `duration`.
+    pub fn advance(&self, duration: Duration) {
+        let micros = u64::try_from(duration.as_micros()).unwrap_or(u64::MAX);
+        self.0.fetch_add(micros, Ordering::SeqCst);
+    }
+}
+
+impl Default for MockClock {
+    /// Creates a new `MockClock` that starts at the current time.
+    fn default() -> MockClock {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        MockClock::new(u64::try_from(now.as_micros()).unwrap_or(u64::MAX))
+    }
+}
+
+impl Clock for MockClock {
+    fn now_micros(&self) -> u64 {
+        self.0.load(Ordering::SeqCst)
+    }
+
+    fn sleep_for_micros(&self, micros: u64) {
+        self.0.fetch_add(micros, Ordering::SeqCst);
+    }
+}
+
+pub(crate) fn create_clock<C>(clock: C) -> *mut ffi::rocksdb_system_clock_t
+where
+    C: Clock + 'static,
+{
+    unsafe {
+        ffi::rocksdb_system_clock_create(
+            Box::into_raw(Box::new(clock)) as *mut c_void,
+            Some(destructor_callback::<C>),
+            Some(now_micros_callback::<C>),
+            Some(now_nanos_callback::<C>),
+            Some(sleep_for_micros_callback::<C>),
+        )
+    }
+}
+
+unsafe extern "C" fn destructor_callback<C: Clock>(raw: *mut c_void) {
+    drop(Box::from_raw(raw as *mut C));
+}
+
+unsafe extern "C" fn now_micros_callback<C: Clock>(raw: *mut c_void) -> u64 {
+    let clock = &*(raw as *mut C);
+    clock.now_micros()
+}
+
+unsafe extern "C" fn now_nanos_callback<C: Clock>(raw: *mut c_void) -> u64 {
+    let clock = &*(raw as *mut C);
+    clock.now_nanos()
+}
+
+unsafe extern "C" fn sleep_for_micros_callback<C: Clock>(raw: *mut c_void, micros: u64) {
+    let clock = &*(raw as *mut C);
+    clock.sleep_for_micros(micros);
+}
@@ -13,15 +13,18 @@
 // limitations under the License.
 //
 
-use libc::{c_char, c_int, c_uchar, c_void, size_t};
+use libc::{c_char, c_int, c_void, size_t};
 use std::ffi::{CStr, CString};
 use std::slice;
 
+use crate::ffi;
+
 /// Decision about how to handle compacting an object
 ///
 /// This is returned by a compaction filter callback. Depending
 /// on the value, the object may be kept, removed, or changed
 /// in the database during a compaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Decision {
     /// Keep the old value
     Keep,
@@ -29,6 +32,76 @@ pub enum Decision {
     Remove,
     /// Change the value for the key
     Change(&'static [u8]),
+    /// Change the value for the key to a value computed by the filter. RocksDB
+    /// copies it before the buffer is dropped.
+    ChangeValue(Vec<u8>),
+    /// Remove the object and all the objects with keys from the current key
+    /// up to the given key, excluded, without reading them. Behaves like
+    /// `Keep` if the given key is not after the current key.
+    ///
+    /// Unlike `Remove`, the removed keys are not replaced by tombstones, so
+    /// older values of these keys in lower levels may reappear, and they
+    /// disappear from snapshots too.
+    RemoveAndSkipUntil(Vec<u8>),
+}
+
+impl Decision {
+    // Values of `CompactionFilter::Decision` in cpp.
+    fn into_raw(self, result: *mut ffi::rocksdb_compactionfilter_result_t) -> c_int {
+        match self {
+            Decision::Keep => 0,
+            Decision::Remove => 1,
+            Decision::Change(value) => unsafe {
+                ffi::rocksdb_compactionfilter_result_set_new_value(
+                    result,
+                    value.as_ptr() as *const c_char,
+                    value.len() as size_t,
+                );
+                2
+            },
+            Decision::ChangeValue(value) => unsafe {
+                ffi::rocksdb_compactionfilter_result_set_new_value(
+                    result,
+                    value.as_ptr() as *const c_char,
+                    value.len() as size_t,
+                );
+                2
+            },
+            Decision::RemoveAndSkipUntil(key) => unsafe {
+                ffi::rocksdb_compactionfilter_result_set_skip_until(
+                    result,
+                    key.as_ptr() as *const c_char,
+                    key.len() as size_t,
+                );
+                3
+            },
+        }
+    }
+}
+
+/// Type of a value passed to [`CompactionFilter::filter_v2`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ValueType {
+    /// Plain value
+    Value,
+    /// Merge operand
+    MergeOperand,
+    /// Reference to a value of the stacked BlobDB, never passed with the
+    /// integrated blob files, whose values are read and passed as `Value`
+    BlobIndex,
+    /// Wide-column entity
+    WideColumnEntity,
+}
+
+impl ValueType {
+    fn from_raw(raw: c_int) -> Self {
+        match raw {
+            1 => ValueType::MergeOperand,
+            2 => ValueType::BlobIndex,
+            3 => ValueType::WideColumnEntity,
+            _ => ValueType::Value,
+        }
+    }
 }
 
 /// CompactionFilter allows an application to modify/delete a key-value at
@@ -57,7 +130,47 @@ pub trait CompactionFilter {
     /// be used by a single thread that is doing the compaction run, and this
     /// call does not need to be thread-safe.  However, multiple filters may be
     /// in existence and operating concurrently.
-    fn filter(&mut self, level: u32, key: &[u8], value: &[u8]) -> Decision;
+    ///
+    /// Filters implementing [`filter_v2`](CompactionFilter::filter_v2) do not
+    /// need to implement this method, which keeps everything by default.
+    fn filter(&mut self, level: u32, key: &[u8], value: &[u8]) -> Decision {
+        let _ = (level, key, value);
+        Decision::Keep
+    }
+
+    /// Like [`filter`](CompactionFilter::filter), but also called for merge
+    /// operands and wide-column entities, told apart by `value_type`. For
+    /// an entity, `value` is the value of its default column, empty if it has
+    /// none, and `ChangeValue` converts it to a plain value.
+    ///
+    /// Removing a merge operand drops it without writing a tombstone.
+    ///
+    /// The default implementation calls [`filter`](CompactionFilter::filter)
+    /// with plain values and keeps everything else.
+    fn filter_v2(
+        &mut self,
+        level: u32,
+        key: &[u8],
+        value_type: ValueType,
+        value: &[u8],
+    ) -> Decision {
+        match value_type {
+            ValueType::Value => self.filter(level, key, value),
+            _ => Decision::Keep,
+        }
+    }
+
+    /// Called for the values stored in blob files, see
+    /// [`Options::set_enable_blob_files`](crate::Options::set_enable_blob_files),
+    /// before they are read. Returns the decision if it can be made from the
+    /// key only, saving the read, or `None` to read the value and pass it to
+    /// [`filter_v2`](CompactionFilter::filter_v2).
+    ///
+    /// The default implementation always reads the value.
+    fn filter_blob_by_key(&mut self, level: u32, key: &[u8]) -> Option<Decision> {
+        let _ = (level, key);
+        None
+    }
 
     /// Returns a name that identifies this compaction filter.
     /// The name will be printed to LOG file on start up for diagnosis.
@@ -96,6 +209,49 @@ where
     }
 }
 
+/// Function to filter compaction with, aware of the type of the values.
+///
+/// This function takes the level of compaction, the key, the type of the
+/// existing value and the existing value, see
+/// [`CompactionFilter::filter_v2`], and returns the decision about how to
+/// handle the Key-Value pair.
+///
+///  See [Options::set_compaction_filter_v2][set_compaction_filter_v2] for more details
+///
+///  [set_compaction_filter_v2]: ../struct.Options.html#method.set_compaction_filter_v2
+pub trait CompactionFilterV2Fn: FnMut(u32, &[u8], ValueType, &[u8]) -> Decision {}
+impl<F> CompactionFilterV2Fn for F where
+    F: FnMut(u32, &[u8], ValueType, &[u8]) -> Decision + Send + 'static
+{
+}
+
+pub struct CompactionFilterV2Callback<F>
+where
+    F: CompactionFilterV2Fn,
+{
+    pub name: CString,
+    pub filter_fn: F,
+}
+
+impl<F> CompactionFilter for CompactionFilterV2Callback<F>
+where
+    F: CompactionFilterV2Fn,
+{
+    fn name(&self) -> &CStr {
+        self.name.as_c_str()
+    }
+
+    fn filter_v2(
+        &mut self,
+        level: u32,
+        key: &[u8],
+        value_type: ValueType,
+        value: &[u8],
+    ) -> Decision {
+        (self.filter_fn)(level, key, value_type, value)
+    }
+}
+
 pub unsafe extern "C" fn destructor_callback<F>(raw_cb: *mut c_void)
 where
     F: CompactionFilter,
@@ -116,40 +272,65 @@ pub unsafe extern "C" fn filter_callback<F>(
     level: c_int,
     raw_key: *const c_char,
     key_length: size_t,
+    value_type: c_int,
     existing_value: *const c_char,
     value_length: size_t,
-    new_value: *mut *mut c_char,
-    new_value_length: *mut size_t,
-    value_changed: *mut c_uchar,
-) -> c_uchar
+    result: *mut ffi::rocksdb_compactionfilter_result_t,
+) -> c_int
 where
     F: CompactionFilter,
 {
-    use self::Decision::{Change, Keep, Remove};
-
     let cb = &mut *(raw_cb as *mut F);
     let key = slice::from_raw_parts(raw_key as *const u8, key_length);
     let oldval = slice::from_raw_parts(existing_value as *const u8, value_length);
-    let result = cb.filter(level as u32, key, oldval);
-    match result {
-        Keep => 0,
-        Remove => 1,
-        Change(newval) => {
-            *new_value = newval.as_ptr() as *mut c_char;
-            *new_value_length = newval.len() as size_t;
-            *value_changed = 1_u8;
-            0
-        }
+    cb.filter_v2(level as u32, key, ValueType::from_raw(value_type), oldval)
+        .into_raw(result)
+}
+
+pub unsafe extern "C" fn filter_blob_by_key_callback<F>(
+    raw_cb: *mut c_void,
+    level: c_int,
+    raw_key: *const c_char,
+    key_length: size_t,
+    result: *mut ffi::rocksdb_compactionfilter_result_t,
+) -> c_int
+where
+    F: CompactionFilter,
+{
+    let cb = &mut *(raw_cb as *mut F);
+    let key = slice::from_raw_parts(raw_key as *const u8, key_length);
+    match cb.filter_blob_by_key(level as u32, key) {
+        Some(decision) => decision.into_raw(result),
+        // `CompactionFilter::Decision::kUndetermined` in cpp
+        None => 8,
+    }
+}
+
+/// Creates the cpp filter calling `filter`, owned by the returned pointer.
+pub(crate) fn create_compaction_filter<F>(filter: Box<F>) -> *mut ffi::rocksdb_compactionfilter_v2_t
+where
+    F: CompactionFilter,
+{
+    unsafe {
+        ffi::rocksdb_compactionfilter_create_v2(
+            Box::into_raw(filter).cast::<c_void>(),
+            Some(destructor_callback::<F>),
+            Some(filter_callback::<F>),
+            Some(filter_blob_by_key_callback::<F>),
+            Some(name_callback::<F>),
+        )
     }
 }
 
 #[cfg(test)]
 #[allow(unused_variables)]
 fn test_filter(level: u32, key: &[u8], value: &[u8]) -> Decision {
-    use self::Decision::{Change, Keep, Remove};
+    use self::Decision::{Change, ChangeValue, Keep, Remove, RemoveAndSkipUntil};
     match key.first() {
         Some(&b'_') => Remove,
         Some(&b'%') => Change(b"secret"),
+        Some(&b'+') => ChangeValue([value, b"+"].concat()),
+        Some(&b'[') => RemoveAndSkipUntil(b"[c".to_vec()),
         _ => Keep,
     }
 }
@@ -167,10 +348,18 @@ fn compaction_filter_test() {
         let _r = db.put(b"k1", b"a");
         let _r = db.put(b"_k", b"b");
         let _r = db.put(b"%k", b"c");
+        let _r = db.put(b"+k", b"d");
+        let _r = db.put(b"[a", b"e");
+        let _r = db.put(b"[b", b"f");
+        let _r = db.put(b"[c", b"g");
         db.compact_range(None::<&[u8]>, None::<&[u8]>);
         assert_eq!(&*db.get(b"k1").unwrap().unwrap(), b"a");
         assert!(db.get(b"_k").unwrap().is_none());
         assert_eq!(&*db.get(b"%k").unwrap().unwrap(), b"secret");
+        assert_eq!(&*db.get(b"+k").unwrap().unwrap(), b"d+");
+        assert!(db.get(b"[a").unwrap().is_none());
+        assert!(db.get(b"[b").unwrap().is_none());
+        assert_eq!(&*db.get(b"[c").unwrap().unwrap(), b"g");
     }
     let result = DB::destroy(&opts, path);
     assert!(result.is_ok());
@@ -1,9 +1,10 @@
 use std::ffi::CStr;
 
-use libc::{self, c_char, c_void};
+use libc::{self, c_char, c_int, c_uchar, c_void};
 
 use crate::{
     compaction_filter::{self, CompactionFilter},
+    event_listener::TableFileCreationReason,
     ffi,
 };
 
@@ -22,6 +23,16 @@ pub trait CompactionFilterFactory {
     /// Returns a CompactionFilter for the compaction process
     fn create(&mut self, context: CompactionFilterContext) -> Self::Filter;
 
+    /// Returns whether the table files created for `reason` are filtered, in
+    /// which case [`create`](CompactionFilterFactory::create) is called for
+    /// them. Filtering flushes lets a filter drop entries before they reach
+    /// level 0, but it only sees the entries of the flushed memtables.
+    ///
+    /// The default implementation only filters compactions.
+    fn should_filter_table_file_creation(&self, reason: TableFileCreationReason) -> bool {
+        reason == TableFileCreationReason::Compaction
+    }
+
     /// Returns a name that identifies this compaction filter factory.
     fn name(&self) -> &CStr;
 }
@@ -41,13 +52,35 @@ where
     self_.name().as_ptr()
 }
 
+pub unsafe extern "C" fn should_filter_table_file_creation_callback<F>(
+    raw_self: *mut c_void,
+    reason: c_int,
+) -> c_uchar
+where
+    F: CompactionFilterFactory,
+{
+    let self_ = &*(raw_self as *const c_void as *const F);
+    let reason = TableFileCreationReason::from_raw(reason as u32);
+    c_uchar::from(self_.should_filter_table_file_creation(reason))
+}
+
 /// Context information of a compaction run
+///
+/// RocksDB 8.3.2's `CompactionFilter::Context` does not expose the name of
+/// the column family, the
+/// [`CompactionReason`](crate::event_listener::CompactionReason) nor the range
+/// of levels of the compaction, so neither does this context.
 pub struct CompactionFilterContext {
     /// Does this compaction run include all data files
     pub is_full_compaction: bool,
     /// Is this compaction requested by the client (true),
     /// or is it occurring as an automatic compaction process
     pub is_manual_compaction: bool,
+    /// Id of the column family the table files are created for
+    pub column_family_id: u32,
+    /// Whether the table files are created by a flush or a compaction, see
+    /// [`CompactionFilterFactory::should_filter_table_file_creation`]
+    pub reason: TableFileCreationReason,
 }
 
 impl CompactionFilterContext {
@@ -56,9 +89,14 @@ impl CompactionFilterContext {
         let is_manual_compaction =
             ffi::rocksdb_compactionfiltercontext_is_manual_compaction(ptr) != 0;
 
+        let column_family_id = ffi::rocksdb_compactionfiltercontext_get_column_family_id(ptr);
+        let reason = ffi::rocksdb_compactionfiltercontext_get_reason(ptr);
+
         Self {
             is_full_compaction,
             is_manual_compaction,
+            column_family_id,
+            reason: TableFileCreationReason::from_raw(reason as u32),
         }
     }
 }
@@ -66,22 +104,14 @@ impl CompactionFilterContext {
 pub unsafe extern "C" fn create_compaction_filter_callback<F>(
     raw_self: *mut c_void,
     context: *mut ffi::rocksdb_compactionfiltercontext_t,
-) -> *mut ffi::rocksdb_compactionfilter_t
+) -> *mut ffi::rocksdb_compactionfilter_v2_t
 where
     F: CompactionFilterFactory,
 {
     let self_ = &mut *(raw_self as *mut F);
     let context = CompactionFilterContext::from_raw(context);
     let filter = Box::new(self_.create(context));
-
-    let filter_ptr = Box::into_raw(filter);
-
-    ffi::rocksdb_compactionfilter_create(
-        filter_ptr as *mut c_void,
-        Some(compaction_filter::destructor_callback::<F::Filter>),
-        Some(compaction_filter::filter_callback::<F::Filter>),
-        Some(compaction_filter::name_callback::<F::Filter>),
-    )
+    compaction_filter::create_compaction_filter(filter)
 }
 
 #[cfg(test)]
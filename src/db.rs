@@ -20,15 +20,20 @@ use crate::{
     db_options::OptionsMustOutliveDB,
     ffi,
     ffi_util::{from_cstr, opt_bytes_to_ptr, raw_data, to_cpath, CStrLike},
-    ColumnFamily, ColumnFamilyDescriptor, CompactOptions, DBIteratorWithThreadMode,
-    DBPinnableSlice, DBRawIteratorWithThreadMode, DBWALIterator, Direction, Error, FlushOptions,
-    IngestExternalFileOptions, IteratorMode, Options, ReadOptions, SnapshotWithThreadMode,
+    iter_range::IterateBounds,
+    metadata::ColumnFamilyMetaData,
+    table_properties::TableProperties,
+    wide_columns::RawWideColumns,
+    ColumnFamily, ColumnFamilyDescriptor, CompactOptions, CompactionOptions,
+    DBIteratorWithThreadMode, DBPinnableSlice, DBRawIteratorWithThreadMode, DBWALIterator,
+    DBWideColumns, Direction, Error, ErrorKind, FlushOptions, IngestExternalFileOptions,
+    IteratorMode, Options, ReadOptions, SnapshotWithThreadMode, WaitForCompactOptions, WideColumn,
     WriteBatch, WriteOptions, DEFAULT_COLUMN_FAMILY_NAME,
 };
 
 use crate::ffi_util::CSlice;
 use libc::{self, c_char, c_int, c_uchar, c_void, size_t};
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap};
 use std::ffi::{CStr, CString};
 use std::fmt;
 use std::fs;
@@ -38,9 +43,11 @@ use std::path::PathBuf;
 use std::ptr;
 use std::slice;
 use std::str;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 use std::sync::RwLock;
-use std::time::Duration;
+use std::thread;
+use std::time::{Duration, Instant};
 
 /// Marker trait to specify single or multi threaded column family alternations for
 /// [`DBWithThreadMode<T>`]
@@ -120,6 +127,10 @@ impl ThreadMode for MultiThreaded {
 /// Get underlying `rocksdb_t`.
 pub trait DBInner {
     fn inner(&self) -> *mut ffi::rocksdb_t;
+
+    /// Closes the underlying database, reporting any error. The database is
+    /// released even if closing fails, and must not be used afterwards.
+    fn close(&mut self) -> Result<(), Error>;
 }
 
 /// A helper type to implement some common methods for [`DBWithThreadMode`]
@@ -130,6 +141,9 @@ pub struct DBCommon<T: ThreadMode, D: DBInner> {
     pub(crate) inner: D,
     cfs: T, // Column families are held differently depending on thread mode
     path: PathBuf,
+    // Number of `pause_background_work` calls not yet matched by
+    // `continue_background_work`, for `WaitForCompactOptions::set_abort_on_pause`.
+    background_work_paused: AtomicUsize,
     _outlive: Vec<OptionsMustOutliveDB>,
 }
 
@@ -283,12 +297,24 @@ impl DBInner for DBWithThreadModeInner {
     fn inner(&self) -> *mut ffi::rocksdb_t {
         self.inner
     }
+
+    fn close(&mut self) -> Result<(), Error> {
+        let inner = std::mem::replace(&mut self.inner, ptr::null_mut());
+        if !inner.is_null() {
+            unsafe {
+                ffi_try!(ffi::rocksdb_close_with_status(inner));
+            }
+        }
+        Ok(())
+    }
 }
 
 impl Drop for DBWithThreadModeInner {
     fn drop(&mut self) {
-        unsafe {
-            ffi::rocksdb_close(self.inner);
+        if !self.inner.is_null() {
+            unsafe {
+                ffi::rocksdb_close(self.inner);
+            }
         }
     }
 }
@@ -669,6 +695,7 @@ impl<T: ThreadMode> DBWithThreadMode<T> {
             inner: DBWithThreadModeInner { inner: db },
             path: path.as_ref().to_path_buf(),
             cfs: T::new_cf_map_internal(cf_map),
+            background_work_paused: AtomicUsize::new(0),
             _outlive: outlive,
         })
     }
@@ -682,22 +709,22 @@ impl<T: ThreadMode> DBWithThreadMode<T> {
             match *access_type {
                 AccessType::ReadOnly {
                     error_if_log_file_exist,
-                } => ffi_try!(ffi::rocksdb_open_for_read_only(
+                } => ffi_try!(ffi::rocksdb_open_for_read_only_with_status(
                     opts.inner,
                     cpath.as_ptr(),
                     c_uchar::from(error_if_log_file_exist),
                 )),
                 AccessType::ReadWrite => {
-                    ffi_try!(ffi::rocksdb_open(opts.inner, cpath.as_ptr()))
+                    ffi_try!(ffi::rocksdb_open_with_status(opts.inner, cpath.as_ptr()))
                 }
                 AccessType::Secondary { secondary_path } => {
-                    ffi_try!(ffi::rocksdb_open_as_secondary(
+                    ffi_try!(ffi::rocksdb_open_as_secondary_with_status(
                         opts.inner,
                         cpath.as_ptr(),
                         to_cpath(secondary_path)?.as_ptr(),
                     ))
                 }
-                AccessType::WithTTL { ttl } => ffi_try!(ffi::rocksdb_open_with_ttl(
+                AccessType::WithTTL { ttl } => ffi_try!(ffi::rocksdb_open_with_ttl_with_status(
                     opts.inner,
                     cpath.as_ptr(),
                     ttl.as_secs() as c_int,
@@ -721,7 +748,7 @@ impl<T: ThreadMode> DBWithThreadMode<T> {
             match *access_type {
                 AccessType::ReadOnly {
                     error_if_log_file_exist,
-                } => ffi_try!(ffi::rocksdb_open_for_read_only_column_families(
+                } => ffi_try!(ffi::rocksdb_open_for_read_only_column_families_with_status(
                     opts.inner,
                     cpath.as_ptr(),
                     cfs_v.len() as c_int,
@@ -730,7 +757,7 @@ impl<T: ThreadMode> DBWithThreadMode<T> {
                     cfhandles.as_mut_ptr(),
                     c_uchar::from(error_if_log_file_exist),
                 )),
-                AccessType::ReadWrite => ffi_try!(ffi::rocksdb_open_column_families(
+                AccessType::ReadWrite => ffi_try!(ffi::rocksdb_open_column_families_with_status(
                     opts.inner,
                     cpath.as_ptr(),
                     cfs_v.len() as c_int,
@@ -739,7 +766,7 @@ impl<T: ThreadMode> DBWithThreadMode<T> {
                     cfhandles.as_mut_ptr(),
                 )),
                 AccessType::Secondary { secondary_path } => {
-                    ffi_try!(ffi::rocksdb_open_as_secondary_column_families(
+                    ffi_try!(ffi::rocksdb_open_as_secondary_column_families_with_status(
                         opts.inner,
                         cpath.as_ptr(),
                         to_cpath(secondary_path)?.as_ptr(),
@@ -751,7 +778,7 @@ impl<T: ThreadMode> DBWithThreadMode<T> {
                 }
                 AccessType::WithTTL { ttl } => {
                     let ttls_v = vec![ttl.as_secs() as c_int; cfs_v.len()];
-                    ffi_try!(ffi::rocksdb_open_column_families_with_ttl(
+                    ffi_try!(ffi::rocksdb_open_column_families_with_ttl_with_status(
                         opts.inner,
                         cpath.as_ptr(),
                         cfs_v.len() as c_int,
@@ -778,7 +805,7 @@ impl<T: ThreadMode> DBWithThreadMode<T> {
         let to = to.as_ref();
 
         unsafe {
-            ffi_try!(ffi::rocksdb_delete_range_cf(
+            ffi_try!(ffi::rocksdb_delete_range_cf_with_status(
                 self.inner.inner(),
                 writeopts.inner,
                 cf.inner(),
@@ -803,7 +830,7 @@ impl<T: ThreadMode> DBWithThreadMode<T> {
 
     pub fn write_opt(&self, batch: WriteBatch, writeopts: &WriteOptions) -> Result<(), Error> {
         unsafe {
-            ffi_try!(ffi::rocksdb_write(
+            ffi_try!(ffi::rocksdb_write_with_status(
                 self.inner.inner(),
                 writeopts.inner,
                 batch.inner
@@ -830,6 +857,7 @@ impl<T: ThreadMode, D: DBInner> DBCommon<T, D> {
             inner,
             cfs,
             path,
+            background_work_paused: AtomicUsize::new(0),
             _outlive: outlive,
         }
     }
@@ -839,7 +867,7 @@ impl<T: ThreadMode, D: DBInner> DBCommon<T, D> {
         let mut length = 0;
 
         unsafe {
-            let ptr = ffi_try!(ffi::rocksdb_list_column_families(
+            let ptr = ffi_try!(ffi::rocksdb_list_column_families_with_status(
                 opts.inner,
                 cpath.as_ptr(),
                 &mut length,
@@ -857,7 +885,10 @@ impl<T: ThreadMode, D: DBInner> DBCommon<T, D> {
     pub fn destroy<P: AsRef<Path>>(opts: &Options, path: P) -> Result<(), Error> {
         let cpath = to_cpath(path)?;
         unsafe {
-            ffi_try!(ffi::rocksdb_destroy_db(opts.inner, cpath.as_ptr()));
+            ffi_try!(ffi::rocksdb_destroy_db_with_status(
+                opts.inner,
+                cpath.as_ptr()
+            ));
         }
         Ok(())
     }
@@ -865,7 +896,10 @@ impl<T: ThreadMode, D: DBInner> DBCommon<T, D> {
     pub fn repair<P: AsRef<Path>>(opts: &Options, path: P) -> Result<(), Error> {
         let cpath = to_cpath(path)?;
         unsafe {
-            ffi_try!(ffi::rocksdb_repair_db(opts.inner, cpath.as_ptr()));
+            ffi_try!(ffi::rocksdb_repair_db_with_status(
+                opts.inner,
+                cpath.as_ptr()
+            ));
         }
         Ok(())
     }
@@ -878,7 +912,7 @@ impl<T: ThreadMode, D: DBInner> DBCommon<T, D> {
     /// the data to disk.
     pub fn flush_wal(&self, sync: bool) -> Result<(), Error> {
         unsafe {
-            ffi_try!(ffi::rocksdb_flush_wal(
+            ffi_try!(ffi::rocksdb_flush_wal_with_status(
                 self.inner.inner(),
                 c_uchar::from(sync)
             ));
@@ -889,7 +923,10 @@ impl<T: ThreadMode, D: DBInner> DBCommon<T, D> {
     /// Flushes database memtables to SST files on the disk.
     pub fn flush_opt(&self, flushopts: &FlushOptions) -> Result<(), Error> {
         unsafe {
-            ffi_try!(ffi::rocksdb_flush(self.inner.inner(), flushopts.inner));
+            ffi_try!(ffi::rocksdb_flush_with_status(
+                self.inner.inner(),
+                flushopts.inner
+            ));
         }
         Ok(())
     }
@@ -906,7 +943,7 @@ impl<T: ThreadMode, D: DBInner> DBCommon<T, D> {
         flushopts: &FlushOptions,
     ) -> Result<(), Error> {
         unsafe {
-            ffi_try!(ffi::rocksdb_flush_cf(
+            ffi_try!(ffi::rocksdb_flush_cf_with_status(
                 self.inner.inner(),
                 flushopts.inner,
                 cf.inner()
@@ -927,7 +964,7 @@ impl<T: ThreadMode, D: DBInner> DBCommon<T, D> {
     ) -> Result<(), Error> {
         let mut cfs = cfs.iter().map(|cf| cf.inner()).collect::<Vec<_>>();
         unsafe {
-            ffi_try!(ffi::rocksdb_flush_cfs(
+            ffi_try!(ffi::rocksdb_flush_cfs_with_status(
                 self.inner.inner(),
                 opts.inner,
                 cfs.as_mut_ptr(),
@@ -1003,7 +1040,7 @@ impl<T: ThreadMode, D: DBInner> DBCommon<T, D> {
 
         let key = key.as_ref();
         unsafe {
-            let val = ffi_try!(ffi::rocksdb_get_pinned(
+            let val = ffi_try!(ffi::rocksdb_get_pinned_with_status(
                 self.inner.inner(),
                 readopts.inner,
                 key.as_ptr() as *const c_char,
@@ -1043,7 +1080,7 @@ impl<T: ThreadMode, D: DBInner> DBCommon<T, D> {
 
         let key = key.as_ref();
         unsafe {
-            let val = ffi_try!(ffi::rocksdb_get_pinned_cf(
+            let val = ffi_try!(ffi::rocksdb_get_pinned_cf_with_status(
                 self.inner.inner(),
                 readopts.inner,
                 cf.inner(),
@@ -1069,6 +1106,87 @@ impl<T: ThreadMode, D: DBInner> DBCommon<T, D> {
         self.get_pinned_cf_opt(cf, key, &ReadOptions::default())
     }
 
+    /// Return the wide-column entity associated with a key. A plain key-value
+    /// written with `put` is returned as a single column named
+    /// [`DEFAULT_WIDE_COLUMN_NAME`](crate::DEFAULT_WIDE_COLUMN_NAME).
+    pub fn get_entity_opt<K: AsRef<[u8]>>(
+        &self,
+        key: K,
+        readopts: &ReadOptions,
+    ) -> Result<Option<DBWideColumns>, Error> {
+        if readopts.inner.is_null() {
+            return Err(Error::new(
+                "Unable to create RocksDB read options. This is a fairly trivial call, and its \
+                 failure may be indicative of a mis-compiled or mis-loaded RocksDB library."
+                    .to_owned(),
+            ));
+        }
+
+        let key = key.as_ref();
+        unsafe {
+            let val = ffi_try!(ffi::rocksdb_get_entity(
+                self.inner.inner(),
+                readopts.inner,
+                key.as_ptr() as *const c_char,
+                key.len() as size_t,
+            ));
+            if val.is_null() {
+                Ok(None)
+            } else {
+                Ok(Some(DBWideColumns::from_c(val)))
+            }
+        }
+    }
+
+    /// Return the wide-column entity associated with a key. Similar to
+    /// get_entity_opt but leverages default options.
+    pub fn get_entity<K: AsRef<[u8]>>(&self, key: K) -> Result<Option<DBWideColumns>, Error> {
+        self.get_entity_opt(key, &ReadOptions::default())
+    }
+
+    /// Return the wide-column entity associated with a key. Similar to
+    /// get_entity_opt but allows specifying ColumnFamily
+    pub fn get_entity_cf_opt<K: AsRef<[u8]>>(
+        &self,
+        cf: &impl AsColumnFamilyRef,
+        key: K,
+        readopts: &ReadOptions,
+    ) -> Result<Option<DBWideColumns>, Error> {
+        if readopts.inner.is_null() {
+            return Err(Error::new(
+                "Unable to create RocksDB read options. This is a fairly trivial call, and its \
+                 failure may be indicative of a mis-compiled or mis-loaded RocksDB library."
+                    .to_owned(),
+            ));
+        }
+
+        let key = key.as_ref();
+        unsafe {
+            let val = ffi_try!(ffi::rocksdb_get_entity_cf(
+                self.inner.inner(),
+                readopts.inner,
+                cf.inner(),
+                key.as_ptr() as *const c_char,
+                key.len() as size_t,
+            ));
+            if val.is_null() {
+                Ok(None)
+            } else {
+                Ok(Some(DBWideColumns::from_c(val)))
+            }
+        }
+    }
+
+    /// Return the wide-column entity associated with a key. Similar to
+    /// get_entity_cf_opt but leverages default options.
+    pub fn get_entity_cf<K: AsRef<[u8]>>(
+        &self,
+        cf: &impl AsColumnFamilyRef,
+        key: K,
+    ) -> Result<Option<DBWideColumns>, Error> {
+        self.get_entity_cf_opt(cf, key, &ReadOptions::default())
+    }
+
     /// Return the values associated with the given keys.
     pub fn multi_get<K, I>(&self, keys: I) -> Vec<Result<Option<Vec<u8>>, Error>>
     where
@@ -1098,7 +1216,7 @@ impl<T: ThreadMode, D: DBInner> DBCommon<T, D> {
         let mut values_sizes = vec![0_usize; keys.len()];
         let mut errors = vec![ptr::null_mut(); keys.len()];
         unsafe {
-            ffi::rocksdb_multi_get(
+            ffi::rocksdb_multi_get_with_status(
                 self.inner.inner(),
                 readopts.inner,
                 ptr_keys.len(),
@@ -1154,7 +1272,7 @@ impl<T: ThreadMode, D: DBInner> DBCommon<T, D> {
         let mut values_sizes = vec![0_usize; ptr_keys.len()];
         let mut errors = vec![ptr::null_mut(); ptr_keys.len()];
         unsafe {
-            ffi::rocksdb_multi_get_cf(
+            ffi::rocksdb_multi_get_cf_with_status(
                 self.inner.inner(),
                 readopts.inner,
                 ptr_cfs.as_ptr(),
@@ -1212,7 +1330,7 @@ impl<T: ThreadMode, D: DBInner> DBCommon<T, D> {
         let mut errors = vec![ptr::null_mut(); ptr_keys.len()];
 
         unsafe {
-            ffi::rocksdb_batched_multi_get_cf(
+            ffi::rocksdb_batched_multi_get_cf_with_status(
                 self.inner.inner(),
                 readopts.inner,
                 cf.inner(),
@@ -1234,7 +1352,7 @@ impl<T: ThreadMode, D: DBInner> DBCommon<T, D> {
                             Ok(Some(DBPinnableSlice::from_c(v)))
                         }
                     } else {
-                        Err(Error::new(crate::ffi_util::error_message(e)))
+                        Err(Error::from_status(e))
                     }
                 })
                 .collect()
@@ -1351,7 +1469,7 @@ impl<T: ThreadMode, D: DBInner> DBCommon<T, D> {
             ))
         })?;
         Ok(unsafe {
-            ffi_try!(ffi::rocksdb_create_column_family(
+            ffi_try!(ffi::rocksdb_create_column_family_with_status(
                 self.inner.inner(),
                 opts.inner,
                 cf_name.as_ptr(),
@@ -1490,7 +1608,7 @@ impl<T: ThreadMode, D: DBInner> DBCommon<T, D> {
         let value = value.as_ref();
 
         unsafe {
-            ffi_try!(ffi::rocksdb_put(
+            ffi_try!(ffi::rocksdb_put_with_status(
                 self.inner.inner(),
                 writeopts.inner,
                 key.as_ptr() as *const c_char,
@@ -1517,7 +1635,7 @@ impl<T: ThreadMode, D: DBInner> DBCommon<T, D> {
         let value = value.as_ref();
 
         unsafe {
-            ffi_try!(ffi::rocksdb_put_cf(
+            ffi_try!(ffi::rocksdb_put_cf_with_status(
                 self.inner.inner(),
                 writeopts.inner,
                 cf.inner(),
@@ -1530,6 +1648,60 @@ impl<T: ThreadMode, D: DBInner> DBCommon<T, D> {
         }
     }
 
+    /// Write a wide-column entity, replacing any existing value or entity
+    /// stored under the key. Column names must be unique.
+    pub fn put_entity_opt<K: AsRef<[u8]>>(
+        &self,
+        key: K,
+        columns: &[WideColumn],
+        writeopts: &WriteOptions,
+    ) -> Result<(), Error> {
+        let key = key.as_ref();
+        let raw = RawWideColumns::new(columns);
+
+        unsafe {
+            ffi_try!(ffi::rocksdb_put_entity(
+                self.inner.inner(),
+                writeopts.inner,
+                key.as_ptr() as *const c_char,
+                key.len() as size_t,
+                raw.len(),
+                raw.names.as_ptr(),
+                raw.names_sizes.as_ptr(),
+                raw.values.as_ptr(),
+                raw.values_sizes.as_ptr(),
+            ));
+            Ok(())
+        }
+    }
+
+    pub fn put_entity_cf_opt<K: AsRef<[u8]>>(
+        &self,
+        cf: &impl AsColumnFamilyRef,
+        key: K,
+        columns: &[WideColumn],
+        writeopts: &WriteOptions,
+    ) -> Result<(), Error> {
+        let key = key.as_ref();
+        let raw = RawWideColumns::new(columns);
+
+        unsafe {
+            ffi_try!(ffi::rocksdb_put_entity_cf(
+                self.inner.inner(),
+                writeopts.inner,
+                cf.inner(),
+                key.as_ptr() as *const c_char,
+                key.len() as size_t,
+                raw.len(),
+                raw.names.as_ptr(),
+                raw.names_sizes.as_ptr(),
+                raw.values.as_ptr(),
+                raw.values_sizes.as_ptr(),
+            ));
+            Ok(())
+        }
+    }
+
     pub fn merge_opt<K, V>(&self, key: K, value: V, writeopts: &WriteOptions) -> Result<(), Error>
     where
         K: AsRef<[u8]>,
@@ -1539,7 +1711,7 @@ impl<T: ThreadMode, D: DBInner> DBCommon<T, D> {
         let value = value.as_ref();
 
         unsafe {
-            ffi_try!(ffi::rocksdb_merge(
+            ffi_try!(ffi::rocksdb_merge_with_status(
                 self.inner.inner(),
                 writeopts.inner,
                 key.as_ptr() as *const c_char,
@@ -1566,7 +1738,7 @@ impl<T: ThreadMode, D: DBInner> DBCommon<T, D> {
         let value = value.as_ref();
 
         unsafe {
-            ffi_try!(ffi::rocksdb_merge_cf(
+            ffi_try!(ffi::rocksdb_merge_cf_with_status(
                 self.inner.inner(),
                 writeopts.inner,
                 cf.inner(),
@@ -1587,7 +1759,7 @@ impl<T: ThreadMode, D: DBInner> DBCommon<T, D> {
         let key = key.as_ref();
 
         unsafe {
-            ffi_try!(ffi::rocksdb_delete(
+            ffi_try!(ffi::rocksdb_delete_with_status(
                 self.inner.inner(),
                 writeopts.inner,
                 key.as_ptr() as *const c_char,
@@ -1606,7 +1778,52 @@ impl<T: ThreadMode, D: DBInner> DBCommon<T, D> {
         let key = key.as_ref();
 
         unsafe {
-            ffi_try!(ffi::rocksdb_delete_cf(
+            ffi_try!(ffi::rocksdb_delete_cf_with_status(
+                self.inner.inner(),
+                writeopts.inner,
+                cf.inner(),
+                key.as_ptr() as *const c_char,
+                key.len() as size_t,
+            ));
+            Ok(())
+        }
+    }
+
+    /// Removes the database entry for `key` using a SingleDelete tombstone.
+    ///
+    /// SingleDelete is only defined for keys which were written exactly once
+    /// since the previous deletion and which were never overwritten or
+    /// merged. Mixing it with other writes to the same key results in
+    /// undefined behavior.
+    pub fn single_delete_opt<K: AsRef<[u8]>>(
+        &self,
+        key: K,
+        writeopts: &WriteOptions,
+    ) -> Result<(), Error> {
+        let key = key.as_ref();
+
+        unsafe {
+            ffi_try!(ffi::rocksdb_singledelete_with_status(
+                self.inner.inner(),
+                writeopts.inner,
+                key.as_ptr() as *const c_char,
+                key.len() as size_t,
+            ));
+            Ok(())
+        }
+    }
+
+    /// Same as `single_delete_opt` but for a specific column family.
+    pub fn single_delete_cf_opt<K: AsRef<[u8]>>(
+        &self,
+        cf: &impl AsColumnFamilyRef,
+        key: K,
+        writeopts: &WriteOptions,
+    ) -> Result<(), Error> {
+        let key = key.as_ref();
+
+        unsafe {
+            ffi_try!(ffi::rocksdb_singledelete_cf_with_status(
                 self.inner.inner(),
                 writeopts.inner,
                 cf.inner(),
@@ -1633,6 +1850,19 @@ impl<T: ThreadMode, D: DBInner> DBCommon<T, D> {
         self.put_cf_opt(cf, key.as_ref(), value.as_ref(), &WriteOptions::default())
     }
 
+    pub fn put_entity<K: AsRef<[u8]>>(&self, key: K, columns: &[WideColumn]) -> Result<(), Error> {
+        self.put_entity_opt(key, columns, &WriteOptions::default())
+    }
+
+    pub fn put_entity_cf<K: AsRef<[u8]>>(
+        &self,
+        cf: &impl AsColumnFamilyRef,
+        key: K,
+        columns: &[WideColumn],
+    ) -> Result<(), Error> {
+        self.put_entity_cf_opt(cf, key, columns, &WriteOptions::default())
+    }
+
     pub fn merge<K, V>(&self, key: K, value: V) -> Result<(), Error>
     where
         K: AsRef<[u8]>,
@@ -1649,6 +1879,44 @@ impl<T: ThreadMode, D: DBInner> DBCommon<T, D> {
         self.merge_cf_opt(cf, key.as_ref(), value.as_ref(), &WriteOptions::default())
     }
 
+    /// Adds `delta` to the counter at `key`, in the format of
+    /// [`UInt64AddOperator`](crate::merge_operator::UInt64AddOperator), which
+    /// must be the merge operator of the database.
+    pub fn increment<K: AsRef<[u8]>>(&self, key: K, delta: u64) -> Result<(), Error> {
+        self.merge(key, delta.to_le_bytes())
+    }
+
+    /// Adds `delta` to the counter at `key` in the column family, in the
+    /// format of [`UInt64AddOperator`](crate::merge_operator::UInt64AddOperator),
+    /// which must be the merge operator of the column family.
+    pub fn increment_cf<K: AsRef<[u8]>>(
+        &self,
+        cf: &impl AsColumnFamilyRef,
+        key: K,
+        delta: u64,
+    ) -> Result<(), Error> {
+        self.merge_cf(cf, key, delta.to_le_bytes())
+    }
+
+    /// Adds `delta` to the counter at `key`, in the format of
+    /// [`Int64AddOperator`](crate::merge_operator::Int64AddOperator), which
+    /// must be the merge operator of the database.
+    pub fn increment_i64<K: AsRef<[u8]>>(&self, key: K, delta: i64) -> Result<(), Error> {
+        self.merge(key, delta.to_le_bytes())
+    }
+
+    /// Adds `delta` to the counter at `key` in the column family, in the
+    /// format of [`Int64AddOperator`](crate::merge_operator::Int64AddOperator),
+    /// which must be the merge operator of the column family.
+    pub fn increment_i64_cf<K: AsRef<[u8]>>(
+        &self,
+        cf: &impl AsColumnFamilyRef,
+        key: K,
+        delta: i64,
+    ) -> Result<(), Error> {
+        self.merge_cf(cf, key, delta.to_le_bytes())
+    }
+
     pub fn delete<K: AsRef<[u8]>>(&self, key: K) -> Result<(), Error> {
         self.delete_opt(key.as_ref(), &WriteOptions::default())
     }
@@ -1661,6 +1929,22 @@ impl<T: ThreadMode, D: DBInner> DBCommon<T, D> {
         self.delete_cf_opt(cf, key.as_ref(), &WriteOptions::default())
     }
 
+    /// Removes the database entry for `key` using a SingleDelete tombstone.
+    ///
+    /// See [`single_delete_opt`](#method.single_delete_opt) for the restrictions
+    /// that apply to SingleDelete.
+    pub fn single_delete<K: AsRef<[u8]>>(&self, key: K) -> Result<(), Error> {
+        self.single_delete_opt(key.as_ref(), &WriteOptions::default())
+    }
+
+    pub fn single_delete_cf<K: AsRef<[u8]>>(
+        &self,
+        cf: &impl AsColumnFamilyRef,
+        key: K,
+    ) -> Result<(), Error> {
+        self.single_delete_cf_opt(cf, key.as_ref(), &WriteOptions::default())
+    }
+
     /// Runs a manual compaction on the Range of keys given. This is not likely to be needed for typical usage.
     pub fn compact_range<S: AsRef<[u8]>, E: AsRef<[u8]>>(&self, start: Option<S>, end: Option<E>) {
         unsafe {
@@ -1746,13 +2030,104 @@ impl<T: ThreadMode, D: DBInner> DBCommon<T, D> {
         }
     }
 
+    /// Compacts the given SST files of the default column family into
+    /// `output_level`. File names are as returned by `live_files`, e.g.
+    /// `/000123.sst`.
+    ///
+    /// Unlike `compact_range`, the compaction runs on the calling thread.
+    pub fn compact_files<I, N>(
+        &self,
+        opts: &CompactionOptions,
+        files: I,
+        output_level: i32,
+    ) -> Result<(), Error>
+    where
+        I: IntoIterator<Item = N>,
+        N: AsRef<str>,
+    {
+        let files = convert_file_names(files)?;
+        let file_ptrs: Vec<_> = files.iter().map(|f| f.as_ptr()).collect();
+        unsafe {
+            ffi_try!(ffi::rocksdb_compact_files(
+                self.inner.inner(),
+                opts.inner,
+                file_ptrs.len(),
+                file_ptrs.as_ptr(),
+                output_level as c_int,
+            ));
+        }
+        Ok(())
+    }
+
+    /// Compacts the given SST files of a column family into `output_level`.
+    /// File names are as returned by `live_files`, e.g. `/000123.sst`.
+    ///
+    /// Unlike `compact_range_cf`, the compaction runs on the calling thread.
+    pub fn compact_files_cf<I, N>(
+        &self,
+        cf: &impl AsColumnFamilyRef,
+        opts: &CompactionOptions,
+        files: I,
+        output_level: i32,
+    ) -> Result<(), Error>
+    where
+        I: IntoIterator<Item = N>,
+        N: AsRef<str>,
+    {
+        let files = convert_file_names(files)?;
+        let file_ptrs: Vec<_> = files.iter().map(|f| f.as_ptr()).collect();
+        unsafe {
+            ffi_try!(ffi::rocksdb_compact_files_cf(
+                self.inner.inner(),
+                opts.inner,
+                cf.inner(),
+                file_ptrs.len(),
+                file_ptrs.as_ptr(),
+                output_level as c_int,
+            ));
+        }
+        Ok(())
+    }
+
+    /// Moves all L0 files of the default column family to `target_level`
+    /// without rewriting them.
+    ///
+    /// This only succeeds if the L0 files have non-overlapping key ranges and
+    /// all levels from 1 to `target_level` are empty, e.g. after ingesting
+    /// sorted files into L0.
+    pub fn promote_l0(&self, target_level: i32) -> Result<(), Error> {
+        unsafe {
+            ffi_try!(ffi::rocksdb_promote_l0(
+                self.inner.inner(),
+                target_level as c_int
+            ));
+        }
+        Ok(())
+    }
+
+    /// Same as `promote_l0`, for the given column family.
+    pub fn promote_l0_cf(
+        &self,
+        cf: &impl AsColumnFamilyRef,
+        target_level: i32,
+    ) -> Result<(), Error> {
+        unsafe {
+            ffi_try!(ffi::rocksdb_promote_l0_cf(
+                self.inner.inner(),
+                cf.inner(),
+                target_level as c_int
+            ));
+        }
+        Ok(())
+    }
+
     pub fn set_options(&self, opts: &[(&str, &str)]) -> Result<(), Error> {
         let copts = convert_options(opts)?;
         let cnames: Vec<*const c_char> = copts.iter().map(|opt| opt.0.as_ptr()).collect();
         let cvalues: Vec<*const c_char> = copts.iter().map(|opt| opt.1.as_ptr()).collect();
         let count = opts.len() as i32;
         unsafe {
-            ffi_try!(ffi::rocksdb_set_options(
+            ffi_try!(ffi::rocksdb_set_options_with_status(
                 self.inner.inner(),
                 count,
                 cnames.as_ptr(),
@@ -1772,7 +2147,7 @@ impl<T: ThreadMode, D: DBInner> DBCommon<T, D> {
         let cvalues: Vec<*const c_char> = copts.iter().map(|opt| opt.1.as_ptr()).collect();
         let count = opts.len() as i32;
         unsafe {
-            ffi_try!(ffi::rocksdb_set_options_cf(
+            ffi_try!(ffi::rocksdb_set_options_cf_with_status(
                 self.inner.inner(),
                 cf.inner(),
                 count,
@@ -1887,6 +2262,161 @@ impl<T: ThreadMode, D: DBInner> DBCommon<T, D> {
         )
     }
 
+    /// Returns the approximate file system space used by keys in each of
+    /// `ranges`, e.g. `b"a".to_vec()..b"c".to_vec()` or
+    /// `PrefixRange(b"user:".to_vec())`, in the default column family.
+    ///
+    /// Only data in SST files is counted; see
+    /// [`get_approximate_sizes_opt`](Self::get_approximate_sizes_opt) to
+    /// include the memtables as well.
+    pub fn get_approximate_sizes<R, I>(&self, ranges: I) -> Result<Vec<u64>, Error>
+    where
+        R: IterateBounds,
+        I: IntoIterator<Item = R>,
+    {
+        self.get_approximate_sizes_opt(ranges, &SizeApproximationOptions::default())
+    }
+
+    pub fn get_approximate_sizes_opt<R, I>(
+        &self,
+        ranges: I,
+        opts: &SizeApproximationOptions,
+    ) -> Result<Vec<u64>, Error>
+    where
+        R: IterateBounds,
+        I: IntoIterator<Item = R>,
+    {
+        self.approximate_sizes_impl(None, ranges, opts)
+    }
+
+    /// Returns the approximate file system space used by keys in each of
+    /// `ranges` in the given column family.
+    pub fn get_approximate_sizes_cf<R, I>(
+        &self,
+        cf: &impl AsColumnFamilyRef,
+        ranges: I,
+    ) -> Result<Vec<u64>, Error>
+    where
+        R: IterateBounds,
+        I: IntoIterator<Item = R>,
+    {
+        self.get_approximate_sizes_cf_opt(cf, ranges, &SizeApproximationOptions::default())
+    }
+
+    pub fn get_approximate_sizes_cf_opt<R, I>(
+        &self,
+        cf: &impl AsColumnFamilyRef,
+        ranges: I,
+        opts: &SizeApproximationOptions,
+    ) -> Result<Vec<u64>, Error>
+    where
+        R: IterateBounds,
+        I: IntoIterator<Item = R>,
+    {
+        self.approximate_sizes_impl(Some(cf.inner()), ranges, opts)
+    }
+
+    fn approximate_sizes_impl<R, I>(
+        &self,
+        cf: Option<*mut ffi::rocksdb_column_family_handle_t>,
+        ranges: I,
+        opts: &SizeApproximationOptions,
+    ) -> Result<Vec<u64>, Error>
+    where
+        R: IterateBounds,
+        I: IntoIterator<Item = R>,
+    {
+        let bounds: Vec<_> = ranges.into_iter().map(IterateBounds::into_bounds).collect();
+        let (start_keys, start_key_lens): (Vec<_>, Vec<_>) = bounds
+            .iter()
+            .map(|(start, _)| bound_ptr(start.as_deref()))
+            .unzip();
+        let (limit_keys, limit_key_lens): (Vec<_>, Vec<_>) = bounds
+            .iter()
+            .map(|(_, limit)| bound_ptr(limit.as_deref()))
+            .unzip();
+        let mut sizes: Vec<u64> = vec![0; bounds.len()];
+
+        unsafe {
+            if let Some(cf) = cf {
+                ffi_try!(ffi::rocksdb_approximate_sizes_cf_with_flags(
+                    self.inner.inner(),
+                    cf,
+                    bounds.len() as c_int,
+                    start_keys.as_ptr(),
+                    start_key_lens.as_ptr(),
+                    limit_keys.as_ptr(),
+                    limit_key_lens.as_ptr(),
+                    c_uchar::from(opts.include_memtables),
+                    c_uchar::from(opts.include_files),
+                    opts.files_size_error_margin,
+                    sizes.as_mut_ptr(),
+                ));
+            } else {
+                ffi_try!(ffi::rocksdb_approximate_sizes_with_flags(
+                    self.inner.inner(),
+                    bounds.len() as c_int,
+                    start_keys.as_ptr(),
+                    start_key_lens.as_ptr(),
+                    limit_keys.as_ptr(),
+                    limit_key_lens.as_ptr(),
+                    c_uchar::from(opts.include_memtables),
+                    c_uchar::from(opts.include_files),
+                    opts.files_size_error_margin,
+                    sizes.as_mut_ptr(),
+                ));
+            }
+        }
+        Ok(sizes)
+    }
+
+    /// Returns the approximate number of entries and their size in the
+    /// memtables of the default column family for the given key range.
+    pub fn get_approximate_memtable_stats(&self, range: impl IterateBounds) -> MemTableStats {
+        let (start, limit) = range.into_bounds();
+        let (start_key, start_key_len) = bound_ptr(start.as_deref());
+        let (limit_key, limit_key_len) = bound_ptr(limit.as_deref());
+        let mut stats = MemTableStats::default();
+        unsafe {
+            ffi::rocksdb_approximate_memtable_stats(
+                self.inner.inner(),
+                start_key,
+                start_key_len,
+                limit_key,
+                limit_key_len,
+                &mut stats.count,
+                &mut stats.size,
+            );
+        }
+        stats
+    }
+
+    /// Returns the approximate number of entries and their size in the
+    /// memtables of the given column family for the given key range.
+    pub fn get_approximate_memtable_stats_cf(
+        &self,
+        cf: &impl AsColumnFamilyRef,
+        range: impl IterateBounds,
+    ) -> MemTableStats {
+        let (start, limit) = range.into_bounds();
+        let (start_key, start_key_len) = bound_ptr(start.as_deref());
+        let (limit_key, limit_key_len) = bound_ptr(limit.as_deref());
+        let mut stats = MemTableStats::default();
+        unsafe {
+            ffi::rocksdb_approximate_memtable_stats_cf(
+                self.inner.inner(),
+                cf.inner(),
+                start_key,
+                start_key_len,
+                limit_key,
+                limit_key_len,
+                &mut stats.count,
+                &mut stats.size,
+            );
+        }
+        stats
+    }
+
     /// The sequence number of the most recent transaction.
     pub fn latest_sequence_number(&self) -> u64 {
         unsafe { ffi::rocksdb_get_latest_sequence_number(self.inner.inner()) }
@@ -1908,7 +2438,7 @@ impl<T: ThreadMode, D: DBInner> DBCommon<T, D> {
             // for creating and destroying it; fortunately we can pass a nullptr
             // here to get the default behavior
             let opts: *const ffi::rocksdb_wal_readoptions_t = ptr::null();
-            let iter = ffi_try!(ffi::rocksdb_get_updates_since(
+            let iter = ffi_try!(ffi::rocksdb_get_updates_since_with_status(
                 self.inner.inner(),
                 seq_number,
                 opts
@@ -1924,7 +2454,9 @@ impl<T: ThreadMode, D: DBInner> DBCommon<T, D> {
     /// log files.
     pub fn try_catch_up_with_primary(&self) -> Result<(), Error> {
         unsafe {
-            ffi_try!(ffi::rocksdb_try_catch_up_with_primary(self.inner.inner()));
+            ffi_try!(ffi::rocksdb_try_catch_up_with_primary_with_status(
+                self.inner.inner()
+            ));
         }
         Ok(())
     }
@@ -1978,7 +2510,7 @@ impl<T: ThreadMode, D: DBInner> DBCommon<T, D> {
         cpaths: &[*const c_char],
     ) -> Result<(), Error> {
         unsafe {
-            ffi_try!(ffi::rocksdb_ingest_external_file(
+            ffi_try!(ffi::rocksdb_ingest_external_file_with_status(
                 self.inner.inner(),
                 cpaths.as_ptr(),
                 paths_v.len(),
@@ -1996,7 +2528,7 @@ impl<T: ThreadMode, D: DBInner> DBCommon<T, D> {
         cpaths: &[*const c_char],
     ) -> Result<(), Error> {
         unsafe {
-            ffi_try!(ffi::rocksdb_ingest_external_file_cf(
+            ffi_try!(ffi::rocksdb_ingest_external_file_cf_with_status(
                 self.inner.inner(),
                 cf.inner(),
                 cpaths.as_ptr(),
@@ -2056,6 +2588,124 @@ impl<T: ThreadMode, D: DBInner> DBCommon<T, D> {
         }
     }
 
+    /// Returns the metadata of the default column family: its SST files
+    /// grouped by level, and its blob files.
+    pub fn column_family_metadata(&self) -> ColumnFamilyMetaData {
+        unsafe {
+            ColumnFamilyMetaData::from_c(ffi::rocksdb_get_column_family_metadata(
+                self.inner.inner(),
+            ))
+        }
+    }
+
+    /// Returns the metadata of the given column family: its SST files grouped
+    /// by level, and its blob files.
+    pub fn column_family_metadata_cf(&self, cf: &impl AsColumnFamilyRef) -> ColumnFamilyMetaData {
+        unsafe {
+            ColumnFamilyMetaData::from_c(ffi::rocksdb_get_column_family_metadata_cf(
+                self.inner.inner(),
+                cf.inner(),
+            ))
+        }
+    }
+
+    /// Returns the properties of all SST files of the default column family,
+    /// keyed by file path.
+    pub fn get_properties_of_all_tables(&self) -> Result<HashMap<String, TableProperties>, Error> {
+        unsafe {
+            let collection = ffi_try!(ffi::rocksdb_get_properties_of_all_tables(
+                self.inner.inner()
+            ));
+            Ok(TableProperties::collection_from_c(collection))
+        }
+    }
+
+    /// Returns the properties of all SST files of the given column family,
+    /// keyed by file path.
+    pub fn get_properties_of_all_tables_cf(
+        &self,
+        cf: &impl AsColumnFamilyRef,
+    ) -> Result<HashMap<String, TableProperties>, Error> {
+        unsafe {
+            let collection = ffi_try!(ffi::rocksdb_get_properties_of_all_tables_cf(
+                self.inner.inner(),
+                cf.inner(),
+            ));
+            Ok(TableProperties::collection_from_c(collection))
+        }
+    }
+
+    /// Returns the properties of the SST files of the default column family
+    /// that overlap any of `ranges`, keyed by file path.
+    pub fn get_properties_of_tables_in_range<R, I>(
+        &self,
+        ranges: I,
+    ) -> Result<HashMap<String, TableProperties>, Error>
+    where
+        R: IterateBounds,
+        I: IntoIterator<Item = R>,
+    {
+        self.properties_of_tables_in_range_impl(None, ranges)
+    }
+
+    /// Returns the properties of the SST files of the given column family
+    /// that overlap any of `ranges`, keyed by file path.
+    pub fn get_properties_of_tables_in_range_cf<R, I>(
+        &self,
+        cf: &impl AsColumnFamilyRef,
+        ranges: I,
+    ) -> Result<HashMap<String, TableProperties>, Error>
+    where
+        R: IterateBounds,
+        I: IntoIterator<Item = R>,
+    {
+        self.properties_of_tables_in_range_impl(Some(cf.inner()), ranges)
+    }
+
+    fn properties_of_tables_in_range_impl<R, I>(
+        &self,
+        cf: Option<*mut ffi::rocksdb_column_family_handle_t>,
+        ranges: I,
+    ) -> Result<HashMap<String, TableProperties>, Error>
+    where
+        R: IterateBounds,
+        I: IntoIterator<Item = R>,
+    {
+        let bounds: Vec<_> = ranges.into_iter().map(IterateBounds::into_bounds).collect();
+        let (start_keys, start_key_lens): (Vec<_>, Vec<_>) = bounds
+            .iter()
+            .map(|(start, _)| bound_ptr(start.as_deref()))
+            .unzip();
+        let (limit_keys, limit_key_lens): (Vec<_>, Vec<_>) = bounds
+            .iter()
+            .map(|(_, limit)| bound_ptr(limit.as_deref()))
+            .unzip();
+
+        unsafe {
+            let collection = if let Some(cf) = cf {
+                ffi_try!(ffi::rocksdb_get_properties_of_tables_in_range_cf(
+                    self.inner.inner(),
+                    cf,
+                    bounds.len() as c_int,
+                    start_keys.as_ptr(),
+                    start_key_lens.as_ptr(),
+                    limit_keys.as_ptr(),
+                    limit_key_lens.as_ptr(),
+                ))
+            } else {
+                ffi_try!(ffi::rocksdb_get_properties_of_tables_in_range(
+                    self.inner.inner(),
+                    bounds.len() as c_int,
+                    start_keys.as_ptr(),
+                    start_key_lens.as_ptr(),
+                    limit_keys.as_ptr(),
+                    limit_key_lens.as_ptr(),
+                ))
+            };
+            Ok(TableProperties::collection_from_c(collection))
+        }
+    }
+
     /// Delete sst files whose keys are entirely in the given range.
     ///
     /// Could leave some keys in the range which are in files which are not
@@ -2068,7 +2718,7 @@ impl<T: ThreadMode, D: DBInner> DBCommon<T, D> {
         let from = from.as_ref();
         let to = to.as_ref();
         unsafe {
-            ffi_try!(ffi::rocksdb_delete_file_in_range(
+            ffi_try!(ffi::rocksdb_delete_file_in_range_with_status(
                 self.inner.inner(),
                 from.as_ptr() as *const c_char,
                 from.len() as size_t,
@@ -2089,7 +2739,7 @@ impl<T: ThreadMode, D: DBInner> DBCommon<T, D> {
         let from = from.as_ref();
         let to = to.as_ref();
         unsafe {
-            ffi_try!(ffi::rocksdb_delete_file_in_range_cf(
+            ffi_try!(ffi::rocksdb_delete_file_in_range_cf_with_status(
                 self.inner.inner(),
                 cf.inner(),
                 from.as_ptr() as *const c_char,
@@ -2101,6 +2751,45 @@ impl<T: ThreadMode, D: DBInner> DBCommon<T, D> {
         }
     }
 
+    /// Closes the database, reporting errors that dropping it would ignore,
+    /// such as a failure to sync the WAL.
+    ///
+    /// Column family handles are released and background work is cancelled,
+    /// waiting for running flushes and compactions to finish, before the
+    /// database is closed. The database is closed even if an error is
+    /// returned.
+    pub fn close(mut self) -> Result<(), Error> {
+        self.cfs.drop_all_cfs_internal();
+        self.cancel_all_background_work(true);
+        self.inner.close()
+    }
+
+    /// Returns the error that made the database stop background work, and
+    /// possibly writes, such as a failed flush on a full disk.
+    ///
+    /// Its [`severity`](Error::severity) tells whether RocksDB will try to
+    /// recover by itself. Otherwise, once the cause is fixed, call `resume`.
+    pub fn background_error(&self) -> Option<Error> {
+        unsafe {
+            let status = ffi::rocksdb_get_background_error(self.inner.inner());
+            if status.is_null() {
+                None
+            } else {
+                Some(Error::from_status(status))
+            }
+        }
+    }
+
+    /// Tries to recover from a background error, flushing memtables that
+    /// could not be flushed and allowing writes again. Does nothing if there
+    /// is no background error.
+    pub fn resume(&self) -> Result<(), Error> {
+        unsafe {
+            ffi_try!(ffi::rocksdb_resume(self.inner.inner()));
+        }
+        Ok(())
+    }
+
     /// Request stopping background work, if wait is true wait until it's done.
     pub fn cancel_all_background_work(&self, wait: bool) {
         unsafe {
@@ -2108,6 +2797,78 @@ impl<T: ThreadMode, D: DBInner> DBCommon<T, D> {
         }
     }
 
+    /// Waits until all running background work has finished and stops
+    /// scheduling new flushes and compactions until `continue_background_work`
+    /// is called, once for every call to this method.
+    pub fn pause_background_work(&self) -> Result<(), Error> {
+        unsafe {
+            ffi_try!(ffi::rocksdb_pause_background_work(self.inner.inner()));
+        }
+        self.background_work_paused.fetch_add(1, Ordering::SeqCst);
+        Ok(())
+    }
+
+    /// Resumes background work stopped by `pause_background_work`.
+    pub fn continue_background_work(&self) -> Result<(), Error> {
+        unsafe {
+            ffi_try!(ffi::rocksdb_continue_background_work(self.inner.inner()));
+        }
+        self.background_work_paused.fetch_sub(1, Ordering::SeqCst);
+        Ok(())
+    }
+
+    /// Makes outstanding and future manual compactions (`compact_range*`,
+    /// `compact_files*`) abort, and waits for the outstanding ones to do so.
+    /// Must be matched by a call to `enable_manual_compaction`.
+    pub fn disable_manual_compaction(&self) {
+        unsafe {
+            ffi::rocksdb_disable_manual_compaction(self.inner.inner());
+        }
+    }
+
+    /// Re-enables manual compactions disabled by `disable_manual_compaction`.
+    pub fn enable_manual_compaction(&self) {
+        unsafe {
+            ffi::rocksdb_enable_manual_compaction(self.inner.inner());
+        }
+    }
+
+    /// Waits until there are no running flushes or compactions and no more
+    /// are needed.
+    ///
+    /// Compactions that are needed but will not be scheduled, because
+    /// `disable_auto_compactions` is set for their column family, are not
+    /// waited for. While background work is paused this waits until the
+    /// timeout expires, unless `abort_on_pause` is set.
+    pub fn wait_for_compact(&self, opts: &WaitForCompactOptions) -> Result<(), Error> {
+        let start = Instant::now();
+        if opts.flush {
+            unsafe {
+                ffi_try!(ffi::rocksdb_flush_all(self.inner.inner()));
+            }
+        }
+        loop {
+            if opts.abort_on_pause && self.background_work_paused.load(Ordering::SeqCst) > 0 {
+                return Err(Error::with_kind(
+                    ErrorKind::Aborted,
+                    "Operation aborted: background work is paused".to_owned(),
+                ));
+            }
+            if unsafe { ffi::rocksdb_has_pending_background_work(self.inner.inner()) } == 0 {
+                return Ok(());
+            }
+            if let Some(timeout) = opts.timeout {
+                if start.elapsed() >= timeout {
+                    return Err(Error::with_kind(
+                        ErrorKind::TimedOut,
+                        "Operation timed out: waiting for compactions".to_owned(),
+                    ));
+                }
+            }
+            thread::sleep(WAIT_FOR_COMPACT_POLL_INTERVAL);
+        }
+    }
+
     fn drop_column_family<C>(
         &self,
         cf_inner: *mut ffi::rocksdb_column_family_handle_t,
@@ -2115,7 +2876,7 @@ impl<T: ThreadMode, D: DBInner> DBCommon<T, D> {
     ) -> Result<(), Error> {
         unsafe {
             // first mark the column family as dropped
-            ffi_try!(ffi::rocksdb_drop_column_family(
+            ffi_try!(ffi::rocksdb_drop_column_family_with_status(
                 self.inner.inner(),
                 cf_inner
             ));
@@ -2218,6 +2979,63 @@ pub struct LiveFile {
     pub num_deletions: u64,
 }
 
+const WAIT_FOR_COMPACT_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Options for [`DBCommon::get_approximate_sizes_opt`] and
+/// [`DBCommon::get_approximate_sizes_cf_opt`]. At least one of
+/// `include_memtables` and `include_files` must be set.
+#[derive(Debug, Clone, Copy)]
+pub struct SizeApproximationOptions {
+    /// Count data in the memtables. Defaults to `false`.
+    pub include_memtables: bool,
+    /// Count data in SST files. Defaults to `true`.
+    pub include_files: bool,
+    /// Allow the SST file estimate to be off by up to this fraction of the
+    /// total files size, in exchange for a cheaper computation. A non-positive
+    /// value (the default) asks for a precise estimate.
+    pub files_size_error_margin: f64,
+}
+
+impl Default for SizeApproximationOptions {
+    fn default() -> Self {
+        Self {
+            include_memtables: false,
+            include_files: true,
+            files_size_error_margin: -1.0,
+        }
+    }
+}
+
+/// Approximate statistics of a key range in the memtables
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MemTableStats {
+    /// Number of entries
+    pub count: u64,
+    /// Total size of the entries in bytes
+    pub size: u64,
+}
+
+/// Converts an optional range bound into a key pointer and length, with a null
+/// pointer standing for an unbounded side.
+fn bound_ptr(bound: Option<&[u8]>) -> (*const c_char, size_t) {
+    bound.map_or((ptr::null(), 0), |key| {
+        (key.as_ptr() as *const c_char, key.len() as size_t)
+    })
+}
+
+fn convert_file_names<I, N>(files: I) -> Result<Vec<CString>, Error>
+where
+    I: IntoIterator<Item = N>,
+    N: AsRef<str>,
+{
+    files
+        .into_iter()
+        .map(|name| {
+            CString::new(name.as_ref()).map_err(|e| Error::new(format!("Invalid file name: `{e}`")))
+        })
+        .collect()
+}
+
 fn convert_options(opts: &[(&str, &str)]) -> Result<Vec<(CString, CString)>, Error> {
     opts.iter()
         .map(|(name, value)| {
@@ -2237,7 +3055,7 @@ fn convert_options(opts: &[(&str, &str)]) -> Result<Vec<(CString, CString)>, Err
 pub(crate) fn convert_values(
     values: Vec<*mut c_char>,
     values_sizes: Vec<usize>,
-    errors: Vec<*mut c_char>,
+    errors: Vec<*mut ffi::rocksdb_status_t>,
 ) -> Vec<Result<Option<Vec<u8>>, Error>> {
     values
         .into_iter()
@@ -2251,7 +3069,7 @@ pub(crate) fn convert_values(
                 }
                 Ok(value)
             } else {
-                Err(Error::new(crate::ffi_util::error_message(e)))
+                Err(unsafe { Error::from_status(e) })
             }
         })
         .collect()
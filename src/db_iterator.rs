@@ -14,7 +14,7 @@
 
 use crate::{
     db::{DBAccess, DB},
-    ffi, Error, ReadOptions, WriteBatch,
+    ffi, Error, ReadOptions, WideColumn, WriteBatch,
 };
 use libc::{c_char, c_uchar, size_t};
 use std::{marker::PhantomData, slice};
@@ -131,7 +131,7 @@ impl<'a, D: DBAccess> DBRawIteratorWithThreadMode<'a, D> {
     /// Performing a seek will discard the current status.
     pub fn status(&self) -> Result<(), Error> {
         unsafe {
-            ffi_try!(ffi::rocksdb_iter_get_error(self.inner.as_ptr()));
+            ffi_try!(ffi::rocksdb_iter_get_error_with_status(self.inner.as_ptr()));
         }
         Ok(())
     }
@@ -328,6 +328,35 @@ impl<'a, D: DBAccess> DBRawIteratorWithThreadMode<'a, D> {
         }
     }
 
+    /// Returns the wide columns of the current entry.
+    ///
+    /// For an entity written with `put_entity` these are its columns in name
+    /// order; a plain key-value reads back as a single column named
+    /// [`DEFAULT_WIDE_COLUMN_NAME`](crate::DEFAULT_WIDE_COLUMN_NAME).
+    pub fn columns(&self) -> Option<Vec<WideColumn>> {
+        if !self.valid() {
+            return None;
+        }
+        // Safety Note: See `key_impl`.
+        unsafe {
+            let iter = self.inner.as_ptr();
+            let count = ffi::rocksdb_iter_columns_count(iter);
+            let columns = (0..count)
+                .map(|i| {
+                    let mut name_len: size_t = 0;
+                    let name_ptr = ffi::rocksdb_iter_column_name(iter, i, &mut name_len);
+                    let mut value_len: size_t = 0;
+                    let value_ptr = ffi::rocksdb_iter_column_value(iter, i, &mut value_len);
+                    WideColumn::new(
+                        slice::from_raw_parts(name_ptr as *const c_uchar, name_len),
+                        slice::from_raw_parts(value_ptr as *const c_uchar, value_len),
+                    )
+                })
+                .collect();
+            Some(columns)
+        }
+    }
+
     /// Returns a slice of the current key; assumes the iterator is valid.
     fn key_impl(&self) -> &[u8] {
         // Safety Note: This is safe as all methods that may invalidate the buffer returned
@@ -533,7 +562,7 @@ impl DBWALIterator {
     /// called.
     pub fn status(&self) -> Result<(), Error> {
         unsafe {
-            ffi_try!(ffi::rocksdb_wal_iter_status(self.inner));
+            ffi_try!(ffi::rocksdb_wal_iter_status_with_status(self.inner));
         }
         Ok(())
     }
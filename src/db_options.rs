@@ -17,22 +17,29 @@ use std::path::Path;
 use std::ptr::{null_mut, NonNull};
 use std::slice;
 use std::sync::Arc;
+use std::time::Duration;
 
 use libc::{self, c_char, c_double, c_int, c_uchar, c_uint, c_void, size_t};
 
 use crate::{
-    compaction_filter::{self, CompactionFilterCallback, CompactionFilterFn},
+    compaction_filter::{
+        self, CompactionFilterCallback, CompactionFilterFn, CompactionFilterV2Callback,
+        CompactionFilterV2Fn,
+    },
     compaction_filter_factory::{self, CompactionFilterFactory},
     comparator::{self, ComparatorCallback, CompareFn},
     db::DBAccess,
     env::Env,
+    event_listener::{self, EventListener},
     ffi,
     ffi_util::{from_cstr, to_cpath, CStrLike},
-    merge_operator::{
-        self, full_merge_callback, partial_merge_callback, MergeFn, MergeOperatorCallback,
-    },
+    logger,
+    merge_operator::{self, MergeFn, MergeOperator, MergeOperatorCallback},
     slice_transform::SliceTransform,
-    ColumnFamilyDescriptor, Error, SnapshotWithThreadMode, WriteBufferManager,
+    statistics::Statistics,
+    table_properties::{self, TablePropertiesCollectorFactory},
+    ColumnFamilyDescriptor, Error, RateLimiter, SnapshotWithThreadMode, SstFileManager,
+    WriteBufferManager,
 };
 
 pub(crate) struct CacheWrapper {
@@ -108,6 +115,8 @@ impl Cache {
 pub(crate) struct OptionsMustOutliveDB {
     env: Option<Env>,
     row_cache: Option<Cache>,
+    rate_limiter: Option<RateLimiter>,
+    sst_file_manager: Option<SstFileManager>,
     block_based: Option<BlockBasedOptionsMustOutliveDB>,
 }
 
@@ -116,6 +125,8 @@ impl OptionsMustOutliveDB {
         Self {
             env: self.env.as_ref().map(Env::clone),
             row_cache: self.row_cache.as_ref().map(Cache::clone),
+            rate_limiter: self.rate_limiter.clone(),
+            sst_file_manager: self.sst_file_manager.clone(),
             block_based: self
                 .block_based
                 .as_ref()
@@ -281,6 +292,7 @@ unsafe impl Send for BlockBasedOptions {}
 unsafe impl Send for CuckooTableOptions {}
 unsafe impl Send for ReadOptions {}
 unsafe impl Send for IngestExternalFileOptions {}
+unsafe impl Send for CompactionOptions {}
 unsafe impl Send for CacheWrapper {}
 
 // Sync is similarly safe for many types because they do not expose interior mutability, and their
@@ -291,6 +303,7 @@ unsafe impl Sync for BlockBasedOptions {}
 unsafe impl Sync for CuckooTableOptions {}
 unsafe impl Sync for ReadOptions {}
 unsafe impl Sync for IngestExternalFileOptions {}
+unsafe impl Sync for CompactionOptions {}
 unsafe impl Sync for CacheWrapper {}
 
 impl Drop for Options {
@@ -737,6 +750,19 @@ pub enum LogLevel {
     Header,
 }
 
+impl LogLevel {
+    pub(crate) fn from_raw(raw: c_int) -> Self {
+        match raw {
+            0 => LogLevel::Debug,
+            1 => LogLevel::Info,
+            2 => LogLevel::Warn,
+            3 => LogLevel::Error,
+            4 => LogLevel::Fatal,
+            _ => LogLevel::Header,
+        }
+    }
+}
+
 impl Options {
     /// Constructs the DBOptions and ColumnFamilyDescriptors by loading the
     /// latest RocksDB options file stored in the specified rocksdb database.
@@ -752,7 +778,7 @@ impl Options {
         let mut column_family_names: *mut *mut c_char = null_mut();
         let mut column_family_options: *mut *mut ffi::rocksdb_options_t = null_mut();
         unsafe {
-            ffi_try!(ffi::rocksdb_load_latest_options(
+            ffi_try!(ffi::rocksdb_load_latest_options_with_status(
                 path.as_ptr(),
                 env.0.inner,
                 ignore_unknown_options,
@@ -1215,23 +1241,11 @@ impl Options {
         name: impl CStrLike,
         full_merge_fn: F,
     ) {
-        let cb = Box::new(MergeOperatorCallback {
+        self.set_merge_operator_trait(MergeOperatorCallback {
             name: name.into_c_string().unwrap(),
             full_merge_fn: full_merge_fn.clone(),
             partial_merge_fn: full_merge_fn,
         });
-
-        unsafe {
-            let mo = ffi::rocksdb_mergeoperator_create(
-                Box::into_raw(cb).cast::<c_void>(),
-                Some(merge_operator::destructor_callback::<F, F>),
-                Some(full_merge_callback::<F, F>),
-                Some(partial_merge_callback::<F, F>),
-                Some(merge_operator::delete_callback),
-                Some(merge_operator::name_callback::<F, F>),
-            );
-            ffi::rocksdb_options_set_merge_operator(self.inner, mo);
-        }
     }
 
     pub fn set_merge_operator<F: MergeFn, PF: MergeFn>(
@@ -1240,22 +1254,22 @@ impl Options {
         full_merge_fn: F,
         partial_merge_fn: PF,
     ) {
-        let cb = Box::new(MergeOperatorCallback {
+        self.set_merge_operator_trait(MergeOperatorCallback {
             name: name.into_c_string().unwrap(),
             full_merge_fn,
             partial_merge_fn,
         });
+    }
 
+    /// Sets the merge operator combining the operands written with `merge`,
+    /// see [`MergeOperator`].
+    pub fn set_merge_operator_trait<M>(&mut self, merge_operator: M)
+    where
+        M: MergeOperator + 'static,
+    {
         unsafe {
-            let mo = ffi::rocksdb_mergeoperator_create(
-                Box::into_raw(cb).cast::<c_void>(),
-                Some(merge_operator::destructor_callback::<F, PF>),
-                Some(full_merge_callback::<F, PF>),
-                Some(partial_merge_callback::<F, PF>),
-                Some(merge_operator::delete_callback),
-                Some(merge_operator::name_callback::<F, PF>),
-            );
-            ffi::rocksdb_options_set_merge_operator(self.inner, mo);
+            let mo = merge_operator::create_merge_operator(merge_operator);
+            ffi::rocksdb_options_set_merge_operator_v2(self.inner, mo);
         }
     }
 
@@ -1287,13 +1301,29 @@ impl Options {
         });
 
         unsafe {
-            let cf = ffi::rocksdb_compactionfilter_create(
-                Box::into_raw(cb).cast::<c_void>(),
-                Some(compaction_filter::destructor_callback::<CompactionFilterCallback<F>>),
-                Some(compaction_filter::filter_callback::<CompactionFilterCallback<F>>),
-                Some(compaction_filter::name_callback::<CompactionFilterCallback<F>>),
-            );
-            ffi::rocksdb_options_set_compaction_filter(self.inner, cf);
+            let cf = compaction_filter::create_compaction_filter(cb);
+            ffi::rocksdb_options_set_compaction_filter_v2(self.inner, cf);
+        }
+    }
+
+    /// Sets a compaction filter like [`set_compaction_filter`], that is also
+    /// called for merge operands and wide-column entities, see
+    /// [`CompactionFilter::filter_v2`].
+    ///
+    /// [`set_compaction_filter`]: Options::set_compaction_filter
+    /// [`CompactionFilter::filter_v2`]: crate::compaction_filter::CompactionFilter::filter_v2
+    pub fn set_compaction_filter_v2<F>(&mut self, name: impl CStrLike, filter_fn: F)
+    where
+        F: CompactionFilterV2Fn + Send + 'static,
+    {
+        let cb = Box::new(CompactionFilterV2Callback {
+            name: name.into_c_string().unwrap(),
+            filter_fn,
+        });
+
+        unsafe {
+            let cf = compaction_filter::create_compaction_filter(cb);
+            ffi::rocksdb_options_set_compaction_filter_v2(self.inner, cf);
         }
     }
 
@@ -1312,14 +1342,132 @@ impl Options {
         let factory = Box::new(factory);
 
         unsafe {
-            let cff = ffi::rocksdb_compactionfilterfactory_create(
+            let cff = ffi::rocksdb_compactionfilterfactory_create_v2(
                 Box::into_raw(factory).cast::<c_void>(),
                 Some(compaction_filter_factory::destructor_callback::<F>),
                 Some(compaction_filter_factory::create_compaction_filter_callback::<F>),
+                Some(compaction_filter_factory::should_filter_table_file_creation_callback::<F>),
                 Some(compaction_filter_factory::name_callback::<F>),
             );
 
-            ffi::rocksdb_options_set_compaction_filter_factory(self.inner, cff);
+            ffi::rocksdb_options_set_compaction_filter_factory_v2(self.inner, cff);
+        }
+    }
+
+    /// Adds a listener to be notified of flushes, compactions and other
+    /// events of the database. Listeners are called in the order they were
+    /// added, and are shared by clones of these options.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rocksdb::{event_listener::CompactionJobInfo, EventListener, Options};
+    ///
+    /// struct CompactionLogger;
+    ///
+    /// impl EventListener for CompactionLogger {
+    ///     fn on_compaction_completed(&self, info: CompactionJobInfo) {
+    ///         println!("compacted {} bytes", info.stats.total_input_bytes);
+    ///     }
+    /// }
+    ///
+    /// let mut opts = Options::default();
+    /// opts.add_event_listener(CompactionLogger);
+    /// ```
+    pub fn add_event_listener<L>(&mut self, listener: L)
+    where
+        L: EventListener + 'static,
+    {
+        let listener = Box::new(listener);
+
+        unsafe {
+            let el = ffi::rocksdb_eventlistener_create(
+                Box::into_raw(listener).cast::<c_void>(),
+                Some(event_listener::destructor_callback::<L>),
+                Some(event_listener::on_flush_begin_callback::<L>),
+                Some(event_listener::on_flush_completed_callback::<L>),
+                Some(event_listener::on_compaction_begin_callback::<L>),
+                Some(event_listener::on_compaction_completed_callback::<L>),
+                Some(event_listener::on_table_file_created_callback::<L>),
+                Some(event_listener::on_table_file_deleted_callback::<L>),
+                Some(event_listener::on_external_file_ingested_callback::<L>),
+                Some(event_listener::on_stall_conditions_changed_callback::<L>),
+                Some(event_listener::on_background_error_callback::<L>),
+            );
+
+            ffi::rocksdb_options_add_eventlistener(self.inner, el);
+        }
+    }
+
+    /// Adds a factory of collectors that store custom properties in each SST
+    /// file written, readable with
+    /// [`DBCommon::get_properties_of_all_tables_cf`](crate::DBCommon::get_properties_of_all_tables_cf).
+    /// Factories are called in the order they were added, and are shared by
+    /// clones of these options.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use std::ffi::CStr;
+    ///
+    /// use rocksdb::table_properties::{
+    ///     EntryType, TablePropertiesCollector, TablePropertiesCollectorContext,
+    ///     TablePropertiesCollectorFactory,
+    /// };
+    /// use rocksdb::Options;
+    ///
+    /// #[derive(Default)]
+    /// struct PutCounter(u64);
+    ///
+    /// impl TablePropertiesCollector for PutCounter {
+    ///     fn add_user_key(&mut self, _: &[u8], _: &[u8], entry_type: EntryType, _: u64, _: u64) {
+    ///         if entry_type == EntryType::Put {
+    ///             self.0 += 1;
+    ///         }
+    ///     }
+    ///
+    ///     fn finish(&mut self) -> BTreeMap<String, Vec<u8>> {
+    ///         BTreeMap::from([("puts".to_owned(), self.0.to_le_bytes().to_vec())])
+    ///     }
+    ///
+    ///     fn name(&self) -> &CStr {
+    ///         CStr::from_bytes_with_nul(b"PutCounter\0").unwrap()
+    ///     }
+    /// }
+    ///
+    /// struct PutCounterFactory;
+    ///
+    /// impl TablePropertiesCollectorFactory for PutCounterFactory {
+    ///     type Collector = PutCounter;
+    ///
+    ///     fn create(&self, _: TablePropertiesCollectorContext) -> PutCounter {
+    ///         PutCounter::default()
+    ///     }
+    ///
+    ///     fn name(&self) -> &CStr {
+    ///         CStr::from_bytes_with_nul(b"PutCounterFactory\0").unwrap()
+    ///     }
+    /// }
+    ///
+    /// let mut opts = Options::default();
+    /// opts.add_table_properties_collector_factory(PutCounterFactory);
+    /// ```
+    pub fn add_table_properties_collector_factory<F>(&mut self, factory: F)
+    where
+        F: TablePropertiesCollectorFactory + 'static,
+    {
+        let factory = Box::new(factory);
+
+        unsafe {
+            let tpcf = ffi::rocksdb_table_properties_collector_factory_create(
+                Box::into_raw(factory).cast::<c_void>(),
+                Some(table_properties::factory_destructor_callback::<F>),
+                Some(table_properties::create_collector_callback::<F>),
+                Some(table_properties::factory_name_callback::<F>),
+            );
+
+            ffi::rocksdb_options_add_table_properties_collector_factory(self.inner, tpcf);
         }
     }
 
@@ -1493,6 +1641,42 @@ impl Options {
         }
     }
 
+    /// Sends the info LOG to `logger` instead of a file, for the messages at
+    /// or above `level`. The logger is called by foreground and background
+    /// threads, possibly concurrently, and is shared by clones of these
+    /// options. `set_log_level` and `set_db_log_dir` have no effect on it.
+    ///
+    /// With the `log` or `tracing` feature enabled, pass `logger::log_crate`
+    /// or `logger::tracing_crate` to forward the messages to those crates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rocksdb::{LogLevel, Options};
+    ///
+    /// let mut opts = Options::default();
+    /// opts.set_logger(LogLevel::Warn, |level, message| {
+    ///     eprintln!("[{:?}] {}", level, message);
+    /// });
+    /// ```
+    pub fn set_logger<F>(&mut self, level: LogLevel, logger: F)
+    where
+        F: Fn(LogLevel, &str) + Send + Sync + 'static,
+    {
+        let logger = Box::new(logger);
+
+        unsafe {
+            let raw = ffi::rocksdb_logger_create_callback_logger(
+                level as c_int,
+                Box::into_raw(logger).cast::<c_void>(),
+                Some(logger::destructor_callback::<F>),
+                Some(logger::log_callback::<F>),
+            );
+            ffi::rocksdb_options_set_info_log(self.inner, raw);
+            ffi::rocksdb_logger_destroy(raw);
+        }
+    }
+
     /// Allows OS to incrementally sync files to disk while they are being
     /// written, asynchronously, in the background. This operation can be used
     /// to smooth out write I/Os over time. Users shouldn't rely on it for
@@ -2534,6 +2718,17 @@ impl Options {
         }
     }
 
+    /// Collects statistics into `statistics`, replacing the ones created by
+    /// `enable_statistics`. The same `Statistics` can be set on the options
+    /// of several databases to aggregate their statistics.
+    pub fn set_statistics(&mut self, statistics: &Statistics) {
+        // Safety: `Statistics` is guaranteed to point to a `shared_ptr` to the
+        // underlying cpp `Statistics`.
+        unsafe {
+            ffi::rocksdb_options_set_statistics(self.inner, statistics.0.inner.as_ptr());
+        }
+    }
+
     /// If not zero, dump `rocksdb.stats` to LOG every `stats_dump_period_sec`.
     ///
     /// Default: `600` (10 mins)
@@ -2723,6 +2918,16 @@ impl Options {
         }
     }
 
+    /// If true, the threads of the database report the operation they are
+    /// running, which [`Env::get_thread_list`] returns, at a small cost.
+    ///
+    /// Default: false
+    pub fn set_enable_thread_tracking(&mut self, enabled: bool) {
+        unsafe {
+            ffi::rocksdb_options_set_enable_thread_tracking(self.inner, c_uchar::from(enabled));
+        }
+    }
+
     /// Specify the maximal number of info log files to be kept.
     ///
     /// Default: 1000
@@ -2860,6 +3065,43 @@ impl Options {
         }
     }
 
+    /// Sets a rate limiter that can be shared with other databases and
+    /// reconfigured while they run. Replaces a limiter set with
+    /// `set_ratelimiter`.
+    ///
+    /// Default: disable
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rocksdb::{Options, RateLimiter};
+    ///
+    /// let limiter = RateLimiter::new_auto_tuned(64 << 20, 100 * 1000, 10);
+    /// let mut options = Options::default();
+    /// options.set_rate_limiter(&limiter);
+    /// ```
+    pub fn set_rate_limiter(&mut self, rate_limiter: &RateLimiter) {
+        unsafe {
+            ffi::rocksdb_options_set_ratelimiter(self.inner, rate_limiter.0.inner.as_ptr());
+        }
+        self.outlive.rate_limiter = Some(rate_limiter.clone());
+    }
+
+    /// Sets the manager that tracks the SST and blob files of the database.
+    /// The same `SstFileManager` can be passed to multiple DBs to limit the
+    /// space they use together and the rate at which they delete files.
+    ///
+    /// Default: disable
+    pub fn set_sst_file_manager(&mut self, sst_file_manager: &SstFileManager) {
+        unsafe {
+            ffi::rocksdb_options_set_sst_file_manager(
+                self.inner,
+                sst_file_manager.0.inner.as_ptr(),
+            );
+        }
+        self.outlive.sst_file_manager = Some(sst_file_manager.clone());
+    }
+
     /// Sets the maximal size of the info log file.
     ///
     /// If the log file is larger than `max_log_file_size`, a new info log file
@@ -3905,6 +4147,101 @@ impl CompactOptions {
     }
 }
 
+/// Options for [`compact_files`](crate::DBCommon::compact_files) and
+/// [`compact_files_cf`](crate::DBCommon::compact_files_cf).
+pub struct CompactionOptions {
+    pub(crate) inner: *mut ffi::rocksdb_compactionoptions_t,
+}
+
+impl Default for CompactionOptions {
+    fn default() -> Self {
+        let opts = unsafe { ffi::rocksdb_compactionoptions_create() };
+        assert!(
+            !opts.is_null(),
+            "Could not create RocksDB Compaction Options"
+        );
+
+        Self { inner: opts }
+    }
+}
+
+impl Drop for CompactionOptions {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::rocksdb_compactionoptions_destroy(self.inner);
+        }
+    }
+}
+
+impl CompactionOptions {
+    /// Sets the compression of the output files.
+    ///
+    /// Default: the compression configured for the output level of the
+    /// column family
+    pub fn set_compression(&mut self, t: DBCompressionType) {
+        unsafe {
+            ffi::rocksdb_compactionoptions_set_compression(self.inner, t as c_int);
+        }
+    }
+
+    /// Compaction will create files of at most this size.
+    ///
+    /// Default: `u64::MAX`, which means that compaction will create a single file
+    pub fn set_output_file_size_limit(&mut self, limit: u64) {
+        unsafe {
+            ffi::rocksdb_compactionoptions_set_output_file_size_limit(self.inner, limit);
+        }
+    }
+
+    /// If > 0, overrides `max_subcompactions` of the DB options for this
+    /// compaction.
+    ///
+    /// Default: 0
+    pub fn set_max_subcompactions(&mut self, max: u32) {
+        unsafe {
+            ffi::rocksdb_compactionoptions_set_max_subcompactions(self.inner, max);
+        }
+    }
+}
+
+/// Options for [`wait_for_compact`](crate::DBCommon::wait_for_compact).
+#[derive(Debug, Clone, Default)]
+pub struct WaitForCompactOptions {
+    pub(crate) abort_on_pause: bool,
+    pub(crate) flush: bool,
+    pub(crate) timeout: Option<Duration>,
+}
+
+impl WaitForCompactOptions {
+    /// If true, return an `Aborted` error as soon as background work is
+    /// paused with `pause_background_work`, instead of waiting for it to be
+    /// continued.
+    ///
+    /// Default: false
+    pub fn set_abort_on_pause(&mut self, v: bool) {
+        self.abort_on_pause = v;
+    }
+
+    /// If true, flush all column families before starting to wait.
+    ///
+    /// Default: false
+    pub fn set_flush(&mut self, v: bool) {
+        self.flush = v;
+    }
+
+    /// Return a `TimedOut` error if the background work has not finished
+    /// within `timeout`. A zero duration waits indefinitely.
+    ///
+    /// Default: no timeout
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.timeout = if timeout.is_zero() {
+            None
+        } else {
+            Some(timeout)
+        };
+    }
+}
+
 /// Represents a path where sst files can be put into
 pub struct DBPath {
     pub(crate) inner: *mut ffi::rocksdb_dbpath_t,
@@ -0,0 +1,173 @@
+//! `EncryptionProvider` encrypts the files of an [`Env`](crate::Env) at rest,
+//! with a `BlockCipher` implemented in Rust.
+use std::ptr::NonNull;
+use std::slice;
+
+use libc::{c_char, c_void};
+
+use crate::{ffi, Error, ErrorKind};
+
+/// Size of the header at the start of each encrypted file.
+const HEADER_SIZE: usize = 4096;
+
+/// A block cipher, such as AES, that [`EncryptionProvider::new_ctr`] uses in
+/// CTR mode. Only [`encrypt`](BlockCipher::encrypt) is needed for CTR mode,
+/// both to encrypt and to decrypt files.
+///
+/// Methods are called concurrently and cannot fail.
+pub trait BlockCipher: Send + Sync {
+    /// Returns the size of a block, in bytes, e.g. 16 for AES. It must be at
+    /// least 8 bytes, and at most half of the 4KiB header of each file. It is
+    /// read once, by [`EncryptionProvider::new_ctr`].
+    fn block_size(&self) -> usize;
+
+    /// Encrypts one block in place.
+    fn encrypt(&self, block: &mut [u8]);
+
+    /// Decrypts one block in place.
+    fn decrypt(&self, block: &mut [u8]);
+}
+
+/// Encrypts and decrypts the files of an env, see
+/// [`Env::encrypted`](crate::Env::encrypted). Data is encrypted in CTR mode.
+/// Each file starts with a 4KiB header whose first two blocks hold the random
+/// initial counter and initialization vector in plaintext. Only the rest of
+/// the header and the data after it are encrypted.
+///
+/// # Examples
+///
+/// ```
+/// use rocksdb::{BlockCipher, EncryptionProvider, Env, Options, DB};
+///
+/// // not a real cipher, use e.g. AES from the `aes` crate
+/// struct XorCipher(u8);
+///
+/// impl BlockCipher for XorCipher {
+///     fn block_size(&self) -> usize {
+///         16
+///     }
+///
+///     fn encrypt(&self, block: &mut [u8]) {
+///         block.iter_mut().for_each(|b| *b ^= self.0);
+///     }
+///
+///     fn decrypt(&self, block: &mut [u8]) {
+///         block.iter_mut().for_each(|b| *b ^= self.0);
+///     }
+/// }
+///
+/// let provider = EncryptionProvider::new_ctr(XorCipher(0x5a)).unwrap();
+/// let env = Env::encrypted(&Env::new().unwrap(), provider).unwrap();
+/// let mut opts = Options::default();
+/// opts.create_if_missing(true);
+/// opts.set_env(&env);
+/// # let path = "_rust_rocksdb_encryption_doc";
+/// let db = DB::open(&opts, path).unwrap();
+/// # drop(db);
+/// # DB::destroy(&opts, path).unwrap();
+/// ```
+pub struct EncryptionProvider {
+    pub(crate) inner: NonNull<ffi::rocksdb_encryption_provider_t>,
+}
+
+// The cpp provider only reads its cipher, which is `Send + Sync`.
+unsafe impl Send for EncryptionProvider {}
+unsafe impl Sync for EncryptionProvider {}
+
+impl Drop for EncryptionProvider {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::rocksdb_encryption_provider_destroy(self.inner.as_ptr());
+        }
+    }
+}
+
+impl EncryptionProvider {
+    /// Creates a new `EncryptionProvider` that encrypts files with `cipher`
+    /// in CTR mode.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `InvalidArgument` error if the block size of `cipher` is
+    /// less than 8 bytes or more than half of the 4KiB header.
+    pub fn new_ctr<C>(cipher: C) -> Result<EncryptionProvider, Error>
+    where
+        C: BlockCipher + 'static,
+    {
+        // The size is read once, the callbacks must not trust later calls to
+        // `block_size` to build their slices.
+        let block_size = check_block_size(cipher.block_size())?;
+        let state = CipherState { cipher, block_size };
+        Ok(Self::from_raw(unsafe {
+            ffi::rocksdb_encryption_provider_create_ctr(
+                Box::into_raw(Box::new(state)) as *mut c_void,
+                Some(destructor_callback::<C>),
+                block_size,
+                Some(encrypt_callback::<C>),
+                Some(decrypt_callback::<C>),
+            )
+        }))
+    }
+
+    /// Creates a new `EncryptionProvider` that "encrypts" files with ROT13 in
+    /// CTR mode. Only meant for tests, it does not protect anything.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `InvalidArgument` error if `block_size` is less than 8 bytes
+    /// or more than half of the 4KiB header.
+    pub fn new_ctr_rot13(block_size: usize) -> Result<EncryptionProvider, Error> {
+        let block_size = check_block_size(block_size)?;
+        Ok(Self::from_raw(unsafe {
+            ffi::rocksdb_encryption_provider_create_ctr_rot13(block_size)
+        }))
+    }
+
+    fn from_raw(ptr: *mut ffi::rocksdb_encryption_provider_t) -> EncryptionProvider {
+        // Safety: the `rocksdb_encryption_provider_create*` functions are
+        // guaranteed to create a non-null and valid pointer.
+        EncryptionProvider {
+            inner: NonNull::new(ptr).unwrap(),
+        }
+    }
+}
+
+/// RocksDB reads the 8 byte initial counter from the first block, and stores
+/// the first two blocks in the header.
+fn check_block_size(block_size: usize) -> Result<usize, Error> {
+    if (8..=HEADER_SIZE / 2).contains(&block_size) {
+        Ok(block_size)
+    } else {
+        Err(Error::with_kind(
+            ErrorKind::InvalidArgument,
+            format!(
+                "Invalid argument: block size must be between 8 and {} bytes, got {}",
+                HEADER_SIZE / 2,
+                block_size
+            ),
+        ))
+    }
+}
+
+struct CipherState<C> {
+    cipher: C,
+    block_size: usize,
+}
+
+unsafe extern "C" fn destructor_callback<C: BlockCipher>(raw: *mut c_void) {
+    drop(Box::from_raw(raw as *mut CipherState<C>));
+}
+
+unsafe extern "C" fn encrypt_callback<C: BlockCipher>(raw: *mut c_void, data: *mut c_char) {
+    let state = &*(raw as *mut CipherState<C>);
+    state
+        .cipher
+        .encrypt(slice::from_raw_parts_mut(data as *mut u8, state.block_size));
+}
+
+unsafe extern "C" fn decrypt_callback<C: BlockCipher>(raw: *mut c_void, data: *mut c_char) {
+    let state = &*(raw as *mut CipherState<C>);
+    state
+        .cipher
+        .decrypt(slice::from_raw_parts_mut(data as *mut u8, state.block_size));
+}
@@ -1,8 +1,19 @@
-use std::sync::Arc;
+use std::collections::HashMap;
+use std::fs::OpenOptions;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::ptr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
 
 use libc::{self, c_int};
 
-use crate::{ffi, Error};
+use crate::clock::{self, Clock, MockClock};
+use crate::file_system::{
+    self, DefaultFileSystem, FileSystem, RandomAccessFile, SequentialFile, WritableFile,
+};
+use crate::thread_status::ThreadStatus;
+use crate::{ffi, EncryptionProvider, Error, ErrorKind, ErrorSubCode};
 
 /// An Env is an interface used by the rocksdb implementation to access
 /// operating system functionality like the filesystem etc. Callers
@@ -19,6 +30,8 @@ pub struct Env(pub(crate) Arc<EnvWrapper>);
 
 pub(crate) struct EnvWrapper {
     pub(crate) inner: *mut ffi::rocksdb_env_t,
+    // The env `inner` delegates to, if it keeps a raw pointer to it.
+    _base: Option<Env>,
 }
 
 impl Drop for EnvWrapper {
@@ -36,7 +49,10 @@ impl Env {
         if env.is_null() {
             Err(Error::new("Could not create mem env".to_owned()))
         } else {
-            Ok(Self(Arc::new(EnvWrapper { inner: env })))
+            Ok(Self(Arc::new(EnvWrapper {
+                inner: env,
+                _base: None,
+            })))
         }
     }
 
@@ -47,7 +63,82 @@ impl Env {
         if env.is_null() {
             Err(Error::new("Could not create mem env".to_owned()))
         } else {
-            Ok(Self(Arc::new(EnvWrapper { inner: env })))
+            Ok(Self(Arc::new(EnvWrapper {
+                inner: env,
+                _base: None,
+            })))
+        }
+    }
+
+    /// Returns a new environment that accesses files through `fs` and
+    /// delegates everything else, e.g. background threads, to the default
+    /// env. See [`file_system`](crate::file_system) for an example.
+    pub fn from_file_system<F>(fs: F) -> Result<Self, Error>
+    where
+        F: file_system::FileSystem + 'static,
+    {
+        let env = unsafe {
+            let fs = file_system::create_file_system(fs);
+            let env = ffi::rocksdb_create_env_from_file_system(fs);
+            // The env holds its own reference to the file system.
+            ffi::rocksdb_file_system_destroy(fs);
+            env
+        };
+        if env.is_null() {
+            Err(Error::new("Could not create file system env".to_owned()))
+        } else {
+            Ok(Self(Arc::new(EnvWrapper {
+                inner: env,
+                _base: None,
+            })))
+        }
+    }
+
+    /// Returns a new environment that reads the time from `clock` and
+    /// delegates everything else to the default env.
+    pub fn with_clock<C>(clock: C) -> Result<Self, Error>
+    where
+        C: Clock + 'static,
+    {
+        let env = unsafe {
+            let clock = clock::create_clock(clock);
+            let env = ffi::rocksdb_create_env_with_clock(ptr::null_mut(), clock);
+            // The env holds its own reference to the clock.
+            ffi::rocksdb_system_clock_destroy(clock);
+            env
+        };
+        if env.is_null() {
+            Err(Error::new("Could not create clock env".to_owned()))
+        } else {
+            Ok(Self(Arc::new(EnvWrapper {
+                inner: env,
+                _base: None,
+            })))
+        }
+    }
+
+    /// Returns a new environment whose clock starts at the current time and
+    /// only moves when the returned [`MockClock`] is advanced.
+    pub fn with_mock_clock() -> Result<(Self, MockClock), Error> {
+        let clock = MockClock::default();
+        Ok((Self::with_clock(clock.clone())?, clock))
+    }
+
+    /// Returns a new environment that encrypts the files it writes through
+    /// `base` with `provider`, and decrypts the files it reads. This covers
+    /// every file of a database, e.g. SST files, WAL files and the MANIFEST,
+    /// except for the info LOG. Backups and checkpoints made through the env
+    /// are encrypted too.
+    pub fn encrypted(base: &Env, provider: EncryptionProvider) -> Result<Self, Error> {
+        let env =
+            unsafe { ffi::rocksdb_create_encrypted_env(base.0.inner, provider.inner.as_ptr()) };
+        if env.is_null() {
+            Err(Error::new("Could not create encrypted env".to_owned()))
+        } else {
+            Ok(Self(Arc::new(EnvWrapper {
+                inner: env,
+                _base: Some(base.clone()),
+            })))
         }
     }
 
@@ -119,7 +210,437 @@ impl Env {
             ffi::rocksdb_env_lower_high_priority_thread_pool_cpu_priority(self.0.inner);
         }
     }
+
+    /// Returns the number of jobs scheduled on the thread pool of `priority`
+    /// that have not started running yet.
+    pub fn thread_pool_queue_len(&self, priority: Priority) -> u32 {
+        unsafe { ffi::rocksdb_env_get_thread_pool_queue_len(self.0.inner, priority as c_int) }
+    }
+
+    /// Returns the number of threads of the thread pool of `priority`.
+    pub fn background_threads(&self, priority: Priority) -> i32 {
+        unsafe {
+            ffi::rocksdb_env_get_background_threads_with_priority(self.0.inner, priority as c_int)
+        }
+    }
+
+    /// Returns the status of the threads of all the databases using this env,
+    /// i.e. of the threads of its thread pools. Only the threads of databases
+    /// opened with [`Options::set_enable_thread_tracking`] report what they
+    /// are doing.
+    ///
+    /// [`Options::set_enable_thread_tracking`]: crate::Options::set_enable_thread_tracking
+    pub fn get_thread_list(&self) -> Result<Vec<ThreadStatus>, Error> {
+        unsafe {
+            let list = ffi_try!(ffi::rocksdb_env_get_thread_list(self.0.inner));
+            Ok(ThreadStatus::list_from_c(list))
+        }
+    }
 }
 
 unsafe impl Send for EnvWrapper {}
 unsafe impl Sync for EnvWrapper {}
+
+/// The thread pools of an [`Env`]. Flushes run in the `High` pool and
+/// compactions in the `Low` pool, or in the `Bottom` pool for compactions to
+/// the bottommost level if it has threads.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Priority {
+    Bottom = 0,
+    Low = 1,
+    High = 2,
+    User = 3,
+}
+
+/// When the reads or writes of a [`FaultInjectionEnv`] fail.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FaultInjection {
+    /// Operations never fail.
+    Never,
+    /// Each operation fails with the given probability, between 0 and 1.
+    /// The failures are pseudo-random but the same on every run.
+    WithProbability(f64),
+    /// The first `n` operations succeed and all following ones fail.
+    AfterOperations(u64),
+}
+
+/// An env for testing how an application copes with IO errors and crashes.
+/// It stores files in the default file system, fails reads and writes as
+/// configured and tracks the data written since the last sync of each file,
+/// so that a crash can be simulated by dropping it.
+///
+/// Reads are the reads of sequential and random access files, writes are the
+/// appends to and syncs of writable files. Injected errors are reported as
+/// IO errors.
+///
+/// # Examples
+///
+/// ```
+/// use rocksdb::{FaultInjectionEnv, Options, WriteOptions, DB};
+///
+/// # let path = "_rust_rocksdb_fault_injection_env_doc";
+/// let fault_env = FaultInjectionEnv::new().unwrap();
+/// let mut opts = Options::default();
+/// opts.create_if_missing(true);
+/// opts.set_env(fault_env.env());
+/// {
+///     let db = DB::open(&opts, path).unwrap();
+///     let mut sync = WriteOptions::default();
+///     sync.set_sync(true);
+///     db.put_opt(b"synced", b"1", &sync).unwrap();
+///     db.put(b"unsynced", b"1").unwrap();
+///
+///     // crash: nothing reaches the files anymore, not even on close
+///     fault_env.set_active(false);
+/// }
+/// fault_env.drop_unsynced_data().unwrap();
+/// fault_env.reset();
+///
+/// let db = DB::open(&opts, path).unwrap();
+/// assert!(db.get(b"synced").unwrap().is_some());
+/// assert!(db.get(b"unsynced").unwrap().is_none());
+/// # drop(db);
+/// # DB::destroy(&Options::default(), path).unwrap();
+/// ```
+#[derive(Clone)]
+pub struct FaultInjectionEnv {
+    env: Env,
+    state: Arc<FaultState>,
+}
+
+impl FaultInjectionEnv {
+    /// Creates a new `FaultInjectionEnv` that does not fail any operation.
+    pub fn new() -> Result<Self, Error> {
+        let state = Arc::new(FaultState {
+            active: AtomicBool::new(true),
+            no_space: AtomicBool::new(false),
+            reads: Mutex::new(FaultCounter::new()),
+            writes: Mutex::new(FaultCounter::new()),
+            files: Mutex::new(HashMap::new()),
+        });
+        let env = Env::from_file_system(FaultFileSystem(state.clone()))?;
+        Ok(FaultInjectionEnv { env, state })
+    }
+
+    /// Returns the env to open databases with, see
+    /// [`Options::set_env`](crate::Options::set_env).
+    pub fn env(&self) -> &Env {
+        &self.env
+    }
+
+    /// Sets when reads fail, counting operations from now on.
+    pub fn set_read_faults(&self, faults: FaultInjection) {
+        self.state.reads.lock().unwrap().reset(faults);
+    }
+
+    /// Sets when writes fail, counting operations from now on.
+    pub fn set_write_faults(&self, faults: FaultInjection) {
+        self.state.writes.lock().unwrap().reset(faults);
+    }
+
+    /// Makes failed writes report that the device is out of space instead of
+    /// a plain IO error. RocksDB can [`resume`](crate::DBCommon::resume) after
+    /// a flush failed this way, while other IO errors during a flush are fatal.
+    pub fn set_no_space(&self, no_space: bool) {
+        self.state.no_space.store(no_space, Ordering::SeqCst);
+    }
+
+    /// Deactivates or reactivates the file system. While it is inactive,
+    /// every operation changing files fails, as if the process had crashed.
+    pub fn set_active(&self, active: bool) {
+        self.state.active.store(active, Ordering::SeqCst);
+    }
+
+    /// Returns whether the file system is active.
+    pub fn is_active(&self) -> bool {
+        self.state.active.load(Ordering::SeqCst)
+    }
+
+    /// Returns the files that are open for writing, sorted.
+    pub fn active_files(&self) -> Vec<PathBuf> {
+        self.state.paths(|file| file.open)
+    }
+
+    /// Returns the files holding data written since their last sync, sorted.
+    pub fn unsynced_files(&self) -> Vec<PathBuf> {
+        self.state.paths(|file| file.size > file.synced_size)
+    }
+
+    /// Truncates every file to its size at its last sync, which is what a
+    /// crash of the machine leaves on disk. Files never synced become empty.
+    ///
+    /// Close the databases using the env, after deactivating it with
+    /// [`set_active`](FaultInjectionEnv::set_active), before calling this.
+    pub fn drop_unsynced_data(&self) -> Result<(), Error> {
+        let mut files = self.state.files.lock().unwrap();
+        for (path, file) in files.iter_mut() {
+            if file.size > file.synced_size {
+                OpenOptions::new()
+                    .write(true)
+                    .open(path)
+                    .and_then(|f| f.set_len(file.synced_size))
+                    .map_err(|e| {
+                        Error::with_kind(
+                            ErrorKind::IOError,
+                            format!("IO error: {}: {e}", path.to_string_lossy()),
+                        )
+                    })?;
+                file.size = file.synced_size;
+            }
+        }
+        Ok(())
+    }
+
+    /// Forgets the tracked files, stops failing operations and reactivates
+    /// the file system, e.g. to reopen the databases after a simulated crash.
+    pub fn reset(&self) {
+        self.state.files.lock().unwrap().clear();
+        self.set_read_faults(FaultInjection::Never);
+        self.set_write_faults(FaultInjection::Never);
+        self.set_no_space(false);
+        self.set_active(true);
+    }
+}
+
+struct FaultState {
+    active: AtomicBool,
+    no_space: AtomicBool,
+    reads: Mutex<FaultCounter>,
+    writes: Mutex<FaultCounter>,
+    files: Mutex<HashMap<PathBuf, FileState>>,
+}
+
+impl FaultState {
+    fn check_active(&self) -> io::Result<()> {
+        if self.active.load(Ordering::SeqCst) {
+            Ok(())
+        } else {
+            Err(io::Error::new(
+                io::ErrorKind::Other,
+                "file system not active",
+            ))
+        }
+    }
+
+    fn check_read(&self) -> io::Result<()> {
+        self.reads.lock().unwrap().check("read")
+    }
+
+    fn check_write(&self) -> io::Result<()> {
+        self.check_active()?;
+        let result = self.writes.lock().unwrap().check("write");
+        if result.is_err() && self.no_space.load(Ordering::SeqCst) {
+            let error = Error {
+                subcode: ErrorSubCode::NoSpace,
+                ..Error::with_kind(
+                    ErrorKind::IOError,
+                    "IO error: No space left on device: injected write error".to_owned(),
+                )
+            };
+            return Err(io::Error::new(io::ErrorKind::Other, error));
+        }
+        result
+    }
+
+    fn update_file(&self, path: &Path, f: impl FnOnce(&mut FileState)) {
+        if let Some(file) = self.files.lock().unwrap().get_mut(path) {
+            f(file);
+        }
+    }
+
+    fn paths(&self, filter: impl Fn(&FileState) -> bool) -> Vec<PathBuf> {
+        let files = self.files.lock().unwrap();
+        let mut paths: Vec<PathBuf> = files
+            .iter()
+            .filter(|(_, file)| filter(file))
+            .map(|(path, _)| path.clone())
+            .collect();
+        paths.sort();
+        paths
+    }
+}
+
+#[derive(Default)]
+struct FileState {
+    open: bool,
+    size: u64,
+    synced_size: u64,
+}
+
+struct FaultCounter {
+    faults: FaultInjection,
+    operations: u64,
+    random: u64,
+}
+
+impl FaultCounter {
+    fn new() -> Self {
+        FaultCounter {
+            faults: FaultInjection::Never,
+            operations: 0,
+            random: 0,
+        }
+    }
+
+    fn reset(&mut self, faults: FaultInjection) {
+        *self = FaultCounter::new();
+        self.faults = faults;
+    }
+
+    fn check(&mut self, operation: &str) -> io::Result<()> {
+        self.operations += 1;
+        let fail = match self.faults {
+            FaultInjection::Never => false,
+            FaultInjection::WithProbability(probability) => self.next_random() < probability,
+            FaultInjection::AfterOperations(n) => self.operations > n,
+        };
+        if fail {
+            Err(io::Error::new(
+                io::ErrorKind::Other,
+                format!("injected {operation} error"),
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Returns a number in `[0, 1)`, using SplitMix64.
+    #[allow(clippy::cast_precision_loss)]
+    fn next_random(&mut self) -> f64 {
+        self.random = self.random.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.random;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^= z >> 31;
+        (z >> 11) as f64 / (1_u64 << 53) as f64
+    }
+}
+
+struct FaultFileSystem(Arc<FaultState>);
+
+impl FileSystem for FaultFileSystem {
+    fn new_sequential_file(&self, path: &Path) -> io::Result<Box<dyn SequentialFile>> {
+        let file = DefaultFileSystem.new_sequential_file(path)?;
+        Ok(Box::new(FaultSequentialFile(file, self.0.clone())))
+    }
+
+    fn new_random_access_file(&self, path: &Path) -> io::Result<Box<dyn RandomAccessFile>> {
+        let file = DefaultFileSystem.new_random_access_file(path)?;
+        Ok(Box::new(FaultRandomAccessFile(file, self.0.clone())))
+    }
+
+    fn new_writable_file(&self, path: &Path) -> io::Result<Box<dyn WritableFile>> {
+        self.0.check_active()?;
+        let file = DefaultFileSystem.new_writable_file(path)?;
+        let state = FileState {
+            open: true,
+            ..FileState::default()
+        };
+        self.0.files.lock().unwrap().insert(path.to_owned(), state);
+        Ok(Box::new(FaultWritableFile {
+            inner: file,
+            path: path.to_owned(),
+            state: self.0.clone(),
+        }))
+    }
+
+    fn delete_file(&self, path: &Path) -> io::Result<()> {
+        self.0.check_active()?;
+        DefaultFileSystem.delete_file(path)?;
+        self.0.files.lock().unwrap().remove(path);
+        Ok(())
+    }
+
+    fn create_dir(&self, path: &Path) -> io::Result<()> {
+        self.0.check_active()?;
+        DefaultFileSystem.create_dir(path)
+    }
+
+    fn create_dir_if_missing(&self, path: &Path) -> io::Result<()> {
+        self.0.check_active()?;
+        DefaultFileSystem.create_dir_if_missing(path)
+    }
+
+    fn delete_dir(&self, path: &Path) -> io::Result<()> {
+        self.0.check_active()?;
+        DefaultFileSystem.delete_dir(path)
+    }
+
+    fn sync_dir(&self, path: &Path) -> io::Result<()> {
+        self.0.check_active()?;
+        DefaultFileSystem.sync_dir(path)
+    }
+
+    fn rename_file(&self, src: &Path, target: &Path) -> io::Result<()> {
+        self.0.check_active()?;
+        DefaultFileSystem.rename_file(src, target)?;
+        let mut files = self.0.files.lock().unwrap();
+        files.remove(target);
+        if let Some(file) = files.remove(src) {
+            files.insert(target.to_owned(), file);
+        }
+        Ok(())
+    }
+}
+
+struct FaultSequentialFile(Box<dyn SequentialFile>, Arc<FaultState>);
+
+impl SequentialFile for FaultSequentialFile {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.1.check_read()?;
+        self.0.read(buf)
+    }
+
+    fn skip(&mut self, n: u64) -> io::Result<()> {
+        self.1.check_read()?;
+        self.0.skip(n)
+    }
+}
+
+struct FaultRandomAccessFile(Box<dyn RandomAccessFile>, Arc<FaultState>);
+
+impl RandomAccessFile for FaultRandomAccessFile {
+    fn read_at(&self, offset: u64, buf: &mut [u8]) -> io::Result<usize> {
+        self.1.check_read()?;
+        self.0.read_at(offset, buf)
+    }
+}
+
+struct FaultWritableFile {
+    inner: Box<dyn WritableFile>,
+    path: PathBuf,
+    state: Arc<FaultState>,
+}
+
+impl WritableFile for FaultWritableFile {
+    fn append(&mut self, data: &[u8]) -> io::Result<()> {
+        self.state.check_write()?;
+        self.inner.append(data)?;
+        self.state
+            .update_file(&self.path, |file| file.size += data.len() as u64);
+        Ok(())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.state.check_active()?;
+        self.inner.flush()
+    }
+
+    fn sync(&mut self) -> io::Result<()> {
+        self.state.check_write()?;
+        self.inner.sync()?;
+        self.state
+            .update_file(&self.path, |file| file.synced_size = file.size);
+        Ok(())
+    }
+
+    fn close(&mut self) -> io::Result<()> {
+        self.state.update_file(&self.path, |file| file.open = false);
+        self.inner.close()?;
+        self.state.check_active()
+    }
+
+    fn file_size(&self) -> u64 {
+        self.inner.file_size()
+    }
+}
@@ -0,0 +1,632 @@
+//! Notifications about flushes, compactions, file creation and deletion,
+//! ingestion, write stalls and background errors.
+//!
+//! Implement [`EventListener`] and register it with
+//! [`Options::add_event_listener`](crate::Options::add_event_listener).
+use crate::{ffi, Error};
+use libc::{c_char, c_void, size_t};
+use std::{ptr, slice};
+
+/// Callbacks for events of a database.
+///
+/// The callbacks are called by RocksDB background threads, possibly
+/// concurrently, and while they run the thread does not make progress on its
+/// job. They should return quickly, e.g. by handing the info over to another
+/// thread, and must not call back into the database that reported the event.
+pub trait EventListener: Send + Sync {
+    /// Called before a flush starts writing memtables to an SST file.
+    fn on_flush_begin(&self, _info: FlushJobInfo) {}
+
+    /// Called after a flush has written its SST file.
+    fn on_flush_completed(&self, _info: FlushJobInfo) {}
+
+    /// Called before a compaction starts. Its job stats are not filled in.
+    fn on_compaction_begin(&self, _info: CompactionJobInfo) {}
+
+    /// Called after a compaction finished, successfully or not.
+    fn on_compaction_completed(&self, _info: CompactionJobInfo) {}
+
+    /// Called after an SST file was written, successfully or not.
+    fn on_table_file_created(&self, _info: TableFileCreationInfo) {}
+
+    /// Called after an SST file was deleted.
+    fn on_table_file_deleted(&self, _info: TableFileDeletionInfo) {}
+
+    /// Called after an external SST file was ingested.
+    fn on_external_file_ingested(&self, _info: ExternalFileIngestionInfo) {}
+
+    /// Called when writes to a column family start or stop being delayed or
+    /// stopped.
+    fn on_stall_conditions_changed(&self, _info: WriteStallInfo) {}
+
+    /// Called when background work hits an error that makes the database
+    /// stop background work, and possibly writes.
+    fn on_background_error(&self, _reason: BackgroundErrorReason, _error: Error) {}
+}
+
+/// Why a flush was started.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FlushReason {
+    Others,
+    GetLiveFiles,
+    ShutDown,
+    ExternalFileIngestion,
+    ManualCompaction,
+    WriteBufferManager,
+    WriteBufferFull,
+    Test,
+    DeleteFiles,
+    AutoCompaction,
+    ManualFlush,
+    ErrorRecovery,
+    ErrorRecoveryRetryFlush,
+    WalFull,
+    CatchUpAfterErrorRecovery,
+}
+
+impl FlushReason {
+    fn from_raw(raw: u32) -> Self {
+        match raw {
+            0x01 => FlushReason::GetLiveFiles,
+            0x02 => FlushReason::ShutDown,
+            0x03 => FlushReason::ExternalFileIngestion,
+            0x04 => FlushReason::ManualCompaction,
+            0x05 => FlushReason::WriteBufferManager,
+            0x06 => FlushReason::WriteBufferFull,
+            0x07 => FlushReason::Test,
+            0x08 => FlushReason::DeleteFiles,
+            0x09 => FlushReason::AutoCompaction,
+            0x0a => FlushReason::ManualFlush,
+            0x0b => FlushReason::ErrorRecovery,
+            0x0c => FlushReason::ErrorRecoveryRetryFlush,
+            0x0d => FlushReason::WalFull,
+            0x0e => FlushReason::CatchUpAfterErrorRecovery,
+            _ => FlushReason::Others,
+        }
+    }
+}
+
+/// Why a compaction was started.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CompactionReason {
+    Unknown,
+    LevelL0FilesNum,
+    LevelMaxLevelSize,
+    UniversalSizeAmplification,
+    UniversalSizeRatio,
+    UniversalSortedRunNum,
+    FIFOMaxSize,
+    FIFOReduceNumFiles,
+    FIFOTtl,
+    ManualCompaction,
+    FilesMarkedForCompaction,
+    BottommostFiles,
+    Ttl,
+    Flush,
+    ExternalSstIngestion,
+    PeriodicCompaction,
+    ChangeTemperature,
+    ForcedBlobGC,
+    RoundRobinTtl,
+    RefitLevel,
+}
+
+impl CompactionReason {
+    fn from_raw(raw: u32) -> Self {
+        match raw {
+            1 => CompactionReason::LevelL0FilesNum,
+            2 => CompactionReason::LevelMaxLevelSize,
+            3 => CompactionReason::UniversalSizeAmplification,
+            4 => CompactionReason::UniversalSizeRatio,
+            5 => CompactionReason::UniversalSortedRunNum,
+            6 => CompactionReason::FIFOMaxSize,
+            7 => CompactionReason::FIFOReduceNumFiles,
+            8 => CompactionReason::FIFOTtl,
+            9 => CompactionReason::ManualCompaction,
+            10 => CompactionReason::FilesMarkedForCompaction,
+            11 => CompactionReason::BottommostFiles,
+            12 => CompactionReason::Ttl,
+            13 => CompactionReason::Flush,
+            14 => CompactionReason::ExternalSstIngestion,
+            15 => CompactionReason::PeriodicCompaction,
+            16 => CompactionReason::ChangeTemperature,
+            17 => CompactionReason::ForcedBlobGC,
+            18 => CompactionReason::RoundRobinTtl,
+            19 => CompactionReason::RefitLevel,
+            _ => CompactionReason::Unknown,
+        }
+    }
+}
+
+/// Why an SST file was written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TableFileCreationReason {
+    Flush,
+    Compaction,
+    Recovery,
+    Misc,
+}
+
+impl TableFileCreationReason {
+    pub(crate) fn from_raw(raw: u32) -> Self {
+        match raw {
+            0 => TableFileCreationReason::Flush,
+            1 => TableFileCreationReason::Compaction,
+            2 => TableFileCreationReason::Recovery,
+            _ => TableFileCreationReason::Misc,
+        }
+    }
+}
+
+/// Whether writes to a column family are slowed down or stopped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WriteStallCondition {
+    Delayed,
+    Stopped,
+    Normal,
+}
+
+impl WriteStallCondition {
+    fn from_raw(raw: u32) -> Self {
+        match raw {
+            0 => WriteStallCondition::Delayed,
+            1 => WriteStallCondition::Stopped,
+            _ => WriteStallCondition::Normal,
+        }
+    }
+}
+
+/// The kind of background work that hit an error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BackgroundErrorReason {
+    Flush,
+    Compaction,
+    WriteCallback,
+    MemTable,
+    ManifestWrite,
+    FlushNoWAL,
+    ManifestWriteNoWAL,
+}
+
+impl BackgroundErrorReason {
+    fn from_raw(raw: u32) -> Self {
+        match raw {
+            0 => BackgroundErrorReason::Flush,
+            1 => BackgroundErrorReason::Compaction,
+            2 => BackgroundErrorReason::WriteCallback,
+            3 => BackgroundErrorReason::MemTable,
+            4 => BackgroundErrorReason::ManifestWrite,
+            5 => BackgroundErrorReason::FlushNoWAL,
+            _ => BackgroundErrorReason::ManifestWriteNoWAL,
+        }
+    }
+}
+
+/// Info about a flush.
+#[derive(Debug, Clone)]
+pub struct FlushJobInfo {
+    /// Name of the column family being flushed
+    pub cf_name: String,
+    /// Path of the SST file being written
+    pub file_path: String,
+    /// Id of the job, unique within the database
+    pub job_id: i32,
+    /// Id of the thread running the flush
+    pub thread_id: u64,
+    /// Whether writes were slowed down because too many memtables are waiting
+    /// for a flush
+    pub triggered_writes_slowdown: bool,
+    /// Whether writes were stopped because too many memtables are waiting
+    /// for a flush
+    pub triggered_writes_stop: bool,
+    /// Smallest sequence number in the flushed memtables
+    pub smallest_seqno: u64,
+    /// Largest sequence number in the flushed memtables
+    pub largest_seqno: u64,
+    /// Why the flush was started
+    pub flush_reason: FlushReason,
+}
+
+/// Info about a compaction.
+#[derive(Debug, Clone)]
+pub struct CompactionJobInfo {
+    /// Name of the column family being compacted
+    pub cf_name: String,
+    /// Outcome of the compaction, always `Ok` when it begins
+    pub status: Result<(), Error>,
+    /// Id of the thread running the compaction
+    pub thread_id: u64,
+    /// Id of the job, unique within the database
+    pub job_id: i32,
+    /// Smallest level of the input files
+    pub base_input_level: i32,
+    /// Level of the output files
+    pub output_level: i32,
+    /// Paths of the input files
+    pub input_files: Vec<String>,
+    /// Paths of the output files
+    pub output_files: Vec<String>,
+    /// Why the compaction was started
+    pub compaction_reason: CompactionReason,
+    /// Statistics of the compaction, all zero when it begins
+    pub stats: CompactionJobStats,
+}
+
+/// Statistics of a compaction.
+#[derive(Debug, Clone, Default)]
+pub struct CompactionJobStats {
+    /// Time the compaction took in microseconds
+    pub elapsed_micros: u64,
+    /// CPU time the compaction used in microseconds
+    pub cpu_micros: u64,
+    /// Number of records read
+    pub num_input_records: u64,
+    /// Number of files read
+    pub num_input_files: usize,
+    /// Number of files read from the output level
+    pub num_input_files_at_output_level: usize,
+    /// Number of records written
+    pub num_output_records: u64,
+    /// Number of files written
+    pub num_output_files: usize,
+    /// Whether all files of the column family were compacted
+    pub is_full_compaction: bool,
+    /// Whether the compaction was requested by the application
+    pub is_manual_compaction: bool,
+    /// Size of the input files in bytes
+    pub total_input_bytes: u64,
+    /// Size of the output files in bytes
+    pub total_output_bytes: u64,
+    /// Number of records replaced by newer records of the same key
+    pub num_records_replaced: u64,
+    /// Size of the keys read, before compression
+    pub total_input_raw_key_bytes: u64,
+    /// Size of the values read, before compression
+    pub total_input_raw_value_bytes: u64,
+    /// Number of deletions read
+    pub num_input_deletion_records: u64,
+    /// Number of deletions dropped because nothing older is left to delete
+    pub num_expired_deletion_records: u64,
+    /// Number of corrupt keys found
+    pub num_corrupt_keys: u64,
+}
+
+/// Info about a written SST file.
+#[derive(Debug, Clone)]
+pub struct TableFileCreationInfo {
+    /// Outcome of writing the file
+    pub status: Result<(), Error>,
+    /// Path of the database
+    pub db_name: String,
+    /// Name of the column family the file belongs to
+    pub cf_name: String,
+    /// Path of the file
+    pub file_path: String,
+    /// Id of the job that wrote the file
+    pub job_id: i32,
+    /// Why the file was written
+    pub reason: TableFileCreationReason,
+    /// Size of the file in bytes
+    pub file_size: u64,
+    /// Checksum of the file, if file checksums are enabled
+    pub file_checksum: Vec<u8>,
+    /// Name of the function used to compute `file_checksum`
+    pub file_checksum_func_name: String,
+}
+
+/// Info about a deleted SST file.
+#[derive(Debug, Clone)]
+pub struct TableFileDeletionInfo {
+    /// Outcome of deleting the file
+    pub status: Result<(), Error>,
+    /// Path of the database
+    pub db_name: String,
+    /// Path of the file
+    pub file_path: String,
+    /// Id of the job that deleted the file
+    pub job_id: i32,
+}
+
+/// Info about an ingested external SST file.
+#[derive(Debug, Clone)]
+pub struct ExternalFileIngestionInfo {
+    /// Name of the column family the file was ingested into
+    pub cf_name: String,
+    /// Path of the file outside of the database
+    pub external_file_path: String,
+    /// Path of the file within the database
+    pub internal_file_path: String,
+    /// Sequence number assigned to the keys of the file
+    pub global_seqno: u64,
+}
+
+/// Info about a change of the write stall condition of a column family.
+#[derive(Debug, Clone)]
+pub struct WriteStallInfo {
+    /// Name of the column family
+    pub cf_name: String,
+    /// The new condition
+    pub cur: WriteStallCondition,
+    /// The previous condition
+    pub prev: WriteStallCondition,
+}
+
+unsafe fn to_string(ptr: *const c_char, len: size_t) -> String {
+    String::from_utf8_lossy(slice::from_raw_parts(ptr as *const u8, len)).into_owned()
+}
+
+unsafe fn to_status(status: *mut ffi::rocksdb_status_t) -> Result<(), Error> {
+    if status.is_null() {
+        Ok(())
+    } else {
+        Err(Error::from_status(status))
+    }
+}
+
+impl FlushJobInfo {
+    unsafe fn from_c(info: *const ffi::rocksdb_flushjobinfo_t) -> Self {
+        let mut len: size_t = 0;
+        let cf_name = to_string(ffi::rocksdb_flushjobinfo_cf_name(info, &mut len), len);
+        let file_path = to_string(ffi::rocksdb_flushjobinfo_file_path(info, &mut len), len);
+        FlushJobInfo {
+            cf_name,
+            file_path,
+            job_id: ffi::rocksdb_flushjobinfo_job_id(info),
+            thread_id: ffi::rocksdb_flushjobinfo_thread_id(info),
+            triggered_writes_slowdown: ffi::rocksdb_flushjobinfo_triggered_writes_slowdown(info)
+                != 0,
+            triggered_writes_stop: ffi::rocksdb_flushjobinfo_triggered_writes_stop(info) != 0,
+            smallest_seqno: ffi::rocksdb_flushjobinfo_smallest_seqno(info),
+            largest_seqno: ffi::rocksdb_flushjobinfo_largest_seqno(info),
+            flush_reason: FlushReason::from_raw(ffi::rocksdb_flushjobinfo_flush_reason(info)),
+        }
+    }
+}
+
+impl CompactionJobInfo {
+    unsafe fn from_c(info: *const ffi::rocksdb_compactionjobinfo_t) -> Self {
+        let mut len: size_t = 0;
+        let mut status: *mut ffi::rocksdb_status_t = ptr::null_mut();
+        ffi::rocksdb_compactionjobinfo_status(info, &mut status);
+        let cf_name = to_string(ffi::rocksdb_compactionjobinfo_cf_name(info, &mut len), len);
+        let input_files = (0..ffi::rocksdb_compactionjobinfo_input_files_count(info))
+            .map(|i| {
+                to_string(
+                    ffi::rocksdb_compactionjobinfo_input_file_at(info, i, &mut len),
+                    len,
+                )
+            })
+            .collect();
+        let output_files = (0..ffi::rocksdb_compactionjobinfo_output_files_count(info))
+            .map(|i| {
+                to_string(
+                    ffi::rocksdb_compactionjobinfo_output_file_at(info, i, &mut len),
+                    len,
+                )
+            })
+            .collect();
+        CompactionJobInfo {
+            cf_name,
+            status: to_status(status),
+            thread_id: ffi::rocksdb_compactionjobinfo_thread_id(info),
+            job_id: ffi::rocksdb_compactionjobinfo_job_id(info),
+            base_input_level: ffi::rocksdb_compactionjobinfo_base_input_level(info),
+            output_level: ffi::rocksdb_compactionjobinfo_output_level(info),
+            input_files,
+            output_files,
+            compaction_reason: CompactionReason::from_raw(
+                ffi::rocksdb_compactionjobinfo_compaction_reason(info),
+            ),
+            stats: CompactionJobStats::from_c(info),
+        }
+    }
+}
+
+impl CompactionJobStats {
+    unsafe fn from_c(info: *const ffi::rocksdb_compactionjobinfo_t) -> Self {
+        CompactionJobStats {
+            elapsed_micros: ffi::rocksdb_compactionjobinfo_elapsed_micros(info),
+            cpu_micros: ffi::rocksdb_compactionjobinfo_cpu_micros(info),
+            num_input_records: ffi::rocksdb_compactionjobinfo_input_records(info),
+            num_input_files: ffi::rocksdb_compactionjobinfo_num_input_files(info),
+            num_input_files_at_output_level:
+                ffi::rocksdb_compactionjobinfo_num_input_files_at_output_level(info),
+            num_output_records: ffi::rocksdb_compactionjobinfo_output_records(info),
+            num_output_files: ffi::rocksdb_compactionjobinfo_num_output_files(info),
+            is_full_compaction: ffi::rocksdb_compactionjobinfo_is_full_compaction(info) != 0,
+            is_manual_compaction: ffi::rocksdb_compactionjobinfo_is_manual_compaction(info) != 0,
+            total_input_bytes: ffi::rocksdb_compactionjobinfo_total_input_bytes(info),
+            total_output_bytes: ffi::rocksdb_compactionjobinfo_total_output_bytes(info),
+            num_records_replaced: ffi::rocksdb_compactionjobinfo_num_records_replaced(info),
+            total_input_raw_key_bytes: ffi::rocksdb_compactionjobinfo_total_input_raw_key_bytes(
+                info,
+            ),
+            total_input_raw_value_bytes: ffi::rocksdb_compactionjobinfo_total_input_raw_value_bytes(
+                info,
+            ),
+            num_input_deletion_records: ffi::rocksdb_compactionjobinfo_num_input_deletion_records(
+                info,
+            ),
+            num_expired_deletion_records:
+                ffi::rocksdb_compactionjobinfo_num_expired_deletion_records(info),
+            num_corrupt_keys: ffi::rocksdb_compactionjobinfo_num_corrupt_keys(info),
+        }
+    }
+}
+
+impl TableFileCreationInfo {
+    unsafe fn from_c(info: *const ffi::rocksdb_tablefilecreationinfo_t) -> Self {
+        let mut len: size_t = 0;
+        let mut status: *mut ffi::rocksdb_status_t = ptr::null_mut();
+        ffi::rocksdb_tablefilecreationinfo_status(info, &mut status);
+        let db_name = to_string(
+            ffi::rocksdb_tablefilecreationinfo_db_name(info, &mut len),
+            len,
+        );
+        let cf_name = to_string(
+            ffi::rocksdb_tablefilecreationinfo_cf_name(info, &mut len),
+            len,
+        );
+        let file_path = to_string(
+            ffi::rocksdb_tablefilecreationinfo_file_path(info, &mut len),
+            len,
+        );
+        let checksum = ffi::rocksdb_tablefilecreationinfo_file_checksum(info, &mut len);
+        let file_checksum = slice::from_raw_parts(checksum as *const u8, len).to_vec();
+        let file_checksum_func_name = to_string(
+            ffi::rocksdb_tablefilecreationinfo_file_checksum_func_name(info, &mut len),
+            len,
+        );
+        TableFileCreationInfo {
+            status: to_status(status),
+            db_name,
+            cf_name,
+            file_path,
+            job_id: ffi::rocksdb_tablefilecreationinfo_job_id(info),
+            reason: TableFileCreationReason::from_raw(ffi::rocksdb_tablefilecreationinfo_reason(
+                info,
+            )),
+            file_size: ffi::rocksdb_tablefilecreationinfo_file_size(info),
+            file_checksum,
+            file_checksum_func_name,
+        }
+    }
+}
+
+impl TableFileDeletionInfo {
+    unsafe fn from_c(info: *const ffi::rocksdb_tablefiledeletioninfo_t) -> Self {
+        let mut len: size_t = 0;
+        let mut status: *mut ffi::rocksdb_status_t = ptr::null_mut();
+        ffi::rocksdb_tablefiledeletioninfo_status(info, &mut status);
+        let db_name = to_string(
+            ffi::rocksdb_tablefiledeletioninfo_db_name(info, &mut len),
+            len,
+        );
+        let file_path = to_string(
+            ffi::rocksdb_tablefiledeletioninfo_file_path(info, &mut len),
+            len,
+        );
+        TableFileDeletionInfo {
+            status: to_status(status),
+            db_name,
+            file_path,
+            job_id: ffi::rocksdb_tablefiledeletioninfo_job_id(info),
+        }
+    }
+}
+
+impl ExternalFileIngestionInfo {
+    unsafe fn from_c(info: *const ffi::rocksdb_externalfileingestioninfo_t) -> Self {
+        let mut len: size_t = 0;
+        let cf_name = to_string(
+            ffi::rocksdb_externalfileingestioninfo_cf_name(info, &mut len),
+            len,
+        );
+        let external_file_path = to_string(
+            ffi::rocksdb_externalfileingestioninfo_external_file_path(info, &mut len),
+            len,
+        );
+        let internal_file_path = to_string(
+            ffi::rocksdb_externalfileingestioninfo_internal_file_path(info, &mut len),
+            len,
+        );
+        ExternalFileIngestionInfo {
+            cf_name,
+            external_file_path,
+            internal_file_path,
+            global_seqno: ffi::rocksdb_externalfileingestioninfo_global_seqno(info),
+        }
+    }
+}
+
+impl WriteStallInfo {
+    unsafe fn from_c(info: *const ffi::rocksdb_writestallinfo_t) -> Self {
+        let mut len: size_t = 0;
+        let cf_name = to_string(ffi::rocksdb_writestallinfo_cf_name(info, &mut len), len);
+        WriteStallInfo {
+            cf_name,
+            cur: WriteStallCondition::from_raw(ffi::rocksdb_writestallinfo_cur(info)),
+            prev: WriteStallCondition::from_raw(ffi::rocksdb_writestallinfo_prev(info)),
+        }
+    }
+}
+
+pub(crate) unsafe extern "C" fn destructor_callback<L: EventListener>(raw_cb: *mut c_void) {
+    drop(Box::from_raw(raw_cb as *mut L));
+}
+
+pub(crate) unsafe extern "C" fn on_flush_begin_callback<L: EventListener>(
+    raw_cb: *mut c_void,
+    info: *const ffi::rocksdb_flushjobinfo_t,
+) {
+    let cb = &*(raw_cb as *mut L);
+    cb.on_flush_begin(FlushJobInfo::from_c(info));
+}
+
+pub(crate) unsafe extern "C" fn on_flush_completed_callback<L: EventListener>(
+    raw_cb: *mut c_void,
+    info: *const ffi::rocksdb_flushjobinfo_t,
+) {
+    let cb = &*(raw_cb as *mut L);
+    cb.on_flush_completed(FlushJobInfo::from_c(info));
+}
+
+pub(crate) unsafe extern "C" fn on_compaction_begin_callback<L: EventListener>(
+    raw_cb: *mut c_void,
+    info: *const ffi::rocksdb_compactionjobinfo_t,
+) {
+    let cb = &*(raw_cb as *mut L);
+    cb.on_compaction_begin(CompactionJobInfo::from_c(info));
+}
+
+pub(crate) unsafe extern "C" fn on_compaction_completed_callback<L: EventListener>(
+    raw_cb: *mut c_void,
+    info: *const ffi::rocksdb_compactionjobinfo_t,
+) {
+    let cb = &*(raw_cb as *mut L);
+    cb.on_compaction_completed(CompactionJobInfo::from_c(info));
+}
+
+pub(crate) unsafe extern "C" fn on_table_file_created_callback<L: EventListener>(
+    raw_cb: *mut c_void,
+    info: *const ffi::rocksdb_tablefilecreationinfo_t,
+) {
+    let cb = &*(raw_cb as *mut L);
+    cb.on_table_file_created(TableFileCreationInfo::from_c(info));
+}
+
+pub(crate) unsafe extern "C" fn on_table_file_deleted_callback<L: EventListener>(
+    raw_cb: *mut c_void,
+    info: *const ffi::rocksdb_tablefiledeletioninfo_t,
+) {
+    let cb = &*(raw_cb as *mut L);
+    cb.on_table_file_deleted(TableFileDeletionInfo::from_c(info));
+}
+
+pub(crate) unsafe extern "C" fn on_external_file_ingested_callback<L: EventListener>(
+    raw_cb: *mut c_void,
+    info: *const ffi::rocksdb_externalfileingestioninfo_t,
+) {
+    let cb = &*(raw_cb as *mut L);
+    cb.on_external_file_ingested(ExternalFileIngestionInfo::from_c(info));
+}
+
+pub(crate) unsafe extern "C" fn on_stall_conditions_changed_callback<L: EventListener>(
+    raw_cb: *mut c_void,
+    info: *const ffi::rocksdb_writestallinfo_t,
+) {
+    let cb = &*(raw_cb as *mut L);
+    cb.on_stall_conditions_changed(WriteStallInfo::from_c(info));
+}
+
+pub(crate) unsafe extern "C" fn on_background_error_callback<L: EventListener>(
+    raw_cb: *mut c_void,
+    reason: u32,
+    status: *const ffi::rocksdb_status_t,
+) {
+    let cb = &*(raw_cb as *mut L);
+    cb.on_background_error(
+        BackgroundErrorReason::from_raw(reason),
+        Error::from_status_ref(status),
+    );
+}
@@ -35,14 +35,6 @@ pub(crate) unsafe fn raw_data(ptr: *const c_char, size: usize) -> Option<Vec<u8>
     }
 }
 
-pub fn error_message(ptr: *const c_char) -> String {
-    unsafe {
-        let s = from_cstr(ptr);
-        ffi::rocksdb_free(ptr as *mut c_void);
-        s
-    }
-}
-
 pub fn opt_bytes_to_ptr<T: AsRef<[u8]>>(opt: Option<T>) -> *const c_char {
     match opt {
         Some(v) => v.as_ref().as_ptr() as *const c_char,
@@ -71,10 +63,10 @@ macro_rules! ffi_try {
 
 macro_rules! ffi_try_impl {
     ( $($function:ident)::*( $($arg:expr,)*) ) => {{
-        let mut err: *mut ::libc::c_char = ::std::ptr::null_mut();
-        let result = $($function)::*($($arg,)* &mut err);
-        if !err.is_null() {
-            return Err(Error::new($crate::ffi_util::error_message(err)));
+        let mut status: *mut $crate::ffi::rocksdb_status_t = ::std::ptr::null_mut();
+        let result = $($function)::*($($arg,)* &mut status);
+        if !status.is_null() {
+            return Err(Error::from_status(status));
         }
         result
     }};
@@ -0,0 +1,1019 @@
+//! Implementing the file system RocksDB reads and writes its files through,
+//! e.g. to account for quotas, trace IO or store files in a custom layout.
+//!
+//! Implement [`FileSystem`] and wrap it into an [`Env`](crate::Env) with
+//! [`Env::from_file_system`](crate::Env::from_file_system). Every method
+//! defaults to the operating system's file system, which is also available
+//! as [`DefaultFileSystem`] to delegate to.
+//!
+//! # Examples
+//!
+//! ```
+//! use std::io;
+//! use std::path::Path;
+//! use std::sync::atomic::{AtomicUsize, Ordering};
+//!
+//! use rocksdb::file_system::{DefaultFileSystem, FileSystem, WritableFile};
+//! use rocksdb::{Env, Options, DB};
+//!
+//! #[derive(Default)]
+//! struct CountingFileSystem {
+//!     created: AtomicUsize,
+//! }
+//!
+//! impl FileSystem for CountingFileSystem {
+//!     fn new_writable_file(&self, path: &Path) -> io::Result<Box<dyn WritableFile>> {
+//!         self.created.fetch_add(1, Ordering::Relaxed);
+//!         DefaultFileSystem.new_writable_file(path)
+//!     }
+//! }
+//!
+//! let env = Env::from_file_system(CountingFileSystem::default()).unwrap();
+//! let mut opts = Options::default();
+//! opts.create_if_missing(true);
+//! opts.set_env(&env);
+//! # let path = "_rust_rocksdb_file_system_doc";
+//! let db = DB::open(&opts, path).unwrap();
+//! # drop(db);
+//! # DB::destroy(&Options::default(), path).unwrap();
+//! ```
+use std::borrow::Cow;
+use std::cell::Cell;
+use std::mem::ManuallyDrop;
+#[cfg(unix)]
+use std::os::unix::ffi::{OsStrExt, OsStringExt};
+use std::path::{Path, PathBuf};
+use std::ptr::{self, NonNull};
+use std::{io, slice};
+
+use libc::{c_char, c_uchar, c_void, size_t};
+
+use crate::ffi_util::CSlice;
+use crate::{ffi, Error, ErrorKind, ErrorSubCode};
+
+/// A file read from start to end, e.g. a write-ahead log or the MANIFEST
+/// during recovery.
+pub trait SequentialFile: Send {
+    /// Reads up to `buf.len()` bytes into `buf` and returns how many were
+    /// read, like [`io::Read::read`]. 0 means the end of the file.
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize>;
+
+    /// Skips `n` bytes. Skipping past the end of the file is not an error.
+    fn skip(&mut self, n: u64) -> io::Result<()>;
+}
+
+/// A file read at arbitrary offsets, e.g. an SST file. It is read from
+/// several threads at once.
+pub trait RandomAccessFile: Send + Sync {
+    /// Reads up to `buf.len()` bytes at `offset` into `buf` and returns how
+    /// many were read. 0 means `offset` is at or past the end of the file.
+    fn read_at(&self, offset: u64, buf: &mut [u8]) -> io::Result<usize>;
+}
+
+/// A file written by appending to it.
+pub trait WritableFile: Send {
+    /// Appends all of `data` to the file.
+    fn append(&mut self, data: &[u8]) -> io::Result<()>;
+
+    /// Flushes the data buffered by the file, if any.
+    fn flush(&mut self) -> io::Result<()>;
+
+    /// Persists the data of the file to storage.
+    fn sync(&mut self) -> io::Result<()>;
+
+    /// Closes the file. It is not used anymore afterwards.
+    fn close(&mut self) -> io::Result<()>;
+
+    /// Returns the size of the file, including the appended data.
+    fn file_size(&self) -> u64;
+}
+
+/// A lock on a file, e.g. on the `LOCK` file of a database, preventing other
+/// processes from opening it.
+pub trait FileLock: Send {
+    /// Releases the lock.
+    fn unlock(self: Box<Self>) -> io::Result<()>;
+}
+
+/// The file system RocksDB accesses its files through. Paths are the ones
+/// RocksDB builds from the database path.
+///
+/// Methods are called concurrently from foreground and background threads.
+/// Errors are reported to RocksDB by their [`io::ErrorKind`]: `NotFound`
+/// becomes a "path not found" IO error, `Unsupported` a `NotSupported` error,
+/// `InvalidInput` an `InvalidArgument` error and `ENOSPC` a "no space" IO
+/// error. An [`io::Error`] wrapping an [`Error`] keeps its code, subcode and
+/// whether it is retryable.
+///
+/// Every method defaults to [`DefaultFileSystem`]. Info logs are written to a
+/// file created with [`new_writable_file`](FileSystem::new_writable_file).
+pub trait FileSystem: Send + Sync {
+    /// Opens an existing file for sequential reading.
+    fn new_sequential_file(&self, path: &Path) -> io::Result<Box<dyn SequentialFile>> {
+        DefaultFileSystem.new_sequential_file(path)
+    }
+
+    /// Opens an existing file for random reads.
+    fn new_random_access_file(&self, path: &Path) -> io::Result<Box<dyn RandomAccessFile>> {
+        DefaultFileSystem.new_random_access_file(path)
+    }
+
+    /// Creates a file for writing, truncating an existing file.
+    fn new_writable_file(&self, path: &Path) -> io::Result<Box<dyn WritableFile>> {
+        DefaultFileSystem.new_writable_file(path)
+    }
+
+    /// Returns whether a file or directory exists at `path`.
+    fn file_exists(&self, path: &Path) -> io::Result<bool> {
+        DefaultFileSystem.file_exists(path)
+    }
+
+    /// Returns the names of the entries of `dir`, relative to it.
+    fn get_children(&self, dir: &Path) -> io::Result<Vec<String>> {
+        DefaultFileSystem.get_children(dir)
+    }
+
+    /// Deletes a file.
+    fn delete_file(&self, path: &Path) -> io::Result<()> {
+        DefaultFileSystem.delete_file(path)
+    }
+
+    /// Creates a directory, failing if it exists.
+    fn create_dir(&self, path: &Path) -> io::Result<()> {
+        DefaultFileSystem.create_dir(path)
+    }
+
+    /// Creates a directory unless it exists.
+    fn create_dir_if_missing(&self, path: &Path) -> io::Result<()> {
+        DefaultFileSystem.create_dir_if_missing(path)
+    }
+
+    /// Deletes an empty directory.
+    fn delete_dir(&self, path: &Path) -> io::Result<()> {
+        DefaultFileSystem.delete_dir(path)
+    }
+
+    /// Persists the entries of a directory, e.g. after files were created or
+    /// renamed in it.
+    fn sync_dir(&self, path: &Path) -> io::Result<()> {
+        DefaultFileSystem.sync_dir(path)
+    }
+
+    /// Returns the size of a file, in bytes.
+    fn get_file_size(&self, path: &Path) -> io::Result<u64> {
+        DefaultFileSystem.get_file_size(path)
+    }
+
+    /// Returns the last modification time of a file, in seconds since the
+    /// Unix epoch.
+    fn get_file_modification_time(&self, path: &Path) -> io::Result<u64> {
+        DefaultFileSystem.get_file_modification_time(path)
+    }
+
+    /// Renames `src` to `target`, replacing `target` if it exists.
+    fn rename_file(&self, src: &Path, target: &Path) -> io::Result<()> {
+        DefaultFileSystem.rename_file(src, target)
+    }
+
+    /// Locks a file, creating it if needed. Fails if the file is already
+    /// locked, including by this process.
+    fn lock_file(&self, path: &Path) -> io::Result<Box<dyn FileLock>> {
+        DefaultFileSystem.lock_file(path)
+    }
+
+    /// Returns whether `path` is a directory. Also used to check a directory
+    /// exists before syncing it.
+    fn is_directory(&self, path: &Path) -> io::Result<bool> {
+        DefaultFileSystem.is_directory(path)
+    }
+
+    /// Returns the absolute path of `path`, e.g. of the database directory.
+    fn get_absolute_path(&self, path: &Path) -> io::Result<PathBuf> {
+        DefaultFileSystem.get_absolute_path(path)
+    }
+}
+
+/// The file system RocksDB uses by default, i.e. the one of the operating
+/// system.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DefaultFileSystem;
+
+impl DefaultFileSystem {
+    fn raw() -> *mut ffi::rocksdb_file_system_t {
+        unsafe { ffi::rocksdb_file_system_default() }
+    }
+}
+
+impl FileSystem for DefaultFileSystem {
+    fn new_sequential_file(&self, path: &Path) -> io::Result<Box<dyn SequentialFile>> {
+        let path = path_bytes(path);
+        let file = ffi_io(|err| unsafe {
+            ffi::rocksdb_file_system_new_sequential_file(
+                Self::raw(),
+                path.as_ptr() as *const c_char,
+                path.len(),
+                err,
+            )
+        })?;
+        Ok(Box::new(DefaultSequentialFile(NonNull::new(file).unwrap())))
+    }
+
+    fn new_random_access_file(&self, path: &Path) -> io::Result<Box<dyn RandomAccessFile>> {
+        let path = path_bytes(path);
+        let file = ffi_io(|err| unsafe {
+            ffi::rocksdb_file_system_new_random_access_file(
+                Self::raw(),
+                path.as_ptr() as *const c_char,
+                path.len(),
+                err,
+            )
+        })?;
+        Ok(Box::new(DefaultRandomAccessFile(
+            NonNull::new(file).unwrap(),
+        )))
+    }
+
+    fn new_writable_file(&self, path: &Path) -> io::Result<Box<dyn WritableFile>> {
+        let path = path_bytes(path);
+        let file = ffi_io(|err| unsafe {
+            ffi::rocksdb_file_system_new_writable_file(
+                Self::raw(),
+                path.as_ptr() as *const c_char,
+                path.len(),
+                err,
+            )
+        })?;
+        Ok(Box::new(DefaultWritableFile(NonNull::new(file).unwrap())))
+    }
+
+    fn file_exists(&self, path: &Path) -> io::Result<bool> {
+        let path = path_bytes(path);
+        let exists = ffi_io(|err| unsafe {
+            ffi::rocksdb_file_system_file_exists(
+                Self::raw(),
+                path.as_ptr() as *const c_char,
+                path.len(),
+                err,
+            )
+        })?;
+        Ok(exists != 0)
+    }
+
+    fn get_children(&self, dir: &Path) -> io::Result<Vec<String>> {
+        let dir = path_bytes(dir);
+        let mut children: Vec<String> = Vec::new();
+        ffi_io(|err| unsafe {
+            ffi::rocksdb_file_system_get_children(
+                Self::raw(),
+                dir.as_ptr() as *const c_char,
+                dir.len(),
+                ptr::addr_of_mut!(children) as *mut c_void,
+                Some(push_child_callback),
+                err,
+            );
+        })?;
+        Ok(children)
+    }
+
+    fn delete_file(&self, path: &Path) -> io::Result<()> {
+        let path = path_bytes(path);
+        ffi_io(|err| unsafe {
+            ffi::rocksdb_file_system_delete_file(
+                Self::raw(),
+                path.as_ptr() as *const c_char,
+                path.len(),
+                err,
+            );
+        })
+    }
+
+    fn create_dir(&self, path: &Path) -> io::Result<()> {
+        let path = path_bytes(path);
+        ffi_io(|err| unsafe {
+            ffi::rocksdb_file_system_create_dir(
+                Self::raw(),
+                path.as_ptr() as *const c_char,
+                path.len(),
+                err,
+            );
+        })
+    }
+
+    fn create_dir_if_missing(&self, path: &Path) -> io::Result<()> {
+        let path = path_bytes(path);
+        ffi_io(|err| unsafe {
+            ffi::rocksdb_file_system_create_dir_if_missing(
+                Self::raw(),
+                path.as_ptr() as *const c_char,
+                path.len(),
+                err,
+            );
+        })
+    }
+
+    fn delete_dir(&self, path: &Path) -> io::Result<()> {
+        let path = path_bytes(path);
+        ffi_io(|err| unsafe {
+            ffi::rocksdb_file_system_delete_dir(
+                Self::raw(),
+                path.as_ptr() as *const c_char,
+                path.len(),
+                err,
+            );
+        })
+    }
+
+    fn sync_dir(&self, path: &Path) -> io::Result<()> {
+        let path = path_bytes(path);
+        ffi_io(|err| unsafe {
+            ffi::rocksdb_file_system_fsync_dir(
+                Self::raw(),
+                path.as_ptr() as *const c_char,
+                path.len(),
+                err,
+            );
+        })
+    }
+
+    fn get_file_size(&self, path: &Path) -> io::Result<u64> {
+        let path = path_bytes(path);
+        ffi_io(|err| unsafe {
+            ffi::rocksdb_file_system_get_file_size(
+                Self::raw(),
+                path.as_ptr() as *const c_char,
+                path.len(),
+                err,
+            )
+        })
+    }
+
+    fn get_file_modification_time(&self, path: &Path) -> io::Result<u64> {
+        let path = path_bytes(path);
+        ffi_io(|err| unsafe {
+            ffi::rocksdb_file_system_get_file_modification_time(
+                Self::raw(),
+                path.as_ptr() as *const c_char,
+                path.len(),
+                err,
+            )
+        })
+    }
+
+    fn rename_file(&self, src: &Path, target: &Path) -> io::Result<()> {
+        let src = path_bytes(src);
+        let target = path_bytes(target);
+        ffi_io(|err| unsafe {
+            ffi::rocksdb_file_system_rename_file(
+                Self::raw(),
+                src.as_ptr() as *const c_char,
+                src.len(),
+                target.as_ptr() as *const c_char,
+                target.len(),
+                err,
+            );
+        })
+    }
+
+    fn lock_file(&self, path: &Path) -> io::Result<Box<dyn FileLock>> {
+        let path = path_bytes(path);
+        let lock = ffi_io(|err| unsafe {
+            ffi::rocksdb_file_system_lock_file(
+                Self::raw(),
+                path.as_ptr() as *const c_char,
+                path.len(),
+                err,
+            )
+        })?;
+        Ok(Box::new(DefaultFileLock(NonNull::new(lock).unwrap())))
+    }
+
+    fn is_directory(&self, path: &Path) -> io::Result<bool> {
+        let path = path_bytes(path);
+        let is_dir = ffi_io(|err| unsafe {
+            ffi::rocksdb_file_system_is_directory(
+                Self::raw(),
+                path.as_ptr() as *const c_char,
+                path.len(),
+                err,
+            )
+        })?;
+        Ok(is_dir != 0)
+    }
+
+    fn get_absolute_path(&self, path: &Path) -> io::Result<PathBuf> {
+        let path = path_bytes(path);
+        let mut len = 0;
+        let output = ffi_io(|err| unsafe {
+            ffi::rocksdb_file_system_get_absolute_path(
+                Self::raw(),
+                path.as_ptr() as *const c_char,
+                path.len(),
+                &mut len,
+                err,
+            )
+        })?;
+        if output.is_null() {
+            return Ok(PathBuf::new());
+        }
+        let output = unsafe { CSlice::from_raw_parts(output, len) };
+        Ok(path_from_bytes(output.as_ref()))
+    }
+}
+
+struct DefaultSequentialFile(NonNull<ffi::rocksdb_fs_sequential_file_t>);
+
+// The cpp file is only used by one thread at a time.
+unsafe impl Send for DefaultSequentialFile {}
+
+impl Drop for DefaultSequentialFile {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::rocksdb_fs_sequential_file_destroy(self.0.as_ptr());
+        }
+    }
+}
+
+impl SequentialFile for DefaultSequentialFile {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        ffi_io(|err| unsafe {
+            ffi::rocksdb_fs_sequential_file_read(
+                self.0.as_ptr(),
+                buf.as_mut_ptr() as *mut c_char,
+                buf.len(),
+                err,
+            )
+        })
+    }
+
+    fn skip(&mut self, n: u64) -> io::Result<()> {
+        ffi_io(|err| unsafe {
+            ffi::rocksdb_fs_sequential_file_skip(self.0.as_ptr(), n, err);
+        })
+    }
+}
+
+struct DefaultRandomAccessFile(NonNull<ffi::rocksdb_fs_random_access_file_t>);
+
+// Reads of the cpp file are `const` and safe to do concurrently.
+unsafe impl Send for DefaultRandomAccessFile {}
+unsafe impl Sync for DefaultRandomAccessFile {}
+
+impl Drop for DefaultRandomAccessFile {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::rocksdb_fs_random_access_file_destroy(self.0.as_ptr());
+        }
+    }
+}
+
+impl RandomAccessFile for DefaultRandomAccessFile {
+    fn read_at(&self, offset: u64, buf: &mut [u8]) -> io::Result<usize> {
+        ffi_io(|err| unsafe {
+            ffi::rocksdb_fs_random_access_file_read(
+                self.0.as_ptr(),
+                offset,
+                buf.as_mut_ptr() as *mut c_char,
+                buf.len(),
+                err,
+            )
+        })
+    }
+}
+
+struct DefaultWritableFile(NonNull<ffi::rocksdb_fs_writable_file_t>);
+
+// The cpp file is only used by one thread at a time.
+unsafe impl Send for DefaultWritableFile {}
+
+impl Drop for DefaultWritableFile {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::rocksdb_fs_writable_file_destroy(self.0.as_ptr());
+        }
+    }
+}
+
+impl WritableFile for DefaultWritableFile {
+    fn append(&mut self, data: &[u8]) -> io::Result<()> {
+        ffi_io(|err| unsafe {
+            ffi::rocksdb_fs_writable_file_append(
+                self.0.as_ptr(),
+                data.as_ptr() as *const c_char,
+                data.len(),
+                err,
+            );
+        })
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        ffi_io(|err| unsafe { ffi::rocksdb_fs_writable_file_flush(self.0.as_ptr(), err) })
+    }
+
+    fn sync(&mut self) -> io::Result<()> {
+        ffi_io(|err| unsafe { ffi::rocksdb_fs_writable_file_sync(self.0.as_ptr(), err) })
+    }
+
+    fn close(&mut self) -> io::Result<()> {
+        ffi_io(|err| unsafe { ffi::rocksdb_fs_writable_file_close(self.0.as_ptr(), err) })
+    }
+
+    fn file_size(&self) -> u64 {
+        unsafe { ffi::rocksdb_fs_writable_file_get_file_size(self.0.as_ptr()) }
+    }
+}
+
+struct DefaultFileLock(NonNull<ffi::rocksdb_fs_file_lock_t>);
+
+unsafe impl Send for DefaultFileLock {}
+
+impl Drop for DefaultFileLock {
+    // A lock that is dropped without being unlocked is released anyway.
+    fn drop(&mut self) {
+        let mut status: *mut ffi::rocksdb_status_t = ptr::null_mut();
+        unsafe {
+            ffi::rocksdb_file_system_unlock_file(self.0.as_ptr(), &mut status);
+            if !status.is_null() {
+                ffi::rocksdb_status_destroy(status);
+            }
+        }
+    }
+}
+
+impl FileLock for DefaultFileLock {
+    fn unlock(self: Box<Self>) -> io::Result<()> {
+        let lock = ManuallyDrop::new(*self);
+        ffi_io(|err| unsafe { ffi::rocksdb_file_system_unlock_file(lock.0.as_ptr(), err) })
+    }
+}
+
+/// Converts a `Status` reported through `statusptr` into an [`io::Error`].
+fn ffi_io<T>(f: impl FnOnce(*mut *mut ffi::rocksdb_status_t) -> T) -> io::Result<T> {
+    let mut status: *mut ffi::rocksdb_status_t = ptr::null_mut();
+    let result = f(&mut status);
+    if status.is_null() {
+        Ok(result)
+    } else {
+        Err(into_io_error(unsafe { Error::from_status(status) }))
+    }
+}
+
+fn into_io_error(e: Error) -> io::Error {
+    let kind = match (e.kind(), e.subcode()) {
+        (ErrorKind::NotFound, _) | (ErrorKind::IOError, ErrorSubCode::PathNotFound) => {
+            io::ErrorKind::NotFound
+        }
+        (ErrorKind::NotSupported, _) => io::ErrorKind::Unsupported,
+        (ErrorKind::InvalidArgument, _) => io::ErrorKind::InvalidInput,
+        _ => io::ErrorKind::Other,
+    };
+    io::Error::new(kind, e)
+}
+
+/// Creates the status reporting `e` to RocksDB.
+fn io_error_status(e: &io::Error) -> *mut ffi::rocksdb_status_t {
+    if let Some(e) = e.get_ref().and_then(|e| e.downcast_ref::<Error>()) {
+        return create_status(e.kind(), e.subcode(), e.retryable, e.as_ref());
+    }
+    let (kind, subcode) = match e.kind() {
+        io::ErrorKind::NotFound => (ErrorKind::IOError, ErrorSubCode::PathNotFound),
+        io::ErrorKind::Unsupported => (ErrorKind::NotSupported, ErrorSubCode::None),
+        io::ErrorKind::InvalidInput => (ErrorKind::InvalidArgument, ErrorSubCode::None),
+        _ if e.raw_os_error() == Some(libc::ENOSPC) => (ErrorKind::IOError, ErrorSubCode::NoSpace),
+        _ => (ErrorKind::IOError, ErrorSubCode::None),
+    };
+    create_status(kind, subcode, false, &e.to_string())
+}
+
+fn create_status(
+    kind: ErrorKind,
+    subcode: ErrorSubCode,
+    retryable: bool,
+    message: &str,
+) -> *mut ffi::rocksdb_status_t {
+    unsafe {
+        ffi::rocksdb_status_create(
+            kind.to_raw(),
+            subcode.to_raw(),
+            c_uchar::from(retryable),
+            message.as_ptr() as *const c_char,
+            message.len(),
+        )
+    }
+}
+
+/// Reports `result` through `statusptr`, returning `default` on error.
+unsafe fn save_result<T>(
+    result: io::Result<T>,
+    default: T,
+    statusptr: *mut *mut ffi::rocksdb_status_t,
+) -> T {
+    match result {
+        Ok(value) => value,
+        Err(e) => {
+            *statusptr = io_error_status(&e);
+            default
+        }
+    }
+}
+
+#[cfg(unix)]
+fn path_bytes(path: &Path) -> Cow<'_, [u8]> {
+    Cow::Borrowed(path.as_os_str().as_bytes())
+}
+
+// RocksDB expects UTF-8 paths on other platforms.
+#[cfg(not(unix))]
+fn path_bytes(path: &Path) -> Cow<'_, [u8]> {
+    Cow::Owned(path.to_string_lossy().into_owned().into_bytes())
+}
+
+#[cfg(unix)]
+fn path_from_bytes(path: &[u8]) -> PathBuf {
+    PathBuf::from(std::ffi::OsString::from_vec(path.to_vec()))
+}
+
+#[cfg(not(unix))]
+fn path_from_bytes(path: &[u8]) -> PathBuf {
+    PathBuf::from(String::from_utf8_lossy(path).into_owned())
+}
+
+unsafe fn raw_path(path: *const c_char, path_len: size_t) -> PathBuf {
+    path_from_bytes(slice::from_raw_parts(path as *const u8, path_len))
+}
+
+/// Fills as much of `buf` as `read` can, stopping at the end of the file.
+fn read_full(
+    buf: &mut [u8],
+    mut read: impl FnMut(usize, &mut [u8]) -> io::Result<usize>,
+) -> io::Result<usize> {
+    let mut len = 0;
+    while len < buf.len() {
+        match read(len, &mut buf[len..]) {
+            Ok(0) => break,
+            Ok(n) => len += n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(len)
+}
+
+thread_local! {
+    static READ_BUFFER: Cell<Vec<u8>> = const { Cell::new(Vec::new()) };
+}
+
+/// Fills as much of `n` bytes at `scratch` as `read` can. The buffers RocksDB
+/// reads into are not initialized, so `read` gets a buffer of the thread
+/// instead, which is only zeroed when it grows, and the data is copied over.
+unsafe fn read_into(
+    scratch: *mut c_char,
+    n: size_t,
+    read: impl FnMut(usize, &mut [u8]) -> io::Result<usize>,
+) -> io::Result<usize> {
+    let mut buf = READ_BUFFER.with(Cell::take);
+    if buf.len() < n {
+        buf.resize(n, 0);
+    }
+    let result = read_full(&mut buf[..n], read);
+    if let Ok(len) = result {
+        ptr::copy_nonoverlapping(buf.as_ptr(), scratch as *mut u8, len);
+    }
+    READ_BUFFER.with(|cell| cell.set(buf));
+    result
+}
+
+unsafe extern "C" fn push_child_callback(raw: *mut c_void, name: *const c_char, name_len: size_t) {
+    let children = &mut *(raw as *mut Vec<String>);
+    let name = slice::from_raw_parts(name as *const u8, name_len);
+    children.push(String::from_utf8_lossy(name).into_owned());
+}
+
+pub(crate) fn create_file_system<F>(fs: F) -> *mut ffi::rocksdb_file_system_t
+where
+    F: FileSystem + 'static,
+{
+    unsafe {
+        ffi::rocksdb_file_system_create(
+            Box::into_raw(Box::new(fs)) as *mut c_void,
+            Some(destructor_callback::<F>),
+            Some(new_sequential_file_callback::<F>),
+            Some(new_random_access_file_callback::<F>),
+            Some(new_writable_file_callback::<F>),
+            Some(file_exists_callback::<F>),
+            Some(get_children_callback::<F>),
+            Some(delete_file_callback::<F>),
+            Some(create_dir_callback::<F>),
+            Some(create_dir_if_missing_callback::<F>),
+            Some(delete_dir_callback::<F>),
+            Some(sync_dir_callback::<F>),
+            Some(get_file_size_callback::<F>),
+            Some(get_file_modification_time_callback::<F>),
+            Some(rename_file_callback::<F>),
+            Some(lock_file_callback::<F>),
+            Some(is_directory_callback::<F>),
+            Some(get_absolute_path_callback::<F>),
+        )
+    }
+}
+
+unsafe extern "C" fn destructor_callback<T>(raw: *mut c_void) {
+    drop(Box::from_raw(raw as *mut T));
+}
+
+unsafe extern "C" fn new_sequential_file_callback<F: FileSystem>(
+    raw: *mut c_void,
+    path: *const c_char,
+    path_len: size_t,
+    statusptr: *mut *mut ffi::rocksdb_status_t,
+) -> *mut ffi::rocksdb_fs_sequential_file_t {
+    let fs = &*(raw as *mut F);
+    let file = fs
+        .new_sequential_file(&raw_path(path, path_len))
+        .map(|file| {
+            ffi::rocksdb_fs_sequential_file_create(
+                Box::into_raw(Box::new(file)) as *mut c_void,
+                Some(destructor_callback::<Box<dyn SequentialFile>>),
+                Some(sequential_read_callback),
+                Some(sequential_skip_callback),
+            )
+        });
+    save_result(file, ptr::null_mut(), statusptr)
+}
+
+unsafe extern "C" fn new_random_access_file_callback<F: FileSystem>(
+    raw: *mut c_void,
+    path: *const c_char,
+    path_len: size_t,
+    statusptr: *mut *mut ffi::rocksdb_status_t,
+) -> *mut ffi::rocksdb_fs_random_access_file_t {
+    let fs = &*(raw as *mut F);
+    let file = fs
+        .new_random_access_file(&raw_path(path, path_len))
+        .map(|file| {
+            ffi::rocksdb_fs_random_access_file_create(
+                Box::into_raw(Box::new(file)) as *mut c_void,
+                Some(destructor_callback::<Box<dyn RandomAccessFile>>),
+                Some(random_access_read_callback),
+            )
+        });
+    save_result(file, ptr::null_mut(), statusptr)
+}
+
+unsafe extern "C" fn new_writable_file_callback<F: FileSystem>(
+    raw: *mut c_void,
+    path: *const c_char,
+    path_len: size_t,
+    statusptr: *mut *mut ffi::rocksdb_status_t,
+) -> *mut ffi::rocksdb_fs_writable_file_t {
+    let fs = &*(raw as *mut F);
+    let file = fs.new_writable_file(&raw_path(path, path_len)).map(|file| {
+        ffi::rocksdb_fs_writable_file_create(
+            Box::into_raw(Box::new(file)) as *mut c_void,
+            Some(destructor_callback::<Box<dyn WritableFile>>),
+            Some(writable_append_callback),
+            Some(writable_flush_callback),
+            Some(writable_sync_callback),
+            Some(writable_close_callback),
+            Some(writable_file_size_callback),
+        )
+    });
+    save_result(file, ptr::null_mut(), statusptr)
+}
+
+unsafe extern "C" fn file_exists_callback<F: FileSystem>(
+    raw: *mut c_void,
+    path: *const c_char,
+    path_len: size_t,
+    statusptr: *mut *mut ffi::rocksdb_status_t,
+) -> c_uchar {
+    let fs = &*(raw as *mut F);
+    let exists = fs.file_exists(&raw_path(path, path_len));
+    c_uchar::from(save_result(exists, false, statusptr))
+}
+
+unsafe extern "C" fn get_children_callback<F: FileSystem>(
+    raw: *mut c_void,
+    dir: *const c_char,
+    dir_len: size_t,
+    result: *mut ffi::rocksdb_fs_children_t,
+    statusptr: *mut *mut ffi::rocksdb_status_t,
+) {
+    let fs = &*(raw as *mut F);
+    let children = fs.get_children(&raw_path(dir, dir_len)).map(|children| {
+        for child in children {
+            ffi::rocksdb_fs_children_push(result, child.as_ptr() as *const c_char, child.len());
+        }
+    });
+    save_result(children, (), statusptr);
+}
+
+unsafe extern "C" fn delete_file_callback<F: FileSystem>(
+    raw: *mut c_void,
+    path: *const c_char,
+    path_len: size_t,
+    statusptr: *mut *mut ffi::rocksdb_status_t,
+) {
+    let fs = &*(raw as *mut F);
+    save_result(fs.delete_file(&raw_path(path, path_len)), (), statusptr);
+}
+
+unsafe extern "C" fn create_dir_callback<F: FileSystem>(
+    raw: *mut c_void,
+    path: *const c_char,
+    path_len: size_t,
+    statusptr: *mut *mut ffi::rocksdb_status_t,
+) {
+    let fs = &*(raw as *mut F);
+    save_result(fs.create_dir(&raw_path(path, path_len)), (), statusptr);
+}
+
+unsafe extern "C" fn create_dir_if_missing_callback<F: FileSystem>(
+    raw: *mut c_void,
+    path: *const c_char,
+    path_len: size_t,
+    statusptr: *mut *mut ffi::rocksdb_status_t,
+) {
+    let fs = &*(raw as *mut F);
+    save_result(
+        fs.create_dir_if_missing(&raw_path(path, path_len)),
+        (),
+        statusptr,
+    );
+}
+
+unsafe extern "C" fn delete_dir_callback<F: FileSystem>(
+    raw: *mut c_void,
+    path: *const c_char,
+    path_len: size_t,
+    statusptr: *mut *mut ffi::rocksdb_status_t,
+) {
+    let fs = &*(raw as *mut F);
+    save_result(fs.delete_dir(&raw_path(path, path_len)), (), statusptr);
+}
+
+unsafe extern "C" fn sync_dir_callback<F: FileSystem>(
+    raw: *mut c_void,
+    path: *const c_char,
+    path_len: size_t,
+    statusptr: *mut *mut ffi::rocksdb_status_t,
+) {
+    let fs = &*(raw as *mut F);
+    save_result(fs.sync_dir(&raw_path(path, path_len)), (), statusptr);
+}
+
+unsafe extern "C" fn get_file_size_callback<F: FileSystem>(
+    raw: *mut c_void,
+    path: *const c_char,
+    path_len: size_t,
+    statusptr: *mut *mut ffi::rocksdb_status_t,
+) -> u64 {
+    let fs = &*(raw as *mut F);
+    save_result(fs.get_file_size(&raw_path(path, path_len)), 0, statusptr)
+}
+
+unsafe extern "C" fn get_file_modification_time_callback<F: FileSystem>(
+    raw: *mut c_void,
+    path: *const c_char,
+    path_len: size_t,
+    statusptr: *mut *mut ffi::rocksdb_status_t,
+) -> u64 {
+    let fs = &*(raw as *mut F);
+    let mtime = fs.get_file_modification_time(&raw_path(path, path_len));
+    save_result(mtime, 0, statusptr)
+}
+
+unsafe extern "C" fn rename_file_callback<F: FileSystem>(
+    raw: *mut c_void,
+    src: *const c_char,
+    src_len: size_t,
+    target: *const c_char,
+    target_len: size_t,
+    statusptr: *mut *mut ffi::rocksdb_status_t,
+) {
+    let fs = &*(raw as *mut F);
+    let renamed = fs.rename_file(&raw_path(src, src_len), &raw_path(target, target_len));
+    save_result(renamed, (), statusptr);
+}
+
+unsafe extern "C" fn lock_file_callback<F: FileSystem>(
+    raw: *mut c_void,
+    path: *const c_char,
+    path_len: size_t,
+    statusptr: *mut *mut ffi::rocksdb_status_t,
+) -> *mut ffi::rocksdb_fs_file_lock_t {
+    let fs = &*(raw as *mut F);
+    let lock = fs.lock_file(&raw_path(path, path_len)).map(|lock| {
+        ffi::rocksdb_fs_file_lock_create(
+            Box::into_raw(Box::new(lock)) as *mut c_void,
+            Some(unlock_callback),
+        )
+    });
+    save_result(lock, ptr::null_mut(), statusptr)
+}
+
+unsafe extern "C" fn is_directory_callback<F: FileSystem>(
+    raw: *mut c_void,
+    path: *const c_char,
+    path_len: size_t,
+    statusptr: *mut *mut ffi::rocksdb_status_t,
+) -> c_uchar {
+    let fs = &*(raw as *mut F);
+    let is_dir = fs.is_directory(&raw_path(path, path_len));
+    c_uchar::from(save_result(is_dir, false, statusptr))
+}
+
+unsafe extern "C" fn get_absolute_path_callback<F: FileSystem>(
+    raw: *mut c_void,
+    path: *const c_char,
+    path_len: size_t,
+    result: *mut ffi::rocksdb_fs_path_t,
+    statusptr: *mut *mut ffi::rocksdb_status_t,
+) {
+    let fs = &*(raw as *mut F);
+    let output = fs
+        .get_absolute_path(&raw_path(path, path_len))
+        .map(|output| {
+            let output = path_bytes(&output);
+            ffi::rocksdb_fs_path_set(result, output.as_ptr() as *const c_char, output.len());
+        });
+    save_result(output, (), statusptr);
+}
+
+unsafe extern "C" fn unlock_callback(raw: *mut c_void, statusptr: *mut *mut ffi::rocksdb_status_t) {
+    let lock = Box::from_raw(raw as *mut Box<dyn FileLock>);
+    save_result(lock.unlock(), (), statusptr);
+}
+
+unsafe extern "C" fn sequential_read_callback(
+    raw: *mut c_void,
+    scratch: *mut c_char,
+    n: size_t,
+    statusptr: *mut *mut ffi::rocksdb_status_t,
+) -> size_t {
+    let file = &mut *(raw as *mut Box<dyn SequentialFile>);
+    let read = read_into(scratch, n, |_, buf| file.read(buf));
+    save_result(read, 0, statusptr)
+}
+
+unsafe extern "C" fn sequential_skip_callback(
+    raw: *mut c_void,
+    n: u64,
+    statusptr: *mut *mut ffi::rocksdb_status_t,
+) {
+    let file = &mut *(raw as *mut Box<dyn SequentialFile>);
+    save_result(file.skip(n), (), statusptr);
+}
+
+unsafe extern "C" fn random_access_read_callback(
+    raw: *mut c_void,
+    offset: u64,
+    scratch: *mut c_char,
+    n: size_t,
+    statusptr: *mut *mut ffi::rocksdb_status_t,
+) -> size_t {
+    let file = &*(raw as *mut Box<dyn RandomAccessFile>);
+    let read = read_into(scratch, n, |len, buf| {
+        file.read_at(offset + len as u64, buf)
+    });
+    save_result(read, 0, statusptr)
+}
+
+unsafe extern "C" fn writable_append_callback(
+    raw: *mut c_void,
+    data: *const c_char,
+    len: size_t,
+    statusptr: *mut *mut ffi::rocksdb_status_t,
+) {
+    let file = &mut *(raw as *mut Box<dyn WritableFile>);
+    let data = slice::from_raw_parts(data as *const u8, len);
+    save_result(file.append(data), (), statusptr);
+}
+
+unsafe extern "C" fn writable_flush_callback(
+    raw: *mut c_void,
+    statusptr: *mut *mut ffi::rocksdb_status_t,
+) {
+    let file = &mut *(raw as *mut Box<dyn WritableFile>);
+    save_result(file.flush(), (), statusptr);
+}
+
+unsafe extern "C" fn writable_sync_callback(
+    raw: *mut c_void,
+    statusptr: *mut *mut ffi::rocksdb_status_t,
+) {
+    let file = &mut *(raw as *mut Box<dyn WritableFile>);
+    save_result(file.sync(), (), statusptr);
+}
+
+unsafe extern "C" fn writable_close_callback(
+    raw: *mut c_void,
+    statusptr: *mut *mut ffi::rocksdb_status_t,
+) {
+    let file = &mut *(raw as *mut Box<dyn WritableFile>);
+    save_result(file.close(), (), statusptr);
+}
+
+unsafe extern "C" fn writable_file_size_callback(raw: *mut c_void) -> u64 {
+    let file = &*(raw as *mut Box<dyn WritableFile>);
+    file.file_size()
+}
@@ -77,6 +77,7 @@ mod ffi_util;
 
 pub mod backup;
 pub mod checkpoint;
+mod clock;
 mod column_family;
 pub mod compaction_filter;
 pub mod compaction_filter_factory;
@@ -85,28 +86,40 @@ mod db;
 mod db_iterator;
 mod db_options;
 mod db_pinnable_slice;
+mod encryption;
 mod env;
+pub mod event_listener;
+pub mod file_system;
 mod iter_range;
+pub mod logger;
 pub mod merge_operator;
+pub mod metadata;
 pub mod perf;
 mod prop_name;
 pub mod properties;
+mod rate_limiter;
 mod slice_transform;
 mod snapshot;
+mod sst_file_manager;
 mod sst_file_writer;
+pub mod statistics;
+pub mod table_properties;
+pub mod thread_status;
 mod transactions;
+mod wide_columns;
 mod write_batch;
 mod write_buffer_manager;
 
 pub use crate::{
+    clock::{Clock, MockClock},
     column_family::{
         AsColumnFamilyRef, BoundColumnFamily, ColumnFamily, ColumnFamilyDescriptor,
         ColumnFamilyRef, DEFAULT_COLUMN_FAMILY_NAME,
     },
     compaction_filter::Decision as CompactionDecision,
     db::{
-        DBAccess, DBCommon, DBWithThreadMode, LiveFile, MultiThreaded, SingleThreaded, ThreadMode,
-        DB,
+        DBAccess, DBCommon, DBWithThreadMode, LiveFile, MemTableStats, MultiThreaded,
+        SingleThreaded, SizeApproximationOptions, ThreadMode, DB,
     },
     db_iterator::{
         DBIterator, DBIteratorWithThreadMode, DBRawIterator, DBRawIteratorWithThreadMode,
@@ -114,35 +127,44 @@ pub use crate::{
     },
     db_options::{
         BlockBasedIndexType, BlockBasedOptions, BottommostLevelCompaction, Cache, ChecksumType,
-        CompactOptions, CuckooTableOptions, DBCompactionStyle, DBCompressionType, DBPath,
-        DBRecoveryMode, DataBlockIndexType, FifoCompactOptions, FlushOptions,
-        IngestExternalFileOptions, LogLevel, MemtableFactory, Options, PlainTableFactoryOptions,
-        ReadOptions, UniversalCompactOptions, UniversalCompactionStopStyle, WriteOptions,
+        CompactOptions, CompactionOptions, CuckooTableOptions, DBCompactionStyle,
+        DBCompressionType, DBPath, DBRecoveryMode, DataBlockIndexType, FifoCompactOptions,
+        FlushOptions, IngestExternalFileOptions, LogLevel, MemtableFactory, Options,
+        PlainTableFactoryOptions, ReadOptions, UniversalCompactOptions,
+        UniversalCompactionStopStyle, WaitForCompactOptions, WriteOptions,
     },
     db_pinnable_slice::DBPinnableSlice,
-    env::Env,
+    encryption::{BlockCipher, EncryptionProvider},
+    env::{Env, FaultInjection, FaultInjectionEnv, Priority},
+    event_listener::EventListener,
     ffi_util::CStrLike,
     iter_range::{IterateBounds, PrefixRange},
-    merge_operator::MergeOperands,
+    merge_operator::{MergeError, MergeOperands, MergeOperator},
     perf::{PerfContext, PerfMetric, PerfStatsLevel},
+    rate_limiter::RateLimiter,
     slice_transform::SliceTransform,
     snapshot::{Snapshot, SnapshotWithThreadMode},
+    sst_file_manager::SstFileManager,
     sst_file_writer::SstFileWriter,
+    statistics::{Histogram, HistogramData, Statistics, StatsLevel, Ticker},
     transactions::{
         OptimisticTransactionDB, OptimisticTransactionOptions, Transaction, TransactionDB,
         TransactionDBOptions, TransactionOptions,
     },
+    wide_columns::{DBWideColumns, WideColumn, DEFAULT_WIDE_COLUMN_NAME},
     write_batch::{WriteBatch, WriteBatchIterator, WriteBatchWithTransaction},
     write_buffer_manager::WriteBufferManager,
 };
 
 use librocksdb_sys as ffi;
 
+use libc::c_int;
 use std::error;
 use std::fmt;
+use std::slice;
 
 /// RocksDB error kind.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ErrorKind {
     NotFound,
     Corruption,
@@ -162,42 +184,252 @@ pub enum ErrorKind {
     Unknown,
 }
 
-/// A simple wrapper round a string, used for errors reported from
-/// ffi calls.
+/// RocksDB error subcode, refining an [`ErrorKind`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorSubCode {
+    None,
+    MutexTimeout,
+    LockTimeout,
+    LockLimit,
+    NoSpace,
+    Deadlock,
+    StaleFile,
+    MemoryLimit,
+    SpaceLimit,
+    PathNotFound,
+    MergeOperandsInsufficientCapacity,
+    ManualCompactionPaused,
+    Overwritten,
+    TxnNotPrepared,
+    IOFenced,
+    MergeOperatorFailed,
+}
+
+/// Severity of an error reported by background work.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ErrorSeverity {
+    NoError,
+    SoftError,
+    HardError,
+    FatalError,
+    UnrecoverableError,
+}
+
+impl ErrorKind {
+    /// Maps a `Status::Code`. `kOk` is never reported as an error.
+    fn from_raw(raw: c_int) -> Self {
+        match raw {
+            1 => ErrorKind::NotFound,
+            2 => ErrorKind::Corruption,
+            3 => ErrorKind::NotSupported,
+            4 => ErrorKind::InvalidArgument,
+            5 => ErrorKind::IOError,
+            6 => ErrorKind::MergeInProgress,
+            7 => ErrorKind::Incomplete,
+            8 => ErrorKind::ShutdownInProgress,
+            9 => ErrorKind::TimedOut,
+            10 => ErrorKind::Aborted,
+            11 => ErrorKind::Busy,
+            12 => ErrorKind::Expired,
+            13 => ErrorKind::TryAgain,
+            14 => ErrorKind::CompactionTooLarge,
+            15 => ErrorKind::ColumnFamilyDropped,
+            _ => ErrorKind::Unknown,
+        }
+    }
+
+    /// Maps back to a `Status::Code`. RocksDB has no code for `Unknown`, the
+    /// kind of errors raised by this crate, which becomes `kIOError`.
+    fn to_raw(self) -> c_int {
+        match self {
+            ErrorKind::NotFound => 1,
+            ErrorKind::Corruption => 2,
+            ErrorKind::NotSupported => 3,
+            ErrorKind::InvalidArgument => 4,
+            ErrorKind::IOError | ErrorKind::Unknown => 5,
+            ErrorKind::MergeInProgress => 6,
+            ErrorKind::Incomplete => 7,
+            ErrorKind::ShutdownInProgress => 8,
+            ErrorKind::TimedOut => 9,
+            ErrorKind::Aborted => 10,
+            ErrorKind::Busy => 11,
+            ErrorKind::Expired => 12,
+            ErrorKind::TryAgain => 13,
+            ErrorKind::CompactionTooLarge => 14,
+            ErrorKind::ColumnFamilyDropped => 15,
+        }
+    }
+}
+
+impl ErrorSubCode {
+    /// Maps a `Status::SubCode`.
+    fn from_raw(raw: c_int) -> Self {
+        match raw {
+            1 => ErrorSubCode::MutexTimeout,
+            2 => ErrorSubCode::LockTimeout,
+            3 => ErrorSubCode::LockLimit,
+            4 => ErrorSubCode::NoSpace,
+            5 => ErrorSubCode::Deadlock,
+            6 => ErrorSubCode::StaleFile,
+            7 => ErrorSubCode::MemoryLimit,
+            8 => ErrorSubCode::SpaceLimit,
+            9 => ErrorSubCode::PathNotFound,
+            10 => ErrorSubCode::MergeOperandsInsufficientCapacity,
+            11 => ErrorSubCode::ManualCompactionPaused,
+            12 => ErrorSubCode::Overwritten,
+            13 => ErrorSubCode::TxnNotPrepared,
+            14 => ErrorSubCode::IOFenced,
+            15 => ErrorSubCode::MergeOperatorFailed,
+            _ => ErrorSubCode::None,
+        }
+    }
+
+    /// Maps back to a `Status::SubCode`.
+    fn to_raw(self) -> c_int {
+        match self {
+            ErrorSubCode::None => 0,
+            ErrorSubCode::MutexTimeout => 1,
+            ErrorSubCode::LockTimeout => 2,
+            ErrorSubCode::LockLimit => 3,
+            ErrorSubCode::NoSpace => 4,
+            ErrorSubCode::Deadlock => 5,
+            ErrorSubCode::StaleFile => 6,
+            ErrorSubCode::MemoryLimit => 7,
+            ErrorSubCode::SpaceLimit => 8,
+            ErrorSubCode::PathNotFound => 9,
+            ErrorSubCode::MergeOperandsInsufficientCapacity => 10,
+            ErrorSubCode::ManualCompactionPaused => 11,
+            ErrorSubCode::Overwritten => 12,
+            ErrorSubCode::TxnNotPrepared => 13,
+            ErrorSubCode::IOFenced => 14,
+            ErrorSubCode::MergeOperatorFailed => 15,
+        }
+    }
+}
+
+impl ErrorSeverity {
+    /// Maps a `Status::Severity`.
+    fn from_raw(raw: c_int) -> Self {
+        match raw {
+            0 => ErrorSeverity::NoError,
+            1 => ErrorSeverity::SoftError,
+            2 => ErrorSeverity::HardError,
+            3 => ErrorSeverity::FatalError,
+            _ => ErrorSeverity::UnrecoverableError,
+        }
+    }
+}
+
+/// An error reported from ffi calls, carrying the code, subcode and severity
+/// of the RocksDB `Status` it was created from.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Error {
     message: String,
+    kind: ErrorKind,
+    subcode: ErrorSubCode,
+    severity: ErrorSeverity,
+    retryable: bool,
 }
 
 impl Error {
+    /// Creates an error raised by this crate rather than by RocksDB, of kind
+    /// [`ErrorKind::Unknown`].
     fn new(message: String) -> Error {
-        Error { message }
+        Error::with_kind(ErrorKind::Unknown, message)
+    }
+
+    fn with_kind(kind: ErrorKind, message: String) -> Error {
+        Error {
+            message,
+            kind,
+            subcode: ErrorSubCode::None,
+            severity: ErrorSeverity::NoError,
+            retryable: false,
+        }
+    }
+
+    /// Creates an error from a status reported by the C API, without taking
+    /// ownership of it.
+    ///
+    /// # Safety
+    ///
+    /// `status` must point to a valid `rocksdb_status_t`.
+    unsafe fn from_status_ref(status: *const ffi::rocksdb_status_t) -> Error {
+        let mut len = 0;
+        let message = ffi::rocksdb_status_message(status, &mut len);
+        Error {
+            message: String::from_utf8_lossy(slice::from_raw_parts(message as *const u8, len))
+                .into_owned(),
+            kind: ErrorKind::from_raw(ffi::rocksdb_status_code(status)),
+            subcode: ErrorSubCode::from_raw(ffi::rocksdb_status_subcode(status)),
+            severity: ErrorSeverity::from_raw(ffi::rocksdb_status_severity(status)),
+            retryable: ffi::rocksdb_status_retryable(status) != 0,
+        }
+    }
+
+    /// Creates an error from a status reported by the C API and destroys the
+    /// status.
+    ///
+    /// # Safety
+    ///
+    /// `status` must point to a valid `rocksdb_status_t` that is not used
+    /// afterwards.
+    unsafe fn from_status(status: *mut ffi::rocksdb_status_t) -> Error {
+        let error = Error::from_status_ref(status);
+        ffi::rocksdb_status_destroy(status);
+        error
     }
 
     pub fn into_string(self) -> String {
         self.into()
     }
 
-    /// Parse corresponding [`ErrorKind`] from error message.
+    /// Returns the code of the error.
     pub fn kind(&self) -> ErrorKind {
-        match self.message.split(':').next().unwrap_or("") {
-            "NotFound" => ErrorKind::NotFound,
-            "Corruption" => ErrorKind::Corruption,
-            "Not implemented" => ErrorKind::NotSupported,
-            "Invalid argument" => ErrorKind::InvalidArgument,
-            "IO error" => ErrorKind::IOError,
-            "Merge in progress" => ErrorKind::MergeInProgress,
-            "Result incomplete" => ErrorKind::Incomplete,
-            "Shutdown in progress" => ErrorKind::ShutdownInProgress,
-            "Operation timed out" => ErrorKind::TimedOut,
-            "Operation aborted" => ErrorKind::Aborted,
-            "Resource busy" => ErrorKind::Busy,
-            "Operation expired" => ErrorKind::Expired,
-            "Operation failed. Try again." => ErrorKind::TryAgain,
-            "Compaction too large" => ErrorKind::CompactionTooLarge,
-            "Column family dropped" => ErrorKind::ColumnFamilyDropped,
-            _ => ErrorKind::Unknown,
-        }
+        self.kind
+    }
+
+    /// Returns the subcode of the error, [`ErrorSubCode::None`] if it has none.
+    pub fn subcode(&self) -> ErrorSubCode {
+        self.subcode
+    }
+
+    /// Returns the severity of the error. Only errors of background work
+    /// have a severity, others report [`ErrorSeverity::NoError`].
+    pub fn severity(&self) -> ErrorSeverity {
+        self.severity
+    }
+
+    /// Whether the operation may succeed if retried as is, e.g. a transaction
+    /// that failed on a write conflict, a deadlock or a lock timeout, or an
+    /// I/O error the file system marked as retryable.
+    pub fn is_retryable(&self) -> bool {
+        self.retryable
+            || matches!(
+                self.kind,
+                ErrorKind::Busy | ErrorKind::TimedOut | ErrorKind::TryAgain
+            )
+    }
+
+    /// Whether a lock on a key could not be acquired in time.
+    pub fn is_lock_timeout(&self) -> bool {
+        self.kind == ErrorKind::TimedOut && self.subcode == ErrorSubCode::LockTimeout
+    }
+
+    /// Whether acquiring a lock would have caused a deadlock.
+    pub fn is_deadlock(&self) -> bool {
+        self.kind == ErrorKind::Busy && self.subcode == ErrorSubCode::Deadlock
+    }
+
+    /// Whether the device ran out of space.
+    pub fn is_no_space(&self) -> bool {
+        self.kind == ErrorKind::IOError && self.subcode == ErrorSubCode::NoSpace
+    }
+
+    /// Whether a memory limit, such as the transaction lock memory limit, was
+    /// reached.
+    pub fn is_memory_limit(&self) -> bool {
+        self.kind == ErrorKind::Aborted && self.subcode == ErrorSubCode::MemoryLimit
     }
 }
 
@@ -228,8 +460,9 @@ impl fmt::Display for Error {
 #[cfg(test)]
 mod test {
     use crate::{
-        OptimisticTransactionDB, OptimisticTransactionOptions, Transaction, TransactionDB,
-        TransactionDBOptions, TransactionOptions, WriteBufferManager,
+        FaultInjectionEnv, OptimisticTransactionDB, OptimisticTransactionOptions, RateLimiter,
+        SstFileManager, Statistics, Transaction, TransactionDB, TransactionDBOptions,
+        TransactionOptions, WriteBufferManager,
     };
 
     use super::{
@@ -237,8 +470,9 @@ mod test {
         db_options::CacheWrapper,
         env::{Env, EnvWrapper},
         BlockBasedOptions, BoundColumnFamily, Cache, ColumnFamily, ColumnFamilyDescriptor,
-        DBIterator, DBRawIterator, IngestExternalFileOptions, Options, PlainTableFactoryOptions,
-        ReadOptions, Snapshot, SstFileWriter, WriteBatch, WriteOptions, DB,
+        CompactionOptions, DBIterator, DBRawIterator, Error, ErrorKind, ErrorSeverity,
+        ErrorSubCode, IngestExternalFileOptions, Options, PlainTableFactoryOptions, ReadOptions,
+        Snapshot, SstFileWriter, WriteBatch, WriteOptions, DB,
     };
 
     #[test]
@@ -258,6 +492,7 @@ mod test {
         is_send::<ReadOptions>();
         is_send::<WriteOptions>();
         is_send::<IngestExternalFileOptions>();
+        is_send::<CompactionOptions>();
         is_send::<BlockBasedOptions>();
         is_send::<PlainTableFactoryOptions>();
         is_send::<ColumnFamilyDescriptor>();
@@ -277,6 +512,10 @@ mod test {
         is_send::<OptimisticTransactionOptions>();
         is_send::<TransactionOptions>();
         is_send::<WriteBufferManager>();
+        is_send::<Statistics>();
+        is_send::<RateLimiter>();
+        is_send::<SstFileManager>();
+        is_send::<FaultInjectionEnv>();
     }
 
     #[test]
@@ -293,6 +532,7 @@ mod test {
         is_sync::<ReadOptions>();
         is_sync::<WriteOptions>();
         is_sync::<IngestExternalFileOptions>();
+        is_sync::<CompactionOptions>();
         is_sync::<BlockBasedOptions>();
         is_sync::<PlainTableFactoryOptions>();
         is_sync::<UnboundColumnFamily>();
@@ -308,5 +548,37 @@ mod test {
         is_sync::<OptimisticTransactionOptions>();
         is_sync::<TransactionOptions>();
         is_sync::<WriteBufferManager>();
+        is_sync::<Statistics>();
+        is_sync::<RateLimiter>();
+        is_sync::<SstFileManager>();
+        is_sync::<FaultInjectionEnv>();
+    }
+
+    #[test]
+    fn error_code_and_subcode() {
+        assert_eq!(ErrorKind::from_raw(9), ErrorKind::TimedOut);
+        assert_eq!(ErrorSubCode::from_raw(2), ErrorSubCode::LockTimeout);
+        assert_eq!(ErrorKind::from_raw(13), ErrorKind::TryAgain);
+        assert_eq!(ErrorSubCode::from_raw(0), ErrorSubCode::None);
+        assert_eq!(
+            ErrorSubCode::from_raw(15),
+            ErrorSubCode::MergeOperatorFailed
+        );
+        assert_eq!(ErrorSeverity::from_raw(2), ErrorSeverity::HardError);
+        for raw in 1..=15 {
+            assert_eq!(ErrorKind::from_raw(raw).to_raw(), raw);
+        }
+        for raw in 0..=15 {
+            assert_eq!(ErrorSubCode::from_raw(raw).to_raw(), raw);
+        }
+        assert_eq!(ErrorKind::Unknown.to_raw(), ErrorKind::IOError.to_raw());
+
+        let err = Error::with_kind(ErrorKind::IOError, "IO error: cannot open".to_owned());
+        assert_eq!(err.kind(), ErrorKind::IOError);
+        assert!(!err.is_retryable());
+
+        let err = Error::new("Invalid column family: cf1".to_owned());
+        assert_eq!(err.kind(), ErrorKind::Unknown);
+        assert_eq!(err.subcode(), ErrorSubCode::None);
     }
 }
@@ -0,0 +1,74 @@
+//! Routing the info LOG of a database to Rust code.
+//!
+//! Register a callback with [`Options::set_logger`](crate::Options::set_logger).
+//! With the `log` or `tracing` feature enabled, `log_crate` and
+//! `tracing_crate` forward the messages to those crates.
+use crate::LogLevel;
+use libc::{c_char, c_int, c_void, size_t};
+use std::slice;
+
+/// Forwards a message to the [`log`](https://docs.rs/log) crate, with the
+/// `rocksdb` target. `Fatal` messages are logged as errors and `Header`
+/// messages as info.
+///
+/// # Examples
+///
+/// ```
+/// use rocksdb::{logger, LogLevel, Options};
+///
+/// let mut opts = Options::default();
+/// opts.set_logger(LogLevel::Info, logger::log_crate);
+/// ```
+#[cfg(feature = "log")]
+pub fn log_crate(level: LogLevel, message: &str) {
+    let level = match level {
+        LogLevel::Debug => log::Level::Debug,
+        LogLevel::Info | LogLevel::Header => log::Level::Info,
+        LogLevel::Warn => log::Level::Warn,
+        LogLevel::Error | LogLevel::Fatal => log::Level::Error,
+    };
+    log::log!(target: "rocksdb", level, "{message}");
+}
+
+/// Forwards a message to the [`tracing`](https://docs.rs/tracing) crate as an
+/// event with the `rocksdb` target. `Fatal` messages are recorded as errors
+/// and `Header` messages as info.
+///
+/// # Examples
+///
+/// ```
+/// use rocksdb::{logger, LogLevel, Options};
+///
+/// let mut opts = Options::default();
+/// opts.set_logger(LogLevel::Info, logger::tracing_crate);
+/// ```
+#[cfg(feature = "tracing")]
+pub fn tracing_crate(level: LogLevel, message: &str) {
+    match level {
+        LogLevel::Debug => tracing::debug!(target: "rocksdb", "{message}"),
+        LogLevel::Info | LogLevel::Header => tracing::info!(target: "rocksdb", "{message}"),
+        LogLevel::Warn => tracing::warn!(target: "rocksdb", "{message}"),
+        LogLevel::Error | LogLevel::Fatal => tracing::error!(target: "rocksdb", "{message}"),
+    }
+}
+
+pub(crate) unsafe extern "C" fn destructor_callback<F>(raw_cb: *mut c_void)
+where
+    F: Fn(LogLevel, &str),
+{
+    drop(Box::from_raw(raw_cb as *mut F));
+}
+
+pub(crate) unsafe extern "C" fn log_callback<F>(
+    raw_cb: *mut c_void,
+    level: c_int,
+    message: *const c_char,
+    message_len: size_t,
+) where
+    F: Fn(LogLevel, &str),
+{
+    let cb = &*(raw_cb as *mut F);
+    let message = slice::from_raw_parts(message as *const u8, message_len);
+    let message = String::from_utf8_lossy(message);
+    cb(LogLevel::from_raw(level), message.trim_end_matches('\n'));
+}
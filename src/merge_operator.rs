@@ -55,12 +55,119 @@
 //!let _ = DB::destroy(&opts, path);
 //! ```
 
-use libc::{self, c_char, c_int, c_void, size_t};
-use std::ffi::CString;
+use libc::{self, c_char, c_int, c_uchar, c_void, size_t};
+use std::collections::BTreeSet;
+use std::convert::TryFrom;
+use std::ffi::{CStr, CString};
+use std::fmt;
 use std::mem;
 use std::ptr;
 use std::slice;
 
+use crate::ffi;
+
+/// A merge operator, which combines the operands written with `merge` into
+/// the value of a key, see
+/// [`Options::set_merge_operator_trait`](crate::Options::set_merge_operator_trait).
+///
+/// Methods are called concurrently, from reads, flushes and compactions.
+pub trait MergeOperator: Send + Sync {
+    /// Returns a name that identifies this merge operator, which must not
+    /// change between opens of the database.
+    fn name(&self) -> &CStr;
+
+    /// Applies the operands, oldest first, to the existing value of `key`,
+    /// `None` if the key does not exist or was deleted.
+    fn full_merge(
+        &self,
+        key: &[u8],
+        existing_value: Option<&[u8]>,
+        operands: &MergeOperands,
+    ) -> Result<Vec<u8>, MergeError>;
+
+    /// Combines two consecutive operands into one, if they can be combined
+    /// without knowing the existing value.
+    ///
+    /// The default implementation never combines operands.
+    fn partial_merge(&self, key: &[u8], left: &[u8], right: &[u8]) -> Option<Vec<u8>> {
+        let _ = (key, left, right);
+        None
+    }
+
+    /// Combines two or more consecutive operands, oldest first, into one.
+    /// This is what RocksDB calls when it combines operands.
+    ///
+    /// The default implementation combines them pairwise with
+    /// [`partial_merge`](MergeOperator::partial_merge).
+    fn partial_merge_multi(&self, key: &[u8], operands: &MergeOperands) -> Option<Vec<u8>> {
+        let mut operands = operands.iter();
+        let first = operands.next()?;
+        let mut merged = self.partial_merge(key, first, operands.next()?)?;
+        for operand in operands {
+            merged = self.partial_merge(key, &merged, operand)?;
+        }
+        Some(merged)
+    }
+
+    /// Returns whether the operands read so far, newest first, are enough to
+    /// merge the value of a key, in which case reads stop looking for older
+    /// operands and the existing value, and call
+    /// [`full_merge`](MergeOperator::full_merge) without it. This lets e.g.
+    /// an operator that keeps the latest `N` operands stop reading after `N`.
+    ///
+    /// The default implementation reads all the operands.
+    fn should_merge(&self, operands: &MergeOperands) -> bool {
+        let _ = operands;
+        false
+    }
+
+    /// Returns whether [`partial_merge`](MergeOperator::partial_merge) and
+    /// [`partial_merge_multi`](MergeOperator::partial_merge_multi) may also
+    /// be called with a single operand, e.g. to normalize it, or with a
+    /// `Put` value as the first operand.
+    ///
+    /// Default: false
+    fn allow_single_operand(&self) -> bool {
+        false
+    }
+}
+
+/// Why [`MergeOperator::full_merge`] failed. Its message is written to the
+/// info log, and the failed operation returns a `Corruption` error.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MergeError {
+    /// Fails all the operations that merge the key, including flushes and
+    /// compactions, which put the database in read-only mode.
+    TryMerge(String),
+    /// Only fails the operations that must merge the key, like reads.
+    /// Flushes and compactions keep the operands as they are.
+    MustMerge(String),
+}
+
+impl MergeError {
+    // Values of `MergeOperator::OpFailureScope` in cpp.
+    fn op_failure_scope(&self) -> c_int {
+        match self {
+            MergeError::TryMerge(_) => 1,
+            MergeError::MustMerge(_) => 2,
+        }
+    }
+
+    fn message(&self) -> &str {
+        match self {
+            MergeError::TryMerge(message) | MergeError::MustMerge(message) => message,
+        }
+    }
+}
+
+impl fmt::Display for MergeError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        self.message().fmt(formatter)
+    }
+}
+
+impl std::error::Error for MergeError {}
+
 pub trait MergeFn:
     Fn(&[u8], Option<&[u8]>, &MergeOperands) -> Option<Vec<u8>> + Send + Sync + 'static
 {
@@ -76,10 +183,38 @@ pub struct MergeOperatorCallback<F: MergeFn, PF: MergeFn> {
     pub partial_merge_fn: PF,
 }
 
+impl<F: MergeFn, PF: MergeFn> MergeOperator for MergeOperatorCallback<F, PF> {
+    fn name(&self) -> &CStr {
+        self.name.as_c_str()
+    }
+
+    fn full_merge(
+        &self,
+        key: &[u8],
+        existing_value: Option<&[u8]>,
+        operands: &MergeOperands,
+    ) -> Result<Vec<u8>, MergeError> {
+        (self.full_merge_fn)(key, existing_value, operands)
+            .ok_or_else(|| MergeError::TryMerge("merge function returned None".to_owned()))
+    }
+
+    fn partial_merge_multi(&self, key: &[u8], operands: &MergeOperands) -> Option<Vec<u8>> {
+        (self.partial_merge_fn)(key, None, operands)
+    }
+}
+
+#[deprecated(
+    since = "0.21.0",
+    note = "merge operators are created through `Options::set_merge_operator_trait`"
+)]
 pub unsafe extern "C" fn destructor_callback<F: MergeFn, PF: MergeFn>(raw_cb: *mut c_void) {
     drop(Box::from_raw(raw_cb as *mut MergeOperatorCallback<F, PF>));
 }
 
+#[deprecated(
+    since = "0.21.0",
+    note = "merge operators are created through `Options::set_merge_operator_trait`"
+)]
 pub unsafe extern "C" fn delete_callback(
     _raw_cb: *mut c_void,
     value: *const c_char,
@@ -93,6 +228,10 @@ pub unsafe extern "C" fn delete_callback(
     }
 }
 
+#[deprecated(
+    since = "0.21.0",
+    note = "merge operators are created through `Options::set_merge_operator_trait`"
+)]
 pub unsafe extern "C" fn name_callback<F: MergeFn, PF: MergeFn>(
     raw_cb: *mut c_void,
 ) -> *const c_char {
@@ -100,6 +239,10 @@ pub unsafe extern "C" fn name_callback<F: MergeFn, PF: MergeFn>(
     cb.name.as_ptr()
 }
 
+#[deprecated(
+    since = "0.21.0",
+    note = "merge operators are created through `Options::set_merge_operator_trait`"
+)]
 pub unsafe extern "C" fn full_merge_callback<F: MergeFn, PF: MergeFn>(
     raw_cb: *mut c_void,
     raw_key: *const c_char,
@@ -137,6 +280,10 @@ pub unsafe extern "C" fn full_merge_callback<F: MergeFn, PF: MergeFn>(
     )
 }
 
+#[deprecated(
+    since = "0.21.0",
+    note = "merge operators are created through `Options::set_merge_operator_trait`"
+)]
 pub unsafe extern "C" fn partial_merge_callback<F: MergeFn, PF: MergeFn>(
     raw_cb: *mut c_void,
     raw_key: *const c_char,
@@ -164,6 +311,133 @@ pub unsafe extern "C" fn partial_merge_callback<F: MergeFn, PF: MergeFn>(
     )
 }
 
+/// Creates the cpp merge operator calling `merge_operator`, owned by the
+/// returned pointer.
+pub(crate) fn create_merge_operator<M>(merge_operator: M) -> *mut ffi::rocksdb_mergeoperator_v2_t
+where
+    M: MergeOperator + 'static,
+{
+    let allow_single_operand = merge_operator.allow_single_operand();
+    unsafe {
+        ffi::rocksdb_mergeoperator_create_v2(
+            Box::into_raw(Box::new(merge_operator)).cast::<c_void>(),
+            Some(destructor_v2_callback::<M>),
+            Some(full_merge_v2_callback::<M>),
+            Some(partial_merge_v2_callback::<M>),
+            Some(partial_merge_multi_v2_callback::<M>),
+            Some(should_merge_v2_callback::<M>),
+            c_uchar::from(allow_single_operand),
+            Some(name_v2_callback::<M>),
+        )
+    }
+}
+
+unsafe fn set_value(
+    result: *mut ffi::rocksdb_mergeoperator_result_t,
+    value: Option<Vec<u8>>,
+) -> c_uchar {
+    match value {
+        Some(value) => {
+            ffi::rocksdb_mergeoperator_result_set_value(
+                result,
+                value.as_ptr() as *const c_char,
+                value.len() as size_t,
+            );
+            1
+        }
+        None => 0,
+    }
+}
+
+unsafe extern "C" fn destructor_v2_callback<M: MergeOperator>(raw_cb: *mut c_void) {
+    drop(Box::from_raw(raw_cb as *mut M));
+}
+
+unsafe extern "C" fn name_v2_callback<M: MergeOperator>(raw_cb: *mut c_void) -> *const c_char {
+    let cb = &*(raw_cb as *mut M);
+    cb.name().as_ptr()
+}
+
+unsafe extern "C" fn full_merge_v2_callback<M: MergeOperator>(
+    raw_cb: *mut c_void,
+    raw_key: *const c_char,
+    key_len: size_t,
+    existing_value: *const c_char,
+    existing_value_len: size_t,
+    operands_list: *const *const c_char,
+    operands_list_len: *const size_t,
+    num_operands: c_int,
+    result: *mut ffi::rocksdb_mergeoperator_result_t,
+) -> c_uchar {
+    let cb = &*(raw_cb as *mut M);
+    let operands = &MergeOperands::new(operands_list, operands_list_len, num_operands);
+    let key = slice::from_raw_parts(raw_key as *const u8, key_len);
+    let oldval = if existing_value.is_null() {
+        None
+    } else {
+        Some(slice::from_raw_parts(
+            existing_value as *const u8,
+            existing_value_len,
+        ))
+    };
+    match cb.full_merge(key, oldval, operands) {
+        Ok(value) => set_value(result, Some(value)),
+        Err(error) => {
+            let message = error.message();
+            ffi::rocksdb_mergeoperator_result_set_error(
+                result,
+                error.op_failure_scope(),
+                message.as_ptr() as *const c_char,
+                message.len() as size_t,
+            );
+            0
+        }
+    }
+}
+
+unsafe extern "C" fn partial_merge_v2_callback<M: MergeOperator>(
+    raw_cb: *mut c_void,
+    raw_key: *const c_char,
+    key_len: size_t,
+    left: *const c_char,
+    left_len: size_t,
+    right: *const c_char,
+    right_len: size_t,
+    result: *mut ffi::rocksdb_mergeoperator_result_t,
+) -> c_uchar {
+    let cb = &*(raw_cb as *mut M);
+    let key = slice::from_raw_parts(raw_key as *const u8, key_len);
+    let left = slice::from_raw_parts(left as *const u8, left_len);
+    let right = slice::from_raw_parts(right as *const u8, right_len);
+    set_value(result, cb.partial_merge(key, left, right))
+}
+
+unsafe extern "C" fn partial_merge_multi_v2_callback<M: MergeOperator>(
+    raw_cb: *mut c_void,
+    raw_key: *const c_char,
+    key_len: size_t,
+    operands_list: *const *const c_char,
+    operands_list_len: *const size_t,
+    num_operands: c_int,
+    result: *mut ffi::rocksdb_mergeoperator_result_t,
+) -> c_uchar {
+    let cb = &*(raw_cb as *mut M);
+    let operands = &MergeOperands::new(operands_list, operands_list_len, num_operands);
+    let key = slice::from_raw_parts(raw_key as *const u8, key_len);
+    set_value(result, cb.partial_merge_multi(key, operands))
+}
+
+unsafe extern "C" fn should_merge_v2_callback<M: MergeOperator>(
+    raw_cb: *mut c_void,
+    operands_list: *const *const c_char,
+    operands_list_len: *const size_t,
+    num_operands: c_int,
+) -> c_uchar {
+    let cb = &*(raw_cb as *mut M);
+    let operands = &MergeOperands::new(operands_list, operands_list_len, num_operands);
+    c_uchar::from(cb.should_merge(operands))
+}
+
 pub struct MergeOperands {
     operands_list: *const *const c_char,
     operands_list_len: *const size_t,
@@ -251,3 +525,308 @@ impl<'a> IntoIterator for &'a MergeOperands {
         }
     }
 }
+
+fn static_name(name: &'static [u8]) -> &'static CStr {
+    CStr::from_bytes_with_nul(name).unwrap()
+}
+
+fn decode_fixed64(operator: &str, value: &[u8]) -> Result<[u8; 8], MergeError> {
+    <[u8; 8]>::try_from(value).map_err(|_| {
+        MergeError::MustMerge(format!(
+            "{}: expected a value of 8 bytes, got {}",
+            operator,
+            value.len()
+        ))
+    })
+}
+
+/// Adds unsigned 64-bit integers encoded in 8 little-endian bytes, wrapping
+/// around on overflow. A missing value counts as 0. Same format as the
+/// `UInt64AddOperator` of RocksDB, see [`DB::increment`](crate::DB::increment).
+///
+/// # Examples
+///
+/// ```
+/// use rocksdb::{merge_operator::UInt64AddOperator, Options, DB};
+///
+/// # let path = "_rust_rocksdb_uint64_add_doc";
+/// let mut opts = Options::default();
+/// opts.create_if_missing(true);
+/// opts.set_merge_operator_trait(UInt64AddOperator);
+/// let db = DB::open(&opts, path).unwrap();
+/// db.increment(b"visits", 1).unwrap();
+/// db.increment(b"visits", 2).unwrap();
+/// assert_eq!(db.get(b"visits").unwrap().unwrap(), 3u64.to_le_bytes());
+/// # drop(db);
+/// # DB::destroy(&opts, path).unwrap();
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UInt64AddOperator;
+
+impl UInt64AddOperator {
+    fn sum<'a>(mut values: impl Iterator<Item = &'a [u8]>) -> Result<u64, MergeError> {
+        values.try_fold(0_u64, |sum, value| {
+            let value = decode_fixed64("UInt64AddOperator", value)?;
+            Ok(sum.wrapping_add(u64::from_le_bytes(value)))
+        })
+    }
+}
+
+impl MergeOperator for UInt64AddOperator {
+    fn name(&self) -> &CStr {
+        static_name(b"UInt64AddOperator\0")
+    }
+
+    fn full_merge(
+        &self,
+        _key: &[u8],
+        existing_value: Option<&[u8]>,
+        operands: &MergeOperands,
+    ) -> Result<Vec<u8>, MergeError> {
+        let sum = Self::sum(existing_value.into_iter().chain(operands))?;
+        Ok(sum.to_le_bytes().to_vec())
+    }
+
+    fn partial_merge_multi(&self, _key: &[u8], operands: &MergeOperands) -> Option<Vec<u8>> {
+        let sum = Self::sum(operands.iter()).ok()?;
+        Some(sum.to_le_bytes().to_vec())
+    }
+}
+
+/// Adds signed 64-bit integers encoded in 8 little-endian bytes, wrapping
+/// around on overflow. A missing value counts as 0, see
+/// [`DB::increment_i64`](crate::DB::increment_i64).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Int64AddOperator;
+
+impl Int64AddOperator {
+    fn sum<'a>(mut values: impl Iterator<Item = &'a [u8]>) -> Result<i64, MergeError> {
+        values.try_fold(0_i64, |sum, value| {
+            let value = decode_fixed64("Int64AddOperator", value)?;
+            Ok(sum.wrapping_add(i64::from_le_bytes(value)))
+        })
+    }
+}
+
+impl MergeOperator for Int64AddOperator {
+    fn name(&self) -> &CStr {
+        static_name(b"Int64AddOperator\0")
+    }
+
+    fn full_merge(
+        &self,
+        _key: &[u8],
+        existing_value: Option<&[u8]>,
+        operands: &MergeOperands,
+    ) -> Result<Vec<u8>, MergeError> {
+        let sum = Self::sum(existing_value.into_iter().chain(operands))?;
+        Ok(sum.to_le_bytes().to_vec())
+    }
+
+    fn partial_merge_multi(&self, _key: &[u8], operands: &MergeOperands) -> Option<Vec<u8>> {
+        let sum = Self::sum(operands.iter()).ok()?;
+        Some(sum.to_le_bytes().to_vec())
+    }
+}
+
+/// Appends each operand to the value, separated by a delimiter, like the
+/// `StringAppendOperator` of RocksDB.
+///
+/// # Examples
+///
+/// ```
+/// use rocksdb::{merge_operator::StringAppendOperator, Options, DB};
+///
+/// # let path = "_rust_rocksdb_string_append_doc";
+/// let mut opts = Options::default();
+/// opts.create_if_missing(true);
+/// opts.set_merge_operator_trait(StringAppendOperator::new(","));
+/// let db = DB::open(&opts, path).unwrap();
+/// db.merge(b"tags", b"a").unwrap();
+/// db.merge(b"tags", b"b").unwrap();
+/// assert_eq!(db.get(b"tags").unwrap().unwrap(), b"a,b");
+/// # drop(db);
+/// # DB::destroy(&opts, path).unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct StringAppendOperator {
+    delimiter: Vec<u8>,
+}
+
+impl StringAppendOperator {
+    /// Creates a new `StringAppendOperator` separating values with
+    /// `delimiter`, which may be empty.
+    pub fn new(delimiter: impl Into<Vec<u8>>) -> StringAppendOperator {
+        StringAppendOperator {
+            delimiter: delimiter.into(),
+        }
+    }
+
+    fn append<'a>(&self, values: impl Iterator<Item = &'a [u8]>) -> Vec<u8> {
+        let values: Vec<&[u8]> = values.collect();
+        values.join(self.delimiter.as_slice())
+    }
+}
+
+impl MergeOperator for StringAppendOperator {
+    fn name(&self) -> &CStr {
+        static_name(b"StringAppendOperator\0")
+    }
+
+    fn full_merge(
+        &self,
+        _key: &[u8],
+        existing_value: Option<&[u8]>,
+        operands: &MergeOperands,
+    ) -> Result<Vec<u8>, MergeError> {
+        Ok(self.append(existing_value.into_iter().chain(operands)))
+    }
+
+    fn partial_merge_multi(&self, _key: &[u8], operands: &MergeOperands) -> Option<Vec<u8>> {
+        Some(self.append(operands.iter()))
+    }
+}
+
+/// Keeps the bytewise greatest of the value and the operands, like the
+/// `MaxOperator` of RocksDB.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MaxOperator;
+
+impl MergeOperator for MaxOperator {
+    fn name(&self) -> &CStr {
+        static_name(b"MaxOperator\0")
+    }
+
+    fn full_merge(
+        &self,
+        _key: &[u8],
+        existing_value: Option<&[u8]>,
+        operands: &MergeOperands,
+    ) -> Result<Vec<u8>, MergeError> {
+        let max = existing_value.into_iter().chain(operands).max();
+        Ok(max.unwrap_or_default().to_vec())
+    }
+
+    fn partial_merge_multi(&self, _key: &[u8], operands: &MergeOperands) -> Option<Vec<u8>> {
+        operands.iter().max().map(<[u8]>::to_vec)
+    }
+}
+
+/// Keeps the bytewise smallest of the value and the operands.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MinOperator;
+
+impl MergeOperator for MinOperator {
+    fn name(&self) -> &CStr {
+        static_name(b"MinOperator\0")
+    }
+
+    fn full_merge(
+        &self,
+        _key: &[u8],
+        existing_value: Option<&[u8]>,
+        operands: &MergeOperands,
+    ) -> Result<Vec<u8>, MergeError> {
+        let min = existing_value.into_iter().chain(operands).min();
+        Ok(min.unwrap_or_default().to_vec())
+    }
+
+    fn partial_merge_multi(&self, _key: &[u8], operands: &MergeOperands) -> Option<Vec<u8>> {
+        operands.iter().min().map(<[u8]>::to_vec)
+    }
+}
+
+/// Merges sets of byte strings into their union, sorted bytewise and
+/// without duplicates. The value and the operands are sets encoded with
+/// [`encode`](SortedSetUnionOperator::encode), each element as its length in
+/// 4 little-endian bytes followed by its bytes.
+///
+/// # Examples
+///
+/// ```
+/// use rocksdb::{merge_operator::SortedSetUnionOperator, Options, DB};
+///
+/// # let path = "_rust_rocksdb_sorted_set_union_doc";
+/// let mut opts = Options::default();
+/// opts.create_if_missing(true);
+/// opts.set_merge_operator_trait(SortedSetUnionOperator);
+/// let db = DB::open(&opts, path).unwrap();
+/// db.merge(b"set", SortedSetUnionOperator::encode(["b", "a"])).unwrap();
+/// db.merge(b"set", SortedSetUnionOperator::encode(["c", "a"])).unwrap();
+/// let set = db.get(b"set").unwrap().unwrap();
+/// assert_eq!(
+///     SortedSetUnionOperator::decode(&set).unwrap(),
+///     [&b"a"[..], b"b", b"c"]
+/// );
+/// # drop(db);
+/// # DB::destroy(&opts, path).unwrap();
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SortedSetUnionOperator;
+
+impl SortedSetUnionOperator {
+    /// Encodes `elements` as a set, sorting them and removing duplicates.
+    pub fn encode<I, E>(elements: I) -> Vec<u8>
+    where
+        I: IntoIterator<Item = E>,
+        E: AsRef<[u8]>,
+    {
+        let elements: Vec<E> = elements.into_iter().collect();
+        let set: BTreeSet<&[u8]> = elements.iter().map(AsRef::as_ref).collect();
+        Self::encode_set(&set)
+    }
+
+    /// Decodes a set encoded with [`encode`](SortedSetUnionOperator::encode),
+    /// or returns `None` if `value` is not a valid encoding.
+    pub fn decode(value: &[u8]) -> Option<Vec<&[u8]>> {
+        let mut elements = Vec::new();
+        let mut rest = value;
+        while !rest.is_empty() {
+            let len = <[u8; 4]>::try_from(rest.get(..4)?).ok()?;
+            let len = usize::try_from(u32::from_le_bytes(len)).ok()?;
+            elements.push(rest.get(4..4 + len)?);
+            rest = &rest[4 + len..];
+        }
+        Some(elements)
+    }
+
+    fn encode_set(set: &BTreeSet<&[u8]>) -> Vec<u8> {
+        let mut encoded = Vec::with_capacity(set.iter().map(|element| 4 + element.len()).sum());
+        for element in set {
+            let len = u32::try_from(element.len()).expect("set element larger than 4GiB");
+            encoded.extend_from_slice(&len.to_le_bytes());
+            encoded.extend_from_slice(element);
+        }
+        encoded
+    }
+
+    fn union<'a>(values: impl Iterator<Item = &'a [u8]>) -> Result<Vec<u8>, MergeError> {
+        let mut set = BTreeSet::new();
+        for value in values {
+            let elements = Self::decode(value).ok_or_else(|| {
+                MergeError::MustMerge("SortedSetUnionOperator: invalid set encoding".to_owned())
+            })?;
+            set.extend(elements);
+        }
+        Ok(Self::encode_set(&set))
+    }
+}
+
+impl MergeOperator for SortedSetUnionOperator {
+    fn name(&self) -> &CStr {
+        static_name(b"SortedSetUnionOperator\0")
+    }
+
+    fn full_merge(
+        &self,
+        _key: &[u8],
+        existing_value: Option<&[u8]>,
+        operands: &MergeOperands,
+    ) -> Result<Vec<u8>, MergeError> {
+        Self::union(existing_value.into_iter().chain(operands))
+    }
+
+    fn partial_merge_multi(&self, _key: &[u8], operands: &MergeOperands) -> Option<Vec<u8>> {
+        Self::union(operands.iter()).ok()
+    }
+}
@@ -0,0 +1,262 @@
+//! Structured description of the SST and blob files of a column family, as
+//! returned by [`DBCommon::column_family_metadata_cf`](crate::DBCommon::column_family_metadata_cf).
+use crate::ffi;
+use crate::ffi_util::from_cstr;
+use libc::{c_char, c_void, size_t};
+use std::slice;
+
+/// Storage temperature of a file, as assigned by tiered storage settings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Temperature {
+    Unknown,
+    Hot,
+    Warm,
+    Cold,
+}
+
+impl Temperature {
+    fn from_raw(raw: u8) -> Self {
+        match raw {
+            0x04 => Temperature::Hot,
+            0x08 => Temperature::Warm,
+            0x0C => Temperature::Cold,
+            _ => Temperature::Unknown,
+        }
+    }
+}
+
+/// The metadata of a column family: its levels and blob files.
+#[derive(Debug, Clone)]
+pub struct ColumnFamilyMetaData {
+    /// Name of the column family
+    pub name: String,
+    /// Total size of the SST files of all levels in bytes
+    pub size: u64,
+    /// Number of SST files
+    pub file_count: usize,
+    /// Metadata of each level, starting at L0
+    pub levels: Vec<LevelMetaData>,
+    /// Total size of the blob files in bytes
+    pub blob_file_size: u64,
+    /// Metadata of each blob file
+    pub blob_files: Vec<BlobMetaData>,
+}
+
+/// The metadata of a single level of a column family
+#[derive(Debug, Clone)]
+pub struct LevelMetaData {
+    /// The level this metadata describes
+    pub level: i32,
+    /// Total size of the SST files in this level in bytes
+    pub size: u64,
+    /// Metadata of the SST files in this level
+    pub files: Vec<SstFileMetaData>,
+}
+
+/// The metadata of a single SST file
+#[derive(Debug, Clone)]
+pub struct SstFileMetaData {
+    /// Name of the file within the DB directory, e.g. `000123.sst`
+    pub relative_filename: String,
+    /// Number of the file
+    pub file_number: u64,
+    /// Size of the file in bytes
+    pub size: u64,
+    /// Storage temperature of the file
+    pub temperature: Temperature,
+    /// Smallest user defined key in the file
+    pub smallest_key: Vec<u8>,
+    /// Largest user defined key in the file
+    pub largest_key: Vec<u8>,
+    /// Smallest sequence number in the file
+    pub smallest_seqno: u64,
+    /// Largest sequence number in the file
+    pub largest_seqno: u64,
+    /// How many times the file has been read, sampled
+    pub num_reads_sampled: u64,
+    /// Whether the file is currently being compacted
+    pub being_compacted: bool,
+    /// Number of entries in the file
+    pub num_entries: u64,
+    /// Number of deletions in the file
+    pub num_deletions: u64,
+    /// Number of the oldest blob file referenced by the file, 0 if none
+    pub oldest_blob_file_number: u64,
+    /// Creation time of the oldest SST file this file was compacted from, in
+    /// seconds since the epoch. 0 if unknown.
+    pub oldest_ancestor_time: u64,
+    /// Creation time of the file in seconds since the epoch. 0 if unknown.
+    pub file_creation_time: u64,
+    /// Order in which the file was flushed or ingested. For L0, a larger
+    /// epoch number means a newer file. 0 if unknown.
+    pub epoch_number: u64,
+    /// Checksum of the file content, if file checksums are enabled
+    pub file_checksum: Vec<u8>,
+    /// Name of the function used to compute `file_checksum`
+    pub file_checksum_func_name: String,
+}
+
+/// The metadata of a single blob file
+#[derive(Debug, Clone)]
+pub struct BlobMetaData {
+    /// Number of the file
+    pub file_number: u64,
+    /// Name of the file
+    pub file_name: String,
+    /// Directory containing the file
+    pub file_path: String,
+    /// Size of the file in bytes
+    pub file_size: u64,
+    /// Number of blobs in the file
+    pub total_blob_count: u64,
+    /// Total size of the blobs in the file in bytes
+    pub total_blob_bytes: u64,
+    /// Number of blobs no longer referenced by any SST file
+    pub garbage_blob_count: u64,
+    /// Total size of the blobs no longer referenced by any SST file in bytes
+    pub garbage_blob_bytes: u64,
+}
+
+unsafe fn owned_string(ptr: *mut c_char) -> String {
+    let s = from_cstr(ptr);
+    ffi::rocksdb_free(ptr as *mut c_void);
+    s
+}
+
+unsafe fn owned_bytes(ptr: *mut c_char, len: size_t) -> Vec<u8> {
+    let bytes = slice::from_raw_parts(ptr as *const u8, len).to_vec();
+    ffi::rocksdb_free(ptr as *mut c_void);
+    bytes
+}
+
+unsafe fn borrowed_bytes(ptr: *const c_char, len: size_t) -> Vec<u8> {
+    slice::from_raw_parts(ptr as *const u8, len).to_vec()
+}
+
+unsafe fn borrowed_string(ptr: *const c_char, len: size_t) -> String {
+    String::from_utf8_lossy(slice::from_raw_parts(ptr as *const u8, len)).into_owned()
+}
+
+impl ColumnFamilyMetaData {
+    /// Copies the metadata out of `ptr`, which is destroyed afterwards.
+    ///
+    /// # Unsafe
+    /// Requires that the pointer must be generated by
+    /// rocksdb_get_column_family_metadata or
+    /// rocksdb_get_column_family_metadata_cf
+    pub(crate) unsafe fn from_c(ptr: *mut ffi::rocksdb_column_family_metadata_t) -> Self {
+        let level_count = ffi::rocksdb_column_family_metadata_get_level_count(ptr);
+        let levels = (0..level_count)
+            .map(|i| {
+                let level = ffi::rocksdb_column_family_metadata_get_level_metadata(ptr, i);
+                let metadata = LevelMetaData::from_c(level);
+                ffi::rocksdb_level_metadata_destroy(level);
+                metadata
+            })
+            .collect();
+        let blob_file_count = ffi::rocksdb_column_family_metadata_get_blob_file_count(ptr);
+        let blob_files = (0..blob_file_count)
+            .map(|i| {
+                let blob = ffi::rocksdb_column_family_metadata_get_blob_file_metadata(ptr, i);
+                let metadata = BlobMetaData::from_c(blob);
+                ffi::rocksdb_blob_metadata_destroy(blob);
+                metadata
+            })
+            .collect();
+        let metadata = ColumnFamilyMetaData {
+            name: owned_string(ffi::rocksdb_column_family_metadata_get_name(ptr)),
+            size: ffi::rocksdb_column_family_metadata_get_size(ptr),
+            file_count: ffi::rocksdb_column_family_metadata_get_file_count(ptr),
+            levels,
+            blob_file_size: ffi::rocksdb_column_family_metadata_get_blob_file_size(ptr),
+            blob_files,
+        };
+        ffi::rocksdb_column_family_metadata_destroy(ptr);
+        metadata
+    }
+}
+
+impl LevelMetaData {
+    unsafe fn from_c(ptr: *mut ffi::rocksdb_level_metadata_t) -> Self {
+        let file_count = ffi::rocksdb_level_metadata_get_file_count(ptr);
+        let files = (0..file_count)
+            .map(|i| {
+                let file = ffi::rocksdb_level_metadata_get_sst_file_metadata(ptr, i);
+                let metadata = SstFileMetaData::from_c(file);
+                ffi::rocksdb_sst_file_metadata_destroy(file);
+                metadata
+            })
+            .collect();
+        LevelMetaData {
+            level: ffi::rocksdb_level_metadata_get_level(ptr),
+            size: ffi::rocksdb_level_metadata_get_size(ptr),
+            files,
+        }
+    }
+}
+
+impl SstFileMetaData {
+    unsafe fn from_c(ptr: *mut ffi::rocksdb_sst_file_metadata_t) -> Self {
+        let mut len: size_t = 0;
+        let smallest_key = owned_bytes(
+            ffi::rocksdb_sst_file_metadata_get_smallestkey(ptr, &mut len),
+            len,
+        );
+        let largest_key = owned_bytes(
+            ffi::rocksdb_sst_file_metadata_get_largestkey(ptr, &mut len),
+            len,
+        );
+        let file_checksum = borrowed_bytes(
+            ffi::rocksdb_sst_file_metadata_get_file_checksum(ptr, &mut len),
+            len,
+        );
+        let file_checksum_func_name = borrowed_string(
+            ffi::rocksdb_sst_file_metadata_get_file_checksum_func_name(ptr, &mut len),
+            len,
+        );
+        SstFileMetaData {
+            relative_filename: owned_string(ffi::rocksdb_sst_file_metadata_get_relative_filename(
+                ptr,
+            )),
+            file_number: ffi::rocksdb_sst_file_metadata_get_file_number(ptr),
+            size: ffi::rocksdb_sst_file_metadata_get_size(ptr),
+            temperature: Temperature::from_raw(ffi::rocksdb_sst_file_metadata_get_temperature(ptr)),
+            smallest_key,
+            largest_key,
+            smallest_seqno: ffi::rocksdb_sst_file_metadata_get_smallest_seqno(ptr),
+            largest_seqno: ffi::rocksdb_sst_file_metadata_get_largest_seqno(ptr),
+            num_reads_sampled: ffi::rocksdb_sst_file_metadata_get_num_reads_sampled(ptr),
+            being_compacted: ffi::rocksdb_sst_file_metadata_get_being_compacted(ptr) != 0,
+            num_entries: ffi::rocksdb_sst_file_metadata_get_num_entries(ptr),
+            num_deletions: ffi::rocksdb_sst_file_metadata_get_num_deletions(ptr),
+            oldest_blob_file_number: ffi::rocksdb_sst_file_metadata_get_oldest_blob_file_number(
+                ptr,
+            ),
+            oldest_ancestor_time: ffi::rocksdb_sst_file_metadata_get_oldest_ancester_time(ptr),
+            file_creation_time: ffi::rocksdb_sst_file_metadata_get_file_creation_time(ptr),
+            epoch_number: ffi::rocksdb_sst_file_metadata_get_epoch_number(ptr),
+            file_checksum,
+            file_checksum_func_name,
+        }
+    }
+}
+
+impl BlobMetaData {
+    unsafe fn from_c(ptr: *mut ffi::rocksdb_blob_metadata_t) -> Self {
+        let mut len: size_t = 0;
+        let file_name =
+            borrowed_string(ffi::rocksdb_blob_metadata_get_file_name(ptr, &mut len), len);
+        let file_path =
+            borrowed_string(ffi::rocksdb_blob_metadata_get_file_path(ptr, &mut len), len);
+        BlobMetaData {
+            file_number: ffi::rocksdb_blob_metadata_get_file_number(ptr),
+            file_name,
+            file_path,
+            file_size: ffi::rocksdb_blob_metadata_get_file_size(ptr),
+            total_blob_count: ffi::rocksdb_blob_metadata_get_total_blob_count(ptr),
+            total_blob_bytes: ffi::rocksdb_blob_metadata_get_total_blob_bytes(ptr),
+            garbage_blob_count: ffi::rocksdb_blob_metadata_get_garbage_blob_count(ptr),
+            garbage_blob_bytes: ffi::rocksdb_blob_metadata_get_garbage_blob_bytes(ptr),
+        }
+    }
+}
@@ -256,7 +256,9 @@ impl MemoryUsageBuilder {
     /// Build up MemoryUsage
     fn build(&self) -> Result<MemoryUsage, Error> {
         unsafe {
-            let mu = ffi_try!(ffi::rocksdb_approximate_memory_usage_create(self.inner));
+            let mu = ffi_try!(ffi::rocksdb_approximate_memory_usage_create_with_status(
+                self.inner
+            ));
             Ok(MemoryUsage { inner: mu })
         }
     }
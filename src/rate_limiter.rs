@@ -0,0 +1,121 @@
+//! `RateLimiter` controls the rate of the writes of flushes and compactions
+//! of one or more databases.
+use std::ptr::NonNull;
+use std::sync::Arc;
+
+use crate::ffi;
+
+pub(crate) struct RateLimiterWrapper {
+    pub(crate) inner: NonNull<ffi::rocksdb_ratelimiter_t>,
+}
+
+// The underlying `GenericRateLimiter` protects its state with a mutex and
+// atomics, and sharing it across databases is its intended use.
+unsafe impl Send for RateLimiterWrapper {}
+unsafe impl Sync for RateLimiterWrapper {}
+
+impl Drop for RateLimiterWrapper {
+    // Safety: `inner` is guaranteed to point to a `shared_ptr` to the
+    // underlying cpp `RateLimiter`.
+    fn drop(&mut self) {
+        unsafe {
+            ffi::rocksdb_ratelimiter_destroy(self.inner.as_ptr());
+        }
+    }
+}
+
+/// A `RateLimiter`, which can be `Cloned` and shared across RocksDB instances
+/// to limit their combined write rate, and reconfigured while they run. See
+/// <https://github.com/facebook/rocksdb/wiki/Rate-Limiter> for more
+/// information.
+///
+/// # Examples
+///
+/// ```
+/// use rocksdb::{Options, RateLimiter};
+///
+/// let limiter = RateLimiter::new(16 << 20, 100 * 1000, 10);
+/// let mut opts = Options::default();
+/// opts.set_rate_limiter(&limiter);
+///
+/// // later, e.g. outside of peak hours
+/// limiter.set_bytes_per_second(64 << 20);
+/// ```
+#[derive(Clone)]
+pub struct RateLimiter(pub(crate) Arc<RateLimiterWrapper>);
+
+impl RateLimiter {
+    /// Creates a new `RateLimiter`.
+    ///
+    /// rate_bytes_per_sec: the total write rate, in bytes per second.
+    ///
+    /// refill_period_us: how often tokens are refilled, in microseconds.
+    /// Smaller values spread the writes more evenly at the cost of more CPU.
+    /// 100ms is a good default.
+    ///
+    /// fairness: how often low priority requests (compactions) are served
+    /// before high priority requests (flushes) waiting at the same time, as
+    /// 1 in `fairness`. 10 is a good default.
+    pub fn new(rate_bytes_per_sec: i64, refill_period_us: i64, fairness: i32) -> RateLimiter {
+        Self::from_raw(unsafe {
+            ffi::rocksdb_ratelimiter_create(rate_bytes_per_sec, refill_period_us, fairness)
+        })
+    }
+
+    /// Creates a new `RateLimiter` that adjusts its rate to the demand, between
+    /// `rate_bytes_per_sec / 20` and `rate_bytes_per_sec`. It starts at half of
+    /// `rate_bytes_per_sec`.
+    ///
+    /// See [`RateLimiter::new`] for the parameters.
+    pub fn new_auto_tuned(
+        rate_bytes_per_sec: i64,
+        refill_period_us: i64,
+        fairness: i32,
+    ) -> RateLimiter {
+        Self::from_raw(unsafe {
+            ffi::rocksdb_ratelimiter_create_auto_tuned(
+                rate_bytes_per_sec,
+                refill_period_us,
+                fairness,
+            )
+        })
+    }
+
+    fn from_raw(ptr: *mut ffi::rocksdb_ratelimiter_t) -> RateLimiter {
+        // Safety: the `rocksdb_ratelimiter_create*` functions are guaranteed to
+        // create a non-null and valid pointer to the underlying cpp type.
+        RateLimiter(Arc::new(RateLimiterWrapper {
+            inner: NonNull::new(ptr).unwrap(),
+        }))
+    }
+
+    /// Changes the rate, in bytes per second. Must be positive. An auto-tuned
+    /// limiter keeps tuning the new rate within the bounds given at
+    /// construction.
+    pub fn set_bytes_per_second(&self, bytes_per_second: i64) {
+        unsafe {
+            ffi::rocksdb_ratelimiter_set_bytes_per_second(self.0.inner.as_ptr(), bytes_per_second);
+        }
+    }
+
+    /// Returns the current rate, in bytes per second.
+    pub fn bytes_per_second(&self) -> i64 {
+        unsafe { ffi::rocksdb_ratelimiter_get_bytes_per_second(self.0.inner.as_ptr()) }
+    }
+
+    /// Returns the largest number of bytes a single request is granted at
+    /// once.
+    pub fn single_burst_bytes(&self) -> i64 {
+        unsafe { ffi::rocksdb_ratelimiter_get_single_burst_bytes(self.0.inner.as_ptr()) }
+    }
+
+    /// Returns the total number of bytes that went through the limiter.
+    pub fn total_bytes_through(&self) -> i64 {
+        unsafe { ffi::rocksdb_ratelimiter_get_total_bytes_through(self.0.inner.as_ptr()) }
+    }
+
+    /// Returns the total number of requests that went through the limiter.
+    pub fn total_requests(&self) -> i64 {
+        unsafe { ffi::rocksdb_ratelimiter_get_total_requests(self.0.inner.as_ptr()) }
+    }
+}
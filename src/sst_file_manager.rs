@@ -0,0 +1,148 @@
+//! `SstFileManager` tracks the SST and blob files of one or more databases,
+//! limits the space they use and rate limits their deletion.
+use std::ptr::NonNull;
+use std::sync::Arc;
+
+use crate::{ffi, Env};
+
+pub(crate) struct SstFileManagerWrapper {
+    pub(crate) inner: NonNull<ffi::rocksdb_sst_file_manager_t>,
+    // The cpp `SstFileManager` keeps a raw pointer to the env.
+    _env: Env,
+}
+
+// The underlying `SstFileManagerImpl` protects its state with a mutex, and
+// sharing it across databases is its intended use.
+unsafe impl Send for SstFileManagerWrapper {}
+unsafe impl Sync for SstFileManagerWrapper {}
+
+impl Drop for SstFileManagerWrapper {
+    // Safety: `inner` is guaranteed to point to a `shared_ptr` to the
+    // underlying cpp `SstFileManager`.
+    fn drop(&mut self) {
+        unsafe {
+            ffi::rocksdb_sst_file_manager_destroy(self.inner.as_ptr());
+        }
+    }
+}
+
+/// An `SstFileManager`, which can be `Cloned` and shared across RocksDB
+/// instances to limit the space used by all of them. See
+/// <https://github.com/facebook/rocksdb/wiki/Managing-Disk-Space-Utilization>
+/// for more information.
+///
+/// # Examples
+///
+/// ```
+/// use rocksdb::{Env, Options, SstFileManager};
+///
+/// let manager = SstFileManager::new(&Env::new().unwrap());
+/// manager.set_max_allowed_space_usage(64 << 30);
+/// manager.set_delete_rate_bytes_per_second(64 << 20);
+///
+/// let mut opts = Options::default();
+/// opts.set_sst_file_manager(&manager);
+/// ```
+#[derive(Clone)]
+pub struct SstFileManager(pub(crate) Arc<SstFileManagerWrapper>);
+
+impl SstFileManager {
+    /// Creates a new `SstFileManager` that accesses files through `env`,
+    /// without a space limit and deleting files immediately.
+    pub fn new(env: &Env) -> SstFileManager {
+        SstFileManager(Arc::new(SstFileManagerWrapper {
+            // Safety: `rocksdb_sst_file_manager_create` is guaranteed to create a non-null and
+            // valid pointer to the underlying cpp type.
+            inner: NonNull::new(unsafe { ffi::rocksdb_sst_file_manager_create(env.0.inner) })
+                .unwrap(),
+            _env: env.clone(),
+        }))
+    }
+
+    /// Sets the maximum space the SST and blob files may use, in bytes. Once
+    /// it is reached, writes fail with a `NoSpace` error and compactions are
+    /// not started. 0 means no limit.
+    pub fn set_max_allowed_space_usage(&self, max_allowed_space: u64) {
+        unsafe {
+            ffi::rocksdb_sst_file_manager_set_max_allowed_space_usage(
+                self.0.inner.as_ptr(),
+                max_allowed_space,
+            );
+        }
+    }
+
+    /// Sets the space, in bytes, kept free for compactions. A compaction is
+    /// only started if its estimated output fits in the maximum allowed space
+    /// minus this buffer.
+    pub fn set_compaction_buffer_size(&self, compaction_buffer_size: u64) {
+        unsafe {
+            ffi::rocksdb_sst_file_manager_set_compaction_buffer_size(
+                self.0.inner.as_ptr(),
+                compaction_buffer_size,
+            );
+        }
+    }
+
+    /// Returns true if the total size of the files reached the maximum
+    /// allowed space.
+    pub fn is_max_allowed_space_reached(&self) -> bool {
+        unsafe { ffi::rocksdb_sst_file_manager_is_max_allowed_space_reached(self.0.inner.as_ptr()) }
+    }
+
+    /// Returns true if the total size of the files, plus the estimated output
+    /// of the running compactions, reached the maximum allowed space.
+    pub fn is_max_allowed_space_reached_including_compactions(&self) -> bool {
+        unsafe {
+            ffi::rocksdb_sst_file_manager_is_max_allowed_space_reached_including_compactions(
+                self.0.inner.as_ptr(),
+            )
+        }
+    }
+
+    /// Returns the total size of the tracked files, in bytes.
+    pub fn total_size(&self) -> u64 {
+        unsafe { ffi::rocksdb_sst_file_manager_get_total_size(self.0.inner.as_ptr()) }
+    }
+
+    /// Returns the rate at which obsolete files are deleted, in bytes per
+    /// second. 0 means they are deleted immediately.
+    pub fn delete_rate_bytes_per_second(&self) -> i64 {
+        unsafe {
+            ffi::rocksdb_sst_file_manager_get_delete_rate_bytes_per_second(self.0.inner.as_ptr())
+        }
+    }
+
+    /// Sets the rate at which obsolete files are deleted, in bytes per
+    /// second. Files waiting to be deleted are moved to the trash. 0 deletes
+    /// files immediately.
+    pub fn set_delete_rate_bytes_per_second(&self, delete_rate: i64) {
+        unsafe {
+            ffi::rocksdb_sst_file_manager_set_delete_rate_bytes_per_second(
+                self.0.inner.as_ptr(),
+                delete_rate,
+            );
+        }
+    }
+
+    /// Returns the ratio of trash to database size above which files are
+    /// deleted immediately, regardless of the delete rate.
+    pub fn max_trash_db_ratio(&self) -> f64 {
+        unsafe { ffi::rocksdb_sst_file_manager_get_max_trash_db_ratio(self.0.inner.as_ptr()) }
+    }
+
+    /// Sets the ratio of trash to database size above which files are deleted
+    /// immediately, regardless of the delete rate.
+    ///
+    /// Default: 0.25
+    pub fn set_max_trash_db_ratio(&self, ratio: f64) {
+        unsafe {
+            ffi::rocksdb_sst_file_manager_set_max_trash_db_ratio(self.0.inner.as_ptr(), ratio);
+        }
+    }
+
+    /// Returns the total size of the files waiting in the trash to be
+    /// deleted, in bytes.
+    pub fn total_trash_size(&self) -> u64 {
+        unsafe { ffi::rocksdb_sst_file_manager_get_total_trash_size(self.0.inner.as_ptr()) }
+    }
+}
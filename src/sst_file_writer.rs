@@ -74,7 +74,7 @@ impl<'a> SstFileWriter<'a> {
 
     fn open_raw(&'a self, cpath: &CString) -> Result<(), Error> {
         unsafe {
-            ffi_try!(ffi::rocksdb_sstfilewriter_open(
+            ffi_try!(ffi::rocksdb_sstfilewriter_open_with_status(
                 self.inner,
                 cpath.as_ptr() as *const _
             ));
@@ -86,7 +86,7 @@ impl<'a> SstFileWriter<'a> {
     /// Finalize writing to sst file and close file.
     pub fn finish(&mut self) -> Result<(), Error> {
         unsafe {
-            ffi_try!(ffi::rocksdb_sstfilewriter_finish(self.inner,));
+            ffi_try!(ffi::rocksdb_sstfilewriter_finish_with_status(self.inner,));
             Ok(())
         }
     }
@@ -111,7 +111,7 @@ impl<'a> SstFileWriter<'a> {
         let value = value.as_ref();
 
         unsafe {
-            ffi_try!(ffi::rocksdb_sstfilewriter_put(
+            ffi_try!(ffi::rocksdb_sstfilewriter_put_with_status(
                 self.inner,
                 key.as_ptr() as *const c_char,
                 key.len() as size_t,
@@ -133,7 +133,7 @@ impl<'a> SstFileWriter<'a> {
         let value = value.as_ref();
 
         unsafe {
-            ffi_try!(ffi::rocksdb_sstfilewriter_merge(
+            ffi_try!(ffi::rocksdb_sstfilewriter_merge_with_status(
                 self.inner,
                 key.as_ptr() as *const c_char,
                 key.len() as size_t,
@@ -150,7 +150,7 @@ impl<'a> SstFileWriter<'a> {
         let key = key.as_ref();
 
         unsafe {
-            ffi_try!(ffi::rocksdb_sstfilewriter_delete(
+            ffi_try!(ffi::rocksdb_sstfilewriter_delete_with_status(
                 self.inner,
                 key.as_ptr() as *const c_char,
                 key.len() as size_t,
@@ -0,0 +1,1115 @@
+//! `Statistics` collects counters ([`Ticker`]) and distributions
+//! ([`Histogram`]) of the operations of one or more databases.
+use std::convert::TryFrom;
+use std::fmt;
+use std::ptr::NonNull;
+use std::sync::Arc;
+
+use libc::{c_char, c_void};
+
+use crate::{ffi, ffi_util::from_cstr, Error};
+
+/// Which statistics are collected. Lower levels skip the more expensive
+/// statistics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u8)]
+pub enum StatsLevel {
+    /// Collect no statistics
+    DisableAll = 0,
+    /// Skip histograms and timers
+    ExceptHistogramOrTimers,
+    /// Skip timers
+    ExceptTimers,
+    /// Skip the time spent inside mutexes and on compression
+    ExceptDetailedTimers,
+    /// Skip the time spent inside mutexes
+    ExceptTimeForMutex,
+    /// Collect everything, including the time spent inside mutexes. This may
+    /// reduce the scalability of writes if reading the time is expensive.
+    All,
+}
+
+impl StatsLevel {
+    fn from_raw(raw: u8) -> Self {
+        match raw {
+            0 => StatsLevel::DisableAll,
+            1 => StatsLevel::ExceptHistogramOrTimers,
+            2 => StatsLevel::ExceptTimers,
+            3 => StatsLevel::ExceptDetailedTimers,
+            4 => StatsLevel::ExceptTimeForMutex,
+            _ => StatsLevel::All,
+        }
+    }
+}
+
+/// Summary of the values recorded in a [`Histogram`].
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct HistogramData {
+    pub median: f64,
+    pub p95: f64,
+    pub p99: f64,
+    pub average: f64,
+    pub std_dev: f64,
+    pub max: f64,
+    pub min: f64,
+    pub count: u64,
+    pub sum: u64,
+}
+
+pub(crate) struct StatisticsWrapper {
+    pub(crate) inner: NonNull<ffi::rocksdb_statistics_t>,
+}
+
+// Like the other shared types, this relies on the underlying cpp
+// `Statistics`, which RocksDB updates from many threads at once, being
+// thread-safe.
+unsafe impl Send for StatisticsWrapper {}
+unsafe impl Sync for StatisticsWrapper {}
+
+impl Drop for StatisticsWrapper {
+    // Safety: `inner` is guaranteed to point to a `shared_ptr` to the
+    // underlying cpp `Statistics`.
+    fn drop(&mut self) {
+        unsafe {
+            ffi::rocksdb_statistics_destroy(self.inner.as_ptr());
+        }
+    }
+}
+
+/// Statistics of one or more databases, which can be `Clone`d and shared
+/// across RocksDB instances to aggregate their statistics.
+///
+/// # Examples
+///
+/// ```
+/// use rocksdb::{Histogram, Options, Statistics, Ticker, DB};
+///
+/// let stats = Statistics::new();
+/// let mut opts = Options::default();
+/// opts.create_if_missing(true);
+/// opts.set_statistics(&stats);
+///
+/// let path = "_rust_rocksdb_statistics_doc";
+/// {
+///     let db = DB::open(&opts, path).unwrap();
+///     db.put(b"key", b"value").unwrap();
+///     db.get(b"key").unwrap();
+/// }
+/// assert_eq!(stats.ticker(Ticker::NumberKeysWritten), 1);
+/// assert_eq!(stats.histogram(Histogram::DbGet).count, 1);
+/// let _ = DB::destroy(&Options::default(), path);
+/// ```
+#[derive(Clone)]
+pub struct Statistics(pub(crate) Arc<StatisticsWrapper>);
+
+impl Statistics {
+    /// Creates statistics collecting everything but the time spent inside
+    /// mutexes.
+    pub fn new() -> Statistics {
+        Statistics(Arc::new(StatisticsWrapper {
+            // Safety: `rocksdb_statistics_create` is guaranteed to create a
+            // non-null and valid pointer to the underlying cpp type.
+            inner: NonNull::new(unsafe { ffi::rocksdb_statistics_create() }).unwrap(),
+        }))
+    }
+
+    /// Returns the current value of `ticker`.
+    pub fn ticker(&self, ticker: Ticker) -> u64 {
+        match ticker.raw() {
+            Some(raw) => unsafe {
+                ffi::rocksdb_statistics_get_ticker_count(self.0.inner.as_ptr(), raw)
+            },
+            None => 0,
+        }
+    }
+
+    /// Returns a summary of the values recorded in `histogram`.
+    pub fn histogram(&self, histogram: Histogram) -> HistogramData {
+        let raw = match histogram.raw() {
+            Some(raw) => raw,
+            None => return HistogramData::default(),
+        };
+        unsafe {
+            let data = ffi::rocksdb_statistics_histogram_data_create();
+            ffi::rocksdb_statistics_get_histogram_data(self.0.inner.as_ptr(), raw, data);
+            let result = HistogramData {
+                median: ffi::rocksdb_statistics_histogram_data_get_median(data),
+                p95: ffi::rocksdb_statistics_histogram_data_get_p95(data),
+                p99: ffi::rocksdb_statistics_histogram_data_get_p99(data),
+                average: ffi::rocksdb_statistics_histogram_data_get_average(data),
+                std_dev: ffi::rocksdb_statistics_histogram_data_get_std_dev(data),
+                max: ffi::rocksdb_statistics_histogram_data_get_max(data),
+                min: ffi::rocksdb_statistics_histogram_data_get_min(data),
+                count: ffi::rocksdb_statistics_histogram_data_get_count(data),
+                sum: ffi::rocksdb_statistics_histogram_data_get_sum(data),
+            };
+            ffi::rocksdb_statistics_histogram_data_destroy(data);
+            result
+        }
+    }
+
+    /// Resets all tickers and histograms to zero.
+    pub fn reset(&self) -> Result<(), Error> {
+        unsafe {
+            ffi_try!(ffi::rocksdb_statistics_reset(self.0.inner.as_ptr()));
+        }
+        Ok(())
+    }
+
+    /// Sets which statistics are collected from now on.
+    pub fn set_stats_level(&self, level: StatsLevel) {
+        unsafe {
+            ffi::rocksdb_statistics_set_stats_level(self.0.inner.as_ptr(), level as u8);
+        }
+    }
+
+    /// Returns which statistics are collected.
+    pub fn stats_level(&self) -> StatsLevel {
+        StatsLevel::from_raw(unsafe {
+            ffi::rocksdb_statistics_get_stats_level(self.0.inner.as_ptr())
+        })
+    }
+}
+
+impl Default for Statistics {
+    fn default() -> Self {
+        Statistics::new()
+    }
+}
+
+/// Formats all tickers and histograms in the same human-readable form as
+/// [`Options::get_statistics`](crate::Options::get_statistics).
+impl fmt::Display for Statistics {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = unsafe {
+            let value = ffi::rocksdb_statistics_to_string(self.0.inner.as_ptr());
+            let s = from_cstr(value);
+            ffi::rocksdb_free(value as *mut c_void);
+            s
+        };
+        f.write_str(&s)
+    }
+}
+
+/// Looks up the type RocksDB assigns to the ticker or histogram `name`. The
+/// types change between RocksDB versions, the names do not.
+fn raw_type(lookup: unsafe extern "C" fn(*const c_char, usize) -> i32, name: &str) -> Option<u32> {
+    let raw = unsafe { lookup(name.as_ptr() as *const c_char, name.len()) };
+    u32::try_from(raw).ok()
+}
+
+impl Ticker {
+    fn raw(self) -> Option<u32> {
+        raw_type(ffi::rocksdb_statistics_ticker_type, self.name()).or_else(|| {
+            // Before RocksDB 8.4 these were misspelled.
+            let legacy_name = match self {
+                Ticker::ErrorHandlerBgErrorCount => "rocksdb.error.handler.bg.errro.count",
+                Ticker::ErrorHandlerBgIoErrorCount => "rocksdb.error.handler.bg.io.errro.count",
+                Ticker::ErrorHandlerBgRetryableIoErrorCount => {
+                    "rocksdb.error.handler.bg.retryable.io.errro.count"
+                }
+                _ => return None,
+            };
+            raw_type(ffi::rocksdb_statistics_ticker_type, legacy_name)
+        })
+    }
+}
+
+impl Histogram {
+    fn raw(self) -> Option<u32> {
+        raw_type(ffi::rocksdb_statistics_histogram_type, self.name())
+    }
+}
+
+/// A counter recorded by [`Statistics`].
+///
+/// Variants that the linked RocksDB version does not record read as zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Ticker {
+    BlockCacheMiss,
+    BlockCacheHit,
+    BlockCacheAdd,
+    BlockCacheAddFailures,
+    BlockCacheIndexMiss,
+    BlockCacheIndexHit,
+    BlockCacheIndexAdd,
+    BlockCacheIndexBytesInsert,
+    BlockCacheFilterMiss,
+    BlockCacheFilterHit,
+    BlockCacheFilterAdd,
+    BlockCacheFilterBytesInsert,
+    BlockCacheDataMiss,
+    BlockCacheDataHit,
+    BlockCacheDataAdd,
+    BlockCacheDataBytesInsert,
+    BlockCacheBytesRead,
+    BlockCacheBytesWrite,
+    BloomFilterUseful,
+    BloomFilterFullPositive,
+    BloomFilterFullTruePositive,
+    PersistentCacheHit,
+    PersistentCacheMiss,
+    SimBlockCacheHit,
+    SimBlockCacheMiss,
+    MemtableHit,
+    MemtableMiss,
+    GetHitL0,
+    GetHitL1,
+    GetHitL2AndUp,
+    CompactionKeyDropNewerEntry,
+    CompactionKeyDropObsolete,
+    CompactionKeyDropRangeDel,
+    CompactionKeyDropUser,
+    CompactionRangeDelDropObsolete,
+    CompactionOptimizedDelDropObsolete,
+    CompactionCancelled,
+    NumberKeysWritten,
+    NumberKeysRead,
+    NumberKeysUpdated,
+    BytesWritten,
+    BytesRead,
+    NumberDbSeek,
+    NumberDbNext,
+    NumberDbPrev,
+    NumberDbSeekFound,
+    NumberDbNextFound,
+    NumberDbPrevFound,
+    IterBytesRead,
+    NoFileOpens,
+    NoFileErrors,
+    StallMicros,
+    DbMutexWaitMicros,
+    NumberMultigetCalls,
+    NumberMultigetKeysRead,
+    NumberMultigetBytesRead,
+    NumberMergeFailures,
+    BloomFilterPrefixChecked,
+    BloomFilterPrefixUseful,
+    BloomFilterPrefixTruePositive,
+    NumberOfReseeksInIteration,
+    GetUpdatesSinceCalls,
+    WalFileSynced,
+    WalFileBytes,
+    WriteDoneBySelf,
+    WriteDoneByOther,
+    WriteWithWal,
+    CompactReadBytes,
+    CompactWriteBytes,
+    FlushWriteBytes,
+    CompactReadBytesMarked,
+    CompactReadBytesPeriodic,
+    CompactReadBytesTtl,
+    CompactWriteBytesMarked,
+    CompactWriteBytesPeriodic,
+    CompactWriteBytesTtl,
+    NumberDirectLoadTableProperties,
+    NumberSuperversionAcquires,
+    NumberSuperversionReleases,
+    NumberSuperversionCleanups,
+    NumberBlockCompressed,
+    NumberBlockDecompressed,
+    NumberBlockNotCompressed,
+    MergeOperationTotalTime,
+    FilterOperationTotalTime,
+    CompactionCpuTotalTime,
+    RowCacheHit,
+    RowCacheMiss,
+    ReadAmpEstimateUsefulBytes,
+    ReadAmpTotalReadBytes,
+    NumberRateLimiterDrains,
+    NumberIterSkip,
+    BlobDbNumPut,
+    BlobDbNumWrite,
+    BlobDbNumGet,
+    BlobDbNumMultiget,
+    BlobDbNumSeek,
+    BlobDbNumNext,
+    BlobDbNumPrev,
+    BlobDbNumKeysWritten,
+    BlobDbNumKeysRead,
+    BlobDbBytesWritten,
+    BlobDbBytesRead,
+    BlobDbWriteInlined,
+    BlobDbWriteInlinedTtl,
+    BlobDbWriteBlob,
+    BlobDbWriteBlobTtl,
+    BlobDbBlobFileBytesWritten,
+    BlobDbBlobFileBytesRead,
+    BlobDbBlobFileSynced,
+    BlobDbBlobIndexExpiredCount,
+    BlobDbBlobIndexExpiredSize,
+    BlobDbBlobIndexEvictedCount,
+    BlobDbBlobIndexEvictedSize,
+    BlobDbGcNumFiles,
+    BlobDbGcNumNewFiles,
+    BlobDbGcFailures,
+    BlobDbGcNumKeysRelocated,
+    BlobDbGcBytesRelocated,
+    BlobDbFifoNumFilesEvicted,
+    BlobDbFifoNumKeysEvicted,
+    BlobDbFifoBytesEvicted,
+    TxnPrepareMutexOverhead,
+    TxnOldCommitMapMutexOverhead,
+    TxnDuplicateKeyOverhead,
+    TxnSnapshotMutexOverhead,
+    TxnGetTryAgain,
+    NumberMultigetKeysFound,
+    NoIteratorCreated,
+    NoIteratorDeleted,
+    BlockCacheCompressionDictMiss,
+    BlockCacheCompressionDictHit,
+    BlockCacheCompressionDictAdd,
+    BlockCacheCompressionDictBytesInsert,
+    BlockCacheAddRedundant,
+    BlockCacheIndexAddRedundant,
+    BlockCacheFilterAddRedundant,
+    BlockCacheDataAddRedundant,
+    BlockCacheCompressionDictAddRedundant,
+    FilesMarkedTrash,
+    FilesDeletedFromTrashQueue,
+    FilesDeletedImmediately,
+    ErrorHandlerBgErrorCount,
+    ErrorHandlerBgIoErrorCount,
+    ErrorHandlerBgRetryableIoErrorCount,
+    ErrorHandlerAutoresumeCount,
+    ErrorHandlerAutoresumeRetryTotalCount,
+    ErrorHandlerAutoresumeSuccessCount,
+    MemtablePayloadBytesAtFlush,
+    MemtableGarbageBytesAtFlush,
+    SecondaryCacheHits,
+    VerifyChecksumReadBytes,
+    BackupReadBytes,
+    BackupWriteBytes,
+    RemoteCompactReadBytes,
+    RemoteCompactWriteBytes,
+    HotFileReadBytes,
+    WarmFileReadBytes,
+    ColdFileReadBytes,
+    HotFileReadCount,
+    WarmFileReadCount,
+    ColdFileReadCount,
+    LastLevelReadBytes,
+    LastLevelReadCount,
+    NonLastLevelReadBytes,
+    NonLastLevelReadCount,
+    LastLevelSeekFiltered,
+    LastLevelSeekFilterMatch,
+    LastLevelSeekData,
+    LastLevelSeekDataUsefulNoFilter,
+    LastLevelSeekDataUsefulFilterMatch,
+    NonLastLevelSeekFiltered,
+    NonLastLevelSeekFilterMatch,
+    NonLastLevelSeekData,
+    NonLastLevelSeekDataUsefulNoFilter,
+    NonLastLevelSeekDataUsefulFilterMatch,
+    BlockChecksumComputeCount,
+    BlockChecksumMismatchCount,
+    MultigetCoroutineCount,
+    BlobDbCacheMiss,
+    BlobDbCacheHit,
+    BlobDbCacheAdd,
+    BlobDbCacheAddFailures,
+    BlobDbCacheBytesRead,
+    BlobDbCacheBytesWrite,
+    ReadAsyncMicros,
+    AsyncReadErrorCount,
+    SecondaryCacheFilterHits,
+    SecondaryCacheIndexHits,
+    SecondaryCacheDataHits,
+    TableOpenPrefetchTailMiss,
+    TableOpenPrefetchTailHit,
+    TimestampFilterTableChecked,
+    TimestampFilterTableFiltered,
+    BytesCompressedFrom,
+    BytesCompressedTo,
+    BytesCompressionBypassed,
+    BytesCompressionRejected,
+    NumberBlockCompressionBypassed,
+    NumberBlockCompressionRejected,
+    BytesDecompressedFrom,
+    BytesDecompressedTo,
+    ReadaheadTrimmed,
+    FifoMaxSizeCompactions,
+    FifoTtlCompactions,
+    PrefetchBytes,
+    PrefetchBytesUseful,
+    PrefetchHits,
+    CompressedSecondaryCacheDummyHits,
+    CompressedSecondaryCacheHits,
+    CompressedSecondaryCachePromotions,
+    CompressedSecondaryCachePromotionSkips,
+}
+
+impl Ticker {
+    /// All variants, in RocksDB's order.
+    pub const ALL: &'static [Ticker] = &[
+        Ticker::BlockCacheMiss,
+        Ticker::BlockCacheHit,
+        Ticker::BlockCacheAdd,
+        Ticker::BlockCacheAddFailures,
+        Ticker::BlockCacheIndexMiss,
+        Ticker::BlockCacheIndexHit,
+        Ticker::BlockCacheIndexAdd,
+        Ticker::BlockCacheIndexBytesInsert,
+        Ticker::BlockCacheFilterMiss,
+        Ticker::BlockCacheFilterHit,
+        Ticker::BlockCacheFilterAdd,
+        Ticker::BlockCacheFilterBytesInsert,
+        Ticker::BlockCacheDataMiss,
+        Ticker::BlockCacheDataHit,
+        Ticker::BlockCacheDataAdd,
+        Ticker::BlockCacheDataBytesInsert,
+        Ticker::BlockCacheBytesRead,
+        Ticker::BlockCacheBytesWrite,
+        Ticker::BloomFilterUseful,
+        Ticker::BloomFilterFullPositive,
+        Ticker::BloomFilterFullTruePositive,
+        Ticker::PersistentCacheHit,
+        Ticker::PersistentCacheMiss,
+        Ticker::SimBlockCacheHit,
+        Ticker::SimBlockCacheMiss,
+        Ticker::MemtableHit,
+        Ticker::MemtableMiss,
+        Ticker::GetHitL0,
+        Ticker::GetHitL1,
+        Ticker::GetHitL2AndUp,
+        Ticker::CompactionKeyDropNewerEntry,
+        Ticker::CompactionKeyDropObsolete,
+        Ticker::CompactionKeyDropRangeDel,
+        Ticker::CompactionKeyDropUser,
+        Ticker::CompactionRangeDelDropObsolete,
+        Ticker::CompactionOptimizedDelDropObsolete,
+        Ticker::CompactionCancelled,
+        Ticker::NumberKeysWritten,
+        Ticker::NumberKeysRead,
+        Ticker::NumberKeysUpdated,
+        Ticker::BytesWritten,
+        Ticker::BytesRead,
+        Ticker::NumberDbSeek,
+        Ticker::NumberDbNext,
+        Ticker::NumberDbPrev,
+        Ticker::NumberDbSeekFound,
+        Ticker::NumberDbNextFound,
+        Ticker::NumberDbPrevFound,
+        Ticker::IterBytesRead,
+        Ticker::NoFileOpens,
+        Ticker::NoFileErrors,
+        Ticker::StallMicros,
+        Ticker::DbMutexWaitMicros,
+        Ticker::NumberMultigetCalls,
+        Ticker::NumberMultigetKeysRead,
+        Ticker::NumberMultigetBytesRead,
+        Ticker::NumberMergeFailures,
+        Ticker::BloomFilterPrefixChecked,
+        Ticker::BloomFilterPrefixUseful,
+        Ticker::BloomFilterPrefixTruePositive,
+        Ticker::NumberOfReseeksInIteration,
+        Ticker::GetUpdatesSinceCalls,
+        Ticker::WalFileSynced,
+        Ticker::WalFileBytes,
+        Ticker::WriteDoneBySelf,
+        Ticker::WriteDoneByOther,
+        Ticker::WriteWithWal,
+        Ticker::CompactReadBytes,
+        Ticker::CompactWriteBytes,
+        Ticker::FlushWriteBytes,
+        Ticker::CompactReadBytesMarked,
+        Ticker::CompactReadBytesPeriodic,
+        Ticker::CompactReadBytesTtl,
+        Ticker::CompactWriteBytesMarked,
+        Ticker::CompactWriteBytesPeriodic,
+        Ticker::CompactWriteBytesTtl,
+        Ticker::NumberDirectLoadTableProperties,
+        Ticker::NumberSuperversionAcquires,
+        Ticker::NumberSuperversionReleases,
+        Ticker::NumberSuperversionCleanups,
+        Ticker::NumberBlockCompressed,
+        Ticker::NumberBlockDecompressed,
+        Ticker::NumberBlockNotCompressed,
+        Ticker::MergeOperationTotalTime,
+        Ticker::FilterOperationTotalTime,
+        Ticker::CompactionCpuTotalTime,
+        Ticker::RowCacheHit,
+        Ticker::RowCacheMiss,
+        Ticker::ReadAmpEstimateUsefulBytes,
+        Ticker::ReadAmpTotalReadBytes,
+        Ticker::NumberRateLimiterDrains,
+        Ticker::NumberIterSkip,
+        Ticker::BlobDbNumPut,
+        Ticker::BlobDbNumWrite,
+        Ticker::BlobDbNumGet,
+        Ticker::BlobDbNumMultiget,
+        Ticker::BlobDbNumSeek,
+        Ticker::BlobDbNumNext,
+        Ticker::BlobDbNumPrev,
+        Ticker::BlobDbNumKeysWritten,
+        Ticker::BlobDbNumKeysRead,
+        Ticker::BlobDbBytesWritten,
+        Ticker::BlobDbBytesRead,
+        Ticker::BlobDbWriteInlined,
+        Ticker::BlobDbWriteInlinedTtl,
+        Ticker::BlobDbWriteBlob,
+        Ticker::BlobDbWriteBlobTtl,
+        Ticker::BlobDbBlobFileBytesWritten,
+        Ticker::BlobDbBlobFileBytesRead,
+        Ticker::BlobDbBlobFileSynced,
+        Ticker::BlobDbBlobIndexExpiredCount,
+        Ticker::BlobDbBlobIndexExpiredSize,
+        Ticker::BlobDbBlobIndexEvictedCount,
+        Ticker::BlobDbBlobIndexEvictedSize,
+        Ticker::BlobDbGcNumFiles,
+        Ticker::BlobDbGcNumNewFiles,
+        Ticker::BlobDbGcFailures,
+        Ticker::BlobDbGcNumKeysRelocated,
+        Ticker::BlobDbGcBytesRelocated,
+        Ticker::BlobDbFifoNumFilesEvicted,
+        Ticker::BlobDbFifoNumKeysEvicted,
+        Ticker::BlobDbFifoBytesEvicted,
+        Ticker::TxnPrepareMutexOverhead,
+        Ticker::TxnOldCommitMapMutexOverhead,
+        Ticker::TxnDuplicateKeyOverhead,
+        Ticker::TxnSnapshotMutexOverhead,
+        Ticker::TxnGetTryAgain,
+        Ticker::NumberMultigetKeysFound,
+        Ticker::NoIteratorCreated,
+        Ticker::NoIteratorDeleted,
+        Ticker::BlockCacheCompressionDictMiss,
+        Ticker::BlockCacheCompressionDictHit,
+        Ticker::BlockCacheCompressionDictAdd,
+        Ticker::BlockCacheCompressionDictBytesInsert,
+        Ticker::BlockCacheAddRedundant,
+        Ticker::BlockCacheIndexAddRedundant,
+        Ticker::BlockCacheFilterAddRedundant,
+        Ticker::BlockCacheDataAddRedundant,
+        Ticker::BlockCacheCompressionDictAddRedundant,
+        Ticker::FilesMarkedTrash,
+        Ticker::FilesDeletedFromTrashQueue,
+        Ticker::FilesDeletedImmediately,
+        Ticker::ErrorHandlerBgErrorCount,
+        Ticker::ErrorHandlerBgIoErrorCount,
+        Ticker::ErrorHandlerBgRetryableIoErrorCount,
+        Ticker::ErrorHandlerAutoresumeCount,
+        Ticker::ErrorHandlerAutoresumeRetryTotalCount,
+        Ticker::ErrorHandlerAutoresumeSuccessCount,
+        Ticker::MemtablePayloadBytesAtFlush,
+        Ticker::MemtableGarbageBytesAtFlush,
+        Ticker::SecondaryCacheHits,
+        Ticker::VerifyChecksumReadBytes,
+        Ticker::BackupReadBytes,
+        Ticker::BackupWriteBytes,
+        Ticker::RemoteCompactReadBytes,
+        Ticker::RemoteCompactWriteBytes,
+        Ticker::HotFileReadBytes,
+        Ticker::WarmFileReadBytes,
+        Ticker::ColdFileReadBytes,
+        Ticker::HotFileReadCount,
+        Ticker::WarmFileReadCount,
+        Ticker::ColdFileReadCount,
+        Ticker::LastLevelReadBytes,
+        Ticker::LastLevelReadCount,
+        Ticker::NonLastLevelReadBytes,
+        Ticker::NonLastLevelReadCount,
+        Ticker::LastLevelSeekFiltered,
+        Ticker::LastLevelSeekFilterMatch,
+        Ticker::LastLevelSeekData,
+        Ticker::LastLevelSeekDataUsefulNoFilter,
+        Ticker::LastLevelSeekDataUsefulFilterMatch,
+        Ticker::NonLastLevelSeekFiltered,
+        Ticker::NonLastLevelSeekFilterMatch,
+        Ticker::NonLastLevelSeekData,
+        Ticker::NonLastLevelSeekDataUsefulNoFilter,
+        Ticker::NonLastLevelSeekDataUsefulFilterMatch,
+        Ticker::BlockChecksumComputeCount,
+        Ticker::BlockChecksumMismatchCount,
+        Ticker::MultigetCoroutineCount,
+        Ticker::BlobDbCacheMiss,
+        Ticker::BlobDbCacheHit,
+        Ticker::BlobDbCacheAdd,
+        Ticker::BlobDbCacheAddFailures,
+        Ticker::BlobDbCacheBytesRead,
+        Ticker::BlobDbCacheBytesWrite,
+        Ticker::ReadAsyncMicros,
+        Ticker::AsyncReadErrorCount,
+        Ticker::SecondaryCacheFilterHits,
+        Ticker::SecondaryCacheIndexHits,
+        Ticker::SecondaryCacheDataHits,
+        Ticker::TableOpenPrefetchTailMiss,
+        Ticker::TableOpenPrefetchTailHit,
+        Ticker::TimestampFilterTableChecked,
+        Ticker::TimestampFilterTableFiltered,
+        Ticker::BytesCompressedFrom,
+        Ticker::BytesCompressedTo,
+        Ticker::BytesCompressionBypassed,
+        Ticker::BytesCompressionRejected,
+        Ticker::NumberBlockCompressionBypassed,
+        Ticker::NumberBlockCompressionRejected,
+        Ticker::BytesDecompressedFrom,
+        Ticker::BytesDecompressedTo,
+        Ticker::ReadaheadTrimmed,
+        Ticker::FifoMaxSizeCompactions,
+        Ticker::FifoTtlCompactions,
+        Ticker::PrefetchBytes,
+        Ticker::PrefetchBytesUseful,
+        Ticker::PrefetchHits,
+        Ticker::CompressedSecondaryCacheDummyHits,
+        Ticker::CompressedSecondaryCacheHits,
+        Ticker::CompressedSecondaryCachePromotions,
+        Ticker::CompressedSecondaryCachePromotionSkips,
+    ];
+
+    /// Returns the name RocksDB uses for this ticker in its statistics dump.
+    #[allow(clippy::too_many_lines)]
+    pub fn name(&self) -> &'static str {
+        match self {
+            Ticker::BlockCacheMiss => "rocksdb.block.cache.miss",
+            Ticker::BlockCacheHit => "rocksdb.block.cache.hit",
+            Ticker::BlockCacheAdd => "rocksdb.block.cache.add",
+            Ticker::BlockCacheAddFailures => "rocksdb.block.cache.add.failures",
+            Ticker::BlockCacheIndexMiss => "rocksdb.block.cache.index.miss",
+            Ticker::BlockCacheIndexHit => "rocksdb.block.cache.index.hit",
+            Ticker::BlockCacheIndexAdd => "rocksdb.block.cache.index.add",
+            Ticker::BlockCacheIndexBytesInsert => "rocksdb.block.cache.index.bytes.insert",
+            Ticker::BlockCacheFilterMiss => "rocksdb.block.cache.filter.miss",
+            Ticker::BlockCacheFilterHit => "rocksdb.block.cache.filter.hit",
+            Ticker::BlockCacheFilterAdd => "rocksdb.block.cache.filter.add",
+            Ticker::BlockCacheFilterBytesInsert => "rocksdb.block.cache.filter.bytes.insert",
+            Ticker::BlockCacheDataMiss => "rocksdb.block.cache.data.miss",
+            Ticker::BlockCacheDataHit => "rocksdb.block.cache.data.hit",
+            Ticker::BlockCacheDataAdd => "rocksdb.block.cache.data.add",
+            Ticker::BlockCacheDataBytesInsert => "rocksdb.block.cache.data.bytes.insert",
+            Ticker::BlockCacheBytesRead => "rocksdb.block.cache.bytes.read",
+            Ticker::BlockCacheBytesWrite => "rocksdb.block.cache.bytes.write",
+            Ticker::BloomFilterUseful => "rocksdb.bloom.filter.useful",
+            Ticker::BloomFilterFullPositive => "rocksdb.bloom.filter.full.positive",
+            Ticker::BloomFilterFullTruePositive => "rocksdb.bloom.filter.full.true.positive",
+            Ticker::PersistentCacheHit => "rocksdb.persistent.cache.hit",
+            Ticker::PersistentCacheMiss => "rocksdb.persistent.cache.miss",
+            Ticker::SimBlockCacheHit => "rocksdb.sim.block.cache.hit",
+            Ticker::SimBlockCacheMiss => "rocksdb.sim.block.cache.miss",
+            Ticker::MemtableHit => "rocksdb.memtable.hit",
+            Ticker::MemtableMiss => "rocksdb.memtable.miss",
+            Ticker::GetHitL0 => "rocksdb.l0.hit",
+            Ticker::GetHitL1 => "rocksdb.l1.hit",
+            Ticker::GetHitL2AndUp => "rocksdb.l2andup.hit",
+            Ticker::CompactionKeyDropNewerEntry => "rocksdb.compaction.key.drop.new",
+            Ticker::CompactionKeyDropObsolete => "rocksdb.compaction.key.drop.obsolete",
+            Ticker::CompactionKeyDropRangeDel => "rocksdb.compaction.key.drop.range_del",
+            Ticker::CompactionKeyDropUser => "rocksdb.compaction.key.drop.user",
+            Ticker::CompactionRangeDelDropObsolete => "rocksdb.compaction.range_del.drop.obsolete",
+            Ticker::CompactionOptimizedDelDropObsolete => {
+                "rocksdb.compaction.optimized.del.drop.obsolete"
+            }
+            Ticker::CompactionCancelled => "rocksdb.compaction.cancelled",
+            Ticker::NumberKeysWritten => "rocksdb.number.keys.written",
+            Ticker::NumberKeysRead => "rocksdb.number.keys.read",
+            Ticker::NumberKeysUpdated => "rocksdb.number.keys.updated",
+            Ticker::BytesWritten => "rocksdb.bytes.written",
+            Ticker::BytesRead => "rocksdb.bytes.read",
+            Ticker::NumberDbSeek => "rocksdb.number.db.seek",
+            Ticker::NumberDbNext => "rocksdb.number.db.next",
+            Ticker::NumberDbPrev => "rocksdb.number.db.prev",
+            Ticker::NumberDbSeekFound => "rocksdb.number.db.seek.found",
+            Ticker::NumberDbNextFound => "rocksdb.number.db.next.found",
+            Ticker::NumberDbPrevFound => "rocksdb.number.db.prev.found",
+            Ticker::IterBytesRead => "rocksdb.db.iter.bytes.read",
+            Ticker::NoFileOpens => "rocksdb.no.file.opens",
+            Ticker::NoFileErrors => "rocksdb.no.file.errors",
+            Ticker::StallMicros => "rocksdb.stall.micros",
+            Ticker::DbMutexWaitMicros => "rocksdb.db.mutex.wait.micros",
+            Ticker::NumberMultigetCalls => "rocksdb.number.multiget.get",
+            Ticker::NumberMultigetKeysRead => "rocksdb.number.multiget.keys.read",
+            Ticker::NumberMultigetBytesRead => "rocksdb.number.multiget.bytes.read",
+            Ticker::NumberMergeFailures => "rocksdb.number.merge.failures",
+            Ticker::BloomFilterPrefixChecked => "rocksdb.bloom.filter.prefix.checked",
+            Ticker::BloomFilterPrefixUseful => "rocksdb.bloom.filter.prefix.useful",
+            Ticker::BloomFilterPrefixTruePositive => "rocksdb.bloom.filter.prefix.true.positive",
+            Ticker::NumberOfReseeksInIteration => "rocksdb.number.reseeks.iteration",
+            Ticker::GetUpdatesSinceCalls => "rocksdb.getupdatessince.calls",
+            Ticker::WalFileSynced => "rocksdb.wal.synced",
+            Ticker::WalFileBytes => "rocksdb.wal.bytes",
+            Ticker::WriteDoneBySelf => "rocksdb.write.self",
+            Ticker::WriteDoneByOther => "rocksdb.write.other",
+            Ticker::WriteWithWal => "rocksdb.write.wal",
+            Ticker::CompactReadBytes => "rocksdb.compact.read.bytes",
+            Ticker::CompactWriteBytes => "rocksdb.compact.write.bytes",
+            Ticker::FlushWriteBytes => "rocksdb.flush.write.bytes",
+            Ticker::CompactReadBytesMarked => "rocksdb.compact.read.marked.bytes",
+            Ticker::CompactReadBytesPeriodic => "rocksdb.compact.read.periodic.bytes",
+            Ticker::CompactReadBytesTtl => "rocksdb.compact.read.ttl.bytes",
+            Ticker::CompactWriteBytesMarked => "rocksdb.compact.write.marked.bytes",
+            Ticker::CompactWriteBytesPeriodic => "rocksdb.compact.write.periodic.bytes",
+            Ticker::CompactWriteBytesTtl => "rocksdb.compact.write.ttl.bytes",
+            Ticker::NumberDirectLoadTableProperties => {
+                "rocksdb.number.direct.load.table.properties"
+            }
+            Ticker::NumberSuperversionAcquires => "rocksdb.number.superversion_acquires",
+            Ticker::NumberSuperversionReleases => "rocksdb.number.superversion_releases",
+            Ticker::NumberSuperversionCleanups => "rocksdb.number.superversion_cleanups",
+            Ticker::NumberBlockCompressed => "rocksdb.number.block.compressed",
+            Ticker::NumberBlockDecompressed => "rocksdb.number.block.decompressed",
+            Ticker::NumberBlockNotCompressed => "rocksdb.number.block.not_compressed",
+            Ticker::MergeOperationTotalTime => "rocksdb.merge.operation.time.nanos",
+            Ticker::FilterOperationTotalTime => "rocksdb.filter.operation.time.nanos",
+            Ticker::CompactionCpuTotalTime => "rocksdb.compaction.total.time.cpu_micros",
+            Ticker::RowCacheHit => "rocksdb.row.cache.hit",
+            Ticker::RowCacheMiss => "rocksdb.row.cache.miss",
+            Ticker::ReadAmpEstimateUsefulBytes => "rocksdb.read.amp.estimate.useful.bytes",
+            Ticker::ReadAmpTotalReadBytes => "rocksdb.read.amp.total.read.bytes",
+            Ticker::NumberRateLimiterDrains => "rocksdb.number.rate_limiter.drains",
+            Ticker::NumberIterSkip => "rocksdb.number.iter.skip",
+            Ticker::BlobDbNumPut => "rocksdb.blobdb.num.put",
+            Ticker::BlobDbNumWrite => "rocksdb.blobdb.num.write",
+            Ticker::BlobDbNumGet => "rocksdb.blobdb.num.get",
+            Ticker::BlobDbNumMultiget => "rocksdb.blobdb.num.multiget",
+            Ticker::BlobDbNumSeek => "rocksdb.blobdb.num.seek",
+            Ticker::BlobDbNumNext => "rocksdb.blobdb.num.next",
+            Ticker::BlobDbNumPrev => "rocksdb.blobdb.num.prev",
+            Ticker::BlobDbNumKeysWritten => "rocksdb.blobdb.num.keys.written",
+            Ticker::BlobDbNumKeysRead => "rocksdb.blobdb.num.keys.read",
+            Ticker::BlobDbBytesWritten => "rocksdb.blobdb.bytes.written",
+            Ticker::BlobDbBytesRead => "rocksdb.blobdb.bytes.read",
+            Ticker::BlobDbWriteInlined => "rocksdb.blobdb.write.inlined",
+            Ticker::BlobDbWriteInlinedTtl => "rocksdb.blobdb.write.inlined.ttl",
+            Ticker::BlobDbWriteBlob => "rocksdb.blobdb.write.blob",
+            Ticker::BlobDbWriteBlobTtl => "rocksdb.blobdb.write.blob.ttl",
+            Ticker::BlobDbBlobFileBytesWritten => "rocksdb.blobdb.blob.file.bytes.written",
+            Ticker::BlobDbBlobFileBytesRead => "rocksdb.blobdb.blob.file.bytes.read",
+            Ticker::BlobDbBlobFileSynced => "rocksdb.blobdb.blob.file.synced",
+            Ticker::BlobDbBlobIndexExpiredCount => "rocksdb.blobdb.blob.index.expired.count",
+            Ticker::BlobDbBlobIndexExpiredSize => "rocksdb.blobdb.blob.index.expired.size",
+            Ticker::BlobDbBlobIndexEvictedCount => "rocksdb.blobdb.blob.index.evicted.count",
+            Ticker::BlobDbBlobIndexEvictedSize => "rocksdb.blobdb.blob.index.evicted.size",
+            Ticker::BlobDbGcNumFiles => "rocksdb.blobdb.gc.num.files",
+            Ticker::BlobDbGcNumNewFiles => "rocksdb.blobdb.gc.num.new.files",
+            Ticker::BlobDbGcFailures => "rocksdb.blobdb.gc.failures",
+            Ticker::BlobDbGcNumKeysRelocated => "rocksdb.blobdb.gc.num.keys.relocated",
+            Ticker::BlobDbGcBytesRelocated => "rocksdb.blobdb.gc.bytes.relocated",
+            Ticker::BlobDbFifoNumFilesEvicted => "rocksdb.blobdb.fifo.num.files.evicted",
+            Ticker::BlobDbFifoNumKeysEvicted => "rocksdb.blobdb.fifo.num.keys.evicted",
+            Ticker::BlobDbFifoBytesEvicted => "rocksdb.blobdb.fifo.bytes.evicted",
+            Ticker::TxnPrepareMutexOverhead => "rocksdb.txn.overhead.mutex.prepare",
+            Ticker::TxnOldCommitMapMutexOverhead => "rocksdb.txn.overhead.mutex.old.commit.map",
+            Ticker::TxnDuplicateKeyOverhead => "rocksdb.txn.overhead.duplicate.key",
+            Ticker::TxnSnapshotMutexOverhead => "rocksdb.txn.overhead.mutex.snapshot",
+            Ticker::TxnGetTryAgain => "rocksdb.txn.get.tryagain",
+            Ticker::NumberMultigetKeysFound => "rocksdb.number.multiget.keys.found",
+            Ticker::NoIteratorCreated => "rocksdb.num.iterator.created",
+            Ticker::NoIteratorDeleted => "rocksdb.num.iterator.deleted",
+            Ticker::BlockCacheCompressionDictMiss => "rocksdb.block.cache.compression.dict.miss",
+            Ticker::BlockCacheCompressionDictHit => "rocksdb.block.cache.compression.dict.hit",
+            Ticker::BlockCacheCompressionDictAdd => "rocksdb.block.cache.compression.dict.add",
+            Ticker::BlockCacheCompressionDictBytesInsert => {
+                "rocksdb.block.cache.compression.dict.bytes.insert"
+            }
+            Ticker::BlockCacheAddRedundant => "rocksdb.block.cache.add.redundant",
+            Ticker::BlockCacheIndexAddRedundant => "rocksdb.block.cache.index.add.redundant",
+            Ticker::BlockCacheFilterAddRedundant => "rocksdb.block.cache.filter.add.redundant",
+            Ticker::BlockCacheDataAddRedundant => "rocksdb.block.cache.data.add.redundant",
+            Ticker::BlockCacheCompressionDictAddRedundant => {
+                "rocksdb.block.cache.compression.dict.add.redundant"
+            }
+            Ticker::FilesMarkedTrash => "rocksdb.files.marked.trash",
+            Ticker::FilesDeletedFromTrashQueue => "rocksdb.files.marked.trash.deleted",
+            Ticker::FilesDeletedImmediately => "rocksdb.files.deleted.immediately",
+            Ticker::ErrorHandlerBgErrorCount => "rocksdb.error.handler.bg.error.count",
+            Ticker::ErrorHandlerBgIoErrorCount => "rocksdb.error.handler.bg.io.error.count",
+            Ticker::ErrorHandlerBgRetryableIoErrorCount => {
+                "rocksdb.error.handler.bg.retryable.io.error.count"
+            }
+            Ticker::ErrorHandlerAutoresumeCount => "rocksdb.error.handler.autoresume.count",
+            Ticker::ErrorHandlerAutoresumeRetryTotalCount => {
+                "rocksdb.error.handler.autoresume.retry.total.count"
+            }
+            Ticker::ErrorHandlerAutoresumeSuccessCount => {
+                "rocksdb.error.handler.autoresume.success.count"
+            }
+            Ticker::MemtablePayloadBytesAtFlush => "rocksdb.memtable.payload.bytes.at.flush",
+            Ticker::MemtableGarbageBytesAtFlush => "rocksdb.memtable.garbage.bytes.at.flush",
+            Ticker::SecondaryCacheHits => "rocksdb.secondary.cache.hits",
+            Ticker::VerifyChecksumReadBytes => "rocksdb.verify_checksum.read.bytes",
+            Ticker::BackupReadBytes => "rocksdb.backup.read.bytes",
+            Ticker::BackupWriteBytes => "rocksdb.backup.write.bytes",
+            Ticker::RemoteCompactReadBytes => "rocksdb.remote.compact.read.bytes",
+            Ticker::RemoteCompactWriteBytes => "rocksdb.remote.compact.write.bytes",
+            Ticker::HotFileReadBytes => "rocksdb.hot.file.read.bytes",
+            Ticker::WarmFileReadBytes => "rocksdb.warm.file.read.bytes",
+            Ticker::ColdFileReadBytes => "rocksdb.cold.file.read.bytes",
+            Ticker::HotFileReadCount => "rocksdb.hot.file.read.count",
+            Ticker::WarmFileReadCount => "rocksdb.warm.file.read.count",
+            Ticker::ColdFileReadCount => "rocksdb.cold.file.read.count",
+            Ticker::LastLevelReadBytes => "rocksdb.last.level.read.bytes",
+            Ticker::LastLevelReadCount => "rocksdb.last.level.read.count",
+            Ticker::NonLastLevelReadBytes => "rocksdb.non.last.level.read.bytes",
+            Ticker::NonLastLevelReadCount => "rocksdb.non.last.level.read.count",
+            Ticker::LastLevelSeekFiltered => "rocksdb.last.level.seek.filtered",
+            Ticker::LastLevelSeekFilterMatch => "rocksdb.last.level.seek.filter.match",
+            Ticker::LastLevelSeekData => "rocksdb.last.level.seek.data",
+            Ticker::LastLevelSeekDataUsefulNoFilter => {
+                "rocksdb.last.level.seek.data.useful.no.filter"
+            }
+            Ticker::LastLevelSeekDataUsefulFilterMatch => {
+                "rocksdb.last.level.seek.data.useful.filter.match"
+            }
+            Ticker::NonLastLevelSeekFiltered => "rocksdb.non.last.level.seek.filtered",
+            Ticker::NonLastLevelSeekFilterMatch => "rocksdb.non.last.level.seek.filter.match",
+            Ticker::NonLastLevelSeekData => "rocksdb.non.last.level.seek.data",
+            Ticker::NonLastLevelSeekDataUsefulNoFilter => {
+                "rocksdb.non.last.level.seek.data.useful.no.filter"
+            }
+            Ticker::NonLastLevelSeekDataUsefulFilterMatch => {
+                "rocksdb.non.last.level.seek.data.useful.filter.match"
+            }
+            Ticker::BlockChecksumComputeCount => "rocksdb.block.checksum.compute.count",
+            Ticker::BlockChecksumMismatchCount => "rocksdb.block.checksum.mismatch.count",
+            Ticker::MultigetCoroutineCount => "rocksdb.multiget.coroutine.count",
+            Ticker::BlobDbCacheMiss => "rocksdb.blobdb.cache.miss",
+            Ticker::BlobDbCacheHit => "rocksdb.blobdb.cache.hit",
+            Ticker::BlobDbCacheAdd => "rocksdb.blobdb.cache.add",
+            Ticker::BlobDbCacheAddFailures => "rocksdb.blobdb.cache.add.failures",
+            Ticker::BlobDbCacheBytesRead => "rocksdb.blobdb.cache.bytes.read",
+            Ticker::BlobDbCacheBytesWrite => "rocksdb.blobdb.cache.bytes.write",
+            Ticker::ReadAsyncMicros => "rocksdb.read.async.micros",
+            Ticker::AsyncReadErrorCount => "rocksdb.async.read.error.count",
+            Ticker::SecondaryCacheFilterHits => "rocksdb.secondary.cache.filter.hits",
+            Ticker::SecondaryCacheIndexHits => "rocksdb.secondary.cache.index.hits",
+            Ticker::SecondaryCacheDataHits => "rocksdb.secondary.cache.data.hits",
+            Ticker::TableOpenPrefetchTailMiss => "rocksdb.table.open.prefetch.tail.miss",
+            Ticker::TableOpenPrefetchTailHit => "rocksdb.table.open.prefetch.tail.hit",
+            Ticker::TimestampFilterTableChecked => "rocksdb.timestamp.filter.table.checked",
+            Ticker::TimestampFilterTableFiltered => "rocksdb.timestamp.filter.table.filtered",
+            Ticker::BytesCompressedFrom => "rocksdb.bytes.compressed.from",
+            Ticker::BytesCompressedTo => "rocksdb.bytes.compressed.to",
+            Ticker::BytesCompressionBypassed => "rocksdb.bytes.compression_bypassed",
+            Ticker::BytesCompressionRejected => "rocksdb.bytes.compression.rejected",
+            Ticker::NumberBlockCompressionBypassed => "rocksdb.number.block_compression_bypassed",
+            Ticker::NumberBlockCompressionRejected => "rocksdb.number.block_compression_rejected",
+            Ticker::BytesDecompressedFrom => "rocksdb.bytes.decompressed.from",
+            Ticker::BytesDecompressedTo => "rocksdb.bytes.decompressed.to",
+            Ticker::ReadaheadTrimmed => "rocksdb.readahead.trimmed",
+            Ticker::FifoMaxSizeCompactions => "rocksdb.fifo.max.size.compactions",
+            Ticker::FifoTtlCompactions => "rocksdb.fifo.ttl.compactions",
+            Ticker::PrefetchBytes => "rocksdb.prefetch.bytes",
+            Ticker::PrefetchBytesUseful => "rocksdb.prefetch.bytes.useful",
+            Ticker::PrefetchHits => "rocksdb.prefetch.hits",
+            Ticker::CompressedSecondaryCacheDummyHits => {
+                "rocksdb.compressed.secondary.cache.dummy.hits"
+            }
+            Ticker::CompressedSecondaryCacheHits => "rocksdb.compressed.secondary.cache.hits",
+            Ticker::CompressedSecondaryCachePromotions => {
+                "rocksdb.compressed.secondary.cache.promotions"
+            }
+            Ticker::CompressedSecondaryCachePromotionSkips => {
+                "rocksdb.compressed.secondary.cache.promotion.skips"
+            }
+        }
+    }
+}
+
+/// A distribution of values recorded by [`Statistics`].
+///
+/// Variants that the linked RocksDB version does not record read as zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Histogram {
+    DbGet,
+    DbWrite,
+    CompactionTime,
+    CompactionCpuTime,
+    SubcompactionSetupTime,
+    TableSyncMicros,
+    CompactionOutfileSyncMicros,
+    WalFileSyncMicros,
+    ManifestFileSyncMicros,
+    TableOpenIoMicros,
+    DbMultiget,
+    ReadBlockCompactionMicros,
+    ReadBlockGetMicros,
+    WriteRawBlockMicros,
+    NumFilesInSingleCompaction,
+    DbSeek,
+    WriteStall,
+    SstReadMicros,
+    FileReadFlushMicros,
+    FileReadCompactionMicros,
+    FileReadDbOpenMicros,
+    FileReadGetMicros,
+    FileReadMultigetMicros,
+    FileReadDbIteratorMicros,
+    FileReadVerifyDbChecksumMicros,
+    FileReadVerifyFileChecksumsMicros,
+    NumSubcompactionsScheduled,
+    BytesPerRead,
+    BytesPerWrite,
+    BytesPerMultiget,
+    BytesCompressed,
+    BytesDecompressed,
+    CompressionTimesNanos,
+    DecompressionTimesNanos,
+    ReadNumMergeOperands,
+    BlobDbKeySize,
+    BlobDbValueSize,
+    BlobDbWriteMicros,
+    BlobDbGetMicros,
+    BlobDbMultigetMicros,
+    BlobDbSeekMicros,
+    BlobDbNextMicros,
+    BlobDbPrevMicros,
+    BlobDbBlobFileWriteMicros,
+    BlobDbBlobFileReadMicros,
+    BlobDbBlobFileSyncMicros,
+    BlobDbCompressionMicros,
+    BlobDbDecompressionMicros,
+    FlushTime,
+    SstBatchSize,
+    NumIndexAndFilterBlocksReadPerLevel,
+    NumSstReadPerLevel,
+    ErrorHandlerAutoresumeRetryCount,
+    AsyncReadBytes,
+    PollWaitMicros,
+    PrefetchedBytesDiscarded,
+    MultigetIoBatchSize,
+    NumLevelReadPerMultiget,
+    AsyncPrefetchAbortMicros,
+    TableOpenPrefetchTailReadBytes,
+}
+
+impl Histogram {
+    /// All variants, in RocksDB's order.
+    pub const ALL: &'static [Histogram] = &[
+        Histogram::DbGet,
+        Histogram::DbWrite,
+        Histogram::CompactionTime,
+        Histogram::CompactionCpuTime,
+        Histogram::SubcompactionSetupTime,
+        Histogram::TableSyncMicros,
+        Histogram::CompactionOutfileSyncMicros,
+        Histogram::WalFileSyncMicros,
+        Histogram::ManifestFileSyncMicros,
+        Histogram::TableOpenIoMicros,
+        Histogram::DbMultiget,
+        Histogram::ReadBlockCompactionMicros,
+        Histogram::ReadBlockGetMicros,
+        Histogram::WriteRawBlockMicros,
+        Histogram::NumFilesInSingleCompaction,
+        Histogram::DbSeek,
+        Histogram::WriteStall,
+        Histogram::SstReadMicros,
+        Histogram::FileReadFlushMicros,
+        Histogram::FileReadCompactionMicros,
+        Histogram::FileReadDbOpenMicros,
+        Histogram::FileReadGetMicros,
+        Histogram::FileReadMultigetMicros,
+        Histogram::FileReadDbIteratorMicros,
+        Histogram::FileReadVerifyDbChecksumMicros,
+        Histogram::FileReadVerifyFileChecksumsMicros,
+        Histogram::NumSubcompactionsScheduled,
+        Histogram::BytesPerRead,
+        Histogram::BytesPerWrite,
+        Histogram::BytesPerMultiget,
+        Histogram::BytesCompressed,
+        Histogram::BytesDecompressed,
+        Histogram::CompressionTimesNanos,
+        Histogram::DecompressionTimesNanos,
+        Histogram::ReadNumMergeOperands,
+        Histogram::BlobDbKeySize,
+        Histogram::BlobDbValueSize,
+        Histogram::BlobDbWriteMicros,
+        Histogram::BlobDbGetMicros,
+        Histogram::BlobDbMultigetMicros,
+        Histogram::BlobDbSeekMicros,
+        Histogram::BlobDbNextMicros,
+        Histogram::BlobDbPrevMicros,
+        Histogram::BlobDbBlobFileWriteMicros,
+        Histogram::BlobDbBlobFileReadMicros,
+        Histogram::BlobDbBlobFileSyncMicros,
+        Histogram::BlobDbCompressionMicros,
+        Histogram::BlobDbDecompressionMicros,
+        Histogram::FlushTime,
+        Histogram::SstBatchSize,
+        Histogram::NumIndexAndFilterBlocksReadPerLevel,
+        Histogram::NumSstReadPerLevel,
+        Histogram::ErrorHandlerAutoresumeRetryCount,
+        Histogram::AsyncReadBytes,
+        Histogram::PollWaitMicros,
+        Histogram::PrefetchedBytesDiscarded,
+        Histogram::MultigetIoBatchSize,
+        Histogram::NumLevelReadPerMultiget,
+        Histogram::AsyncPrefetchAbortMicros,
+        Histogram::TableOpenPrefetchTailReadBytes,
+    ];
+
+    /// Returns the name RocksDB uses for this histogram in its statistics dump.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Histogram::DbGet => "rocksdb.db.get.micros",
+            Histogram::DbWrite => "rocksdb.db.write.micros",
+            Histogram::CompactionTime => "rocksdb.compaction.times.micros",
+            Histogram::CompactionCpuTime => "rocksdb.compaction.times.cpu_micros",
+            Histogram::SubcompactionSetupTime => "rocksdb.subcompaction.setup.times.micros",
+            Histogram::TableSyncMicros => "rocksdb.table.sync.micros",
+            Histogram::CompactionOutfileSyncMicros => "rocksdb.compaction.outfile.sync.micros",
+            Histogram::WalFileSyncMicros => "rocksdb.wal.file.sync.micros",
+            Histogram::ManifestFileSyncMicros => "rocksdb.manifest.file.sync.micros",
+            Histogram::TableOpenIoMicros => "rocksdb.table.open.io.micros",
+            Histogram::DbMultiget => "rocksdb.db.multiget.micros",
+            Histogram::ReadBlockCompactionMicros => "rocksdb.read.block.compaction.micros",
+            Histogram::ReadBlockGetMicros => "rocksdb.read.block.get.micros",
+            Histogram::WriteRawBlockMicros => "rocksdb.write.raw.block.micros",
+            Histogram::NumFilesInSingleCompaction => "rocksdb.numfiles.in.singlecompaction",
+            Histogram::DbSeek => "rocksdb.db.seek.micros",
+            Histogram::WriteStall => "rocksdb.db.write.stall",
+            Histogram::SstReadMicros => "rocksdb.sst.read.micros",
+            Histogram::FileReadFlushMicros => "rocksdb.file.read.flush.micros",
+            Histogram::FileReadCompactionMicros => "rocksdb.file.read.compaction.micros",
+            Histogram::FileReadDbOpenMicros => "rocksdb.file.read.db.open.micros",
+            Histogram::FileReadGetMicros => "rocksdb.file.read.get.micros",
+            Histogram::FileReadMultigetMicros => "rocksdb.file.read.multiget.micros",
+            Histogram::FileReadDbIteratorMicros => "rocksdb.file.read.db.iterator.micros",
+            Histogram::FileReadVerifyDbChecksumMicros => {
+                "rocksdb.file.read.verify.db.checksum.micros"
+            }
+            Histogram::FileReadVerifyFileChecksumsMicros => {
+                "rocksdb.file.read.verify.file.checksums.micros"
+            }
+            Histogram::NumSubcompactionsScheduled => "rocksdb.num.subcompactions.scheduled",
+            Histogram::BytesPerRead => "rocksdb.bytes.per.read",
+            Histogram::BytesPerWrite => "rocksdb.bytes.per.write",
+            Histogram::BytesPerMultiget => "rocksdb.bytes.per.multiget",
+            Histogram::BytesCompressed => "rocksdb.bytes.compressed",
+            Histogram::BytesDecompressed => "rocksdb.bytes.decompressed",
+            Histogram::CompressionTimesNanos => "rocksdb.compression.times.nanos",
+            Histogram::DecompressionTimesNanos => "rocksdb.decompression.times.nanos",
+            Histogram::ReadNumMergeOperands => "rocksdb.read.num.merge_operands",
+            Histogram::BlobDbKeySize => "rocksdb.blobdb.key.size",
+            Histogram::BlobDbValueSize => "rocksdb.blobdb.value.size",
+            Histogram::BlobDbWriteMicros => "rocksdb.blobdb.write.micros",
+            Histogram::BlobDbGetMicros => "rocksdb.blobdb.get.micros",
+            Histogram::BlobDbMultigetMicros => "rocksdb.blobdb.multiget.micros",
+            Histogram::BlobDbSeekMicros => "rocksdb.blobdb.seek.micros",
+            Histogram::BlobDbNextMicros => "rocksdb.blobdb.next.micros",
+            Histogram::BlobDbPrevMicros => "rocksdb.blobdb.prev.micros",
+            Histogram::BlobDbBlobFileWriteMicros => "rocksdb.blobdb.blob.file.write.micros",
+            Histogram::BlobDbBlobFileReadMicros => "rocksdb.blobdb.blob.file.read.micros",
+            Histogram::BlobDbBlobFileSyncMicros => "rocksdb.blobdb.blob.file.sync.micros",
+            Histogram::BlobDbCompressionMicros => "rocksdb.blobdb.compression.micros",
+            Histogram::BlobDbDecompressionMicros => "rocksdb.blobdb.decompression.micros",
+            Histogram::FlushTime => "rocksdb.db.flush.micros",
+            Histogram::SstBatchSize => "rocksdb.sst.batch.size",
+            Histogram::NumIndexAndFilterBlocksReadPerLevel => {
+                "rocksdb.num.index.and.filter.blocks.read.per.level"
+            }
+            Histogram::NumSstReadPerLevel => "rocksdb.num.sst.read.per.level",
+            Histogram::ErrorHandlerAutoresumeRetryCount => {
+                "rocksdb.error.handler.autoresume.retry.count"
+            }
+            Histogram::AsyncReadBytes => "rocksdb.async.read.bytes",
+            Histogram::PollWaitMicros => "rocksdb.poll.wait.micros",
+            Histogram::PrefetchedBytesDiscarded => "rocksdb.prefetched.bytes.discarded",
+            Histogram::MultigetIoBatchSize => "rocksdb.multiget.io.batch.size",
+            Histogram::NumLevelReadPerMultiget => "rocksdb.num.level.read.per.multiget",
+            Histogram::AsyncPrefetchAbortMicros => "rocksdb.async.prefetch.abort.micros",
+            Histogram::TableOpenPrefetchTailReadBytes => {
+                "rocksdb.table.open.prefetch.tail.read.bytes"
+            }
+        }
+    }
+}
@@ -0,0 +1,355 @@
+//! Properties of SST files, as returned by
+//! [`DBCommon::get_properties_of_all_tables_cf`](crate::DBCommon::get_properties_of_all_tables_cf),
+//! and collectors adding custom properties to the files being written.
+//!
+//! Register a [`TablePropertiesCollectorFactory`] with
+//! [`Options::add_table_properties_collector_factory`](crate::Options::add_table_properties_collector_factory).
+use std::collections::{BTreeMap, HashMap};
+use std::ffi::CStr;
+use std::{ptr, slice};
+
+use libc::{c_char, c_int, c_uchar, c_void, size_t};
+
+use crate::ffi;
+
+/// Properties of a single SST file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableProperties {
+    /// Number of the file when it was created. It may differ from the current
+    /// file number if the file was imported or ingested.
+    pub orig_file_number: u64,
+    /// Total size of the data blocks in bytes
+    pub data_size: u64,
+    /// Size of the index block in bytes
+    pub index_size: u64,
+    /// Size of the filter block in bytes
+    pub filter_size: u64,
+    /// Total size of the keys before compression in bytes
+    pub raw_key_size: u64,
+    /// Total size of the values before compression in bytes
+    pub raw_value_size: u64,
+    /// Number of data blocks
+    pub num_data_blocks: u64,
+    /// Number of entries
+    pub num_entries: u64,
+    /// Number of deletions
+    pub num_deletions: u64,
+    /// Number of merge operands
+    pub num_merge_operands: u64,
+    /// Number of range deletions
+    pub num_range_deletions: u64,
+    /// Format version of the table
+    pub format_version: u64,
+    /// Length of the keys if they all have the same length, otherwise 0
+    pub fixed_key_len: u64,
+    /// Id of the column family the file belongs to
+    pub column_family_id: u64,
+    /// Name of the column family the file belongs to
+    pub column_family_name: String,
+    /// Time the oldest key of the file was written to the database, in
+    /// seconds since the epoch. 0 if unknown.
+    pub creation_time: u64,
+    /// Time of the oldest key in the file, in seconds since the epoch. 0 if
+    /// unknown.
+    pub oldest_key_time: u64,
+    /// Time the file was created, in seconds since the epoch. 0 if unknown.
+    pub file_creation_time: u64,
+    /// Name of the filter policy, empty if none
+    pub filter_policy_name: String,
+    /// Name of the comparator
+    pub comparator_name: String,
+    /// Name of the merge operator, `nullptr` if none
+    pub merge_operator_name: String,
+    /// Name of the prefix extractor, `nullptr` if none
+    pub prefix_extractor_name: String,
+    /// Names of the table properties collectors, e.g. `[a, b]`
+    pub property_collectors_names: String,
+    /// Name of the compression algorithm
+    pub compression_name: String,
+    /// Options of the compression algorithm
+    pub compression_options: String,
+    /// Properties added by table properties collectors
+    pub user_collected_properties: BTreeMap<String, Vec<u8>>,
+}
+
+unsafe fn borrowed_string(ptr: *const c_char, len: size_t) -> String {
+    String::from_utf8_lossy(slice::from_raw_parts(ptr as *const u8, len)).into_owned()
+}
+
+unsafe extern "C" fn insert_user_collected_property(
+    raw_map: *mut c_void,
+    key: *const c_char,
+    key_len: size_t,
+    value: *const c_char,
+    value_len: size_t,
+) {
+    let map = &mut *(raw_map as *mut BTreeMap<String, Vec<u8>>);
+    map.insert(
+        borrowed_string(key, key_len),
+        slice::from_raw_parts(value as *const u8, value_len).to_vec(),
+    );
+}
+
+impl TableProperties {
+    unsafe fn from_c(ptr: *const ffi::rocksdb_table_properties_t) -> Self {
+        let mut len: size_t = 0;
+        let mut string = |getter: unsafe extern "C" fn(
+            *const ffi::rocksdb_table_properties_t,
+            *mut size_t,
+        ) -> *const c_char| {
+            let s = getter(ptr, &mut len);
+            borrowed_string(s, len)
+        };
+
+        let column_family_name = string(ffi::rocksdb_table_properties_get_column_family_name);
+        let filter_policy_name = string(ffi::rocksdb_table_properties_get_filter_policy_name);
+        let comparator_name = string(ffi::rocksdb_table_properties_get_comparator_name);
+        let merge_operator_name = string(ffi::rocksdb_table_properties_get_merge_operator_name);
+        let prefix_extractor_name = string(ffi::rocksdb_table_properties_get_prefix_extractor_name);
+        let property_collectors_names =
+            string(ffi::rocksdb_table_properties_get_property_collectors_names);
+        let compression_name = string(ffi::rocksdb_table_properties_get_compression_name);
+        let compression_options = string(ffi::rocksdb_table_properties_get_compression_options);
+
+        let mut user_collected_properties = BTreeMap::new();
+        ffi::rocksdb_table_properties_iterate_user_collected_properties(
+            ptr,
+            ptr::addr_of_mut!(user_collected_properties).cast::<c_void>(),
+            Some(insert_user_collected_property),
+        );
+
+        TableProperties {
+            orig_file_number: ffi::rocksdb_table_properties_get_orig_file_number(ptr),
+            data_size: ffi::rocksdb_table_properties_get_data_size(ptr),
+            index_size: ffi::rocksdb_table_properties_get_index_size(ptr),
+            filter_size: ffi::rocksdb_table_properties_get_filter_size(ptr),
+            raw_key_size: ffi::rocksdb_table_properties_get_raw_key_size(ptr),
+            raw_value_size: ffi::rocksdb_table_properties_get_raw_value_size(ptr),
+            num_data_blocks: ffi::rocksdb_table_properties_get_num_data_blocks(ptr),
+            num_entries: ffi::rocksdb_table_properties_get_num_entries(ptr),
+            num_deletions: ffi::rocksdb_table_properties_get_num_deletions(ptr),
+            num_merge_operands: ffi::rocksdb_table_properties_get_num_merge_operands(ptr),
+            num_range_deletions: ffi::rocksdb_table_properties_get_num_range_deletions(ptr),
+            format_version: ffi::rocksdb_table_properties_get_format_version(ptr),
+            fixed_key_len: ffi::rocksdb_table_properties_get_fixed_key_len(ptr),
+            column_family_id: ffi::rocksdb_table_properties_get_column_family_id(ptr),
+            column_family_name,
+            creation_time: ffi::rocksdb_table_properties_get_creation_time(ptr),
+            oldest_key_time: ffi::rocksdb_table_properties_get_oldest_key_time(ptr),
+            file_creation_time: ffi::rocksdb_table_properties_get_file_creation_time(ptr),
+            filter_policy_name,
+            comparator_name,
+            merge_operator_name,
+            prefix_extractor_name,
+            property_collectors_names,
+            compression_name,
+            compression_options,
+            user_collected_properties,
+        }
+    }
+
+    /// Copies the properties out of `ptr`, keyed by file path, and destroys
+    /// it afterwards.
+    ///
+    /// # Unsafe
+    /// Requires that the pointer must be generated by one of the
+    /// `rocksdb_get_properties_of_*` functions
+    pub(crate) unsafe fn collection_from_c(
+        ptr: *mut ffi::rocksdb_table_properties_collection_t,
+    ) -> HashMap<String, TableProperties> {
+        let count = ffi::rocksdb_table_properties_collection_count(ptr);
+        let collection = (0..count)
+            .map(|i| {
+                let mut len: size_t = 0;
+                let path = ffi::rocksdb_table_properties_collection_file_path(ptr, i, &mut len);
+                let props = ffi::rocksdb_table_properties_collection_get(ptr, i);
+                (borrowed_string(path, len), TableProperties::from_c(props))
+            })
+            .collect();
+        ffi::rocksdb_table_properties_collection_destroy(ptr);
+        collection
+    }
+}
+
+/// Type of an entry passed to [`TablePropertiesCollector::add_user_key`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EntryType {
+    Put,
+    Delete,
+    SingleDelete,
+    Merge,
+    RangeDeletion,
+    BlobIndex,
+    DeleteWithTimestamp,
+    WideColumnEntity,
+    Other,
+}
+
+impl EntryType {
+    fn from_raw(raw: c_int) -> Self {
+        match raw {
+            0 => EntryType::Put,
+            1 => EntryType::Delete,
+            2 => EntryType::SingleDelete,
+            3 => EntryType::Merge,
+            4 => EntryType::RangeDeletion,
+            5 => EntryType::BlobIndex,
+            6 => EntryType::DeleteWithTimestamp,
+            7 => EntryType::WideColumnEntity,
+            _ => EntryType::Other,
+        }
+    }
+}
+
+/// Collects custom properties of a single SST file while it is written.
+///
+/// The properties returned by [`finish`](TablePropertiesCollector::finish)
+/// are stored in the file and show up in
+/// [`TableProperties::user_collected_properties`].
+pub trait TablePropertiesCollector: Send {
+    /// Called for each entry added to the file, in key order.
+    fn add_user_key(
+        &mut self,
+        key: &[u8],
+        value: &[u8],
+        entry_type: EntryType,
+        seq: u64,
+        file_size: u64,
+    );
+
+    /// Called once all entries are added, returns the properties to store in
+    /// the file.
+    fn finish(&mut self) -> BTreeMap<String, Vec<u8>>;
+
+    /// Whether the file should be compacted again. Checked after `finish`.
+    fn need_compact(&self) -> bool {
+        false
+    }
+
+    /// Returns a name that identifies this collector.
+    fn name(&self) -> &CStr;
+}
+
+/// Context of the file a [`TablePropertiesCollector`] is created for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TablePropertiesCollectorContext {
+    /// Id of the column family the file belongs to
+    pub column_family_id: u32,
+    /// Level the file is created at, `None` if unknown
+    pub level_at_creation: Option<i32>,
+}
+
+/// Creates a [`TablePropertiesCollector`] for each SST file being written.
+pub trait TablePropertiesCollectorFactory: Send + Sync {
+    type Collector: TablePropertiesCollector;
+
+    /// Returns a collector for a new file. Called by foreground and background
+    /// threads, possibly concurrently.
+    fn create(&self, context: TablePropertiesCollectorContext) -> Self::Collector;
+
+    /// Returns a name that identifies this collector factory.
+    fn name(&self) -> &CStr;
+}
+
+pub(crate) unsafe extern "C" fn factory_destructor_callback<F>(raw_self: *mut c_void)
+where
+    F: TablePropertiesCollectorFactory,
+{
+    drop(Box::from_raw(raw_self as *mut F));
+}
+
+pub(crate) unsafe extern "C" fn factory_name_callback<F>(raw_self: *mut c_void) -> *const c_char
+where
+    F: TablePropertiesCollectorFactory,
+{
+    let self_ = &*(raw_self as *const F);
+    self_.name().as_ptr()
+}
+
+pub(crate) unsafe extern "C" fn create_collector_callback<F>(
+    raw_self: *mut c_void,
+    column_family_id: u32,
+    level_at_creation: c_int,
+) -> *mut ffi::rocksdb_table_properties_collector_t
+where
+    F: TablePropertiesCollectorFactory,
+{
+    let self_ = &*(raw_self as *const F);
+    let context = TablePropertiesCollectorContext {
+        column_family_id,
+        level_at_creation: if level_at_creation < 0 {
+            None
+        } else {
+            Some(level_at_creation)
+        },
+    };
+    let collector = Box::new(self_.create(context));
+
+    ffi::rocksdb_table_properties_collector_create(
+        Box::into_raw(collector).cast::<c_void>(),
+        Some(collector_destructor_callback::<F::Collector>),
+        Some(add_user_key_callback::<F::Collector>),
+        Some(finish_callback::<F::Collector>),
+        Some(need_compact_callback::<F::Collector>),
+        Some(collector_name_callback::<F::Collector>),
+    )
+}
+
+unsafe extern "C" fn collector_destructor_callback<C>(raw_self: *mut c_void)
+where
+    C: TablePropertiesCollector,
+{
+    drop(Box::from_raw(raw_self as *mut C));
+}
+
+unsafe extern "C" fn add_user_key_callback<C>(
+    raw_self: *mut c_void,
+    key: *const c_char,
+    key_len: size_t,
+    value: *const c_char,
+    value_len: size_t,
+    entry_type: c_int,
+    seq: u64,
+    file_size: u64,
+) where
+    C: TablePropertiesCollector,
+{
+    let self_ = &mut *(raw_self as *mut C);
+    let key = slice::from_raw_parts(key as *const u8, key_len);
+    let value = slice::from_raw_parts(value as *const u8, value_len);
+    self_.add_user_key(key, value, EntryType::from_raw(entry_type), seq, file_size);
+}
+
+unsafe extern "C" fn finish_callback<C>(
+    raw_self: *mut c_void,
+    properties: *mut ffi::rocksdb_user_collected_properties_t,
+) where
+    C: TablePropertiesCollector,
+{
+    let self_ = &mut *(raw_self as *mut C);
+    for (key, value) in self_.finish() {
+        ffi::rocksdb_user_collected_properties_insert(
+            properties,
+            key.as_ptr() as *const c_char,
+            key.len() as size_t,
+            value.as_ptr() as *const c_char,
+            value.len() as size_t,
+        );
+    }
+}
+
+unsafe extern "C" fn need_compact_callback<C>(raw_self: *mut c_void) -> c_uchar
+where
+    C: TablePropertiesCollector,
+{
+    let self_ = &*(raw_self as *const C);
+    c_uchar::from(self_.need_compact())
+}
+
+unsafe extern "C" fn collector_name_callback<C>(raw_self: *mut c_void) -> *const c_char
+where
+    C: TablePropertiesCollector,
+{
+    let self_ = &*(raw_self as *const C);
+    self_.name().as_ptr()
+}
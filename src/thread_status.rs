@@ -0,0 +1,193 @@
+//! `ThreadStatus` describes what a thread of an [`Env`](crate::Env) is doing,
+//! see [`Env::get_thread_list`](crate::Env::get_thread_list).
+use std::collections::BTreeMap;
+use std::ptr;
+use std::slice;
+use std::time::Duration;
+
+use libc::{c_char, c_int, c_void, size_t};
+
+use crate::ffi;
+
+/// The status of a thread, as returned by
+/// [`Env::get_thread_list`](crate::Env::get_thread_list).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ThreadStatus {
+    /// Unique id of the thread
+    pub thread_id: u64,
+    /// Thread pool, or user thread, the thread belongs to
+    pub thread_type: ThreadType,
+    /// Path of the database the thread is working on, empty if none
+    pub db_name: String,
+    /// Name of the column family the thread is working on, empty if none
+    pub cf_name: String,
+    /// Operation the thread is running
+    pub operation_type: OperationType,
+    /// Time spent in the current operation
+    pub op_elapsed: Duration,
+    /// Stage of the current operation
+    pub operation_stage: OperationStage,
+    /// Properties of the current operation, e.g. the `JobID`, `InputOutputLevel`
+    /// and `TotalInputBytes` of a compaction
+    pub operation_properties: BTreeMap<String, u64>,
+    /// Whether the thread is waiting, e.g. on a mutex
+    pub state_type: StateType,
+}
+
+/// The kind of thread of a [`ThreadStatus`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ThreadType {
+    HighPriority,
+    LowPriority,
+    User,
+    BottomPriority,
+    Unknown,
+}
+
+impl ThreadType {
+    fn from_raw(raw: c_int) -> Self {
+        match raw {
+            0 => ThreadType::HighPriority,
+            1 => ThreadType::LowPriority,
+            2 => ThreadType::User,
+            3 => ThreadType::BottomPriority,
+            _ => ThreadType::Unknown,
+        }
+    }
+}
+
+/// The operation a thread is running.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OperationType {
+    Unknown,
+    Compaction,
+    Flush,
+}
+
+impl OperationType {
+    fn from_raw(raw: c_int) -> Self {
+        match raw {
+            1 => OperationType::Compaction,
+            2 => OperationType::Flush,
+            _ => OperationType::Unknown,
+        }
+    }
+}
+
+/// The stage of the operation a thread is running.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OperationStage {
+    Unknown,
+    FlushRun,
+    FlushWriteL0,
+    CompactionPrepare,
+    CompactionRun,
+    CompactionProcessKV,
+    CompactionInstall,
+    CompactionSyncFile,
+    PickMemtablesToFlush,
+    MemtableRollback,
+    MemtableInstallFlushResults,
+}
+
+impl OperationStage {
+    fn from_raw(raw: c_int) -> Self {
+        match raw {
+            1 => OperationStage::FlushRun,
+            2 => OperationStage::FlushWriteL0,
+            3 => OperationStage::CompactionPrepare,
+            4 => OperationStage::CompactionRun,
+            5 => OperationStage::CompactionProcessKV,
+            6 => OperationStage::CompactionInstall,
+            7 => OperationStage::CompactionSyncFile,
+            8 => OperationStage::PickMemtablesToFlush,
+            9 => OperationStage::MemtableRollback,
+            10 => OperationStage::MemtableInstallFlushResults,
+            _ => OperationStage::Unknown,
+        }
+    }
+}
+
+/// Whether a thread is waiting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StateType {
+    Unknown,
+    MutexWait,
+}
+
+impl StateType {
+    fn from_raw(raw: c_int) -> Self {
+        match raw {
+            1 => StateType::MutexWait,
+            _ => StateType::Unknown,
+        }
+    }
+}
+
+unsafe fn borrowed_string(ptr: *const c_char, len: size_t) -> String {
+    String::from_utf8_lossy(slice::from_raw_parts(ptr as *const u8, len)).into_owned()
+}
+
+unsafe extern "C" fn insert_operation_property(
+    raw_map: *mut c_void,
+    name: *const c_char,
+    name_len: size_t,
+    value: u64,
+) {
+    let map = &mut *(raw_map as *mut BTreeMap<String, u64>);
+    map.insert(borrowed_string(name, name_len), value);
+}
+
+impl ThreadStatus {
+    /// Copies the statuses out of `list` and destroys it afterwards.
+    ///
+    /// # Unsafe
+    /// Requires that the pointer must be generated by
+    /// `rocksdb_env_get_thread_list`
+    pub(crate) unsafe fn list_from_c(
+        list: *mut ffi::rocksdb_thread_status_list_t,
+    ) -> Vec<ThreadStatus> {
+        let count = ffi::rocksdb_thread_status_list_count(list);
+        let statuses = (0..count)
+            .map(|i| {
+                let mut len: size_t = 0;
+                let db_name = ffi::rocksdb_thread_status_list_get_db_name(list, i, &mut len);
+                let db_name = borrowed_string(db_name, len);
+                let cf_name = ffi::rocksdb_thread_status_list_get_cf_name(list, i, &mut len);
+                let cf_name = borrowed_string(cf_name, len);
+
+                let mut operation_properties = BTreeMap::new();
+                ffi::rocksdb_thread_status_list_iterate_operation_properties(
+                    list,
+                    i,
+                    ptr::addr_of_mut!(operation_properties).cast::<c_void>(),
+                    Some(insert_operation_property),
+                );
+
+                ThreadStatus {
+                    thread_id: ffi::rocksdb_thread_status_list_get_thread_id(list, i),
+                    thread_type: ThreadType::from_raw(
+                        ffi::rocksdb_thread_status_list_get_thread_type(list, i),
+                    ),
+                    db_name,
+                    cf_name,
+                    operation_type: OperationType::from_raw(
+                        ffi::rocksdb_thread_status_list_get_operation_type(list, i),
+                    ),
+                    op_elapsed: Duration::from_micros(
+                        ffi::rocksdb_thread_status_list_get_op_elapsed_micros(list, i),
+                    ),
+                    operation_stage: OperationStage::from_raw(
+                        ffi::rocksdb_thread_status_list_get_operation_stage(list, i),
+                    ),
+                    operation_properties,
+                    state_type: StateType::from_raw(
+                        ffi::rocksdb_thread_status_list_get_state_type(list, i),
+                    ),
+                }
+            })
+            .collect();
+        ffi::rocksdb_thread_status_list_destroy(list);
+        statuses
+    }
+}
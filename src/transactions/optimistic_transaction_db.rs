@@ -69,13 +69,27 @@ impl DBInner for OptimisticTransactionDBInner {
     fn inner(&self) -> *mut ffi::rocksdb_t {
         self.base
     }
+
+    fn close(&mut self) -> Result<(), Error> {
+        let base = std::mem::replace(&mut self.base, ptr::null_mut());
+        let db = std::mem::replace(&mut self.db, ptr::null_mut());
+        if !db.is_null() {
+            unsafe {
+                ffi::rocksdb_optimistictransactiondb_close_base_db(base);
+                ffi_try!(ffi::rocksdb_optimistictransactiondb_close_with_status(db));
+            }
+        }
+        Ok(())
+    }
 }
 
 impl Drop for OptimisticTransactionDBInner {
     fn drop(&mut self) {
-        unsafe {
-            ffi::rocksdb_optimistictransactiondb_close_base_db(self.base);
-            ffi::rocksdb_optimistictransactiondb_close(self.db);
+        if !self.db.is_null() {
+            unsafe {
+                ffi::rocksdb_optimistictransactiondb_close_base_db(self.base);
+                ffi::rocksdb_optimistictransactiondb_close(self.db);
+            }
         }
     }
 }
@@ -210,7 +224,7 @@ impl<T: ThreadMode> OptimisticTransactionDB<T> {
         cpath: &CString,
     ) -> Result<*mut ffi::rocksdb_optimistictransactiondb_t, Error> {
         unsafe {
-            let db = ffi_try!(ffi::rocksdb_optimistictransactiondb_open(
+            let db = ffi_try!(ffi::rocksdb_optimistictransactiondb_open_with_status(
                 opts.inner,
                 cpath.as_ptr()
             ));
@@ -227,14 +241,16 @@ impl<T: ThreadMode> OptimisticTransactionDB<T> {
         cfhandles: &mut [*mut ffi::rocksdb_column_family_handle_t],
     ) -> Result<*mut ffi::rocksdb_optimistictransactiondb_t, Error> {
         unsafe {
-            let db = ffi_try!(ffi::rocksdb_optimistictransactiondb_open_column_families(
-                opts.inner,
-                cpath.as_ptr(),
-                cfs_v.len() as c_int,
-                cfnames.as_ptr(),
-                cfopts.as_ptr(),
-                cfhandles.as_mut_ptr(),
-            ));
+            let db = ffi_try!(
+                ffi::rocksdb_optimistictransactiondb_open_column_families_with_status(
+                    opts.inner,
+                    cpath.as_ptr(),
+                    cfs_v.len() as c_int,
+                    cfnames.as_ptr(),
+                    cfopts.as_ptr(),
+                    cfhandles.as_mut_ptr(),
+                )
+            );
             Ok(db)
         }
     }
@@ -272,7 +288,7 @@ impl<T: ThreadMode> OptimisticTransactionDB<T> {
         writeopts: &WriteOptions,
     ) -> Result<(), Error> {
         unsafe {
-            ffi_try!(ffi::rocksdb_optimistictransactiondb_write(
+            ffi_try!(ffi::rocksdb_optimistictransactiondb_write_with_status(
                 self.inner.db,
                 writeopts.inner,
                 batch.inner
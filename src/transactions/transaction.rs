@@ -140,7 +140,7 @@ impl<'db, DB> Transaction<'db, DB> {
     /// [`Options::set_max_write_buffer_size_to_maintain`]: crate::Options::set_max_write_buffer_size_to_maintain
     pub fn commit(self) -> Result<(), Error> {
         unsafe {
-            ffi_try!(ffi::rocksdb_transaction_commit(self.inner));
+            ffi_try!(ffi::rocksdb_transaction_commit_with_status(self.inner));
         }
         Ok(())
     }
@@ -149,7 +149,7 @@ impl<'db, DB> Transaction<'db, DB> {
         let ptr = name.as_ptr();
         let len = name.len();
         unsafe {
-            ffi_try!(ffi::rocksdb_transaction_set_name(
+            ffi_try!(ffi::rocksdb_transaction_set_name_with_status(
                 self.inner, ptr as _, len as _
             ));
         }
@@ -174,7 +174,7 @@ impl<'db, DB> Transaction<'db, DB> {
 
     pub fn prepare(&self) -> Result<(), Error> {
         unsafe {
-            ffi_try!(ffi::rocksdb_transaction_prepare(self.inner));
+            ffi_try!(ffi::rocksdb_transaction_prepare_with_status(self.inner));
         }
         Ok(())
     }
@@ -190,7 +190,7 @@ impl<'db, DB> Transaction<'db, DB> {
     /// Discard all batched writes in this transaction.
     pub fn rollback(&self) -> Result<(), Error> {
         unsafe {
-            ffi_try!(ffi::rocksdb_transaction_rollback(self.inner));
+            ffi_try!(ffi::rocksdb_transaction_rollback_with_status(self.inner));
             Ok(())
         }
     }
@@ -213,7 +213,9 @@ impl<'db, DB> Transaction<'db, DB> {
     /// [`set_savepoint`]: Self::set_savepoint
     pub fn rollback_to_savepoint(&self) -> Result<(), Error> {
         unsafe {
-            ffi_try!(ffi::rocksdb_transaction_rollback_to_savepoint(self.inner));
+            ffi_try!(ffi::rocksdb_transaction_rollback_to_savepoint_with_status(
+                self.inner
+            ));
             Ok(())
         }
     }
@@ -322,7 +324,7 @@ impl<'db, DB> Transaction<'db, DB> {
         readopts: &ReadOptions,
     ) -> Result<Option<DBPinnableSlice>, Error> {
         unsafe {
-            let val = ffi_try!(ffi::rocksdb_transaction_get_pinned(
+            let val = ffi_try!(ffi::rocksdb_transaction_get_pinned_with_status(
                 self.inner,
                 readopts.inner,
                 key.as_ref().as_ptr() as *const c_char,
@@ -360,7 +362,7 @@ impl<'db, DB> Transaction<'db, DB> {
         readopts: &ReadOptions,
     ) -> Result<Option<DBPinnableSlice>, Error> {
         unsafe {
-            let val = ffi_try!(ffi::rocksdb_transaction_get_pinned_cf(
+            let val = ffi_try!(ffi::rocksdb_transaction_get_pinned_cf_with_status(
                 self.inner,
                 readopts.inner,
                 cf.inner(),
@@ -400,7 +402,7 @@ impl<'db, DB> Transaction<'db, DB> {
         opts: &ReadOptions,
     ) -> Result<Option<DBPinnableSlice>, Error> {
         unsafe {
-            let val = ffi_try!(ffi::rocksdb_transaction_get_pinned_for_update(
+            let val = ffi_try!(ffi::rocksdb_transaction_get_pinned_for_update_with_status(
                 self.inner,
                 opts.inner,
                 key.as_ref().as_ptr() as *const c_char,
@@ -462,14 +464,16 @@ impl<'db, DB> Transaction<'db, DB> {
         opts: &ReadOptions,
     ) -> Result<Option<DBPinnableSlice>, Error> {
         unsafe {
-            let val = ffi_try!(ffi::rocksdb_transaction_get_pinned_for_update_cf(
-                self.inner,
-                opts.inner,
-                cf.inner(),
-                key.as_ref().as_ptr() as *const c_char,
-                key.as_ref().len() as size_t,
-                u8::from(exclusive),
-            ));
+            let val = ffi_try!(
+                ffi::rocksdb_transaction_get_pinned_for_update_cf_with_status(
+                    self.inner,
+                    opts.inner,
+                    cf.inner(),
+                    key.as_ref().as_ptr() as *const c_char,
+                    key.as_ref().len() as size_t,
+                    u8::from(exclusive),
+                )
+            );
             if val.is_null() {
                 Ok(None)
             } else {
@@ -507,7 +511,7 @@ impl<'db, DB> Transaction<'db, DB> {
         let mut values_sizes = vec![0_usize; keys.len()];
         let mut errors = vec![ptr::null_mut(); keys.len()];
         unsafe {
-            ffi::rocksdb_transaction_multi_get(
+            ffi::rocksdb_transaction_multi_get_with_status(
                 self.inner,
                 readopts.inner,
                 ptr_keys.len(),
@@ -563,7 +567,7 @@ impl<'db, DB> Transaction<'db, DB> {
         let mut values_sizes = vec![0_usize; ptr_keys.len()];
         let mut errors = vec![ptr::null_mut(); ptr_keys.len()];
         unsafe {
-            ffi::rocksdb_transaction_multi_get_cf(
+            ffi::rocksdb_transaction_multi_get_cf_with_status(
                 self.inner,
                 readopts.inner,
                 ptr_cfs.as_ptr(),
@@ -586,7 +590,7 @@ impl<'db, DB> Transaction<'db, DB> {
     /// [`put_cf`]: Self::put_cf
     pub fn put<K: AsRef<[u8]>, V: AsRef<[u8]>>(&self, key: K, value: V) -> Result<(), Error> {
         unsafe {
-            ffi_try!(ffi::rocksdb_transaction_put(
+            ffi_try!(ffi::rocksdb_transaction_put_with_status(
                 self.inner,
                 key.as_ref().as_ptr() as *const c_char,
                 key.as_ref().len() as size_t,
@@ -619,7 +623,7 @@ impl<'db, DB> Transaction<'db, DB> {
         value: V,
     ) -> Result<(), Error> {
         unsafe {
-            ffi_try!(ffi::rocksdb_transaction_put_cf(
+            ffi_try!(ffi::rocksdb_transaction_put_cf_with_status(
                 self.inner,
                 cf.inner(),
                 key.as_ref().as_ptr() as *const c_char,
@@ -638,7 +642,7 @@ impl<'db, DB> Transaction<'db, DB> {
     /// [`merge_cf`]: Self::merge_cf
     pub fn merge<K: AsRef<[u8]>, V: AsRef<[u8]>>(&self, key: K, value: V) -> Result<(), Error> {
         unsafe {
-            ffi_try!(ffi::rocksdb_transaction_merge(
+            ffi_try!(ffi::rocksdb_transaction_merge_with_status(
                 self.inner,
                 key.as_ref().as_ptr() as *const c_char,
                 key.as_ref().len() as size_t,
@@ -671,7 +675,7 @@ impl<'db, DB> Transaction<'db, DB> {
         value: V,
     ) -> Result<(), Error> {
         unsafe {
-            ffi_try!(ffi::rocksdb_transaction_merge_cf(
+            ffi_try!(ffi::rocksdb_transaction_merge_cf_with_status(
                 self.inner,
                 cf.inner(),
                 key.as_ref().as_ptr() as *const c_char,
@@ -690,7 +694,7 @@ impl<'db, DB> Transaction<'db, DB> {
     /// [`delete_cf`]: Self::delete_cf
     pub fn delete<K: AsRef<[u8]>>(&self, key: K) -> Result<(), Error> {
         unsafe {
-            ffi_try!(ffi::rocksdb_transaction_delete(
+            ffi_try!(ffi::rocksdb_transaction_delete_with_status(
                 self.inner,
                 key.as_ref().as_ptr() as *const c_char,
                 key.as_ref().len() as size_t
@@ -719,7 +723,46 @@ impl<'db, DB> Transaction<'db, DB> {
         key: K,
     ) -> Result<(), Error> {
         unsafe {
-            ffi_try!(ffi::rocksdb_transaction_delete_cf(
+            ffi_try!(ffi::rocksdb_transaction_delete_cf_with_status(
+                self.inner,
+                cf.inner(),
+                key.as_ref().as_ptr() as *const c_char,
+                key.as_ref().len() as size_t
+            ));
+        }
+        Ok(())
+    }
+
+    /// Delete the key value using a SingleDelete tombstone and do conflict checking on the key.
+    ///
+    /// See [`single_delete_cf`] for details.
+    ///
+    /// [`single_delete_cf`]: Self::single_delete_cf
+    pub fn single_delete<K: AsRef<[u8]>>(&self, key: K) -> Result<(), Error> {
+        unsafe {
+            ffi_try!(ffi::rocksdb_transaction_singledelete(
+                self.inner,
+                key.as_ref().as_ptr() as *const c_char,
+                key.as_ref().len() as size_t
+            ));
+        }
+        Ok(())
+    }
+
+    /// Delete the key value in the given column family using a SingleDelete tombstone
+    /// and do conflict checking.
+    ///
+    /// SingleDelete is only defined for keys which were written exactly once since the
+    /// previous deletion. Errors are reported in the same way as for [`delete_cf`].
+    ///
+    /// [`delete_cf`]: Self::delete_cf
+    pub fn single_delete_cf<K: AsRef<[u8]>>(
+        &self,
+        cf: &impl AsColumnFamilyRef,
+        key: K,
+    ) -> Result<(), Error> {
+        unsafe {
+            ffi_try!(ffi::rocksdb_transaction_singledelete_cf(
                 self.inner,
                 cf.inner(),
                 key.as_ref().as_ptr() as *const c_char,
@@ -863,10 +906,12 @@ impl<'db, DB> Transaction<'db, DB> {
         writebatch: &WriteBatchWithTransaction<true>,
     ) -> Result<(), Error> {
         unsafe {
-            ffi_try!(ffi::rocksdb_transaction_rebuild_from_writebatch(
-                self.inner,
-                writebatch.inner
-            ));
+            ffi_try!(
+                ffi::rocksdb_transaction_rebuild_from_writebatch_with_status(
+                    self.inner,
+                    writebatch.inner
+                )
+            );
         }
         Ok(())
     }
@@ -36,7 +36,7 @@ use crate::{
     WriteOptions, DB, DEFAULT_COLUMN_FAMILY_NAME,
 };
 use ffi::rocksdb_transaction_t;
-use libc::{c_char, c_int, c_void, size_t};
+use libc::{c_char, c_int, c_uchar, c_void, size_t};
 
 #[cfg(not(feature = "multi-threaded-cf"))]
 type DefaultThreadMode = crate::SingleThreaded;
@@ -322,7 +322,7 @@ impl<T: ThreadMode> TransactionDB<T> {
         cpath: &CString,
     ) -> Result<*mut ffi::rocksdb_transactiondb_t, Error> {
         unsafe {
-            let db = ffi_try!(ffi::rocksdb_transactiondb_open(
+            let db = ffi_try!(ffi::rocksdb_transactiondb_open_with_status(
                 opts.inner,
                 txn_db_opts.inner,
                 cpath.as_ptr()
@@ -341,7 +341,7 @@ impl<T: ThreadMode> TransactionDB<T> {
         cfhandles: &mut [*mut ffi::rocksdb_column_family_handle_t],
     ) -> Result<*mut ffi::rocksdb_transactiondb_t, Error> {
         unsafe {
-            let db = ffi_try!(ffi::rocksdb_transactiondb_open_column_families(
+            let db = ffi_try!(ffi::rocksdb_transactiondb_open_column_families_with_status(
                 opts.inner,
                 txn_db_opts.inner,
                 cpath.as_ptr(),
@@ -364,7 +364,7 @@ impl<T: ThreadMode> TransactionDB<T> {
         })?;
 
         Ok(unsafe {
-            ffi_try!(ffi::rocksdb_transactiondb_create_column_family(
+            ffi_try!(ffi::rocksdb_transactiondb_create_column_family_with_status(
                 self.inner,
                 opts.inner,
                 cf_name.as_ptr(),
@@ -412,6 +412,30 @@ impl<T: ThreadMode> TransactionDB<T> {
         }
     }
 
+    /// Request stopping background work, if wait is true wait until it's done.
+    pub fn cancel_all_background_work(&self, wait: bool) {
+        unsafe {
+            ffi::rocksdb_transactiondb_cancel_all_background_work(self.inner, c_uchar::from(wait));
+        }
+    }
+
+    /// Closes the database, reporting errors that dropping it would ignore.
+    ///
+    /// Prepared transactions that were not recovered with
+    /// `prepared_transactions` and column family handles are released, and
+    /// background work is cancelled, before the database is closed. The
+    /// database is closed even if an error is returned.
+    pub fn close(mut self) -> Result<(), Error> {
+        self.prepared_transactions().clear();
+        self.cfs.drop_all_cfs_internal();
+        self.cancel_all_background_work(true);
+        let inner = std::mem::replace(&mut self.inner, ptr::null_mut());
+        unsafe {
+            ffi_try!(ffi::rocksdb_transactiondb_close_with_status(inner));
+        }
+        Ok(())
+    }
+
     /// Get all prepared transactions for recovery.
     ///
     /// This function is expected to call once after open database.
@@ -485,7 +509,7 @@ impl<T: ThreadMode> TransactionDB<T> {
     ) -> Result<Option<DBPinnableSlice>, Error> {
         let key = key.as_ref();
         unsafe {
-            let val = ffi_try!(ffi::rocksdb_transactiondb_get_pinned(
+            let val = ffi_try!(ffi::rocksdb_transactiondb_get_pinned_with_status(
                 self.inner,
                 readopts.inner,
                 key.as_ptr() as *const c_char,
@@ -507,7 +531,7 @@ impl<T: ThreadMode> TransactionDB<T> {
         readopts: &ReadOptions,
     ) -> Result<Option<DBPinnableSlice>, Error> {
         unsafe {
-            let val = ffi_try!(ffi::rocksdb_transactiondb_get_pinned_cf(
+            let val = ffi_try!(ffi::rocksdb_transactiondb_get_pinned_cf_with_status(
                 self.inner,
                 readopts.inner,
                 cf.inner(),
@@ -551,7 +575,7 @@ impl<T: ThreadMode> TransactionDB<T> {
         let mut values_sizes = vec![0_usize; keys.len()];
         let mut errors = vec![ptr::null_mut(); keys.len()];
         unsafe {
-            ffi::rocksdb_transactiondb_multi_get(
+            ffi::rocksdb_transactiondb_multi_get_with_status(
                 self.inner,
                 readopts.inner,
                 ptr_keys.len(),
@@ -607,7 +631,7 @@ impl<T: ThreadMode> TransactionDB<T> {
         let mut values_sizes = vec![0_usize; ptr_keys.len()];
         let mut errors = vec![ptr::null_mut(); ptr_keys.len()];
         unsafe {
-            ffi::rocksdb_transactiondb_multi_get_cf(
+            ffi::rocksdb_transactiondb_multi_get_cf_with_status(
                 self.inner,
                 readopts.inner,
                 ptr_cfs.as_ptr(),
@@ -645,7 +669,7 @@ impl<T: ThreadMode> TransactionDB<T> {
         V: AsRef<[u8]>,
     {
         unsafe {
-            ffi_try!(ffi::rocksdb_transactiondb_put(
+            ffi_try!(ffi::rocksdb_transactiondb_put_with_status(
                 self.inner,
                 writeopts.inner,
                 key.as_ref().as_ptr() as *const c_char,
@@ -669,7 +693,7 @@ impl<T: ThreadMode> TransactionDB<T> {
         V: AsRef<[u8]>,
     {
         unsafe {
-            ffi_try!(ffi::rocksdb_transactiondb_put_cf(
+            ffi_try!(ffi::rocksdb_transactiondb_put_cf_with_status(
                 self.inner,
                 writeopts.inner,
                 cf.inner(),
@@ -692,7 +716,7 @@ impl<T: ThreadMode> TransactionDB<T> {
         writeopts: &WriteOptions,
     ) -> Result<(), Error> {
         unsafe {
-            ffi_try!(ffi::rocksdb_transactiondb_write(
+            ffi_try!(ffi::rocksdb_transactiondb_write_with_status(
                 self.inner,
                 writeopts.inner,
                 batch.inner
@@ -723,7 +747,7 @@ impl<T: ThreadMode> TransactionDB<T> {
         V: AsRef<[u8]>,
     {
         unsafe {
-            ffi_try!(ffi::rocksdb_transactiondb_merge(
+            ffi_try!(ffi::rocksdb_transactiondb_merge_with_status(
                 self.inner,
                 writeopts.inner,
                 key.as_ref().as_ptr() as *const c_char,
@@ -747,7 +771,7 @@ impl<T: ThreadMode> TransactionDB<T> {
         V: AsRef<[u8]>,
     {
         unsafe {
-            ffi_try!(ffi::rocksdb_transactiondb_merge_cf(
+            ffi_try!(ffi::rocksdb_transactiondb_merge_cf_with_status(
                 self.inner,
                 writeopts.inner,
                 cf.inner(),
@@ -778,7 +802,7 @@ impl<T: ThreadMode> TransactionDB<T> {
         writeopts: &WriteOptions,
     ) -> Result<(), Error> {
         unsafe {
-            ffi_try!(ffi::rocksdb_transactiondb_delete(
+            ffi_try!(ffi::rocksdb_transactiondb_delete_with_status(
                 self.inner,
                 writeopts.inner,
                 key.as_ref().as_ptr() as *const c_char,
@@ -795,7 +819,7 @@ impl<T: ThreadMode> TransactionDB<T> {
         writeopts: &WriteOptions,
     ) -> Result<(), Error> {
         unsafe {
-            ffi_try!(ffi::rocksdb_transactiondb_delete_cf(
+            ffi_try!(ffi::rocksdb_transactiondb_delete_cf_with_status(
                 self.inner,
                 writeopts.inner,
                 cf.inner(),
@@ -935,7 +959,7 @@ impl<T: ThreadMode> TransactionDB<T> {
     ) -> Result<(), Error> {
         unsafe {
             // first mark the column family as dropped
-            ffi_try!(ffi::rocksdb_drop_column_family(
+            ffi_try!(ffi::rocksdb_drop_column_family_with_status(
                 self.inner as *mut ffi::rocksdb_t,
                 cf_inner
             ));
@@ -1006,6 +1030,9 @@ impl TransactionDB<MultiThreaded> {
 
 impl<T: ThreadMode> Drop for TransactionDB<T> {
     fn drop(&mut self) {
+        if self.inner.is_null() {
+            return;
+        }
         unsafe {
             self.prepared_transactions().clear();
             self.cfs.drop_all_cfs_internal();
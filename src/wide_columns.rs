@@ -0,0 +1,133 @@
+//! Wide-column entities: a key mapped to a set of named columns instead of a
+//! single opaque value.
+use crate::{ffi, DB};
+use libc::{c_char, size_t};
+use std::marker::PhantomData;
+use std::slice;
+
+/// Name of the anonymous column of a wide-column entity. A plain key-value
+/// written with `put` reads back as an entity with a single column by this
+/// name, and the value of this column is what `get` returns for an entity.
+pub const DEFAULT_WIDE_COLUMN_NAME: &[u8] = b"";
+
+/// A single name-value pair of a wide-column entity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct WideColumn<'a> {
+    pub name: &'a [u8],
+    pub value: &'a [u8],
+}
+
+impl<'a> WideColumn<'a> {
+    pub fn new(name: &'a [u8], value: &'a [u8]) -> Self {
+        Self { name, value }
+    }
+}
+
+/// Columns of an entity in the layout expected by the C API: parallel arrays
+/// of pointers and lengths for the names and the values.
+pub(crate) struct RawWideColumns {
+    pub names: Vec<*const c_char>,
+    pub names_sizes: Vec<size_t>,
+    pub values: Vec<*const c_char>,
+    pub values_sizes: Vec<size_t>,
+}
+
+impl RawWideColumns {
+    pub fn new(columns: &[WideColumn]) -> Self {
+        Self {
+            names: columns
+                .iter()
+                .map(|c| c.name.as_ptr() as *const c_char)
+                .collect(),
+            names_sizes: columns.iter().map(|c| c.name.len() as size_t).collect(),
+            values: columns
+                .iter()
+                .map(|c| c.value.as_ptr() as *const c_char)
+                .collect(),
+            values_sizes: columns.iter().map(|c| c.value.len() as size_t).collect(),
+        }
+    }
+
+    pub fn len(&self) -> size_t {
+        self.names.len() as size_t
+    }
+}
+
+/// Wrapper around RocksDB PinnableWideColumns struct.
+///
+/// Holds the columns of an entity read with `get_entity`. Like
+/// [`DBPinnableSlice`](crate::DBPinnableSlice), the column data is pinned
+/// within RocksDB and released when this struct is dropped. Columns are
+/// sorted by name.
+pub struct DBWideColumns<'a> {
+    ptr: *mut ffi::rocksdb_pinnable_wide_columns_t,
+    db: PhantomData<&'a DB>,
+}
+
+unsafe impl<'a> Send for DBWideColumns<'a> {}
+unsafe impl<'a> Sync for DBWideColumns<'a> {}
+
+impl<'a> DBWideColumns<'a> {
+    /// Used to wrap a PinnableWideColumns from rocksdb.
+    ///
+    /// # Unsafe
+    /// Requires that the pointer must be generated by rocksdb_get_entity
+    pub(crate) unsafe fn from_c(ptr: *mut ffi::rocksdb_pinnable_wide_columns_t) -> Self {
+        Self {
+            ptr,
+            db: PhantomData,
+        }
+    }
+
+    /// Returns the number of columns in the entity.
+    pub fn len(&self) -> usize {
+        unsafe { ffi::rocksdb_pinnable_wide_columns_count(self.ptr) }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the column at `index`, in name order.
+    pub fn column(&self, index: usize) -> Option<WideColumn> {
+        if index >= self.len() {
+            return None;
+        }
+        unsafe {
+            let mut name_len: size_t = 0;
+            let name = ffi::rocksdb_pinnable_wide_columns_name(self.ptr, index, &mut name_len);
+            let mut value_len: size_t = 0;
+            let value = ffi::rocksdb_pinnable_wide_columns_value(self.ptr, index, &mut value_len);
+            Some(WideColumn::new(
+                slice::from_raw_parts(name as *const u8, name_len),
+                slice::from_raw_parts(value as *const u8, value_len),
+            ))
+        }
+    }
+
+    /// Returns the value of the column called `name`, if the entity has one.
+    pub fn get<N: AsRef<[u8]>>(&self, name: N) -> Option<&[u8]> {
+        let name = name.as_ref();
+        self.iter().find(|c| c.name == name).map(|c| c.value)
+    }
+
+    /// Returns an iterator over the columns, in name order.
+    pub fn iter(&self) -> impl Iterator<Item = WideColumn> + '_ {
+        (0..self.len()).filter_map(move |i| self.column(i))
+    }
+
+    /// Copies the columns into owned name-value pairs.
+    pub fn to_vec(&self) -> Vec<(Vec<u8>, Vec<u8>)> {
+        self.iter()
+            .map(|c| (c.name.to_vec(), c.value.to_vec()))
+            .collect()
+    }
+}
+
+impl<'a> Drop for DBWideColumns<'a> {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::rocksdb_pinnable_wide_columns_destroy(self.ptr);
+        }
+    }
+}
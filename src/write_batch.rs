@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{ffi, AsColumnFamilyRef};
+use crate::{ffi, wide_columns::RawWideColumns, AsColumnFamilyRef, Error, WideColumn};
 use libc::{c_char, c_void, size_t};
 use std::slice;
 
@@ -58,6 +58,12 @@ pub trait WriteBatchIterator {
     fn put(&mut self, key: Box<[u8]>, value: Box<[u8]>);
     /// Called with a key that was `delete`d from the batch.
     fn delete(&mut self, key: Box<[u8]>);
+    /// Called with a key that was `single_delete`d from the batch.
+    ///
+    /// Defaults to forwarding the key to [`delete`](WriteBatchIterator::delete).
+    fn single_delete(&mut self, key: Box<[u8]>) {
+        self.delete(key);
+    }
 }
 
 unsafe extern "C" fn writebatch_put_callback(
@@ -88,6 +94,19 @@ unsafe extern "C" fn writebatch_delete_callback(state: *mut c_void, k: *const c_
     leaked_cb.delete(key.to_vec().into_boxed_slice());
 }
 
+unsafe extern "C" fn writebatch_single_delete_callback(
+    state: *mut c_void,
+    k: *const c_char,
+    klen: usize,
+) {
+    // coerce the raw pointer back into a box, but "leak" it so we prevent
+    // freeing the resource before we are done with it
+    let boxed_cb = Box::from_raw(state as *mut &mut dyn WriteBatchIterator);
+    let leaked_cb = Box::leak(boxed_cb);
+    let key = slice::from_raw_parts(k as *const u8, klen);
+    leaked_cb.single_delete(key.to_vec().into_boxed_slice());
+}
+
 impl<const TRANSACTION: bool> WriteBatchWithTransaction<TRANSACTION> {
     /// Construct with a reference to a byte array serialized by [`WriteBatch`].
     pub fn from_data(data: &[u8]) -> Self {
@@ -130,17 +149,18 @@ impl<const TRANSACTION: bool> WriteBatchWithTransaction<TRANSACTION> {
     }
 
     /// Iterate the put and delete operations within this write batch. Note that
-    /// this does _not_ return an `Iterator` but instead will invoke the `put()`
-    /// and `delete()` member functions of the provided `WriteBatchIterator`
-    /// trait implementation.
+    /// this does _not_ return an `Iterator` but instead will invoke the `put()`,
+    /// `delete()` and `single_delete()` member functions of the provided
+    /// `WriteBatchIterator` trait implementation.
     pub fn iterate(&self, callbacks: &mut dyn WriteBatchIterator) {
         let state = Box::into_raw(Box::new(callbacks));
         unsafe {
-            ffi::rocksdb_writebatch_iterate(
+            ffi::rocksdb_writebatch_iterate_with_single_delete(
                 self.inner,
                 state as *mut c_void,
                 Some(writebatch_put_callback),
                 Some(writebatch_delete_callback),
+                Some(writebatch_single_delete_callback),
             );
             // we must manually set the raw box free since there is no
             // associated "destroy" callback for this object
@@ -188,6 +208,60 @@ impl<const TRANSACTION: bool> WriteBatchWithTransaction<TRANSACTION> {
         }
     }
 
+    /// Insert a wide-column entity into the default column family.
+    ///
+    /// Unlike `put`, this can fail, e.g. if a column name is repeated.
+    pub fn put_entity<K: AsRef<[u8]>>(
+        &mut self,
+        key: K,
+        columns: &[WideColumn],
+    ) -> Result<(), Error> {
+        let key = key.as_ref();
+        let raw = RawWideColumns::new(columns);
+
+        unsafe {
+            ffi_try!(ffi::rocksdb_writebatch_put_entity(
+                self.inner,
+                key.as_ptr() as *const c_char,
+                key.len() as size_t,
+                raw.len(),
+                raw.names.as_ptr(),
+                raw.names_sizes.as_ptr(),
+                raw.values.as_ptr(),
+                raw.values_sizes.as_ptr(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Insert a wide-column entity into the specified column family.
+    ///
+    /// Unlike `put_cf`, this can fail, e.g. if a column name is repeated.
+    pub fn put_entity_cf<K: AsRef<[u8]>>(
+        &mut self,
+        cf: &impl AsColumnFamilyRef,
+        key: K,
+        columns: &[WideColumn],
+    ) -> Result<(), Error> {
+        let key = key.as_ref();
+        let raw = RawWideColumns::new(columns);
+
+        unsafe {
+            ffi_try!(ffi::rocksdb_writebatch_put_entity_cf(
+                self.inner,
+                cf.inner(),
+                key.as_ptr() as *const c_char,
+                key.len() as size_t,
+                raw.len(),
+                raw.names.as_ptr(),
+                raw.names_sizes.as_ptr(),
+                raw.values.as_ptr(),
+                raw.values_sizes.as_ptr(),
+            ));
+        }
+        Ok(())
+    }
+
     pub fn merge<K, V>(&mut self, key: K, value: V)
     where
         K: AsRef<[u8]>,
@@ -253,6 +327,36 @@ impl<const TRANSACTION: bool> WriteBatchWithTransaction<TRANSACTION> {
         }
     }
 
+    /// Removes the database entry for key using a SingleDelete tombstone.
+    ///
+    /// See [`DBCommon::single_delete_opt`](crate::DBCommon::single_delete_opt)
+    /// for the restrictions that apply to SingleDelete.
+    pub fn single_delete<K: AsRef<[u8]>>(&mut self, key: K) {
+        let key = key.as_ref();
+
+        unsafe {
+            ffi::rocksdb_writebatch_singledelete(
+                self.inner,
+                key.as_ptr() as *const c_char,
+                key.len() as size_t,
+            );
+        }
+    }
+
+    /// Removes the database entry in `cf` for key using a SingleDelete tombstone.
+    pub fn single_delete_cf<K: AsRef<[u8]>>(&mut self, cf: &impl AsColumnFamilyRef, key: K) {
+        let key = key.as_ref();
+
+        unsafe {
+            ffi::rocksdb_writebatch_singledelete_cf(
+                self.inner,
+                cf.inner(),
+                key.as_ptr() as *const c_char,
+                key.len() as size_t,
+            );
+        }
+    }
+
     /// Clear all updates buffered in this batch.
     pub fn clear(&mut self) {
         unsafe {
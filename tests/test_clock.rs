@@ -0,0 +1,62 @@
+// Copyright 2023 Tyler Neely
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod util;
+
+use std::time::Duration;
+
+use pretty_assertions::assert_eq;
+
+use rocksdb::{Clock, Env, MockClock, Options, DB};
+use util::DBPath;
+
+#[test]
+fn mock_clock_only_moves_when_advanced() {
+    let clock = MockClock::new(1_000_000);
+    assert_eq!(clock.now_micros(), 1_000_000);
+    assert_eq!(clock.now_nanos(), 1_000_000_000);
+
+    clock.advance(Duration::from_millis(5));
+    assert_eq!(clock.now_micros(), 1_005_000);
+
+    // sleeping advances the clock instead of blocking
+    clock.clone().sleep_for_micros(3600 * 1_000_000);
+    assert_eq!(clock.now_micros(), 3_601_005_000);
+
+    clock.set_now_micros(42);
+    assert_eq!(clock.now_micros(), 42);
+}
+
+#[test]
+fn ttl_expires_with_mock_clock() {
+    let path = DBPath::new("_rust_rocksdb_mock_clock_ttl_test");
+    let (env, clock) = Env::with_mock_clock().unwrap();
+    let mut opts = Options::default();
+    opts.create_if_missing(true);
+    opts.set_env(&env);
+
+    let db = DB::open_with_ttl(&opts, &path, Duration::from_secs(60)).unwrap();
+    db.put(b"k1", b"v1").unwrap();
+    clock.advance(Duration::from_secs(30));
+    db.put(b"k2", b"v2").unwrap();
+
+    clock.advance(Duration::from_secs(31));
+    db.compact_range(None::<&[u8]>, None::<&[u8]>);
+    assert_eq!(db.get(b"k1").unwrap(), None);
+    assert_eq!(db.get(b"k2").unwrap(), Some(b"v2".to_vec()));
+
+    clock.advance(Duration::from_secs(30));
+    db.compact_range(None::<&[u8]>, None::<&[u8]>);
+    assert_eq!(db.get(b"k2").unwrap(), None);
+}
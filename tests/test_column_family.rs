@@ -498,3 +498,62 @@ fn test_no_leaked_column_family() {
         drop(db);
     }
 }
+
+#[test]
+fn test_column_family_metadata() {
+    let n = DBPath::new("_rust_rocksdb_column_family_metadata");
+    {
+        let mut opts = Options::default();
+        opts.create_if_missing(true);
+        opts.create_missing_column_families(true);
+        let mut cf_opts = Options::default();
+        cf_opts.set_enable_blob_files(true);
+        cf_opts.set_min_blob_size(16);
+
+        let db =
+            DB::open_cf_descriptors(&opts, &n, vec![ColumnFamilyDescriptor::new("cf1", cf_opts)])
+                .unwrap();
+        let cf1 = db.cf_handle("cf1").unwrap();
+
+        let metadata = db.column_family_metadata_cf(&cf1);
+        assert_eq!(metadata.name, "cf1");
+        assert_eq!(metadata.file_count, 0);
+        assert!(metadata.levels.iter().all(|level| level.files.is_empty()));
+
+        db.put_cf(&cf1, b"k1", b"small").unwrap();
+        db.put_cf(&cf1, b"k2", [b'x'; 64]).unwrap();
+        db.flush_cf(&cf1).unwrap();
+        db.put_cf(&cf1, b"k3", b"small").unwrap();
+        db.flush_cf(&cf1).unwrap();
+
+        let metadata = db.column_family_metadata_cf(&cf1);
+        assert_eq!(metadata.file_count, 2);
+        let l0 = &metadata.levels[0];
+        assert_eq!(l0.level, 0);
+        assert_eq!(l0.files.len(), 2);
+        assert_eq!(l0.size, l0.files.iter().map(|f| f.size).sum::<u64>());
+        assert_eq!(metadata.size, l0.size);
+
+        let mut files = l0.files.clone();
+        files.sort_by_key(|f| f.epoch_number);
+        let (older, newer) = (&files[0], &files[1]);
+        assert!(older.relative_filename.ends_with(".sst"));
+        assert_eq!(older.smallest_key, b"k1");
+        assert_eq!(older.largest_key, b"k2");
+        assert_eq!(older.num_entries, 2);
+        assert!(older.largest_seqno < newer.smallest_seqno);
+        assert!(older.file_creation_time > 0);
+        assert!(older.oldest_ancestor_time > 0);
+        assert_ne!(older.oldest_blob_file_number, 0);
+        assert_eq!(newer.oldest_blob_file_number, 0);
+
+        assert_eq!(metadata.blob_files.len(), 1);
+        let blob = &metadata.blob_files[0];
+        assert_eq!(blob.file_number, older.oldest_blob_file_number);
+        assert_eq!(blob.total_blob_count, 1);
+        assert_eq!(blob.garbage_blob_count, 0);
+        assert_eq!(metadata.blob_file_size, blob.file_size);
+
+        assert_eq!(db.column_family_metadata().name, DEFAULT_COLUMN_FAMILY_NAME);
+    }
+}
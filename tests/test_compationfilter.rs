@@ -14,9 +14,16 @@
 
 mod util;
 
+use std::ffi::{CStr, CString};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
 use pretty_assertions::assert_eq;
 
-use rocksdb::{CompactionDecision, Options, DB};
+use rocksdb::compaction_filter::{CompactionFilter, ValueType};
+use rocksdb::compaction_filter_factory::{CompactionFilterContext, CompactionFilterFactory};
+use rocksdb::event_listener::TableFileCreationReason;
+use rocksdb::{ColumnFamilyDescriptor, CompactionDecision, MergeOperands, Options, DB};
 use util::DBPath;
 
 #[cfg(test)]
@@ -47,3 +54,234 @@ fn compaction_filter_test() {
         assert_eq!(&*db.get(b"%k").unwrap().unwrap(), b"secret");
     }
 }
+
+#[test]
+fn compaction_filter_owned_decisions_test() {
+    let path = DBPath::new("_rust_rocksdb_filter_owned_decisions_test");
+    let mut opts = Options::default();
+    opts.create_if_missing(true);
+    opts.set_compaction_filter("owned", |_level: u32, key: &[u8], value: &[u8]| match key {
+        b"counter" => CompactionDecision::ChangeValue(value.repeat(2)),
+        b"expired:a" => CompactionDecision::RemoveAndSkipUntil(b"expired;".to_vec()),
+        _ => CompactionDecision::Keep,
+    });
+    {
+        let db = DB::open(&opts, &path).unwrap();
+        db.put(b"counter", b"ab").unwrap();
+        db.put(b"expired:a", b"1").unwrap();
+        db.put(b"expired:b", b"2").unwrap();
+        db.put(b"live", b"3").unwrap();
+        db.compact_range(None::<&[u8]>, None::<&[u8]>);
+        assert_eq!(&*db.get(b"counter").unwrap().unwrap(), b"abab");
+        assert!(db.get(b"expired:a").unwrap().is_none());
+        assert!(db.get(b"expired:b").unwrap().is_none());
+        assert_eq!(&*db.get(b"live").unwrap().unwrap(), b"3");
+    }
+}
+
+fn concat_merge(_key: &[u8], existing: Option<&[u8]>, operands: &MergeOperands) -> Option<Vec<u8>> {
+    let mut result = existing.map(<[u8]>::to_vec).unwrap_or_default();
+    for operand in operands {
+        result.extend_from_slice(operand);
+    }
+    Some(result)
+}
+
+#[test]
+fn compaction_filter_v2_merge_operands_test() {
+    let path = DBPath::new("_rust_rocksdb_filter_v2_merge_operands_test");
+    let mut opts = Options::default();
+    opts.create_if_missing(true);
+    // without partial merges, the flush keeps the operands apart
+    opts.set_merge_operator(
+        "concat",
+        concat_merge,
+        |_key: &[u8], _existing: Option<&[u8]>, _operands: &MergeOperands| None,
+    );
+    opts.set_compaction_filter_v2(
+        "drop_x_operands",
+        |_level: u32, _key: &[u8], value_type: ValueType, value: &[u8]| match value_type {
+            ValueType::MergeOperand if value == b"x" => CompactionDecision::Remove,
+            ValueType::Value if value == b"old" => CompactionDecision::Remove,
+            _ => CompactionDecision::Keep,
+        },
+    );
+    {
+        let db = DB::open(&opts, &path).unwrap();
+        db.merge(b"k1", b"a").unwrap();
+        db.merge(b"k1", b"x").unwrap();
+        db.merge(b"k1", b"b").unwrap();
+        db.put(b"k2", b"old").unwrap();
+        db.flush().unwrap();
+        db.compact_range(None::<&[u8]>, None::<&[u8]>);
+        assert_eq!(&*db.get(b"k1").unwrap().unwrap(), b"ab");
+        assert!(db.get(b"k2").unwrap().is_none());
+    }
+}
+
+struct BlobFilter {
+    name: CString,
+    values_read: Arc<AtomicUsize>,
+}
+
+impl CompactionFilter for BlobFilter {
+    fn filter_v2(
+        &mut self,
+        _level: u32,
+        _key: &[u8],
+        value_type: ValueType,
+        value: &[u8],
+    ) -> CompactionDecision {
+        assert_eq!(value_type, ValueType::Value);
+        self.values_read.fetch_add(1, Ordering::SeqCst);
+        if value.starts_with(b"stale") {
+            CompactionDecision::Remove
+        } else {
+            CompactionDecision::Keep
+        }
+    }
+
+    fn filter_blob_by_key(&mut self, _level: u32, key: &[u8]) -> Option<CompactionDecision> {
+        if key.starts_with(b"tmp:") {
+            Some(CompactionDecision::Remove)
+        } else {
+            None
+        }
+    }
+
+    fn name(&self) -> &CStr {
+        &self.name
+    }
+}
+
+struct BlobFilterFactory(CString, Arc<AtomicUsize>);
+
+impl CompactionFilterFactory for BlobFilterFactory {
+    type Filter = BlobFilter;
+
+    fn create(&mut self, _context: CompactionFilterContext) -> Self::Filter {
+        BlobFilter {
+            name: CString::new("BlobFilter").unwrap(),
+            values_read: self.1.clone(),
+        }
+    }
+
+    fn name(&self) -> &CStr {
+        &self.0
+    }
+}
+
+#[test]
+fn compaction_filter_blob_by_key_test() {
+    let path = DBPath::new("_rust_rocksdb_filter_blob_by_key_test");
+    let values_read = Arc::new(AtomicUsize::new(0));
+    let mut opts = Options::default();
+    opts.create_if_missing(true);
+    opts.set_enable_blob_files(true);
+    opts.set_min_blob_size(0);
+    opts.set_compaction_filter_factory(BlobFilterFactory(
+        CString::new("BlobFilterFactory").unwrap(),
+        values_read.clone(),
+    ));
+    {
+        let db = DB::open(&opts, &path).unwrap();
+        db.put(b"tmp:1", b"stale value").unwrap();
+        db.put(b"tmp:2", b"fresh value").unwrap();
+        db.put(b"user:1", b"stale value").unwrap();
+        db.put(b"user:2", b"fresh value").unwrap();
+        db.flush().unwrap();
+        db.compact_range(None::<&[u8]>, None::<&[u8]>);
+        assert!(db.get(b"tmp:1").unwrap().is_none());
+        assert!(db.get(b"tmp:2").unwrap().is_none());
+        assert!(db.get(b"user:1").unwrap().is_none());
+        assert_eq!(&*db.get(b"user:2").unwrap().unwrap(), b"fresh value");
+    }
+    assert_eq!(values_read.load(Ordering::SeqCst), 2);
+}
+
+struct DropTmpFilter(CString);
+
+impl CompactionFilter for DropTmpFilter {
+    fn filter(&mut self, _level: u32, key: &[u8], _value: &[u8]) -> CompactionDecision {
+        if key.starts_with(b"tmp:") {
+            CompactionDecision::Remove
+        } else {
+            CompactionDecision::Keep
+        }
+    }
+
+    fn name(&self) -> &CStr {
+        &self.0
+    }
+}
+
+// (column family id, reason, is manual compaction) of each created filter
+type Contexts = Arc<Mutex<Vec<(u32, TableFileCreationReason, bool)>>>;
+
+struct FlushingFactory(CString, Contexts);
+
+impl CompactionFilterFactory for FlushingFactory {
+    type Filter = DropTmpFilter;
+
+    fn create(&mut self, context: CompactionFilterContext) -> Self::Filter {
+        self.1.lock().unwrap().push((
+            context.column_family_id,
+            context.reason,
+            context.is_manual_compaction,
+        ));
+        DropTmpFilter(CString::new("DropTmpFilter").unwrap())
+    }
+
+    fn should_filter_table_file_creation(&self, reason: TableFileCreationReason) -> bool {
+        matches!(
+            reason,
+            TableFileCreationReason::Flush | TableFileCreationReason::Compaction
+        )
+    }
+
+    fn name(&self) -> &CStr {
+        &self.0
+    }
+}
+
+#[test]
+fn compaction_filter_factory_context_test() {
+    let path = DBPath::new("_rust_rocksdb_filter_factory_context_test");
+    let contexts = Contexts::default();
+    let mut db_opts = Options::default();
+    db_opts.create_if_missing(true);
+    db_opts.create_missing_column_families(true);
+    let mut cf_opts = Options::default();
+    cf_opts.set_compaction_filter_factory(FlushingFactory(
+        CString::new("FlushingFactory").unwrap(),
+        contexts.clone(),
+    ));
+    {
+        let db = DB::open_cf_descriptors(
+            &db_opts,
+            &path,
+            vec![ColumnFamilyDescriptor::new("cf1", cf_opts)],
+        )
+        .unwrap();
+        let cf1 = db.cf_handle("cf1").unwrap();
+        db.put_cf(cf1, b"tmp:1", b"a").unwrap();
+        db.put_cf(cf1, b"user:1", b"b").unwrap();
+        db.flush_cf(cf1).unwrap();
+        assert!(db.get_cf(cf1, b"tmp:1").unwrap().is_none());
+        assert_eq!(
+            *contexts.lock().unwrap(),
+            vec![(1, TableFileCreationReason::Flush, false)]
+        );
+
+        db.put_cf(cf1, b"tmp:2", b"c").unwrap();
+        db.put_cf(cf1, b"user:2", b"d").unwrap();
+        db.flush_cf(cf1).unwrap();
+        db.compact_range_cf(cf1, None::<&[u8]>, None::<&[u8]>);
+        assert_eq!(
+            contexts.lock().unwrap().last(),
+            Some(&(1, TableFileCreationReason::Compaction, true))
+        );
+        assert_eq!(&*db.get_cf(cf1, b"user:1").unwrap().unwrap(), b"b");
+        assert_eq!(&*db.get_cf(cf1, b"user:2").unwrap().unwrap(), b"d");
+    }
+}
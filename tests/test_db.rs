@@ -21,10 +21,13 @@ use pretty_assertions::assert_eq;
 
 use rocksdb::{
     perf::get_memory_usage_stats, BlockBasedOptions, BottommostLevelCompaction, Cache,
-    ColumnFamilyDescriptor, CompactOptions, CuckooTableOptions, DBAccess, DBCompactionStyle,
-    DBWithThreadMode, Env, Error, ErrorKind, FifoCompactOptions, IteratorMode, MultiThreaded,
-    Options, PerfContext, PerfMetric, ReadOptions, SingleThreaded, SliceTransform, Snapshot,
-    UniversalCompactOptions, UniversalCompactionStopStyle, WriteBatch, DB,
+    ColumnFamilyDescriptor, CompactOptions, CompactionOptions, CuckooTableOptions, DBAccess,
+    DBCompactionStyle, DBCompressionType, DBWithThreadMode, Env, Error, ErrorKind, ErrorSeverity,
+    ErrorSubCode, FaultInjection, FaultInjectionEnv, FifoCompactOptions, IteratorMode,
+    MultiThreaded, Options, PerfContext, PerfMetric, PrefixRange, ReadOptions, SingleThreaded,
+    SizeApproximationOptions, SliceTransform, Snapshot, UniversalCompactOptions,
+    UniversalCompactionStopStyle, WaitForCompactOptions, WideColumn, WriteBatch, DB,
+    DEFAULT_WIDE_COLUMN_NAME,
 };
 use util::{assert_iter, pair, DBPath};
 
@@ -763,6 +766,222 @@ fn fifo_compaction_test() {
     }
 }
 
+#[test]
+fn compact_files_test() {
+    let path = DBPath::new("_rust_rocksdb_compact_files_test");
+    {
+        let mut opts = Options::default();
+        opts.create_if_missing(true);
+        opts.create_missing_column_families(true);
+        opts.set_disable_auto_compactions(true);
+
+        let db = DB::open_cf(&opts, &path, ["cf1"]).unwrap();
+        let cf1 = db.cf_handle("cf1").unwrap();
+        for i in 0..4 {
+            db.put_cf(&cf1, format!("k{i}"), b"value").unwrap();
+            db.flush_cf(&cf1).unwrap();
+        }
+
+        let files: Vec<_> = db
+            .live_files()
+            .unwrap()
+            .into_iter()
+            .filter(|f| f.column_family_name == "cf1")
+            .map(|f| f.name)
+            .collect();
+        assert_eq!(files.len(), 4);
+
+        let mut compaction_opts = CompactionOptions::default();
+        compaction_opts.set_output_file_size_limit(64 << 20);
+        compaction_opts.set_compression(DBCompressionType::None);
+        db.compact_files_cf(&cf1, &compaction_opts, &files, 2)
+            .unwrap();
+
+        let livefiles: Vec<_> = db
+            .live_files()
+            .unwrap()
+            .into_iter()
+            .filter(|f| f.column_family_name == "cf1")
+            .collect();
+        assert_eq!(livefiles.len(), 1);
+        assert_eq!(livefiles[0].level, 2);
+        assert_eq!(livefiles[0].num_entries, 4);
+
+        // the compacted input files are gone
+        assert!(db
+            .compact_files_cf(&cf1, &compaction_opts, &files, 3)
+            .is_err());
+    }
+}
+
+#[test]
+fn promote_l0_test() {
+    let path = DBPath::new("_rust_rocksdb_promote_l0_test");
+    {
+        let mut opts = Options::default();
+        opts.create_if_missing(true);
+        opts.set_disable_auto_compactions(true);
+
+        let db = DB::open(&opts, &path).unwrap();
+        db.put(b"a", b"1").unwrap();
+        db.flush().unwrap();
+        db.put(b"b", b"2").unwrap();
+        db.flush().unwrap();
+
+        db.promote_l0(3).unwrap();
+        let livefiles = db.live_files().unwrap();
+        assert_eq!(livefiles.len(), 2);
+        assert!(livefiles.iter().all(|f| f.level == 3));
+
+        // overlapping L0 files cannot be promoted
+        db.put(b"a", b"3").unwrap();
+        db.flush().unwrap();
+        db.put(b"a", b"4").unwrap();
+        db.flush().unwrap();
+        assert!(db.promote_l0(1).is_err());
+        assert_eq!(db.get(b"a").unwrap().unwrap(), b"4");
+    }
+}
+
+#[test]
+fn wait_for_compact_test() {
+    let path = DBPath::new("_rust_rocksdb_wait_for_compact_test");
+    {
+        let mut opts = Options::default();
+        opts.create_if_missing(true);
+        opts.set_level_zero_file_num_compaction_trigger(2);
+        opts.set_disable_auto_compactions(true);
+
+        let db = DB::open(&opts, &path).unwrap();
+        for i in 0..4 {
+            db.put(format!("k{i}"), b"value").unwrap();
+            db.flush().unwrap();
+        }
+        // compactions are needed but will not be scheduled, so nothing to wait for
+        db.wait_for_compact(&WaitForCompactOptions::default())
+            .unwrap();
+        assert_eq!(db.column_family_metadata().levels[0].files.len(), 4);
+
+        db.pause_background_work().unwrap();
+        db.set_options(&[("disable_auto_compactions", "false")])
+            .unwrap();
+
+        let mut wait_opts = WaitForCompactOptions::default();
+        wait_opts.set_timeout(Duration::from_millis(100));
+        let err = db.wait_for_compact(&wait_opts).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::TimedOut);
+
+        wait_opts.set_abort_on_pause(true);
+        let err = db.wait_for_compact(&wait_opts).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Aborted);
+
+        db.continue_background_work().unwrap();
+        assert!(db.continue_background_work().is_err());
+
+        db.put(b"k4", b"value").unwrap();
+        let mut wait_opts = WaitForCompactOptions::default();
+        wait_opts.set_flush(true);
+        db.wait_for_compact(&wait_opts).unwrap();
+        let metadata = db.column_family_metadata();
+        assert!(metadata.levels[0].files.len() < 2);
+        assert_eq!(
+            metadata
+                .levels
+                .iter()
+                .flat_map(|l| &l.files)
+                .map(|f| f.num_entries)
+                .sum::<u64>(),
+            5
+        );
+    }
+}
+
+#[test]
+fn disable_manual_compaction_test() {
+    let path = DBPath::new("_rust_rocksdb_disable_manual_compaction_test");
+    {
+        let mut opts = Options::default();
+        opts.create_if_missing(true);
+        opts.set_disable_auto_compactions(true);
+
+        let db = DB::open(&opts, &path).unwrap();
+        for i in 0..2 {
+            db.put(format!("k{i}"), b"value").unwrap();
+            db.flush().unwrap();
+        }
+        let files: Vec<_> = db
+            .live_files()
+            .unwrap()
+            .into_iter()
+            .map(|f| f.name)
+            .collect();
+
+        db.disable_manual_compaction();
+        let err = db
+            .compact_files(&CompactionOptions::default(), &files, 1)
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Incomplete);
+
+        db.enable_manual_compaction();
+        db.compact_files(&CompactionOptions::default(), &files, 1)
+            .unwrap();
+        assert!(db.live_files().unwrap().iter().all(|f| f.level == 1));
+    }
+}
+
+#[test]
+fn close_test() {
+    let path = DBPath::new("_rust_rocksdb_close_test");
+    {
+        let mut opts = Options::default();
+        opts.create_if_missing(true);
+        opts.create_missing_column_families(true);
+
+        let db = DB::open_cf(&opts, &path, ["cf1"]).unwrap();
+        let cf1 = db.cf_handle("cf1").unwrap();
+        db.put(b"k1", b"v1").unwrap();
+        db.put_cf(cf1, b"k2", b"v2").unwrap();
+        db.close().unwrap();
+
+        let db = DB::open_cf(&opts, &path, ["cf1"]).unwrap();
+        let cf1 = db.cf_handle("cf1").unwrap();
+        assert_eq!(db.get(b"k1").unwrap().unwrap(), b"v1");
+        assert_eq!(db.get_cf(cf1, b"k2").unwrap().unwrap(), b"v2");
+    }
+}
+
+#[test]
+fn background_error_test() {
+    let path = DBPath::new("_rust_rocksdb_background_error_test");
+    {
+        let fault_env = FaultInjectionEnv::new().unwrap();
+        let mut opts = Options::default();
+        opts.create_if_missing(true);
+        opts.set_env(fault_env.env());
+        let db = DB::open(&opts, &path).unwrap();
+        db.put(b"k1", b"v1").unwrap();
+        assert!(db.background_error().is_none());
+
+        // the flush fails writing the table file and stops writes
+        fault_env.set_no_space(true);
+        fault_env.set_write_faults(FaultInjection::AfterOperations(0));
+        assert!(db.flush().is_err());
+        let error = db.background_error().unwrap();
+        assert_eq!(error.kind(), ErrorKind::IOError);
+        assert_eq!(error.subcode(), ErrorSubCode::NoSpace);
+        assert_eq!(error.severity(), ErrorSeverity::HardError);
+        assert!(db.put(b"k2", b"v2").is_err());
+
+        // once space is available again, resuming flushes and accepts writes
+        fault_env.set_write_faults(FaultInjection::Never);
+        db.resume().unwrap();
+        assert!(db.background_error().is_none());
+        db.put(b"k2", b"v2").unwrap();
+        assert_eq!(db.get(b"k1").unwrap().unwrap(), b"v1");
+        assert_eq!(db.get(b"k2").unwrap().unwrap(), b"v2");
+    }
+}
+
 #[test]
 fn env_and_dbpaths_test() {
     let path = DBPath::new("_rust_rocksdb_dbpath_test");
@@ -1184,6 +1403,161 @@ fn delete_range_test() {
     }
 }
 
+#[test]
+fn single_delete_test() {
+    let path = DBPath::new("_rust_rocksdb_single_delete_test");
+    {
+        let mut opts = Options::default();
+        opts.create_if_missing(true);
+        opts.create_missing_column_families(true);
+
+        let db = DB::open_cf(&opts, &path, ["cf1"]).unwrap();
+        db.put(b"k1", b"v1").unwrap();
+        db.single_delete(b"k1").unwrap();
+        assert!(db.get(b"k1").unwrap().is_none());
+
+        let cf1 = db.cf_handle("cf1").unwrap();
+        db.put_cf(&cf1, b"k2", b"v2").unwrap();
+        db.flush_cf(&cf1).unwrap();
+        db.single_delete_cf(&cf1, b"k2").unwrap();
+        assert!(db.get_cf(&cf1, b"k2").unwrap().is_none());
+
+        db.compact_range_cf(&cf1, None::<&[u8]>, None::<&[u8]>);
+        assert!(db.get_cf(&cf1, b"k2").unwrap().is_none());
+    }
+}
+
+#[test]
+fn wide_column_entity_test() {
+    let path = DBPath::new("_rust_rocksdb_wide_column_entity_test");
+    {
+        let mut opts = Options::default();
+        opts.create_if_missing(true);
+        opts.create_missing_column_families(true);
+
+        let db = DB::open_cf(&opts, &path, ["cf1"]).unwrap();
+        let cf1 = db.cf_handle("cf1").unwrap();
+
+        db.put_entity(
+            b"k1",
+            &[
+                WideColumn::new(b"name", b"alice"),
+                WideColumn::new(DEFAULT_WIDE_COLUMN_NAME, b"v1"),
+            ],
+        )
+        .unwrap();
+        db.put(b"k2", b"v2").unwrap();
+
+        let entity = db.get_entity(b"k1").unwrap().unwrap();
+        assert_eq!(entity.len(), 2);
+        assert_eq!(entity.get(b"name"), Some(&b"alice"[..]));
+        assert_eq!(entity.get(b"missing"), None);
+        // columns are sorted by name, so the default column comes first
+        assert_eq!(
+            entity.to_vec(),
+            vec![
+                (b"".to_vec(), b"v1".to_vec()),
+                (b"name".to_vec(), b"alice".to_vec())
+            ]
+        );
+        // the default column is what a plain `get` sees
+        assert_eq!(db.get(b"k1").unwrap().unwrap(), b"v1");
+
+        let plain = db.get_entity(b"k2").unwrap().unwrap();
+        assert_eq!(plain.get(DEFAULT_WIDE_COLUMN_NAME), Some(&b"v2"[..]));
+        assert!(db.get_entity(b"k3").unwrap().is_none());
+
+        // duplicate column names are rejected
+        assert!(db
+            .put_entity(
+                b"k3",
+                &[WideColumn::new(b"a", b"1"), WideColumn::new(b"a", b"2")]
+            )
+            .is_err());
+
+        let mut batch = WriteBatch::default();
+        batch
+            .put_entity_cf(&cf1, b"k1", &[WideColumn::new(b"age", b"42")])
+            .unwrap();
+        batch
+            .put_entity(b"k4", &[WideColumn::new(b"age", b"3")])
+            .unwrap();
+        db.write(batch).unwrap();
+        let entity = db.get_entity(b"k4").unwrap().unwrap();
+        assert_eq!(entity.get(b"age"), Some(&b"3"[..]));
+        db.put_entity_cf(&cf1, b"k2", &[WideColumn::new(b"age", b"7")])
+            .unwrap();
+        let entity = db.get_entity_cf(&cf1, b"k1").unwrap().unwrap();
+        assert_eq!(entity.get(b"age"), Some(&b"42"[..]));
+
+        let mut iter = db.raw_iterator_cf(&cf1);
+        iter.seek_to_first();
+        assert_eq!(iter.columns(), Some(vec![WideColumn::new(b"age", b"42")]));
+        iter.next();
+        assert_eq!(iter.columns(), Some(vec![WideColumn::new(b"age", b"7")]));
+        iter.next();
+        assert_eq!(iter.columns(), None);
+    }
+}
+
+#[test]
+fn approximate_sizes_test() {
+    let path = DBPath::new("_rust_rocksdb_approximate_sizes_test");
+    {
+        let mut opts = Options::default();
+        opts.create_if_missing(true);
+        opts.create_missing_column_families(true);
+
+        let db = DB::open_cf(&opts, &path, ["cf1"]).unwrap();
+        let cf1 = db.cf_handle("cf1").unwrap();
+        let value = vec![b'x'; 1024];
+        for i in 0..1000 {
+            db.put_cf(&cf1, format!("a{i:04}"), &value).unwrap();
+            db.put_cf(&cf1, format!("b{i:04}"), &value).unwrap();
+        }
+
+        let stats = db.get_approximate_memtable_stats_cf(&cf1, PrefixRange(b"a".to_vec()));
+        assert!(stats.count > 0);
+        assert!(stats.size > 0);
+
+        let memtables_only = SizeApproximationOptions {
+            include_memtables: true,
+            include_files: false,
+            ..SizeApproximationOptions::default()
+        };
+        let sizes = db
+            .get_approximate_sizes_cf_opt(&cf1, [PrefixRange(b"a".to_vec())], &memtables_only)
+            .unwrap();
+        assert!(sizes[0] > 0);
+
+        db.flush_cf(&cf1).unwrap();
+        let sizes = db
+            .get_approximate_sizes_cf(
+                &cf1,
+                [
+                    PrefixRange(b"a".to_vec()),
+                    PrefixRange(b"b".to_vec()),
+                    PrefixRange(b"c".to_vec()),
+                ],
+            )
+            .unwrap();
+        assert!(sizes[0] > 0);
+        assert!(sizes[1] > 0);
+        assert_eq!(sizes[2], 0);
+
+        let sizes = db.get_approximate_sizes_cf(&cf1, [..]).unwrap();
+        assert!(sizes[0] > 0);
+
+        // at least one of memtables and files has to be included
+        let neither = SizeApproximationOptions {
+            include_memtables: false,
+            include_files: false,
+            ..SizeApproximationOptions::default()
+        };
+        assert!(db.get_approximate_sizes_opt([..], &neither).is_err());
+    }
+}
+
 #[test]
 fn multi_get() {
     let path = DBPath::new("_rust_rocksdb_multi_get");
@@ -0,0 +1,149 @@
+// Copyright 2023 Tyler Neely
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod util;
+
+use std::fs;
+
+use pretty_assertions::assert_eq;
+
+use rocksdb::{
+    backup::{BackupEngine, BackupEngineOptions, RestoreOptions},
+    checkpoint::Checkpoint,
+    BlockCipher, EncryptionProvider, Env, ErrorKind, Options, DB,
+};
+use util::DBPath;
+
+const SECRET: &[u8] = b"a secret value that must not be stored in plain text";
+
+struct XorCipher(u8);
+
+impl BlockCipher for XorCipher {
+    fn block_size(&self) -> usize {
+        16
+    }
+
+    fn encrypt(&self, block: &mut [u8]) {
+        block.iter_mut().for_each(|b| *b ^= self.0);
+    }
+
+    fn decrypt(&self, block: &mut [u8]) {
+        block.iter_mut().for_each(|b| *b ^= self.0);
+    }
+}
+
+struct SizedCipher(usize);
+
+impl BlockCipher for SizedCipher {
+    fn block_size(&self) -> usize {
+        self.0
+    }
+
+    fn encrypt(&self, _block: &mut [u8]) {}
+
+    fn decrypt(&self, _block: &mut [u8]) {}
+}
+
+fn encrypted_options(env: &Env) -> Options {
+    let mut opts = Options::default();
+    opts.create_if_missing(true);
+    opts.set_env(env);
+    opts
+}
+
+#[test]
+fn files_are_encrypted() {
+    let path = DBPath::new("_rust_rocksdb_encryption_test");
+    let env = Env::encrypted(
+        &Env::new().unwrap(),
+        EncryptionProvider::new_ctr(XorCipher(0x5a)).unwrap(),
+    )
+    .unwrap();
+    let opts = encrypted_options(&env);
+    {
+        let db = DB::open(&opts, &path).unwrap();
+        db.put(b"k1", SECRET).unwrap();
+        db.flush().unwrap();
+        db.put(b"k2", SECRET).unwrap();
+    }
+
+    for entry in fs::read_dir(&path).unwrap() {
+        let entry = entry.unwrap();
+        if entry.file_name().to_string_lossy().starts_with("LOG") {
+            continue;
+        }
+        let content = fs::read(entry.path()).unwrap();
+        assert!(
+            !content.windows(SECRET.len()).any(|w| w == SECRET),
+            "{:?} is not encrypted",
+            entry.file_name()
+        );
+    }
+
+    assert!(DB::open_default(&path).is_err());
+    let db = DB::open(&opts, &path).unwrap();
+    assert_eq!(db.get(b"k1").unwrap(), Some(SECRET.to_vec()));
+    assert_eq!(db.get(b"k2").unwrap(), Some(SECRET.to_vec()));
+}
+
+#[test]
+fn checkpoints_and_backups_of_encrypted_db() {
+    let path = DBPath::new("_rust_rocksdb_encryption_backup_test");
+    let checkpoint_path = DBPath::new("_rust_rocksdb_encryption_checkpoint");
+    let backup_path = DBPath::new("_rust_rocksdb_encryption_backup");
+    let restore_path = DBPath::new("_rust_rocksdb_encryption_restore");
+    let env = Env::encrypted(
+        &Env::new().unwrap(),
+        EncryptionProvider::new_ctr_rot13(32).unwrap(),
+    )
+    .unwrap();
+    let opts = encrypted_options(&env);
+    {
+        let db = DB::open(&opts, &path).unwrap();
+        db.put(b"k1", SECRET).unwrap();
+
+        Checkpoint::new(&db)
+            .unwrap()
+            .create_checkpoint(&checkpoint_path)
+            .unwrap();
+
+        let backup_opts = BackupEngineOptions::new(&backup_path).unwrap();
+        let mut backup_engine = BackupEngine::open(&backup_opts, &env).unwrap();
+        backup_engine.create_new_backup_flush(&db, true).unwrap();
+        backup_engine
+            .restore_from_latest_backup(&restore_path, &restore_path, &RestoreOptions::default())
+            .unwrap();
+    }
+
+    for path in [&checkpoint_path, &restore_path] {
+        let db = DB::open(&opts, path).unwrap();
+        assert_eq!(db.get(b"k1").unwrap(), Some(SECRET.to_vec()));
+    }
+}
+
+#[test]
+fn invalid_block_sizes_are_rejected() {
+    for block_size in [0, 7, 2049] {
+        let err = EncryptionProvider::new_ctr(SizedCipher(block_size))
+            .err()
+            .unwrap();
+        assert_eq!(err.kind(), ErrorKind::InvalidArgument);
+        let err = EncryptionProvider::new_ctr_rot13(block_size).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::InvalidArgument);
+    }
+    for block_size in [8, 2048] {
+        assert!(EncryptionProvider::new_ctr(SizedCipher(block_size)).is_ok());
+        assert!(EncryptionProvider::new_ctr_rot13(block_size).is_ok());
+    }
+}
@@ -0,0 +1,105 @@
+// Copyright 2023 Tyler Neely
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod util;
+
+use std::sync::{Arc, Mutex};
+
+use pretty_assertions::assert_eq;
+
+use rocksdb::{
+    event_listener::{
+        CompactionJobInfo, CompactionReason, FlushJobInfo, FlushReason, TableFileCreationInfo,
+        TableFileCreationReason, TableFileDeletionInfo,
+    },
+    EventListener, Options, DB,
+};
+use util::DBPath;
+
+#[derive(Default)]
+struct Events {
+    flushes: Vec<FlushJobInfo>,
+    compactions: Vec<CompactionJobInfo>,
+    created: Vec<TableFileCreationInfo>,
+    deleted: Vec<TableFileDeletionInfo>,
+}
+
+struct Recorder(Arc<Mutex<Events>>);
+
+impl EventListener for Recorder {
+    fn on_flush_completed(&self, info: FlushJobInfo) {
+        self.0.lock().unwrap().flushes.push(info);
+    }
+
+    fn on_compaction_completed(&self, info: CompactionJobInfo) {
+        self.0.lock().unwrap().compactions.push(info);
+    }
+
+    fn on_table_file_created(&self, info: TableFileCreationInfo) {
+        self.0.lock().unwrap().created.push(info);
+    }
+
+    fn on_table_file_deleted(&self, info: TableFileDeletionInfo) {
+        self.0.lock().unwrap().deleted.push(info);
+    }
+}
+
+#[test]
+fn flush_and_compaction_events() {
+    let path = DBPath::new("_rust_rocksdb_event_listener_test");
+    let events = Arc::new(Mutex::new(Events::default()));
+    {
+        let mut opts = Options::default();
+        opts.create_if_missing(true);
+        opts.add_event_listener(Recorder(events.clone()));
+
+        let db = DB::open(&opts, &path).unwrap();
+        // overlapping files, so the compaction cannot just move them
+        for i in 0..2 {
+            db.put(b"k1", format!("value{i}")).unwrap();
+            db.put(b"k2", format!("value{i}")).unwrap();
+            db.flush().unwrap();
+        }
+        db.compact_range(None::<&[u8]>, None::<&[u8]>);
+    }
+
+    let events = events.lock().unwrap();
+    assert_eq!(events.flushes.len(), 2);
+    assert_eq!(events.flushes[0].cf_name, "default");
+    assert_eq!(events.flushes[0].flush_reason, FlushReason::ManualFlush);
+
+    assert_eq!(events.compactions.len(), 1);
+    let compaction = &events.compactions[0];
+    assert!(compaction.status.is_ok());
+    assert_eq!(
+        compaction.compaction_reason,
+        CompactionReason::ManualCompaction
+    );
+    assert_eq!(compaction.input_files.len(), 2);
+    assert_eq!(compaction.output_files.len(), 1);
+    assert_eq!(compaction.stats.num_input_records, 4);
+    assert_eq!(compaction.stats.num_output_records, 2);
+
+    assert_eq!(events.created.len(), 3);
+    assert!(events
+        .created
+        .iter()
+        .take(2)
+        .all(|f| f.reason == TableFileCreationReason::Flush && f.file_size > 0));
+    assert_eq!(
+        events.created[2].reason,
+        TableFileCreationReason::Compaction
+    );
+    assert_eq!(events.deleted.len(), 2);
+}
@@ -0,0 +1,89 @@
+// Copyright 2023 Tyler Neely
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod util;
+
+use pretty_assertions::assert_eq;
+
+use rocksdb::{
+    DBRecoveryMode, ErrorKind, FaultInjection, FaultInjectionEnv, Options, WriteOptions, DB,
+};
+use util::DBPath;
+
+fn fault_options(fault_env: &FaultInjectionEnv) -> Options {
+    let mut opts = Options::default();
+    opts.create_if_missing(true);
+    opts.set_env(fault_env.env());
+    opts
+}
+
+#[test]
+fn crash_drops_unsynced_writes() {
+    let path = DBPath::new("_rust_rocksdb_fault_injection_crash_test");
+    let fault_env = FaultInjectionEnv::new().unwrap();
+    let mut opts = fault_options(&fault_env);
+    opts.set_wal_recovery_mode(DBRecoveryMode::PointInTime);
+    {
+        let db = DB::open(&opts, &path).unwrap();
+        let mut sync = WriteOptions::default();
+        sync.set_sync(true);
+        db.put_opt(b"k1", b"v1", &sync).unwrap();
+        assert!(fault_env.unsynced_files().is_empty());
+
+        db.put(b"k2", b"v2").unwrap();
+        let unsynced = fault_env.unsynced_files();
+        assert_eq!(unsynced.len(), 1);
+        assert_eq!(unsynced[0].extension().unwrap(), "log");
+        assert!(fault_env.active_files().contains(&unsynced[0]));
+
+        fault_env.set_active(false);
+    }
+    fault_env.drop_unsynced_data().unwrap();
+    fault_env.reset();
+    assert!(fault_env.is_active());
+
+    let db = DB::open(&opts, &path).unwrap();
+    assert_eq!(db.get(b"k1").unwrap(), Some(b"v1".to_vec()));
+    assert_eq!(db.get(b"k2").unwrap(), None);
+}
+
+#[test]
+fn write_faults_fail_writes() {
+    let path = DBPath::new("_rust_rocksdb_fault_injection_write_test");
+    let fault_env = FaultInjectionEnv::new().unwrap();
+    let db = DB::open(&fault_options(&fault_env), &path).unwrap();
+    db.put(b"k1", b"v1").unwrap();
+
+    fault_env.set_write_faults(FaultInjection::AfterOperations(0));
+    let err = db.put(b"k2", b"v2").unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::IOError);
+    assert!(err.as_ref().contains("injected write error"));
+}
+
+#[test]
+fn read_faults_fail_reads() {
+    let path = DBPath::new("_rust_rocksdb_fault_injection_read_test");
+    let fault_env = FaultInjectionEnv::new().unwrap();
+    let db = DB::open(&fault_options(&fault_env), &path).unwrap();
+    db.put(b"k1", b"v1").unwrap();
+    db.flush().unwrap();
+
+    fault_env.set_read_faults(FaultInjection::WithProbability(1.0));
+    let err = db.get(b"k1").unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::IOError);
+    assert!(err.as_ref().contains("injected read error"));
+
+    fault_env.set_read_faults(FaultInjection::Never);
+    assert_eq!(db.get(b"k1").unwrap(), Some(b"v1".to_vec()));
+}
@@ -0,0 +1,210 @@
+// Copyright 2023 Tyler Neely
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod util;
+
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+use pretty_assertions::assert_eq;
+
+use rocksdb::{
+    file_system::{DefaultFileSystem, FileLock, FileSystem, RandomAccessFile, WritableFile},
+    Env, ErrorKind, Options, DB,
+};
+use util::DBPath;
+
+#[derive(Default)]
+struct Counters {
+    bytes_written: AtomicU64,
+    bytes_read: AtomicU64,
+    locks: AtomicUsize,
+    unlocks: AtomicUsize,
+}
+
+struct CountingFileSystem(Arc<Counters>);
+
+struct CountingWritableFile(Box<dyn WritableFile>, Arc<Counters>);
+
+impl WritableFile for CountingWritableFile {
+    fn append(&mut self, data: &[u8]) -> io::Result<()> {
+        self.1
+            .bytes_written
+            .fetch_add(data.len() as u64, Ordering::Relaxed);
+        self.0.append(data)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.0.flush()
+    }
+
+    fn sync(&mut self) -> io::Result<()> {
+        self.0.sync()
+    }
+
+    fn close(&mut self) -> io::Result<()> {
+        self.0.close()
+    }
+
+    fn file_size(&self) -> u64 {
+        self.0.file_size()
+    }
+}
+
+struct CountingRandomAccessFile(Box<dyn RandomAccessFile>, Arc<Counters>);
+
+impl RandomAccessFile for CountingRandomAccessFile {
+    fn read_at(&self, offset: u64, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.0.read_at(offset, buf)?;
+        self.1.bytes_read.fetch_add(n as u64, Ordering::Relaxed);
+        Ok(n)
+    }
+}
+
+struct CountingFileLock(Box<dyn FileLock>, Arc<Counters>);
+
+impl FileLock for CountingFileLock {
+    fn unlock(self: Box<Self>) -> io::Result<()> {
+        self.1.unlocks.fetch_add(1, Ordering::Relaxed);
+        self.0.unlock()
+    }
+}
+
+impl FileSystem for CountingFileSystem {
+    fn new_writable_file(&self, path: &Path) -> io::Result<Box<dyn WritableFile>> {
+        let file = DefaultFileSystem.new_writable_file(path)?;
+        Ok(Box::new(CountingWritableFile(file, self.0.clone())))
+    }
+
+    fn new_random_access_file(&self, path: &Path) -> io::Result<Box<dyn RandomAccessFile>> {
+        let file = DefaultFileSystem.new_random_access_file(path)?;
+        Ok(Box::new(CountingRandomAccessFile(file, self.0.clone())))
+    }
+
+    fn lock_file(&self, path: &Path) -> io::Result<Box<dyn FileLock>> {
+        self.0.locks.fetch_add(1, Ordering::Relaxed);
+        let lock = DefaultFileSystem.lock_file(path)?;
+        Ok(Box::new(CountingFileLock(lock, self.0.clone())))
+    }
+}
+
+#[test]
+fn file_system_sees_database_io() {
+    let path = DBPath::new("_rust_rocksdb_file_system_test");
+    let counters = Arc::new(Counters::default());
+    {
+        let env = Env::from_file_system(CountingFileSystem(counters.clone())).unwrap();
+        let mut opts = Options::default();
+        opts.create_if_missing(true);
+        opts.set_env(&env);
+
+        let db = DB::open(&opts, &path).unwrap();
+        db.put(b"k1", b"v1").unwrap();
+        db.flush().unwrap();
+        assert_eq!(db.get(b"k1").unwrap(), Some(b"v1".to_vec()));
+        assert!(counters.bytes_written.load(Ordering::Relaxed) > 0);
+        assert!(counters.bytes_read.load(Ordering::Relaxed) > 0);
+        assert_eq!(counters.locks.load(Ordering::Relaxed), 1);
+    }
+    assert_eq!(counters.unlocks.load(Ordering::Relaxed), 1);
+
+    // the files were written through to the default file system
+    let db = DB::open_default(&path).unwrap();
+    assert_eq!(db.get(b"k1").unwrap(), Some(b"v1".to_vec()));
+}
+
+struct ReadOnlyFileSystem;
+
+impl FileSystem for ReadOnlyFileSystem {
+    fn new_writable_file(&self, _path: &Path) -> io::Result<Box<dyn WritableFile>> {
+        Err(io::Error::new(io::ErrorKind::Unsupported, "read only"))
+    }
+}
+
+#[test]
+fn file_system_errors_are_reported() {
+    let path = DBPath::new("_rust_rocksdb_file_system_error_test");
+    let env = Env::from_file_system(ReadOnlyFileSystem).unwrap();
+    let mut opts = Options::default();
+    opts.create_if_missing(true);
+    opts.set_env(&env);
+
+    let err = DB::open(&opts, &path).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::NotSupported);
+    assert!(err.as_ref().contains("read only"));
+}
+
+#[derive(Default)]
+struct Recorded {
+    created: Mutex<Vec<PathBuf>>,
+    directory_checks: AtomicUsize,
+}
+
+struct RecordingFileSystem(Arc<Recorded>);
+
+impl FileSystem for RecordingFileSystem {
+    fn new_writable_file(&self, path: &Path) -> io::Result<Box<dyn WritableFile>> {
+        self.0.created.lock().unwrap().push(path.to_owned());
+        DefaultFileSystem.new_writable_file(path)
+    }
+
+    fn is_directory(&self, path: &Path) -> io::Result<bool> {
+        self.0.directory_checks.fetch_add(1, Ordering::Relaxed);
+        DefaultFileSystem.is_directory(path)
+    }
+}
+
+#[test]
+fn file_system_creates_info_log_and_checks_directories() {
+    let path = DBPath::new("_rust_rocksdb_file_system_info_log_test");
+    let recorded = Arc::new(Recorded::default());
+    {
+        let env = Env::from_file_system(RecordingFileSystem(recorded.clone())).unwrap();
+        let mut opts = Options::default();
+        opts.create_if_missing(true);
+        opts.set_env(&env);
+
+        let db = DB::open(&opts, &path).unwrap();
+        db.put(b"k1", b"v1").unwrap();
+        db.flush().unwrap();
+    }
+    let created = recorded.created.lock().unwrap();
+    assert!(created.iter().any(|file| file.ends_with("LOG")));
+    assert!(recorded.directory_checks.load(Ordering::Relaxed) > 0);
+}
+
+#[test]
+fn default_file_system_resolves_paths() {
+    let path = DBPath::new("_rust_rocksdb_file_system_paths_test");
+    let path = &path;
+    drop(DB::open_default(path).unwrap());
+
+    let dir: &Path = path.as_ref();
+    assert_eq!(DefaultFileSystem.get_absolute_path(dir).unwrap(), dir);
+    assert_eq!(
+        DefaultFileSystem
+            .get_absolute_path(Path::new("relative"))
+            .unwrap(),
+        std::env::current_dir().unwrap().join("relative")
+    );
+    assert!(DefaultFileSystem.is_directory(dir).unwrap());
+    assert!(!DefaultFileSystem
+        .is_directory(&dir.join("CURRENT"))
+        .unwrap());
+    assert!(DefaultFileSystem
+        .is_directory(&dir.join("missing"))
+        .is_err());
+}
@@ -0,0 +1,60 @@
+// Copyright 2023 Tyler Neely
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod util;
+
+use std::sync::{Arc, Mutex};
+
+use rocksdb::{LogLevel, Options, DB};
+use util::DBPath;
+
+fn open_with_logger(path: &DBPath, level: LogLevel) -> Vec<(LogLevel, String)> {
+    let messages = Arc::new(Mutex::new(Vec::new()));
+    {
+        let mut opts = Options::default();
+        opts.create_if_missing(true);
+        let sink = messages.clone();
+        opts.set_logger(level, move |level, message| {
+            sink.lock().unwrap().push((level, message.to_owned()));
+        });
+
+        let db = DB::open(&opts, path).unwrap();
+        db.put(b"k1", b"v1").unwrap();
+        db.flush().unwrap();
+    }
+    let messages = messages.lock().unwrap();
+    messages.clone()
+}
+
+#[test]
+fn logger_receives_messages() {
+    let path = DBPath::new("_rust_rocksdb_logger_test");
+    let messages = open_with_logger(&path, LogLevel::Info);
+
+    assert!(messages
+        .iter()
+        .any(|(level, message)| *level == LogLevel::Header && message.contains("RocksDB version")));
+    assert!(messages.iter().any(|(level, _)| *level == LogLevel::Info));
+    assert!(messages.iter().all(|(_, message)| !message.ends_with('\n')));
+}
+
+#[test]
+fn logger_filters_by_level() {
+    let path = DBPath::new("_rust_rocksdb_logger_level_test");
+    let messages = open_with_logger(&path, LogLevel::Warn);
+
+    assert!(messages
+        .iter()
+        .all(|(level, _)| !matches!(level, LogLevel::Debug | LogLevel::Info)));
+}
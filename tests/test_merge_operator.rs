@@ -14,8 +14,19 @@
 
 mod util;
 
+use std::ffi::{CStr, CString};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
 use pretty_assertions::assert_eq;
-use rocksdb::{merge_operator::MergeFn, DBCompactionStyle, MergeOperands, Options, DB};
+use rocksdb::merge_operator::{
+    Int64AddOperator, MaxOperator, MergeFn, MinOperator, SortedSetUnionOperator,
+    StringAppendOperator, UInt64AddOperator,
+};
+use rocksdb::{
+    ColumnFamilyDescriptor, DBCompactionStyle, MergeError, MergeOperands, MergeOperator, Options,
+    DB,
+};
 use serde::{Deserialize, Serialize};
 use util::DBPath;
 
@@ -345,3 +356,175 @@ fn test_merge_state() {
     }
     assert!(DB::destroy(&opts, path).is_ok());
 }
+
+/// Keeps the last two values, joined with `,`. Values must be ASCII.
+struct LastTwo {
+    name: CString,
+    partial_merges: Arc<AtomicUsize>,
+}
+
+fn last_two<'a>(values: impl Iterator<Item = &'a [u8]>) -> Vec<u8> {
+    let values: Vec<&[u8]> = values
+        .flat_map(|value| value.split(|&b| b == b','))
+        .collect();
+    values[values.len().saturating_sub(2)..].join(&b","[..])
+}
+
+impl MergeOperator for LastTwo {
+    fn name(&self) -> &CStr {
+        &self.name
+    }
+
+    fn full_merge(
+        &self,
+        _key: &[u8],
+        existing_value: Option<&[u8]>,
+        operands: &MergeOperands,
+    ) -> Result<Vec<u8>, MergeError> {
+        if let Some(operand) = operands.iter().find(|operand| !operand.is_ascii()) {
+            return Err(MergeError::MustMerge(format!(
+                "operand {:?} is not ASCII",
+                operand
+            )));
+        }
+        Ok(last_two(existing_value.into_iter().chain(operands)))
+    }
+
+    fn partial_merge(&self, _key: &[u8], left: &[u8], right: &[u8]) -> Option<Vec<u8>> {
+        self.partial_merges.fetch_add(1, Ordering::SeqCst);
+        Some(last_two([left, right].iter().copied()))
+    }
+
+    fn should_merge(&self, operands: &MergeOperands) -> bool {
+        operands.len() >= 2
+    }
+}
+
+#[test]
+fn merge_operator_trait_test() {
+    let path = DBPath::new("_rust_rocksdb_merge_operator_trait_test");
+    let partial_merges = Arc::new(AtomicUsize::new(0));
+    let mut opts = Options::default();
+    opts.create_if_missing(true);
+    opts.set_merge_operator_trait(LastTwo {
+        name: CString::new("last_two").unwrap(),
+        partial_merges: partial_merges.clone(),
+    });
+    let db = DB::open(&opts, &path).unwrap();
+
+    db.put(b"k1", b"base").unwrap();
+    db.merge(b"k1", b"a").unwrap();
+    assert_eq!(db.get(b"k1").unwrap().unwrap(), b"base,a");
+    // `should_merge` stops reading before the base value
+    db.merge(b"k1", b"b").unwrap();
+    db.merge(b"k1", b"c").unwrap();
+    assert_eq!(db.get(b"k1").unwrap().unwrap(), b"b,c");
+
+    db.merge(b"k2", b"x").unwrap();
+    db.merge(b"k2", b"y").unwrap();
+    db.merge(b"k2", b"z").unwrap();
+    db.flush().unwrap();
+    db.compact_range(None::<&[u8]>, None::<&[u8]>);
+    assert_eq!(db.get(b"k2").unwrap().unwrap(), b"y,z");
+    assert!(partial_merges.load(Ordering::SeqCst) > 0);
+}
+
+#[test]
+fn merge_operator_must_merge_error_test() {
+    let path = DBPath::new("_rust_rocksdb_merge_operator_error_test");
+    let mut opts = Options::default();
+    opts.create_if_missing(true);
+    opts.set_merge_operator_trait(LastTwo {
+        name: CString::new("last_two").unwrap(),
+        partial_merges: Arc::default(),
+    });
+    let db = DB::open(&opts, &path).unwrap();
+
+    db.merge(b"k1", b"a").unwrap();
+    db.merge(b"k1", [0xff]).unwrap();
+    let error = db.get(b"k1").unwrap_err().into_string();
+    assert!(error.contains("Merge operator failed"), "{}", error);
+
+    // flushes and compactions keep the operands, the database stays writable
+    db.flush().unwrap();
+    db.compact_range(None::<&[u8]>, None::<&[u8]>);
+    db.put(b"k1", b"fixed").unwrap();
+    assert_eq!(db.get(b"k1").unwrap().unwrap(), b"fixed");
+}
+
+#[test]
+fn builtin_merge_operators_test() {
+    let path = DBPath::new("_rust_rocksdb_builtin_merge_operators_test");
+    let mut db_opts = Options::default();
+    db_opts.create_if_missing(true);
+    db_opts.create_missing_column_families(true);
+    let cf_with = |name: &str, merge_operator: &dyn Fn(&mut Options)| {
+        let mut opts = Options::default();
+        merge_operator(&mut opts);
+        ColumnFamilyDescriptor::new(name, opts)
+    };
+    let cfs = vec![
+        cf_with("u64", &|opts| {
+            opts.set_merge_operator_trait(UInt64AddOperator)
+        }),
+        cf_with("i64", &|opts| {
+            opts.set_merge_operator_trait(Int64AddOperator)
+        }),
+        cf_with("append", &|opts| {
+            opts.set_merge_operator_trait(StringAppendOperator::new("|"))
+        }),
+        cf_with("max", &|opts| opts.set_merge_operator_trait(MaxOperator)),
+        cf_with("min", &|opts| opts.set_merge_operator_trait(MinOperator)),
+        cf_with("set", &|opts| {
+            opts.set_merge_operator_trait(SortedSetUnionOperator)
+        }),
+    ];
+    let db = DB::open_cf_descriptors(&db_opts, &path, cfs).unwrap();
+    let cf = |name| db.cf_handle(name).unwrap();
+
+    db.increment_cf(cf("u64"), b"k", 5).unwrap();
+    db.increment_cf(cf("u64"), b"k", u64::MAX).unwrap();
+    db.increment_i64_cf(cf("i64"), b"k", 5).unwrap();
+    db.increment_i64_cf(cf("i64"), b"k", -7).unwrap();
+    db.put_cf(cf("append"), b"k", b"a").unwrap();
+    db.merge_cf(cf("append"), b"k", b"b").unwrap();
+    db.merge_cf(cf("append"), b"k", b"").unwrap();
+    for value in [&b"b"[..], b"abc", b"ab"] {
+        db.merge_cf(cf("max"), b"k", value).unwrap();
+        db.merge_cf(cf("min"), b"k", value).unwrap();
+    }
+    db.merge_cf(cf("set"), b"k", SortedSetUnionOperator::encode(["y", "x"]))
+        .unwrap();
+    db.merge_cf(
+        cf("set"),
+        b"k",
+        SortedSetUnionOperator::encode(["z", "x", ""]),
+    )
+    .unwrap();
+
+    // the same values before and after merging the operands in compactions
+    for compact in [false, true] {
+        if compact {
+            for name in ["u64", "i64", "append", "max", "min", "set"] {
+                db.flush_cf(cf(name)).unwrap();
+                db.compact_range_cf(cf(name), None::<&[u8]>, None::<&[u8]>);
+            }
+        }
+        let get = |name| db.get_cf(cf(name), b"k").unwrap().unwrap();
+        assert_eq!(get("u64"), 4u64.to_le_bytes());
+        assert_eq!(get("i64"), (-2i64).to_le_bytes());
+        assert_eq!(get("append"), b"a|b|");
+        assert_eq!(get("max"), b"b");
+        assert_eq!(get("min"), b"ab");
+        let set = get("set");
+        assert_eq!(
+            SortedSetUnionOperator::decode(&set).unwrap(),
+            [&b""[..], b"x", b"y", b"z"]
+        );
+    }
+
+    db.merge_cf(cf("u64"), b"bad", b"not 8 bytes").unwrap();
+    let error = db.get_cf(cf("u64"), b"bad").unwrap_err().into_string();
+    assert!(error.contains("Merge operator failed"), "{}", error);
+    assert_eq!(SortedSetUnionOperator::decode(&[1, 0, 0, 0]), None);
+}
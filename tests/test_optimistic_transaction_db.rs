@@ -39,6 +39,25 @@ fn open_default() {
     }
 }
 
+#[test]
+fn close() {
+    let path = DBPath::new("_rust_rocksdb_optimistic_transaction_db_close");
+    {
+        let db: OptimisticTransactionDB<SingleThreaded> =
+            OptimisticTransactionDB::open_default(&path).unwrap();
+        db.put(b"k1", b"v1").unwrap();
+        let txn = db.transaction();
+        txn.put(b"k2", b"v2").unwrap();
+        txn.commit().unwrap();
+        db.close().unwrap();
+
+        let db: OptimisticTransactionDB<SingleThreaded> =
+            OptimisticTransactionDB::open_default(&path).unwrap();
+        assert_eq!(db.get(b"k1").unwrap().unwrap(), b"v1");
+        assert_eq!(db.get(b"k2").unwrap().unwrap(), b"v2");
+    }
+}
+
 #[test]
 fn open_cf() {
     let path = DBPath::new("_rust_rocksdb_optimistic_transaction_db_open_cf");
@@ -408,6 +427,8 @@ fn transaction() {
             // txn1 should fail with ErrorKind::Busy
             let err = txn1.commit().unwrap_err();
             assert_eq!(err.kind(), ErrorKind::Busy);
+            assert!(err.is_retryable());
+            assert!(!err.is_lock_timeout());
         }
 
         {
@@ -0,0 +1,60 @@
+// Copyright 2023 Tyler Neely
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod util;
+
+use pretty_assertions::assert_eq;
+
+use rocksdb::{Options, RateLimiter, DB};
+use util::DBPath;
+
+#[test]
+fn rate_limiter_shared_across_dbs() {
+    let path1 = DBPath::new("_rust_rocksdb_rate_limiter_test_1");
+    let path2 = DBPath::new("_rust_rocksdb_rate_limiter_test_2");
+    let limiter = RateLimiter::new(64 << 20, 100 * 1000, 10);
+    assert_eq!(limiter.bytes_per_second(), 64 << 20);
+    assert_eq!(limiter.total_requests(), 0);
+
+    let mut opts = Options::default();
+    opts.create_if_missing(true);
+    opts.set_rate_limiter(&limiter);
+    let db1 = DB::open(&opts, &path1).unwrap();
+    let db2 = DB::open(&opts, &path2).unwrap();
+    drop(opts);
+
+    db1.put(b"k1", vec![1; 4096]).unwrap();
+    db1.flush().unwrap();
+    let requests = limiter.total_requests();
+    let bytes = limiter.total_bytes_through();
+    assert!(requests > 0);
+    assert!(bytes >= 4096);
+
+    db2.put(b"k1", vec![2; 4096]).unwrap();
+    db2.flush().unwrap();
+    assert!(limiter.total_requests() > requests);
+    assert!(limiter.total_bytes_through() > bytes);
+
+    limiter.set_bytes_per_second(16 << 20);
+    assert_eq!(limiter.bytes_per_second(), 16 << 20);
+    let clone = limiter.clone();
+    assert_eq!(clone.bytes_per_second(), 16 << 20);
+}
+
+#[test]
+fn rate_limiter_auto_tuned() {
+    let limiter = RateLimiter::new_auto_tuned(100 << 20, 100 * 1000, 10);
+    assert_eq!(limiter.bytes_per_second(), 50 << 20);
+    assert!(limiter.single_burst_bytes() > 0);
+}
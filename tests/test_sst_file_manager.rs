@@ -0,0 +1,62 @@
+// Copyright 2023 Tyler Neely
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod util;
+
+use pretty_assertions::assert_eq;
+
+use rocksdb::{Env, Options, SstFileManager, DB};
+use util::DBPath;
+
+#[test]
+fn sst_file_manager_shared_across_dbs() {
+    let path1 = DBPath::new("_rust_rocksdb_sst_file_manager_test_1");
+    let path2 = DBPath::new("_rust_rocksdb_sst_file_manager_test_2");
+    let manager = SstFileManager::new(&Env::new().unwrap());
+    assert_eq!(manager.total_size(), 0);
+    assert!(!manager.is_max_allowed_space_reached());
+
+    let mut opts = Options::default();
+    opts.create_if_missing(true);
+    opts.set_sst_file_manager(&manager);
+    let db1 = DB::open(&opts, &path1).unwrap();
+    let db2 = DB::open(&opts, &path2).unwrap();
+
+    db1.put(b"k1", b"v1").unwrap();
+    db1.flush().unwrap();
+    let size = manager.total_size();
+    assert!(size > 0);
+
+    db2.put(b"k1", b"v1").unwrap();
+    db2.flush().unwrap();
+    assert!(manager.total_size() > size);
+
+    manager.set_max_allowed_space_usage(size);
+    assert!(manager.is_max_allowed_space_reached());
+    assert!(manager.is_max_allowed_space_reached_including_compactions());
+    manager.set_max_allowed_space_usage(0);
+    assert!(!manager.is_max_allowed_space_reached());
+}
+
+#[test]
+fn sst_file_manager_delete_rate() {
+    let manager = SstFileManager::new(&Env::new().unwrap());
+    assert_eq!(manager.delete_rate_bytes_per_second(), 0);
+    manager.set_delete_rate_bytes_per_second(1 << 20);
+    assert_eq!(manager.delete_rate_bytes_per_second(), 1 << 20);
+
+    manager.set_max_trash_db_ratio(0.5);
+    assert_eq!(manager.max_trash_db_ratio(), 0.5);
+    assert_eq!(manager.total_trash_size(), 0);
+}
@@ -0,0 +1,58 @@
+// Copyright 2023 Tyler Neely
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod util;
+
+use pretty_assertions::assert_eq;
+
+use rocksdb::{Histogram, Options, Statistics, StatsLevel, Ticker, DB};
+use util::DBPath;
+
+#[test]
+fn shared_statistics() {
+    let path1 = DBPath::new("_rust_rocksdb_shared_statistics_1");
+    let path2 = DBPath::new("_rust_rocksdb_shared_statistics_2");
+    let stats = Statistics::new();
+    {
+        let mut opts = Options::default();
+        opts.create_if_missing(true);
+        opts.set_statistics(&stats);
+
+        let db1 = DB::open(&opts, &path1).unwrap();
+        let db2 = DB::open(&opts, &path2).unwrap();
+        db1.put(b"k1", b"v1").unwrap();
+        db2.put(b"k2", b"v2").unwrap();
+        assert!(db1.get(b"k1").unwrap().is_some());
+        assert!(db2.get(b"k1").unwrap().is_none());
+
+        assert_eq!(stats.ticker(Ticker::NumberKeysWritten), 2);
+        assert_eq!(stats.ticker(Ticker::NumberKeysRead), 2);
+        assert_eq!(stats.ticker(Ticker::MemtableHit), 1);
+        assert_eq!(stats.ticker(Ticker::MemtableMiss), 1);
+
+        let get = stats.histogram(Histogram::DbGet);
+        assert_eq!(get.count, 2);
+        assert!(get.max >= get.median);
+        assert!(stats.to_string().contains(Ticker::NumberKeysWritten.name()));
+
+        stats.reset().unwrap();
+        assert_eq!(stats.ticker(Ticker::NumberKeysWritten), 0);
+        assert_eq!(stats.histogram(Histogram::DbGet).count, 0);
+
+        stats.set_stats_level(StatsLevel::DisableAll);
+        assert_eq!(stats.stats_level(), StatsLevel::DisableAll);
+        db1.put(b"k3", b"v3").unwrap();
+        assert_eq!(stats.ticker(Ticker::NumberKeysWritten), 0);
+    }
+}
@@ -0,0 +1,162 @@
+// Copyright 2023 Tyler Neely
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod util;
+
+use std::collections::BTreeMap;
+use std::convert::TryInto;
+use std::ffi::CStr;
+
+use pretty_assertions::assert_eq;
+
+use rocksdb::{
+    table_properties::{
+        EntryType, TablePropertiesCollector, TablePropertiesCollectorContext,
+        TablePropertiesCollectorFactory,
+    },
+    Options, DB,
+};
+use util::DBPath;
+
+// Records the smallest and largest timestamp, stored in the first 8 bytes of
+// each value.
+#[derive(Default)]
+struct TimestampCollector {
+    min: Option<u64>,
+    max: Option<u64>,
+}
+
+impl TablePropertiesCollector for TimestampCollector {
+    fn add_user_key(&mut self, _: &[u8], value: &[u8], entry_type: EntryType, _: u64, _: u64) {
+        if entry_type != EntryType::Put {
+            return;
+        }
+        let ts = u64::from_be_bytes(value[..8].try_into().unwrap());
+        self.min = Some(self.min.map_or(ts, |min| min.min(ts)));
+        self.max = Some(self.max.map_or(ts, |max| max.max(ts)));
+    }
+
+    fn finish(&mut self) -> BTreeMap<String, Vec<u8>> {
+        let mut props = BTreeMap::new();
+        if let (Some(min), Some(max)) = (self.min, self.max) {
+            props.insert("ts.min".to_owned(), min.to_be_bytes().to_vec());
+            props.insert("ts.max".to_owned(), max.to_be_bytes().to_vec());
+        }
+        props
+    }
+
+    fn name(&self) -> &CStr {
+        CStr::from_bytes_with_nul(b"TimestampCollector\0").unwrap()
+    }
+}
+
+struct TimestampCollectorFactory;
+
+impl TablePropertiesCollectorFactory for TimestampCollectorFactory {
+    type Collector = TimestampCollector;
+
+    fn create(&self, _context: TablePropertiesCollectorContext) -> TimestampCollector {
+        TimestampCollector::default()
+    }
+
+    fn name(&self) -> &CStr {
+        CStr::from_bytes_with_nul(b"TimestampCollectorFactory\0").unwrap()
+    }
+}
+
+fn value(ts: u64) -> Vec<u8> {
+    let mut value = ts.to_be_bytes().to_vec();
+    value.extend_from_slice(b"payload");
+    value
+}
+
+fn max_timestamp(props: &BTreeMap<String, Vec<u8>>) -> u64 {
+    u64::from_be_bytes(props["ts.max"][..].try_into().unwrap())
+}
+
+#[test]
+fn properties_of_all_tables() {
+    let path = DBPath::new("_rust_rocksdb_table_properties_test");
+    let mut opts = Options::default();
+    opts.create_if_missing(true);
+    opts.add_table_properties_collector_factory(TimestampCollectorFactory);
+    let db = DB::open(&opts, &path).unwrap();
+
+    db.put(b"a", value(10)).unwrap();
+    db.put(b"b", value(20)).unwrap();
+    db.delete(b"c").unwrap();
+    db.flush().unwrap();
+    db.put(b"d", value(30)).unwrap();
+    db.flush().unwrap();
+
+    let props = db.get_properties_of_all_tables().unwrap();
+    assert_eq!(props.len(), 2);
+    let mut files: Vec<_> = props.values().collect();
+    files.sort_by_key(|p| p.orig_file_number);
+
+    let first = files[0];
+    assert_eq!(first.num_entries, 3);
+    assert_eq!(first.num_deletions, 1);
+    assert_eq!(first.column_family_name, "default");
+    assert!(first.raw_key_size > 0 && first.raw_value_size > 0);
+    assert!(first
+        .property_collectors_names
+        .contains("TimestampCollectorFactory"));
+    assert_eq!(max_timestamp(&first.user_collected_properties), 20);
+    assert_eq!(
+        first.user_collected_properties["ts.min"],
+        10u64.to_be_bytes().to_vec()
+    );
+
+    // the files whose newest entry is older than the cutoff have expired
+    let expired: Vec<_> = props
+        .iter()
+        .filter(|(_, p)| max_timestamp(&p.user_collected_properties) < 25)
+        .map(|(path, _)| path)
+        .collect();
+    assert_eq!(expired.len(), 1);
+}
+
+#[test]
+fn properties_of_tables_in_range() {
+    let path = DBPath::new("_rust_rocksdb_table_properties_range_test");
+    let mut opts = Options::default();
+    opts.create_if_missing(true);
+    opts.create_missing_column_families(true);
+    let db = DB::open_cf(&opts, &path, ["cf"]).unwrap();
+    let cf = db.cf_handle("cf").unwrap();
+
+    for key in [b"a", b"m", b"z"] {
+        db.put_cf(&cf, key, b"value").unwrap();
+        db.flush_cf(&cf).unwrap();
+    }
+
+    assert_eq!(db.get_properties_of_all_tables_cf(&cf).unwrap().len(), 3);
+    assert!(db.get_properties_of_all_tables().unwrap().is_empty());
+
+    let props = db
+        .get_properties_of_tables_in_range_cf(&cf, [&b"b"[..]..&b"n"[..]])
+        .unwrap();
+    assert_eq!(props.len(), 1);
+    assert_eq!(props.values().next().unwrap().column_family_name, "cf");
+
+    let props = db
+        .get_properties_of_tables_in_range_cf(&cf, [..&b"b"[..]])
+        .unwrap();
+    assert_eq!(props.len(), 1);
+    let props = db
+        .get_properties_of_tables_in_range_cf(&cf, [&b"b"[..]..])
+        .unwrap();
+    assert_eq!(props.len(), 2);
+}
@@ -0,0 +1,93 @@
+// Copyright 2023 Tyler Neely
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod util;
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use pretty_assertions::assert_eq;
+
+use rocksdb::thread_status::{OperationStage, OperationType, ThreadStatus};
+use rocksdb::{CompactionDecision, Env, Options, Priority, DB};
+use util::DBPath;
+
+#[test]
+fn thread_pool_introspection() {
+    let path = DBPath::new("_rust_rocksdb_thread_pool_test");
+    let env = Env::new().unwrap();
+    let mut opts = Options::default();
+    opts.create_if_missing(true);
+    opts.set_env(&env);
+    let db = DB::open(&opts, &path).unwrap();
+    db.put(b"k1", b"v1").unwrap();
+    db.flush().unwrap();
+
+    assert!(env.background_threads(Priority::Low) >= 1);
+    assert!(env.background_threads(Priority::High) >= 1);
+    assert_eq!(env.thread_pool_queue_len(Priority::User), 0);
+    assert!(!env.get_thread_list().unwrap().is_empty());
+}
+
+#[test]
+fn thread_list_reports_running_compaction() {
+    let path = DBPath::new("_rust_rocksdb_thread_status_test");
+    let release = Arc::new(AtomicBool::new(false));
+    let env = Env::new().unwrap();
+    let mut opts = Options::default();
+    opts.create_if_missing(true);
+    opts.set_env(&env);
+    opts.set_enable_thread_tracking(true);
+    let filter_release = release.clone();
+    opts.set_compaction_filter(
+        "blocking",
+        move |_level: u32, _key: &[u8], _value: &[u8]| {
+            while !filter_release.load(Ordering::SeqCst) {
+                thread::sleep(Duration::from_millis(10));
+            }
+            CompactionDecision::Keep
+        },
+    );
+    let db = Arc::new(DB::open(&opts, &path).unwrap());
+    for i in 0..2 {
+        db.put(format!("k{i}"), b"v").unwrap();
+        db.flush().unwrap();
+    }
+
+    let compacting_db = db.clone();
+    let compaction = thread::spawn(move || {
+        compacting_db.compact_range(None::<&[u8]>, None::<&[u8]>);
+    });
+
+    let deadline = Instant::now() + Duration::from_secs(10);
+    let mut compaction_status: Option<ThreadStatus> = None;
+    while compaction_status.is_none() && Instant::now() < deadline {
+        compaction_status = env
+            .get_thread_list()
+            .unwrap()
+            .into_iter()
+            .find(|status| status.operation_type == OperationType::Compaction);
+        thread::sleep(Duration::from_millis(10));
+    }
+    release.store(true, Ordering::SeqCst);
+    compaction.join().unwrap();
+
+    let status = compaction_status.expect("no thread reported the compaction");
+    assert!(status.db_name.ends_with("_rust_rocksdb_thread_status_test"));
+    assert_eq!(status.cf_name, "default");
+    assert_eq!(status.operation_stage, OperationStage::CompactionProcessKV);
+    assert!(status.operation_properties.contains_key("JobID"));
+}
@@ -18,8 +18,8 @@ mod util;
 use pretty_assertions::assert_eq;
 
 use rocksdb::{
-    CuckooTableOptions, DBAccess, Direction, Error, ErrorKind, IteratorMode, Options, ReadOptions,
-    SliceTransform, TransactionDB, TransactionDBOptions, TransactionOptions,
+    CuckooTableOptions, DBAccess, Direction, Error, ErrorKind, ErrorSubCode, IteratorMode, Options,
+    ReadOptions, SliceTransform, TransactionDB, TransactionDBOptions, TransactionOptions,
     WriteBatchWithTransaction, WriteOptions, DB,
 };
 use util::DBPath;
@@ -41,6 +41,23 @@ fn open_default() {
     }
 }
 
+#[test]
+fn close() {
+    let path = DBPath::new("_rust_rocksdb_transaction_db_close");
+    {
+        let db: TransactionDB = TransactionDB::open_default(&path).unwrap();
+        db.put(b"k1", b"v1").unwrap();
+        let txn = db.transaction();
+        txn.put(b"k2", b"v2").unwrap();
+        txn.commit().unwrap();
+        db.close().unwrap();
+
+        let db: TransactionDB = TransactionDB::open_default(&path).unwrap();
+        assert_eq!(db.get(b"k1").unwrap().unwrap(), b"v1");
+        assert_eq!(db.get(b"k2").unwrap().unwrap(), b"v2");
+    }
+}
+
 #[test]
 fn open_cf() {
     let path = DBPath::new("_rust_rocksdb_transaction_db_open_cf");
@@ -435,6 +452,10 @@ fn transaction() {
         let txn2 = db.transaction();
         let err = txn2.put(b"k1", b"v3").unwrap_err();
         assert_eq!(err.kind(), ErrorKind::TimedOut);
+        assert_eq!(err.subcode(), ErrorSubCode::LockTimeout);
+        assert!(err.is_lock_timeout());
+        assert!(err.is_retryable());
+        assert!(!err.is_no_space());
 
         // modify same key directly, should also get TimedOut
         let err = db.put(b"k1", b"v4").unwrap_err();
@@ -445,6 +466,23 @@ fn transaction() {
     }
 }
 
+#[test]
+fn transaction_single_delete() {
+    let path = DBPath::new("_rust_rocksdb_transaction_db_transaction_single_delete");
+    {
+        let db: TransactionDB = TransactionDB::open_default(&path).unwrap();
+        db.put(b"k1", b"v1").unwrap();
+
+        let txn = db.transaction();
+        txn.single_delete(b"k1").unwrap();
+        assert!(txn.get(b"k1").unwrap().is_none());
+        assert_eq!(db.get(b"k1").unwrap().unwrap(), b"v1");
+
+        txn.commit().unwrap();
+        assert!(db.get(b"k1").unwrap().is_none());
+    }
+}
+
 #[test]
 fn transaction_iterator() {
     let path = DBPath::new("_rust_rocksdb_transaction_db_transaction_iterator");
@@ -66,3 +66,36 @@ fn test_write_batch_with_serialized_data() {
     let mut it = Iterator { data: kvs };
     b2.iterate(&mut it);
 }
+
+#[test]
+fn test_write_batch_iterate_single_delete() {
+    #[derive(Default)]
+    struct Iterator {
+        deleted: Vec<Box<[u8]>>,
+        single_deleted: Vec<Box<[u8]>>,
+    }
+
+    impl WriteBatchIterator for Iterator {
+        fn put(&mut self, _: Box<[u8]>, _: Box<[u8]>) {
+            panic!("invalid put operation");
+        }
+
+        fn delete(&mut self, key: Box<[u8]>) {
+            self.deleted.push(key);
+        }
+
+        fn single_delete(&mut self, key: Box<[u8]>) {
+            self.single_deleted.push(key);
+        }
+    }
+
+    let mut batch = WriteBatch::default();
+    batch.delete(b"k1");
+    batch.single_delete(b"k2");
+    assert_eq!(batch.len(), 2);
+
+    let mut it = Iterator::default();
+    batch.iterate(&mut it);
+    assert_eq!(it.deleted, vec![Box::from(&b"k1"[..])]);
+    assert_eq!(it.single_deleted, vec![Box::from(&b"k2"[..])]);
+}